@@ -0,0 +1,37 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API definition for the affiliates pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use parity_scale_codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait AffiliatesApi<AccountId>
+	where
+		AccountId: Codec,
+	{
+		/// Returns at most `limit` accounts directly affiliated to `account`, starting after
+		/// `start_after` if given. Used to paginate through all affiliatees of an account.
+		fn affiliatees_of(
+			account: AccountId,
+			start_after: Option<AccountId>,
+			limit: u32,
+		) -> Vec<AccountId>;
+	}
+}
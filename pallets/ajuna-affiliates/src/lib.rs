@@ -24,6 +24,7 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+pub mod migration;
 pub mod traits;
 
 use frame_support::pallet_prelude::*;
@@ -33,7 +34,8 @@ use traits::*;
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	use sp_runtime::ArithmeticError;
+	use frame_system::pallet_prelude::BlockNumberFor;
+	use sp_runtime::{ArithmeticError, Saturating};
 	use sp_std::vec::Vec;
 
 	pub type AffiliatedAccountsOf<T, I> =
@@ -42,7 +44,7 @@ pub mod pallet {
 	pub type AccountIdFor<T> = <T as frame_system::Config>::AccountId;
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -63,6 +65,11 @@ pub mod pallet {
 		/// The maximum depth of the affiliate relation chain,
 		#[pallet::constant]
 		type AffiliateMaxLevel: Get<u32>;
+
+		/// The maximum number of affiliatees detached right away when an account gets blocked,
+		/// the remaining ones are detached on idle blocks.
+		#[pallet::constant]
+		type MaxDetachmentsPerCall: Get<u32>;
 	}
 
 	/// Stores the affiliated accounts from the perspectives of the affiliatee
@@ -78,6 +85,26 @@ pub mod pallet {
 	pub type Affiliators<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Identity, T::AccountId, AffiliatorState, ValueQuery>;
 
+	/// Stores the accounts directly affiliated to each affiliator, effectively being the reverse
+	/// mapping of the first account of each chain in [`Affiliatees`].
+	#[pallet::storage]
+	pub type AffiliatorAffiliatees<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Identity, T::AccountId, Identity, T::AccountId, (), OptionQuery>;
+
+	/// Stores the affiliators whose affiliatees are still to be detached from a blocked account,
+	/// keyed by the blocked account and the affiliator. Each entry holds the level of the blocked
+	/// account in the chains of those affiliatees and the last affiliatee visited.
+	#[pallet::storage]
+	pub type PendingDetachments<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Identity,
+		T::AccountId,
+		Identity,
+		T::AccountId,
+		(u32, Option<T::AccountId>),
+		OptionQuery,
+	>;
+
 	/// Stores the affiliate logic rules
 	#[pallet::storage]
 	pub type AffiliateRules<T: Config<I>, I: 'static = ()> =
@@ -100,6 +127,18 @@ pub mod pallet {
 		RuleCleared { rule_id: T::RuleIdentifier },
 	}
 
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		fn on_idle(_: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let step_weight = Self::detach_step_weight();
+			let max_steps =
+				remaining_weight.checked_div_per_component(&step_weight).unwrap_or_default();
+			let steps = Self::detach_pending_affiliatees(max_steps);
+
+			step_weight.saturating_mul(steps)
+		}
+	}
+
 	#[pallet::error]
 	pub enum Error<T, I = ()> {
 		/// An account cannot affiliate itself
@@ -129,8 +168,16 @@ pub mod pallet {
 
 			Self::try_add_account_to(&mut accounts, affiliator.clone())?;
 
-			Affiliatees::<T, I>::insert(affiliatee, accounts);
-			Affiliators::<T, I>::try_mutate(&affiliator, |state| {
+			Affiliatees::<T, I>::insert(&affiliatee, accounts);
+			Self::try_link_affiliatee_to(&affiliator, &affiliatee)
+		}
+
+		fn try_link_affiliatee_to(
+			affiliator: &T::AccountId,
+			affiliatee: &T::AccountId,
+		) -> DispatchResult {
+			AffiliatorAffiliatees::<T, I>::insert(affiliator, affiliatee, ());
+			Affiliators::<T, I>::try_mutate(affiliator, |state| {
 				state.affiliates = state
 					.affiliates
 					.checked_add(1)
@@ -140,6 +187,88 @@ pub mod pallet {
 			})
 		}
 
+		fn try_unlink_affiliatee_from(
+			affiliator: &T::AccountId,
+			affiliatee: &T::AccountId,
+		) -> DispatchResult {
+			AffiliatorAffiliatees::<T, I>::remove(affiliator, affiliatee);
+			Affiliators::<T, I>::try_mutate(affiliator, |state| {
+				state.affiliates = state
+					.affiliates
+					.checked_sub(1)
+					.ok_or(DispatchError::Arithmetic(ArithmeticError::Underflow))?;
+
+				Ok(())
+			})
+		}
+
+		/// Cuts the affiliate chains going through blocked accounts right before them, so that
+		/// their direct affiliatees become unaffiliated and downstream accounts stop at the level
+		/// above them. Visits at most `max_steps` affiliatees, checking for pending detachments
+		/// included, and returns the number of steps taken.
+		fn detach_pending_affiliatees(max_steps: u64) -> u64 {
+			let mut steps = 0_u64;
+			while steps < max_steps {
+				steps.saturating_inc();
+				let Some((blocked, affiliator, (level, last))) =
+					PendingDetachments::<T, I>::iter().next()
+				else {
+					break
+				};
+
+				// Direct affiliatees are unlinked as they are visited, so the first one left is
+				// always the next one.
+				let next = match last {
+					Some(last) if level > 0 => AffiliatorAffiliatees::<T, I>::iter_key_prefix_from(
+						&affiliator,
+						AffiliatorAffiliatees::<T, I>::hashed_key_for(&affiliator, last),
+					)
+					.next(),
+					_ => AffiliatorAffiliatees::<T, I>::iter_key_prefix(&affiliator).next(),
+				};
+				let Some(affiliatee) = next else {
+					PendingDetachments::<T, I>::remove(&blocked, &affiliator);
+					continue
+				};
+
+				if level == 0 {
+					AffiliatorAffiliatees::<T, I>::remove(&blocked, &affiliatee);
+					Affiliators::<T, I>::mutate(&blocked, |state| {
+						state.affiliates.saturating_dec();
+					});
+				} else {
+					PendingDetachments::<T, I>::insert(
+						&blocked,
+						&affiliator,
+						(level, Some(affiliatee.clone())),
+					);
+				}
+				Affiliatees::<T, I>::mutate_exists(&affiliatee, |maybe_chain| {
+					if let Some(chain) = maybe_chain {
+						if chain.get(level as usize) == Some(&blocked) {
+							chain.truncate(level as usize);
+						}
+						if chain.is_empty() {
+							*maybe_chain = None;
+						}
+					}
+				});
+				if level.saturating_add(1) < T::AffiliateMaxLevel::get() {
+					PendingDetachments::<T, I>::insert(
+						&blocked,
+						&affiliatee,
+						(level.saturating_add(1), None::<T::AccountId>),
+					);
+				}
+			}
+			steps
+		}
+
+		/// The weight of visiting a single affiliatee in `detach_pending_affiliatees`.
+		fn detach_step_weight() -> Weight {
+			T::DbWeight::get().reads_writes(4, 4)
+		}
+
 		fn try_add_account_to(
 			accounts: &mut AffiliatedAccountsOf<T, I>,
 			account: T::AccountId,
@@ -153,6 +282,28 @@ pub mod pallet {
 		}
 	}
 
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Returns at most `limit` accounts directly affiliated to `account`.
+		///
+		/// Results can be paginated by passing the last account of the previous page
+		/// as `start_after`, the iteration order is stable but unspecified.
+		pub fn affiliatees_of(
+			account: &AccountIdFor<T>,
+			start_after: Option<AccountIdFor<T>>,
+			limit: u32,
+		) -> Vec<AccountIdFor<T>> {
+			let affiliatees = match start_after {
+				Some(last) => AffiliatorAffiliatees::<T, I>::iter_key_prefix_from(
+					account,
+					AffiliatorAffiliatees::<T, I>::hashed_key_for(account, last),
+				),
+				None => AffiliatorAffiliatees::<T, I>::iter_key_prefix(account),
+			};
+
+			affiliatees.take(limit as usize).collect()
+		}
+	}
+
 	impl<T: Config<I>, I: 'static> AffiliateInspector<AccountIdFor<T>> for Pallet<T, I> {
 		fn get_affiliator_chain_for(account: &AccountIdFor<T>) -> Option<Vec<AccountIdFor<T>>> {
 			Affiliatees::<T, I>::get(account).map(|accounts| accounts.into_inner())
@@ -202,6 +353,8 @@ pub mod pallet {
 			Affiliators::<T, I>::mutate(account, |state| {
 				state.status = AffiliatableStatus::Blocked;
 			});
+			PendingDetachments::<T, I>::insert(account, account, (0, None::<AccountIdFor<T>>));
+			Self::detach_pending_affiliatees(T::MaxDetachmentsPerCall::get().into());
 		}
 
 		fn try_add_affiliate_to(
@@ -248,16 +401,7 @@ pub mod pallet {
 				})
 				.map_or_else(
 					|| Ok(()),
-					|affiliator| {
-						Affiliators::<T, I>::try_mutate(&affiliator, |state| {
-							state.affiliates = state
-								.affiliates
-								.checked_sub(1)
-								.ok_or(DispatchError::Arithmetic(ArithmeticError::Underflow))?;
-
-							Ok(())
-						})
-					},
+					|affiliator| Self::try_unlink_affiliatee_from(&affiliator, account),
 				)
 		}
 
@@ -267,6 +411,11 @@ pub mod pallet {
 		) -> DispatchResult {
 			let chain = AffiliatedAccountsOf::<T, I>::try_from(chain)
 				.map_err(|_| Error::<T, I>::CannotAffiliateMoreAccounts)?;
+
+			Self::try_clear_affiliation_for(account)?;
+			if let Some(affiliator) = chain.first() {
+				Self::try_link_affiliatee_to(affiliator, account)?;
+			}
 			Affiliatees::<T, I>::insert(account, chain);
 
 			Ok(())
@@ -0,0 +1,103 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_support::{migrations::VersionedMigration, traits::UncheckedOnRuntimeUpgrade};
+use sp_runtime::Saturating;
+use sp_std::collections::btree_map::BTreeMap;
+
+pub mod v2 {
+	use super::*;
+
+	/// Populates [`AffiliatorAffiliatees`] from the existing [`Affiliatees`] chains and recounts
+	/// [`Affiliators`] affiliates from it, since chains forced before the index existed were never
+	/// counted.
+	pub struct UncheckedMigrateToV2<T, I>(PhantomData<(T, I)>);
+
+	impl<T: Config<I>, I: 'static> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV2<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut reads = 0_u64;
+			let mut writes = 0_u64;
+
+			let mut counts = BTreeMap::<T::AccountId, u32>::new();
+			for (affiliatee, chain) in Affiliatees::<T, I>::iter() {
+				reads += 1;
+				if let Some(affiliator) = chain.first() {
+					AffiliatorAffiliatees::<T, I>::insert(affiliator, &affiliatee, ());
+					writes += 1;
+					counts.entry(affiliator.clone()).or_default().saturating_inc();
+				}
+			}
+
+			Affiliators::<T, I>::translate_values(|mut state: AffiliatorState| {
+				reads += 1;
+				writes += 1;
+				state.affiliates = 0;
+				Some(state)
+			});
+			for (affiliator, affiliates) in counts {
+				Affiliators::<T, I>::mutate(affiliator, |state| state.affiliates = affiliates);
+				reads += 1;
+				writes += 1;
+			}
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<sp_std::vec::Vec<u8>, sp_runtime::TryRuntimeError> {
+			let affiliated =
+				Affiliatees::<T, I>::iter_values().filter(|chain| !chain.is_empty()).count() as u64;
+			Ok(affiliated.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let affiliated = u64::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the number of affiliated accounts")?;
+
+			for (affiliatee, chain) in Affiliatees::<T, I>::iter() {
+				if let Some(affiliator) = chain.first() {
+					ensure!(
+						AffiliatorAffiliatees::<T, I>::contains_key(affiliator, &affiliatee),
+						"Affiliatee missing from its affiliator's index"
+					);
+				}
+			}
+			for (affiliator, state) in Affiliators::<T, I>::iter() {
+				ensure!(
+					state.affiliates as usize ==
+						AffiliatorAffiliatees::<T, I>::iter_prefix(&affiliator).count(),
+					"Affiliator count doesn't match its index"
+				);
+			}
+			ensure!(
+				AffiliatorAffiliatees::<T, I>::iter().count() as u64 == affiliated,
+				"Affiliator index doesn't match the affiliatee chains"
+			);
+
+			Ok(())
+		}
+	}
+
+	pub type MigrateToV2<T, I> = VersionedMigration<
+		1,
+		2,
+		UncheckedMigrateToV2<T, I>,
+		Pallet<T, I>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
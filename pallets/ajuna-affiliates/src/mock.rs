@@ -18,6 +18,7 @@ use crate::{self as pallet_ajuna_affiliates};
 use frame_support::{
 	parameter_types,
 	traits::{ConstU16, ConstU64},
+	weights::constants::RocksDbWeight,
 };
 use sp_runtime::{
 	testing::{TestSignature, H256},
@@ -51,7 +52,7 @@ impl frame_system::Config for Test {
 	type BaseCallFilter = frame_support::traits::Everything;
 	type BlockWeights = ();
 	type BlockLength = ();
-	type DbWeight = ();
+	type DbWeight = RocksDbWeight;
 	type RuntimeOrigin = RuntimeOrigin;
 	type RuntimeCall = RuntimeCall;
 	type Hash = H256;
@@ -101,6 +102,7 @@ impl pallet_balances::Config for Test {
 
 parameter_types! {
 	pub const AffiliateMaxLevel: u32 = 2;
+	pub static MaxDetachmentsPerCall: u32 = 10;
 }
 
 pub type MockRuleId = u8;
@@ -112,6 +114,7 @@ impl pallet_ajuna_affiliates::Config<AffiliatesInstance1> for Test {
 	type RuleIdentifier = MockRuleId;
 	type RuntimeRule = MockRuntimeRule;
 	type AffiliateMaxLevel = AffiliateMaxLevel;
+	type MaxDetachmentsPerCall = MaxDetachmentsPerCall;
 }
 
 type AffiliatesInstance2 = pallet_ajuna_affiliates::Instance2;
@@ -120,6 +123,7 @@ impl pallet_ajuna_affiliates::Config<AffiliatesInstance2> for Test {
 	type RuleIdentifier = MockRuleId;
 	type RuntimeRule = MockRuntimeRule;
 	type AffiliateMaxLevel = AffiliateMaxLevel;
+	type MaxDetachmentsPerCall = MaxDetachmentsPerCall;
 }
 
 #[derive(Default)]
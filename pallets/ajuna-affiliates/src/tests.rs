@@ -1,5 +1,6 @@
 use crate::{mock::*, *};
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, weights::constants::RocksDbWeight};
+use frame_system::pallet_prelude::BlockNumberFor;
 use sp_runtime::bounded_vec;
 
 mod add_rule {
@@ -190,6 +191,7 @@ mod affiliate_to {
 
 			assert_eq!(Affiliators::<Test, Instance1>::get(BOB).affiliates, 1);
 			assert_eq!(Affiliatees::<Test, Instance1>::get(ALICE), Some(bounded_vec![BOB]));
+			assert_eq!(AffiliatorAffiliatees::<Test, Instance1>::get(BOB, ALICE), Some(()));
 		});
 	}
 
@@ -544,6 +546,7 @@ mod clear_affiliation {
 
 			assert_eq!(Affiliators::<Test, Instance1>::get(BOB).affiliates, 0);
 			assert_eq!(Affiliatees::<Test, Instance1>::get(ALICE), None);
+			assert_eq!(AffiliatorAffiliatees::<Test, Instance1>::get(BOB, ALICE), None);
 		});
 	}
 
@@ -748,6 +751,312 @@ mod force_affiliatees {
 				Affiliatees::<Test, Instance1>::get(account).map(|acc| acc.to_vec()),
 				Some(chain)
 			);
+			assert_eq!(Affiliators::<Test, Instance1>::get(BOB).affiliates, 1);
+			assert_eq!(AffiliatorAffiliatees::<Test, Instance1>::get(BOB, ALICE), Some(()));
+			assert_eq!(AffiliatorAffiliatees::<Test, Instance1>::get(CHARLIE, ALICE), None);
+		});
+	}
+
+	#[test]
+	fn force_set_affiliation_state_replaces_previous_affiliator() {
+		ExtBuilder::default().build().execute_with(|| {
+			let state =
+				AffiliatorState { status: AffiliatableStatus::Affiliatable(0), affiliates: 0 };
+			Affiliators::<Test, Instance1>::insert(BOB, state);
+
+			assert_ok!(
+				<AffiliatesAlpha as AffiliateMutator<AccountIdFor<Test>>>::try_add_affiliate_to(
+					&BOB, &ALICE
+				)
+			);
+
+			assert_ok!(
+				<AffiliatesAlpha as AffiliateMutator<AccountIdFor<Test>>>::force_set_affiliatee_chain_for(
+					&ALICE, vec![CHARLIE]
+				)
+			);
+
+			assert_eq!(Affiliators::<Test, Instance1>::get(BOB).affiliates, 0);
+			assert_eq!(Affiliators::<Test, Instance1>::get(CHARLIE).affiliates, 1);
+			assert_eq!(AffiliatorAffiliatees::<Test, Instance1>::get(BOB, ALICE), None);
+			assert_eq!(AffiliatorAffiliatees::<Test, Instance1>::get(CHARLIE, ALICE), Some(()));
+			assert_eq!(Affiliatees::<Test, Instance1>::get(ALICE), Some(bounded_vec![CHARLIE]));
+		});
+	}
+}
+
+mod affiliatees_of {
+	use super::*;
+
+	#[test]
+	fn affiliatees_of_returns_direct_affiliatees_only() {
+		ExtBuilder::default().build().execute_with(|| {
+			let state =
+				AffiliatorState { status: AffiliatableStatus::Affiliatable(0), affiliates: 0 };
+			Affiliators::<Test, Instance1>::insert(BOB, state);
+			Affiliators::<Test, Instance1>::insert(DAVE, state);
+
+			for (affiliator, affiliatee) in [(BOB, ALICE), (BOB, DAVE), (DAVE, EDWARD)] {
+				assert_ok!(
+					<AffiliatesAlpha as AffiliateMutator<AccountIdFor<Test>>>::try_add_affiliate_to(
+						&affiliator,
+						&affiliatee
+					)
+				);
+			}
+
+			assert_eq!(AffiliatesAlpha::affiliatees_of(&BOB, None, 10), vec![ALICE, DAVE]);
+			assert_eq!(AffiliatesAlpha::affiliatees_of(&DAVE, None, 10), vec![EDWARD]);
+			assert_eq!(
+				AffiliatesAlpha::affiliatees_of(&EDWARD, None, 10),
+				Vec::<AccountIdFor<Test>>::new()
+			);
+			assert_eq!(
+				AffiliatesBeta::affiliatees_of(&BOB, None, 10),
+				Vec::<AccountIdFor<Test>>::new()
+			);
+		});
+	}
+
+	#[test]
+	fn affiliatees_of_paginates() {
+		ExtBuilder::default().build().execute_with(|| {
+			let state =
+				AffiliatorState { status: AffiliatableStatus::Affiliatable(0), affiliates: 0 };
+			Affiliators::<Test, Instance1>::insert(ALICE, state);
+
+			for affiliatee in [BOB, CHARLIE, DAVE, EDWARD] {
+				assert_ok!(
+					<AffiliatesAlpha as AffiliateMutator<AccountIdFor<Test>>>::try_add_affiliate_to(
+						&ALICE,
+						&affiliatee
+					)
+				);
+			}
+
+			let first_page = AffiliatesAlpha::affiliatees_of(&ALICE, None, 3);
+			assert_eq!(first_page.len(), 3);
+
+			let second_page =
+				AffiliatesAlpha::affiliatees_of(&ALICE, first_page.last().cloned(), 3);
+			assert_eq!(second_page.len(), 1);
+
+			let mut all = [first_page, second_page].concat();
+			all.sort();
+			assert_eq!(all, vec![BOB, CHARLIE, DAVE, EDWARD]);
+
+			assert_ok!(
+				<AffiliatesAlpha as AffiliateMutator<AccountIdFor<Test>>>::try_clear_affiliation_for(
+					&CHARLIE
+				)
+			);
+
+			let mut all = AffiliatesAlpha::affiliatees_of(&ALICE, None, 10);
+			all.sort();
+			assert_eq!(all, vec![BOB, DAVE, EDWARD]);
+		});
+	}
+}
+
+mod mark_as_blocked {
+	use super::*;
+
+	#[test]
+	fn mark_as_blocked_detaches_affiliatees() {
+		ExtBuilder::default().build().execute_with(|| {
+			let state =
+				AffiliatorState { status: AffiliatableStatus::Affiliatable(0), affiliates: 0 };
+			Affiliators::<Test, Instance1>::insert(BOB, state);
+			Affiliators::<Test, Instance1>::insert(DAVE, state);
+
+			for (affiliator, affiliatee) in [(BOB, ALICE), (BOB, DAVE), (DAVE, EDWARD)] {
+				assert_ok!(
+					<AffiliatesAlpha as AffiliateMutator<AccountIdFor<Test>>>::try_add_affiliate_to(
+						&affiliator,
+						&affiliatee
+					)
+				);
+			}
+			assert_eq!(Affiliatees::<Test, Instance1>::get(EDWARD), Some(bounded_vec![DAVE, BOB]));
+
+			<AffiliatesAlpha as AffiliateMutator<AccountIdFor<Test>>>::mark_account_as_blocked(
+				&BOB,
+			);
+
+			assert_eq!(
+				Affiliators::<Test, Instance1>::get(BOB),
+				AffiliatorState { status: AffiliatableStatus::Blocked, affiliates: 0 }
+			);
+			assert_eq!(
+				AffiliatesAlpha::affiliatees_of(&BOB, None, 10),
+				Vec::<AccountIdFor<Test>>::new()
+			);
+			assert_eq!(Affiliatees::<Test, Instance1>::get(ALICE), None);
+			assert_eq!(Affiliatees::<Test, Instance1>::get(DAVE), None);
+			assert_eq!(Affiliatees::<Test, Instance1>::get(EDWARD), Some(bounded_vec![DAVE]));
+			assert_eq!(AffiliatesAlpha::affiliatees_of(&DAVE, None, 10), vec![EDWARD]);
+			assert_eq!(Affiliators::<Test, Instance1>::get(DAVE).affiliates, 1);
+			assert_eq!(PendingDetachments::<Test, Instance1>::iter().count(), 0);
+		});
+	}
+
+	#[test]
+	fn mark_as_blocked_detaches_remaining_affiliatees_on_idle() {
+		ExtBuilder::default().build().execute_with(|| {
+			MaxDetachmentsPerCall::set(1);
+			let state =
+				AffiliatorState { status: AffiliatableStatus::Affiliatable(0), affiliates: 0 };
+			Affiliators::<Test, Instance1>::insert(BOB, state);
+			Affiliators::<Test, Instance1>::insert(DAVE, state);
+
+			for (affiliator, affiliatee) in [(BOB, ALICE), (BOB, DAVE), (DAVE, EDWARD)] {
+				assert_ok!(
+					<AffiliatesAlpha as AffiliateMutator<AccountIdFor<Test>>>::try_add_affiliate_to(
+						&affiliator,
+						&affiliatee
+					)
+				);
+			}
+
+			<AffiliatesAlpha as AffiliateMutator<AccountIdFor<Test>>>::mark_account_as_blocked(
+				&BOB,
+			);
+
+			// Only one of the direct affiliatees is detached right away.
+			assert_eq!(
+				Affiliators::<Test, Instance1>::get(BOB),
+				AffiliatorState { status: AffiliatableStatus::Blocked, affiliates: 1 }
+			);
+			assert_eq!(PendingDetachments::<Test, Instance1>::iter().count(), 2);
+
+			// Visiting the other affiliatees and exhausting each affiliator takes 5 steps, plus
+			// the final check for pending detachments.
+			let step_weight = RocksDbWeight::get().reads_writes(4, 4);
+			let weight = <AffiliatesAlpha as Hooks<BlockNumberFor<Test>>>::on_idle(
+				System::block_number(),
+				step_weight.saturating_mul(10),
+			);
+			assert_eq!(weight, step_weight.saturating_mul(6));
+
+			assert_eq!(
+				Affiliators::<Test, Instance1>::get(BOB),
+				AffiliatorState { status: AffiliatableStatus::Blocked, affiliates: 0 }
+			);
+			assert_eq!(Affiliatees::<Test, Instance1>::get(ALICE), None);
+			assert_eq!(Affiliatees::<Test, Instance1>::get(DAVE), None);
+			assert_eq!(Affiliatees::<Test, Instance1>::get(EDWARD), Some(bounded_vec![DAVE]));
+			assert_eq!(PendingDetachments::<Test, Instance1>::iter().count(), 0);
+		});
+	}
+
+	#[test]
+	fn on_idle_detaches_nothing_without_enough_weight() {
+		ExtBuilder::default().build().execute_with(|| {
+			MaxDetachmentsPerCall::set(0);
+			Affiliators::<Test, Instance1>::insert(
+				BOB,
+				AffiliatorState { status: AffiliatableStatus::Affiliatable(0), affiliates: 0 },
+			);
+			assert_ok!(
+				<AffiliatesAlpha as AffiliateMutator<AccountIdFor<Test>>>::try_add_affiliate_to(
+					&BOB, &ALICE
+				)
+			);
+			<AffiliatesAlpha as AffiliateMutator<AccountIdFor<Test>>>::mark_account_as_blocked(
+				&BOB,
+			);
+
+			let step_weight = RocksDbWeight::get().reads_writes(4, 4);
+			let weight = <AffiliatesAlpha as Hooks<BlockNumberFor<Test>>>::on_idle(
+				System::block_number(),
+				step_weight.saturating_sub(Weight::from_parts(1, 0)),
+			);
+			assert_eq!(weight, Weight::zero());
+			assert_eq!(Affiliatees::<Test, Instance1>::get(ALICE), Some(bounded_vec![BOB]));
+		});
+	}
+}
+
+mod migration {
+	use super::*;
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+	fn insert_chain(affiliatee: AccountIdFor<Test>, chain: AffiliatedAccountsOf<Test, Instance1>) {
+		Affiliatees::<Test, Instance1>::insert(affiliatee, chain);
+	}
+
+	#[test]
+	fn v2_backfills_affiliator_affiliatees() {
+		ExtBuilder::default().build().execute_with(|| {
+			StorageVersion::new(1).put::<AffiliatesAlpha>();
+			insert_chain(ALICE, bounded_vec![BOB]);
+			insert_chain(CHARLIE, bounded_vec![BOB, DAVE]);
+			insert_chain(EDWARD, bounded_vec![CHARLIE, BOB]);
+
+			#[cfg(feature = "try-runtime")]
+			let state = crate::migration::v2::MigrateToV2::<Test, Instance1>::pre_upgrade()
+				.expect("pre_upgrade should pass");
+			crate::migration::v2::MigrateToV2::<Test, Instance1>::on_runtime_upgrade();
+			#[cfg(feature = "try-runtime")]
+			crate::migration::v2::MigrateToV2::<Test, Instance1>::post_upgrade(state)
+				.expect("post_upgrade should pass");
+
+			assert_eq!(AffiliatesAlpha::on_chain_storage_version(), StorageVersion::new(2));
+			assert_eq!(AffiliatesAlpha::affiliatees_of(&BOB, None, 10), vec![ALICE, CHARLIE]);
+			assert_eq!(AffiliatesAlpha::affiliatees_of(&CHARLIE, None, 10), vec![EDWARD]);
+			assert_eq!(
+				AffiliatesAlpha::affiliatees_of(&DAVE, None, 10),
+				Vec::<AccountIdFor<Test>>::new()
+			);
+		});
+	}
+
+	#[test]
+	fn v2_recounts_affiliates_so_forced_chains_can_be_replaced() {
+		ExtBuilder::default().build().execute_with(|| {
+			StorageVersion::new(1).put::<AffiliatesAlpha>();
+			let state =
+				AffiliatorState { status: AffiliatableStatus::Affiliatable(0), affiliates: 0 };
+			Affiliators::<Test, Instance1>::insert(BOB, state);
+			Affiliators::<Test, Instance1>::insert(
+				DAVE,
+				AffiliatorState { affiliates: 5, ..state },
+			);
+			// forced before the affiliator index existed, so never counted
+			insert_chain(ALICE, bounded_vec![BOB]);
+			insert_chain(CHARLIE, bounded_vec![BOB]);
+
+			crate::migration::v2::MigrateToV2::<Test, Instance1>::on_runtime_upgrade();
+
+			assert_eq!(Affiliators::<Test, Instance1>::get(BOB).affiliates, 2);
+			assert_eq!(Affiliators::<Test, Instance1>::get(DAVE).affiliates, 0);
+			assert_eq!(
+				Affiliators::<Test, Instance1>::get(BOB).status,
+				AffiliatableStatus::Affiliatable(0)
+			);
+
+			assert_ok!(
+				<AffiliatesAlpha as AffiliateMutator<AccountIdFor<Test>>>::force_set_affiliatee_chain_for(
+					&ALICE, vec![DAVE]
+				)
+			);
+
+			assert_eq!(Affiliators::<Test, Instance1>::get(BOB).affiliates, 1);
+			assert_eq!(Affiliators::<Test, Instance1>::get(DAVE).affiliates, 1);
+			assert_eq!(AffiliatesAlpha::affiliatees_of(&BOB, None, 10), vec![CHARLIE]);
+			assert_eq!(AffiliatesAlpha::affiliatees_of(&DAVE, None, 10), vec![ALICE]);
+		});
+	}
+
+	#[test]
+	fn v2_skips_already_migrated_storage() {
+		ExtBuilder::default().build().execute_with(|| {
+			StorageVersion::new(2).put::<AffiliatesAlpha>();
+			insert_chain(ALICE, bounded_vec![BOB]);
+
+			crate::migration::v2::MigrateToV2::<Test, Instance1>::on_runtime_upgrade();
+
+			assert_eq!(AffiliatorAffiliatees::<Test, Instance1>::get(BOB, ALICE), None);
 		});
 	}
 }
@@ -23,7 +23,8 @@ pub trait AffiliateMutator<AccountId> {
 	/// to do so if the account is in the [AffiliatableStatus::Blocked] state.
 	fn try_mark_account_as_affiliatable(account: &AccountId) -> DispatchResult;
 
-	/// Marks an account as [AffiliatableStatus::Blocked]
+	/// Marks an account as [AffiliatableStatus::Blocked], detaching every affiliatee linked
+	/// through it. Affiliatees beyond the per-call limit are detached on idle blocks.
 	fn mark_account_as_blocked(account: &AccountId);
 
 	/// Attempts to add an affiliate link between affiliate and account
@@ -18,7 +18,7 @@
 
 use frame_support::{
 	parameter_types,
-	traits::{ConstU16, ConstU64},
+	traits::{ConstU16, ConstU32, ConstU64},
 	PalletId,
 };
 use frame_system::pallet_prelude::BlockNumberFor;
@@ -146,6 +146,7 @@ impl pallet_ajuna_affiliates::Config<AffiliatesInstance1> for Runtime {
 	type RuleIdentifier = AffiliateMethods;
 	type RuntimeRule = FeePropagationOf<Runtime>;
 	type AffiliateMaxLevel = AffiliateMaxLevel;
+	type MaxDetachmentsPerCall = ConstU32<10>;
 }
 
 parameter_types! {
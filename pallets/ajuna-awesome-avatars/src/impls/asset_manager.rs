@@ -20,6 +20,7 @@ impl<T: Config> AssetManager for Pallet<T> {
 	type AccountId = AccountIdFor<T>;
 	type AssetId = AvatarIdOf<T>;
 	type Asset = AvatarOf<T>;
+	type Balance = BalanceOf<T>;
 
 	fn ensure_ownership(
 		account: &Self::AccountId,
@@ -84,9 +85,31 @@ impl<T: Config> AssetManager for Pallet<T> {
 		asset: &Self::Asset,
 		player: &Self::AccountId,
 		fee_recipient: &Self::AccountId,
-	) -> Result<(), DispatchError> {
+	) -> Result<FeeReceipt<Self::AccountId, Self::Balance>, DispatchError> {
 		let Season { fee, .. } = Self::seasons(&asset.season_id)?;
-		T::Currency::transfer(player, fee_recipient, fee.prepare_avatar, AllowDeath)
+		let receipt = FeeRouterOf::<T>::route_fee(
+			player,
+			fee.prepare_avatar,
+			vec![FeeCut::Treasury(fee_recipient.clone())],
+		)?;
+		Self::deposit_event(Event::FeeRouted { payer: player.clone(), receipt: receipt.clone() });
+
+		Ok(receipt)
+	}
+
+	fn refund_asset_prepare_fee(
+		receipt: &FeeReceipt<Self::AccountId, Self::Balance>,
+		player: &Self::AccountId,
+		fee_recipient: &Self::AccountId,
+	) -> Self::Balance {
+		let amount = receipt.deposited;
+		if !amount.is_zero() &&
+			T::Currency::transfer(fee_recipient, player, amount, AllowDeath).is_ok()
+		{
+			amount
+		} else {
+			BalanceOf::<T>::zero()
+		}
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
@@ -76,10 +76,11 @@ use crate::{types::*, weights::WeightInfo};
 use ajuna_primitives::{
 	account_manager::{AccountManager, WhitelistKey},
 	asset_manager::{AssetManager, Lock, LockIdentifier},
+	fee_router::{CurrencyFeeRouter, FeeCut, FeeReceipt, FeeRouter},
 };
 use frame_support::{
 	pallet_prelude::*,
-	traits::{Currency, ExistenceRequirement::AllowDeath, Randomness, WithdrawReasons},
+	traits::{Currency, ExistenceRequirement::AllowDeath, Randomness},
 	PalletId,
 };
 use frame_system::{ensure_root, ensure_signed, pallet_prelude::*};
@@ -92,8 +93,8 @@ use pallet_ajuna_tournament::{
 };
 use sp_runtime::{
 	traits::{
-		AccountIdConversion, CheckedDiv, CheckedSub, Hash, Saturating, TrailingZeroInput,
-		UniqueSaturatedInto, Zero,
+		AccountIdConversion, CheckedSub, Hash, Saturating, TrailingZeroInput, UniqueSaturatedInto,
+		Zero,
 	},
 	ArithmeticError,
 };
@@ -103,7 +104,7 @@ use sp_std::prelude::*;
 pub mod pallet {
 	use super::*;
 	use pallet_ajuna_affiliates::traits::{AffiliateId, RuleExecutor};
-	use pallet_ajuna_tournament::{Percentage, TournamentId};
+	use pallet_ajuna_tournament::TournamentId;
 	use sp_std::collections::vec_deque::VecDeque;
 
 	pub(crate) type AccountIdFor<T> = <T as frame_system::Config>::AccountId;
@@ -117,6 +118,9 @@ pub mod pallet {
 	pub type FeePropagationOf<T> = FeePropagation<<T as Config>::FeeChainMaxLength>;
 	pub type AvatarRankerFor<T> = AvatarRanker<AvatarIdOf<T>, BlockNumberFor<T>>;
 	pub type TournamentConfigFor<T> = TournamentConfig<BlockNumberFor<T>, BalanceOf<T>>;
+	pub(crate) type FeeCutOf<T> = FeeCut<AccountIdFor<T>>;
+	pub type FeeReceiptOf<T> = FeeReceipt<AccountIdFor<T>, BalanceOf<T>>;
+	pub(crate) type FeeRouterOf<T> = CurrencyFeeRouter<<T as Config>::Currency>;
 
 	pub(crate) const MAX_PERCENTAGE: u8 = 100;
 
@@ -354,6 +358,8 @@ pub mod pallet {
 		StorageTierUpgraded { account: T::AccountId, season_id: SeasonId },
 		/// Unlock configurations updated.
 		UpdatedUnlockConfigs { season_id: SeasonId, unlock_configs: UnlockConfigs },
+		/// A fee has been charged and split between its recipients.
+		FeeRouted { payer: T::AccountId, receipt: FeeReceiptOf<T> },
 	}
 
 	#[pallet::error]
@@ -594,8 +600,7 @@ pub mod pallet {
 				Error::<T>::FeatureLocked
 			);
 			let Season { fee, .. } = Self::seasons(&avatar.season_id)?;
-			T::Currency::withdraw(&from, fee.transfer_avatar, WithdrawReasons::FEE, AllowDeath)?;
-			Self::deposit_into_treasury(&avatar.season_id, fee.transfer_avatar);
+			Self::try_route_fee(&avatar.season_id, &from, fee.transfer_avatar, Vec::new())?;
 
 			Self::do_transfer_avatar(&from, &to, &avatar.season_id, &avatar_id)?;
 			Self::deposit_event(Event::AvatarTransferred { from, to, avatar_id });
@@ -739,20 +744,15 @@ pub mod pallet {
 			let avatar = Self::ensure_ownership(&seller, &avatar_id)?;
 			let (current_season_id, Season { fee, .. }) = Self::current_season_with_id()?;
 
-			let trade_fee = {
-				let base_fee = fee.buy_minimum.max(
-					price.saturating_mul(fee.buy_percent.unique_saturated_into()) /
-						MAX_PERCENTAGE.unique_saturated_into(),
-				);
-
-				if affiliate_config.mode == AffiliateMode::Open && affiliate_config.enabled_in_buy {
-					Self::try_propagate_chain_fee(AffiliateMethods::Buy, &buyer, base_fee)?
-				} else {
-					base_fee
-				}
-			};
-			T::Currency::withdraw(&buyer, trade_fee, WithdrawReasons::FEE, AllowDeath)?;
-			Self::deposit_into_treasury(&avatar.season_id, trade_fee);
+			let base_fee = fee.buy_minimum.max(
+				price.saturating_mul(fee.buy_percent.unique_saturated_into()) /
+					MAX_PERCENTAGE.unique_saturated_into(),
+			);
+			let mut fee_cuts = Vec::new();
+			if affiliate_config.mode == AffiliateMode::Open && affiliate_config.enabled_in_buy {
+				fee_cuts.extend(Self::try_get_chain_fee_cut(AffiliateMethods::Buy, &buyer)?);
+			}
+			Self::try_route_fee(&avatar.season_id, &buyer, base_fee, fee_cuts)?;
 
 			Self::do_transfer_avatar(&seller, &buyer, &avatar.season_id, &avatar_id)?;
 			Trade::<T>::remove(avatar.season_id, avatar_id);
@@ -803,25 +803,15 @@ pub mod pallet {
 				PlayerSeasonConfigs::<T>::get(&account_to_upgrade, season_id).storage_tier;
 			ensure!(storage_tier != StorageTier::Max, Error::<T>::MaxStorageTierReached);
 
-			let upgrade_fee = {
-				let base_fee = fee.upgrade_storage;
-				let GlobalConfig { affiliate_config, .. } = GlobalConfigs::<T>::get();
-
-				if affiliate_config.mode == AffiliateMode::Open &&
-					affiliate_config.enabled_in_upgrade
-				{
-					Self::try_propagate_chain_fee(
-						AffiliateMethods::UpgradeStorage,
-						&caller,
-						base_fee,
-					)?
-				} else {
-					base_fee
-				}
-			};
-
-			T::Currency::withdraw(&caller, upgrade_fee, WithdrawReasons::FEE, AllowDeath)?;
-			Self::deposit_into_treasury(&season_id, upgrade_fee);
+			let mut fee_cuts = Vec::new();
+			let GlobalConfig { affiliate_config, .. } = GlobalConfigs::<T>::get();
+			if affiliate_config.mode == AffiliateMode::Open && affiliate_config.enabled_in_upgrade {
+				fee_cuts.extend(Self::try_get_chain_fee_cut(
+					AffiliateMethods::UpgradeStorage,
+					&caller,
+				)?);
+			}
+			Self::try_route_fee(&season_id, &caller, fee.upgrade_storage, fee_cuts)?;
 
 			PlayerSeasonConfigs::<T>::mutate(&account_to_upgrade, season_id, |account| {
 				account.storage_tier = storage_tier.upgrade()
@@ -1439,9 +1429,24 @@ pub mod pallet {
 			T::PalletId::get().into_sub_account_truncating(b"technical")
 		}
 
-		pub(crate) fn deposit_into_treasury(season_id: &SeasonId, amount: BalanceOf<T>) {
-			Treasury::<T>::mutate(season_id, |bal| bal.saturating_accrue(amount));
-			T::Currency::deposit_creating(&Self::treasury_account_id(), amount);
+		/// Charges `base_fee` to `payer` by applying `fee_cuts` in order, with whatever remains
+		/// afterwards being deposited into the treasury of `season_id`.
+		pub(crate) fn try_route_fee(
+			season_id: &SeasonId,
+			payer: &T::AccountId,
+			base_fee: BalanceOf<T>,
+			mut fee_cuts: Vec<FeeCutOf<T>>,
+		) -> Result<FeeReceiptOf<T>, DispatchError> {
+			fee_cuts.push(FeeCut::Treasury(Self::treasury_account_id()));
+			let receipt = FeeRouterOf::<T>::route_fee(payer, base_fee, fee_cuts)?;
+
+			Treasury::<T>::mutate(season_id, |bal| bal.saturating_accrue(receipt.deposited));
+			Self::deposit_event(Event::FeeRouted {
+				payer: payer.clone(),
+				receipt: receipt.clone(),
+			});
+
+			Ok(receipt)
 		}
 
 		/// Check that the origin is an organizer account.
@@ -1511,38 +1516,27 @@ pub mod pallet {
 			let GlobalConfig { mint, affiliate_config, .. } = GlobalConfigs::<T>::get();
 			match mint_option.payment {
 				MintPayment::Normal => {
-					let mint_fee = {
-						let base_fee = season.fee.mint.fee_for(&mint_option.pack_size);
+					let base_fee = season.fee.mint.fee_for(&mint_option.pack_size);
 
-						let updated_fee =
-							match T::TournamentHandler::get_active_tournament_config_for(&season_id)
-							{
-								Some((
-									_,
-									TournamentConfig {
-										take_fee_percentage: Some(fee_perc), ..
-									},
-								)) if is_tournament_in_active_period => Self::try_propagate_tournament_fee(
-									&season_id, player, fee_perc, base_fee,
-								)?,
-								_ => base_fee,
-							};
-
-						if affiliate_config.mode == AffiliateMode::Open &&
-							affiliate_config.enabled_in_mint
-						{
-							Self::try_propagate_chain_fee(
-								AffiliateMethods::Mint,
-								player,
-								updated_fee,
-							)?
-						} else {
-							updated_fee
+					let mut fee_cuts = Vec::new();
+					if let Some((_, TournamentConfig { take_fee_percentage: Some(fee_perc), .. })) =
+						T::TournamentHandler::get_active_tournament_config_for(&season_id)
+					{
+						if is_tournament_in_active_period {
+							fee_cuts.push(FeeCut::Percentage {
+								account: T::TournamentHandler::get_treasury_account_for(&season_id),
+								percentage: fee_perc,
+							});
 						}
-					};
+					}
+					if affiliate_config.mode == AffiliateMode::Open &&
+						affiliate_config.enabled_in_mint
+					{
+						fee_cuts
+							.extend(Self::try_get_chain_fee_cut(AffiliateMethods::Mint, player)?);
+					}
 
-					T::Currency::withdraw(player, mint_fee, WithdrawReasons::FEE, AllowDeath)?;
-					Self::deposit_into_treasury(&season_id, mint_fee);
+					Self::try_route_fee(&season_id, player, base_fee, fee_cuts)?;
 				},
 				MintPayment::Free => {
 					let mint_fee = (mint_option.pack_size.as_mint_count())
@@ -2098,56 +2092,24 @@ pub mod pallet {
 			Ok(season_schedule)
 		}
 
-		fn try_propagate_tournament_fee(
-			season_id: &SeasonId,
-			account: &T::AccountId,
-			percentage: Percentage,
-			base_fee: BalanceOf<T>,
-		) -> Result<BalanceOf<T>, DispatchError> {
-			let tournament_fee = base_fee
-				.saturating_mul(percentage.into())
-				.checked_div(&100_u32.into())
-				.unwrap_or_default();
-
-			if tournament_fee > 0_u32.into() {
-				let tournament_account = T::TournamentHandler::get_treasury_account_for(season_id);
-
-				T::Currency::transfer(account, &tournament_account, tournament_fee, AllowDeath)?;
-				Ok(base_fee.saturating_sub(tournament_fee))
-			} else {
-				Ok(base_fee)
-			}
-		}
-
-		fn try_propagate_chain_fee(
+		/// Returns the cut the affiliator chain of `account` takes from a fee, according to the
+		/// rule set for `rule_id`.
+		fn try_get_chain_fee_cut(
 			rule_id: AffiliateMethods,
 			account: &T::AccountId,
-			base_fee: BalanceOf<T>,
-		) -> Result<BalanceOf<T>, DispatchError> {
-			let final_fee = if let Some(chain) =
-				T::AffiliateHandler::get_affiliator_chain_for(account)
-			{
-				T::AffiliateHandler::try_execute_rule_for(rule_id, |rule| {
-					let mut final_fee = base_fee;
-					for (rule_perc, chain_acc) in rule.into_iter().zip(chain.clone()) {
-						let transfer_fee = base_fee
-							.saturating_mul(rule_perc.into())
-							.checked_div(&100_u32.into())
-							.unwrap_or_default();
-
-						if transfer_fee > 0_u32.into() {
-							T::Currency::transfer(account, &chain_acc, transfer_fee, AllowDeath)?;
-							final_fee = final_fee.saturating_sub(transfer_fee);
-						}
-					}
-
-					Ok(final_fee)
-				})?
-			} else {
-				base_fee
-			};
-
-			Ok(final_fee)
+		) -> Result<Option<FeeCutOf<T>>, DispatchError> {
+			T::AffiliateHandler::get_affiliator_chain_for(account)
+				.map(|chain| {
+					T::AffiliateHandler::try_execute_rule_for(rule_id, |rule| {
+						Ok(FeeCut::Chain(
+							rule.into_iter()
+								.zip(chain.clone())
+								.map(|(rule_perc, chain_acc)| (chain_acc, rule_perc))
+								.collect(),
+						))
+					})
+				})
+				.transpose()
 		}
 
 		fn evaluate_unlock_state(
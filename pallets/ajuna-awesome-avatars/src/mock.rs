@@ -17,7 +17,7 @@
 use crate::{self as pallet_ajuna_awesome_avatars, types::*, *};
 use frame_support::{
 	parameter_types,
-	traits::{ConstU16, ConstU64, Hooks},
+	traits::{ConstU16, ConstU32, ConstU64, Hooks},
 	PalletId,
 };
 pub(crate) use sp_runtime::testing::H256;
@@ -132,6 +132,7 @@ impl pallet_ajuna_affiliates::Config<AffiliatesInstance1> for Test {
 	type RuleIdentifier = AffiliateMethods;
 	type RuntimeRule = FeePropagationOf<Test>;
 	type AffiliateMaxLevel = AffiliateMaxLevel;
+	type MaxDetachmentsPerCall = ConstU32<10>;
 }
 
 parameter_types! {
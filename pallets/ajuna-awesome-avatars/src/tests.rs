@@ -3387,6 +3387,15 @@ mod account {
 					PlayerSeasonConfigs::<Test>::get(ALICE, SEASON_ID).storage_tier,
 					StorageTier::Two
 				);
+				System::assert_has_event(mock::RuntimeEvent::AAvatars(crate::Event::FeeRouted {
+					payer: ALICE,
+					receipt: FeeReceipt {
+						base_fee: upgrade_fee,
+						transfers: vec![],
+						burned: 0,
+						deposited: upgrade_fee,
+					},
+				}));
 				assert_eq!(
 					PlayerSeasonConfigs::<Test>::get(ALICE, SEASON_ID).storage_tier as isize,
 					50
@@ -5528,6 +5537,99 @@ mod asset_manager {
 					fee_recipient_initial_balance + avatar_prepare_fee_1
 				);
 				assert_eq!(Balances::total_issuance(), total_supply);
+				System::assert_last_event(mock::RuntimeEvent::AAvatars(crate::Event::FeeRouted {
+					payer: ALICE,
+					receipt: FeeReceipt {
+						base_fee: avatar_prepare_fee_1,
+						transfers: vec![],
+						burned: 0,
+						deposited: avatar_prepare_fee_1,
+					},
+				}));
+			});
+	}
+
+	#[test]
+	fn refund_asset_prepare_fee_works() {
+		let season_id_1 = 123;
+		let avatar_prepare_fee_1 = 888;
+		let initial_balance = MockExistentialDeposit::get() + avatar_prepare_fee_1;
+
+		ExtBuilder::default()
+			.seasons(&[(season_id_1, Season::default().prepare_avatar_fee(avatar_prepare_fee_1))])
+			.balances(&[(ALICE, initial_balance), (BOB, MockExistentialDeposit::get())])
+			.build()
+			.execute_with(|| {
+				let fee_recipient = &BOB;
+				let alice_avatar_id = create_avatars(season_id_1, ALICE, 1)[0];
+				let alice_avatar = Avatars::<Test>::get(alice_avatar_id).unwrap().1;
+
+				let receipt =
+					AAvatars::handle_asset_prepare_fee(&alice_avatar, &ALICE, fee_recipient)
+						.unwrap();
+
+				// the season's fee changing in the meantime doesn't affect the refund
+				Seasons::<Test>::mutate(season_id_1, |season| {
+					season.as_mut().unwrap().fee.prepare_avatar = 2 * avatar_prepare_fee_1
+				});
+				assert_eq!(
+					AAvatars::refund_asset_prepare_fee(&receipt, &ALICE, fee_recipient),
+					avatar_prepare_fee_1
+				);
+
+				assert_eq!(Balances::free_balance(ALICE), initial_balance);
+				assert_eq!(Balances::free_balance(fee_recipient), MockExistentialDeposit::get());
+			});
+	}
+
+	#[test]
+	fn refund_asset_prepare_fee_skips_unaffordable_parts() {
+		let season_id_1 = 123;
+		let avatar_prepare_fee_1 = 888;
+		let initial_balance = MockExistentialDeposit::get() + avatar_prepare_fee_1;
+
+		ExtBuilder::default()
+			.seasons(&[(season_id_1, Season::default().prepare_avatar_fee(avatar_prepare_fee_1))])
+			.balances(&[(ALICE, initial_balance), (BOB, MockExistentialDeposit::get())])
+			.build()
+			.execute_with(|| {
+				let fee_recipient = &BOB;
+				let alice_avatar_id = create_avatars(season_id_1, ALICE, 1)[0];
+				let alice_avatar = Avatars::<Test>::get(alice_avatar_id).unwrap().1;
+
+				let receipt =
+					AAvatars::handle_asset_prepare_fee(&alice_avatar, &ALICE, fee_recipient)
+						.unwrap();
+				assert_ok!(Balances::transfer_all(RuntimeOrigin::signed(BOB), CHARLIE, false));
+
+				assert_eq!(AAvatars::refund_asset_prepare_fee(&receipt, &ALICE, fee_recipient), 0);
+				assert_eq!(Balances::free_balance(ALICE), initial_balance - avatar_prepare_fee_1);
+			});
+	}
+
+	#[test]
+	fn refund_asset_prepare_fee_only_refunds_the_deposited_part() {
+		let initial_balance = MockExistentialDeposit::get() + 100;
+
+		ExtBuilder::default()
+			.balances(&[
+				(ALICE, MockExistentialDeposit::get()),
+				(BOB, initial_balance),
+				(CHARLIE, initial_balance),
+			])
+			.build()
+			.execute_with(|| {
+				let receipt = FeeReceipt {
+					base_fee: 100,
+					transfers: vec![(CHARLIE, 40)],
+					burned: 0,
+					deposited: 60,
+				};
+
+				assert_eq!(AAvatars::refund_asset_prepare_fee(&receipt, &ALICE, &BOB), 60);
+				assert_eq!(Balances::free_balance(ALICE), MockExistentialDeposit::get() + 60);
+				assert_eq!(Balances::free_balance(BOB), initial_balance - 60);
+				assert_eq!(Balances::free_balance(CHARLIE), initial_balance);
 			});
 	}
 }
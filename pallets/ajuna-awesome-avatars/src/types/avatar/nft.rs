@@ -14,9 +14,9 @@
 // You should have received a copy of the GNU Affero General Public License
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
-use crate::types::{Avatar, ByteConvertible, Force, RarityTier};
+use crate::types::{Avatar, ByteConvertible, Force, RarityTier, SeasonId};
 use frame_support::traits::Get;
-use pallet_ajuna_nft_transfer::traits::{NFTAttribute, NftConvertible};
+use pallet_ajuna_nft_transfer::traits::{NFTAttribute, NftCategorized, NftConvertible};
 use parity_scale_codec::alloc::string::ToString;
 use scale_info::prelude::format;
 use sp_runtime::traits::UniqueSaturatedInto;
@@ -94,6 +94,14 @@ where
 	}
 }
 
+impl<BlockNumber> NftCategorized for Avatar<BlockNumber> {
+	type Category = SeasonId;
+
+	fn nft_category(&self) -> Self::Category {
+		self.season_id
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use crate::types::{
@@ -0,0 +1,36 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API definition for the board pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use parity_scale_codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait BoardApi<AccountId>
+	where
+		AccountId: Codec,
+	{
+		/// Returns the rating of `account` in games of the given kind.
+		fn rating_of(game: u8, account: AccountId) -> u32;
+
+		/// Returns at most `limit` accounts with the highest ratings in games of the given kind,
+		/// along with their ratings, from best to worst.
+		fn leaderboard(game: u8, limit: u32) -> Vec<(AccountId, u32)>;
+	}
+}
@@ -34,12 +34,27 @@ fn assert_last_event<T: Config>(event: <T as Config>::RuntimeEvent) {
 	frame_system::Pallet::<T>::assert_last_event(event.into());
 }
 
-fn create_new_game<T: Config>(players: Vec<T::AccountId>) {
-	assert_ok!(AjunaBoard::<T>::queue(RawOrigin::Signed(players[0].clone()).into()));
-	assert_ok!(AjunaBoard::<T>::queue(RawOrigin::Signed(players[1].clone()).into()));
+fn dot4gravity_players<T: Config>() -> Vec<T::AccountId> {
+	let game = T::Games::game(T::BenchmarkHelper::dot4gravity()).unwrap();
+	players::<T::AccountId>(game.players as u32)
 }
 
-fn create_and_play_until_win<T: Config>(players: Vec<T::AccountId>) {
+fn create_new_game<T: Config>(players: Vec<T::AccountId>, stake: Option<StakeOf<T>>) {
+	let game = T::BenchmarkHelper::dot4gravity();
+	for player in players.into_iter().take(2) {
+		assert_ok!(AjunaBoard::<T>::queue(RawOrigin::Signed(player).into(), game, stake.clone()));
+	}
+}
+
+fn native_stake<T: Config>(players: &[T::AccountId]) -> Option<StakeOf<T>> {
+	let amount = BalanceOf::<T>::from(1_000_u32);
+	for player in players {
+		T::Currency::make_free_balance_be(player, amount.saturating_mul(1_000_u32.into()));
+	}
+	Some(Stake { asset: StakeAsset::Native, amount })
+}
+
+fn create_and_play_until_win<T: Config>(players: Vec<T::AccountId>, stake: Option<StakeOf<T>>) {
 	// The seed below generates the following board, where o is empty and x is block:
 	// [o, o, o, o, o, o, o, o, o, o],
 	// [o, o, o, x, o, o, o, o, o, o],
@@ -52,20 +67,20 @@ fn create_and_play_until_win<T: Config>(players: Vec<T::AccountId>) {
 	// [x, o, o, o, o, o, o, o, o, o],
 	// [o, o, o, o, o, o, o, o, o, o],
 	Seed::<T>::put(7357);
-	create_new_game::<T>(players.clone());
+	create_new_game::<T>(players.clone(), stake);
 
 	let mut players = players.into_iter();
 	let player_1: T::RuntimeOrigin = RawOrigin::Signed(players.next().unwrap()).into();
 	let player_2: T::RuntimeOrigin = RawOrigin::Signed(players.next().unwrap()).into();
 
 	let each_player_drops_bomb = |coord: Coordinates| {
-		let drop_bomb: T::PlayersTurn = Turn::DropBomb(coord).into();
+		let drop_bomb = Turn::DropBomb(coord).encode();
 		let _ = AjunaBoard::<T>::play(player_1.clone(), drop_bomb.clone());
 		let _ = AjunaBoard::<T>::play(player_2.clone(), drop_bomb);
 	};
 	let each_player_drops_stone = |win_position: (Side, u8), lose_position: (Side, u8)| {
-		let win: T::PlayersTurn = Turn::DropStone(win_position).into();
-		let lose: T::PlayersTurn = Turn::DropStone(lose_position).into();
+		let win = Turn::DropStone(win_position).encode();
+		let lose = Turn::DropStone(lose_position).encode();
 		let _ = AjunaBoard::<T>::play(player_1.clone(), win);
 		let _ = AjunaBoard::<T>::play(player_2.clone(), lose);
 	};
@@ -92,21 +107,46 @@ fn create_and_play_until_win<T: Config>(players: Vec<T::AccountId>) {
 
 benchmarks! {
 	play {
-		let players = players::<T::AccountId>(T::Players::get());
-		create_new_game::<T>(players.clone());
+		// The worst case finishes the game and settles the stakes of its players. Turns that
+		// don't finish it with a winner also try dropping a stone at every position of every
+		// side, to tell whether the game is a stalemate, which is accounted for in its weight.
+		let board_id = T::BoardId::saturated_from(0_u32);
+		let players = dot4gravity_players::<T>();
+		create_and_play_until_win::<T>(players.clone(), native_stake::<T>(&players));
 
-		let player_1 = players.into_iter().next().unwrap();
-		let turn = Turn::DropBomb(Coordinates::new(1, 2));
-	}: play(RawOrigin::Signed(player_1), turn.into())
+		let winner = players.into_iter().next().unwrap();
+		let turn = Turn::DropStone((Side::South, 1));
+	}: _(RawOrigin::Signed(winner.clone()), turn.encode())
+	verify {
+		assert_last_event::<T>(Event::GameFinished { board_id, winner }.into());
+	}
 
 	play_turn_until_finished {
 		let board_id = T::BoardId::saturated_from(0_u32);
-		let players = players::<T::AccountId>(T::Players::get());
-		create_and_play_until_win::<T>(players.clone());
+		let players = dot4gravity_players::<T>();
+		create_and_play_until_win::<T>(players.clone(), None);
 
 		let winner = players.into_iter().next().unwrap();
 		let turn = Turn::DropStone((Side::South, 1));
-	}: play(RawOrigin::Signed(winner.clone()), turn.into())
+	}: play(RawOrigin::Signed(winner.clone()), turn.encode())
+	verify {
+		assert_last_event::<T>(Event::GameFinished { board_id, winner }.into());
+	}
+
+	claim_victory {
+		let board_id = T::BoardId::saturated_from(0_u32);
+		let players = dot4gravity_players::<T>();
+		// Stakes are settled between the players when the game is aborted.
+		create_new_game::<T>(players.clone(), native_stake::<T>(&players));
+
+		let board_game = BoardGames::<T>::get(board_id).unwrap();
+		let game = T::Games::game(board_game.game).unwrap();
+		let stalling_player = game.next_player(&board_game.state).unwrap();
+		let winner = players.into_iter().find(|player| player != &stalling_player).unwrap();
+
+		let expired = board_game.last_turn + T::TurnTimeout::get() + 1_u32.into();
+		frame_system::Pallet::<T>::set_block_number(expired);
+	}: _(RawOrigin::Signed(winner.clone()))
 	verify {
 		assert_last_event::<T>(Event::GameFinished { board_id, winner }.into());
 	}
@@ -15,8 +15,8 @@
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
 use crate::{Finished, TurnBasedGame};
-use dot4gravity::Game as Dot4Gravity;
 pub use dot4gravity::{Coordinates, GameState, Side};
+use dot4gravity::{Game as Dot4Gravity, GamePhase, BOARD_HEIGHT, BOARD_WIDTH};
 use frame_support::{pallet_prelude::*, Parameter};
 use sp_std::borrow::ToOwned;
 
@@ -27,6 +27,37 @@ pub enum Turn {
 }
 
 pub struct Game<Account>(PhantomData<Account>);
+
+impl<Account> Game<Account>
+where
+	Account: Parameter,
+{
+	/// Whether the next player can neither drop a stone nor a bomb.
+	///
+	/// Only the moves of the current phase are considered. Bombs can be dropped while the player
+	/// has any left, since the few blocks and bombs never fill the board, so that only stones are
+	/// tried, once for every position of every side.
+	fn is_stalemate(state: &GameState<Account>) -> bool {
+		let player = &state.next_player;
+
+		match state.phase {
+			GamePhase::Bomb =>
+				!state.bombs.iter().any(|(owner, bombs)| owner == player && *bombs > 0),
+			GamePhase::Play =>
+				![Side::North, Side::East, Side::South, Side::West].into_iter().any(|side| {
+					let positions = match side {
+						Side::North | Side::South => BOARD_WIDTH,
+						Side::East | Side::West => BOARD_HEIGHT,
+					};
+					(0..positions).any(|position| {
+						Dot4Gravity::drop_stone(state.clone(), player.clone(), side, position)
+							.is_ok()
+					})
+				}),
+		}
+	}
+}
+
 impl<Account> TurnBasedGame for Game<Account>
 where
 	Account: Parameter,
@@ -76,6 +107,7 @@ where
 	fn is_finished(state: &Self::State) -> Finished<Self::Player> {
 		match state.winner.clone() {
 			Some(winner) => Finished::Winner(winner),
+			None if Self::is_stalemate(state) => Finished::Draw,
 			None => Finished::No,
 		}
 	}
@@ -174,6 +206,21 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn stalemate_depends_on_the_phase() {
+		type Dot4GravityGame = Game<Account>;
+
+		let mut state = Dot4Gravity::new_game(PLAYER_1, PLAYER_2, Some(7357));
+		assert_eq!(Dot4GravityGame::is_finished(&state), Finished::No);
+
+		// a player without bombs left cannot play the bomb phase
+		state.bombs = [(PLAYER_1, 0), (PLAYER_2, 1)];
+		assert_eq!(Dot4GravityGame::is_finished(&state), Finished::Draw);
+
+		state.phase = GamePhase::Play;
+		assert_eq!(Dot4GravityGame::is_finished(&state), Finished::No);
+	}
+
 	#[test]
 	fn guessing_works() {
 		let state = MockGame::init(&[PLAYER_1, PLAYER_2], None).unwrap();
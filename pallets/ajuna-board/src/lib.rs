@@ -16,13 +16,24 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use frame_support::pallet_prelude::*;
+use frame_support::{
+	pallet_prelude::*,
+	storage::with_storage_layer,
+	traits::{
+		fungibles, tokens::Preservation::Expendable, BalanceStatus, Currency,
+		NamedReservableCurrency,
+	},
+	PalletId,
+};
 use frame_system::{ensure_signed, pallet_prelude::*};
 pub use pallet::*;
-use pallet_ajuna_matchmaker::MatchFunc;
-use parity_scale_codec::{Codec, Decode, Encode};
-use sp_runtime::traits::{AtLeast32BitUnsigned, BlockNumberProvider, Saturating};
-use sp_std::vec::Vec;
+use pallet_ajuna_matchmaker::{Bracket, MatchFunc, MatchHandler};
+use parity_scale_codec::{Codec, Decode, DecodeAll, Encode};
+use sp_runtime::{
+	traits::{AccountIdConversion, AtLeast32BitUnsigned, BlockNumberProvider, Saturating, Zero},
+	Permill,
+};
+use sp_std::{ops::RangeInclusive, vec::Vec};
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
@@ -34,12 +45,13 @@ mod mock;
 mod tests;
 
 pub mod dot4gravity;
+pub mod migration;
+mod rating;
 mod types;
-use types::*;
-
-pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
-
-pub const DEFAULT_BRACKET: u8 = 0;
+pub mod weights;
+pub use rating::Rating;
+pub use types::*;
+pub use weights::WeightInfo;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -49,25 +61,77 @@ pub mod pallet {
 	pub trait Config: frame_system::Config {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		type Matchmaker: MatchFunc<Self::AccountId>;
+		/// The pallet's id, used for deriving the account holding staked assets.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+		/// The native currency, in which stakes are reserved under the pallet's id
+		type Currency: NamedReservableCurrency<Self::AccountId, ReserveIdentifier = [u8; 8]>;
+		/// Identifier of the fungible assets that can be staked
+		type AssetId: Member + Parameter + MaxEncodedLen;
+		/// The fungible assets that can be staked
+		type Fungibles: fungibles::Inspect<Self::AccountId, AssetId = Self::AssetId, Balance = BalanceOf<Self>>
+			+ fungibles::Mutate<Self::AccountId>;
+		/// Share of the stakes won in a game that is taken as house fee.
+		#[pallet::constant]
+		type HouseFee: Get<Permill>;
+		/// The account receiving house fees
+		type HouseAccount: Get<Self::AccountId>;
 		/// Board id
 		type BoardId: Copy + Default + AtLeast32BitUnsigned + Parameter + MaxEncodedLen;
-		/// A Turn for the game
-		type PlayersTurn: Member + Parameter + From<dot4gravity::Turn>;
-		/// The state of the board
-		type GameState: Codec + TypeInfo + MaxEncodedLen + Clone;
-		/// A turn based game
-		type Game: TurnBasedGame<
-			Player = Self::AccountId,
-			Turn = Self::PlayersTurn,
-			State = Self::GameState,
-		>;
-		/// Number of players required for a game.
+		/// The turn based games that can be played
+		type Games: GameRegistry<Self::AccountId>;
+		/// Maximum number of players required by any of the games.
+		#[pallet::constant]
+		type MaxPlayers: Get<u32>;
+		/// Maximum length of an encoded game state.
+		#[pallet::constant]
+		type MaxStateLen: Get<u32>;
+		/// Number of blocks a player has to play their turn, after which the other players of the
+		/// board can claim victory.
+		#[pallet::constant]
+		type TurnTimeout: Get<BlockNumberFor<Self>>;
+		/// Number of blocks without any turn after which a board is resolved automatically in
+		/// favour of the player that played last.
+		#[pallet::constant]
+		type IdleBoardTimeout: Get<BlockNumberFor<Self>>;
+		/// Maximum number of idle boards resolved in a block, any further boards being resolved
+		/// in the following blocks.
+		#[pallet::constant]
+		type MaxIdleBoardsPerBlock: Get<u32>;
+		/// Weight of decoding and aborting the game of an idle board, on top of its storage
+		/// accesses.
+		#[pallet::constant]
+		type IdleBoardWeight: Get<Weight>;
+		/// Rating of players that have not finished a game of a kind yet.
 		#[pallet::constant]
-		type Players: Get<u32>;
+		type InitialRating: Get<Rating>;
+		/// Maximum change of a player's rating after a single game, known as the K-factor.
+		#[pallet::constant]
+		type RatingKFactor: Get<u32>;
+		/// Number of matchmaker brackets each game kind is split into by the rating of players.
+		#[pallet::constant]
+		type RatingBrackets: Get<u8>;
+		/// Range of ratings covered by each rating bracket, the last of which covers all higher
+		/// ratings.
+		#[pallet::constant]
+		type RatingBracketWidth: Get<Rating>;
+		/// Number of best rated players kept on the leaderboard of each game kind.
+		#[pallet::constant]
+		type LeaderboardSize: Get<u32>;
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper;
+		/// Weight information for the pallet's extrinsics.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	pub trait BenchmarkHelper {
+		/// The game kind under which Dot4Gravity is registered.
+		fn dot4gravity() -> GameKind;
 	}
 
 	#[pallet::pallet]
-	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::storage_version(migration::STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::event]
@@ -76,13 +140,50 @@ pub mod pallet {
 		/// Game has been created
 		GameCreated {
 			board_id: T::BoardId,
+			game: GameKind,
 			players: Vec<T::AccountId>,
+			stake: Option<StakeOf<T>>,
 		},
 		/// Game has finished with the winner
 		GameFinished {
 			board_id: T::BoardId,
 			winner: T::AccountId,
 		},
+		/// Game has finished in a draw
+		GameDrawn {
+			board_id: T::BoardId,
+		},
+		/// Game has finished with the players ranked from best to worst
+		GameRanked {
+			board_id: T::BoardId,
+			ranking: Vec<T::AccountId>,
+		},
+		/// Stakes of the losers have been paid to a winner
+		WinningsPaid {
+			board_id: T::BoardId,
+			winner: T::AccountId,
+			winnings: BalanceOf<T>,
+			fee: BalanceOf<T>,
+		},
+		/// A player did not play their turn in time
+		TurnTimedOut {
+			board_id: T::BoardId,
+			player: T::AccountId,
+		},
+		/// The ratings of the players of a finished game have been updated
+		RatingsUpdated {
+			board_id: T::BoardId,
+			game: GameKind,
+			ratings: Vec<(T::AccountId, Rating)>,
+		},
+		/// The stakes of a finished game could not be settled and have been refunded instead
+		StakesRefunded {
+			board_id: T::BoardId,
+		},
+		/// An idle game could not be resolved and has been cancelled, refunding its stakes
+		GameCancelled {
+			board_id: T::BoardId,
+		},
 
 		NoMatchFound,
 	}
@@ -97,6 +198,12 @@ pub mod pallet {
 		AlreadyQueued,
 		UnknownBoard,
 		BoardInUse,
+		UnknownGame,
+		TurnNotExpired,
+		NotWaiting,
+		InvalidStake,
+		InvalidBracket,
+		StakeUnavailable,
 	}
 
 	#[pallet::storage]
@@ -113,41 +220,134 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type Seed<T> = StorageValue<_, u32>;
 
+	/// Stakes of queued players, held until they are matched
+	#[pallet::storage]
+	pub type QueuedStakes<T: Config> = StorageMap<_, Identity, T::AccountId, StakeOf<T>>;
+
+	/// The stake wagered by each player of a board
+	#[pallet::storage]
+	pub type BoardStakes<T: Config> = StorageMap<_, Identity, T::BoardId, StakeOf<T>>;
+
+	/// Boards to resolve at a block, unless a turn is played on them before
+	#[pallet::storage]
+	pub type IdleBoards<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Identity, T::BoardId, ()>;
+
+	/// The earliest block with idle boards left over, which are resolved before those of later
+	/// blocks
+	#[pallet::storage]
+	pub type IdleBoardsBacklog<T: Config> = StorageValue<_, BlockNumberFor<T>>;
+
+	#[pallet::type_value]
+	pub fn DefaultRating<T: Config>() -> Rating {
+		T::InitialRating::get()
+	}
+
+	/// Rating of players in each game kind
+	#[pallet::storage]
+	pub type Ratings<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		GameKind,
+		Identity,
+		T::AccountId,
+		Rating,
+		ValueQuery,
+		DefaultRating<T>,
+	>;
+
+	/// The best rated players of each game kind, from best to worst
+	#[pallet::storage]
+	pub type Leaderboards<T: Config> = StorageMap<
+		_,
+		Identity,
+		GameKind,
+		BoundedVec<(T::AccountId, Rating), T::LeaderboardSize>,
+		ValueQuery,
+	>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			Self::resolve_idle_boards(now)
+		}
+
+		fn integrity_test() {
+			assert!(
+				T::TurnTimeout::get() <= T::IdleBoardTimeout::get(),
+				"Idle boards must not be resolved before turns can be claimed"
+			);
+			assert!(T::MaxIdleBoardsPerBlock::get() > 0, "Idle boards must be resolved eventually");
+			assert!(T::RatingBrackets::get() > 0, "Every game kind needs a rating bracket");
+			assert!(T::RatingBracketWidth::get() > 0, "Rating brackets must not be empty");
+			assert!(
+				(0..=GameKind::MAX).filter(|game| T::Games::game(*game).is_some()).all(|game| {
+					Self::game_brackets(game)
+						.is_some_and(|brackets| *brackets.end() < T::Matchmaker::brackets_count())
+				}),
+				"The matchmaker needs the rating brackets of every game kind"
+			);
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		#[pallet::weight({12_345})]
 		#[pallet::call_index(0)]
-		pub fn queue(origin: OriginFor<T>) -> DispatchResult {
+		pub fn queue(
+			origin: OriginFor<T>,
+			game: GameKind,
+			stake: Option<StakeOf<T>>,
+		) -> DispatchResult {
 			let player = ensure_signed(origin)?;
-			ensure!(
-				T::Matchmaker::add_queue(player, DEFAULT_BRACKET).is_ok(),
-				Error::<T>::AlreadyQueued
-			);
+			let registered_game = T::Games::game(game).ok_or(Error::<T>::UnknownGame)?;
+			ensure!(PlayerBoards::<T>::get(&player).is_none(), Error::<T>::AlreadyInGame);
+			ensure!(!T::Matchmaker::is_queued(player.clone()), Error::<T>::AlreadyQueued);
+			// Players are matched with players of the same game, preferring those of the same
+			// rating bracket.
+			let brackets = Self::game_brackets(game).ok_or(Error::<T>::InvalidBracket)?;
+			let bracket = Self::rating_bracket(game, &player).ok_or(Error::<T>::InvalidBracket)?;
+			T::Matchmaker::add_queue(player.clone(), bracket)?;
+
+			if let Some(stake) = stake {
+				ensure!(!stake.amount.is_zero(), Error::<T>::InvalidStake);
+				Self::escrow(&stake.asset, &player, stake.amount)?;
+				QueuedStakes::<T>::insert(&player, stake);
+			}
 
-			let players = T::Matchmaker::try_match();
+			let players = T::Matchmaker::try_match_in(brackets, registered_game.players);
 
-			if players.len() as u32 == T::Players::get() {
-				Self::create_game(players)?;
+			if players.len() == registered_game.players as usize {
+				Self::create_game(game, &registered_game, players)?;
 			}
 
 			Ok(())
 		}
 
-		#[pallet::weight({12_345})]
+		#[pallet::weight(T::WeightInfo::play())]
 		#[pallet::call_index(1)]
-		pub fn play(origin: OriginFor<T>, turn: T::PlayersTurn) -> DispatchResult {
+		pub fn play(origin: OriginFor<T>, turn: Vec<u8>) -> DispatchResult {
 			let player = ensure_signed(origin)?;
 			let board_id = PlayerBoards::<T>::get(&player).ok_or(Error::<T>::NotPlaying)?;
 
 			let mut board_game = BoardGames::<T>::get(board_id).ok_or(Error::<T>::UnknownBoard)?;
-			let new_state = T::Game::play_turn(player, board_game.state, turn)
+			let game = T::Games::game(board_game.game).ok_or(Error::<T>::UnknownGame)?;
+			let new_state = game
+				.play_turn(player, &board_game.state, &turn)
 				.ok_or(Error::<T>::InvalidTurn)?;
 
-			if let Finished::Winner::<T::AccountId>(winner) = T::Game::is_finished(&new_state) {
-				Self::finish_game(board_id, winner)?;
-			} else {
-				board_game.state = new_state;
-				BoardGames::<T>::insert(board_id, board_game);
+			match game.is_finished(&new_state).ok_or(Error::<T>::InvalidGameState)? {
+				Finished::No => {
+					let now = frame_system::Pallet::<T>::current_block_number();
+					IdleBoards::<T>::remove(Self::idle_deadline(board_game.last_turn), board_id);
+					IdleBoards::<T>::insert(Self::idle_deadline(now), board_id, ());
+
+					board_game.state =
+						new_state.try_into().map_err(|_| Error::<T>::InvalidGameState)?;
+					board_game.last_turn = now;
+					BoardGames::<T>::insert(board_id, board_game);
+				},
+				finished => Self::finish_game(board_id, finished, None)?,
 			}
 			Ok(())
 		}
@@ -176,45 +376,554 @@ pub mod pallet {
 			})
 			.map_err(|err| err.into())
 		}
+
+		#[pallet::weight(T::WeightInfo::claim_victory())]
+		#[pallet::call_index(3)]
+		pub fn claim_victory(origin: OriginFor<T>) -> DispatchResult {
+			let player = ensure_signed(origin)?;
+			let board_id = PlayerBoards::<T>::get(&player).ok_or(Error::<T>::NotPlaying)?;
+
+			let board_game = BoardGames::<T>::get(board_id).ok_or(Error::<T>::UnknownBoard)?;
+			let now = frame_system::Pallet::<T>::current_block_number();
+			ensure!(
+				now > board_game.last_turn.saturating_add(T::TurnTimeout::get()),
+				Error::<T>::TurnNotExpired
+			);
+
+			let game = T::Games::game(board_game.game).ok_or(Error::<T>::UnknownGame)?;
+			let stalling_player =
+				game.next_player(&board_game.state).ok_or(Error::<T>::InvalidGameState)?;
+			ensure!(stalling_player != player, Error::<T>::NotWaiting);
+
+			Self::abort_game(board_id, board_game, &game, stalling_player)
+		}
+
+		#[pallet::weight(
+			T::Matchmaker::remove_queue_weight()
+				.saturating_add(T::DbWeight::get().reads_writes(4, 4))
+		)]
+		#[pallet::call_index(4)]
+		pub fn leave_queue(origin: OriginFor<T>) -> DispatchResult {
+			let player = ensure_signed(origin)?;
+			T::Matchmaker::remove_queue(player.clone())?;
+
+			if let Some(stake) = QueuedStakes::<T>::take(&player) {
+				Self::release(&stake.asset, &player, stake.amount)?;
+			}
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
-	fn create_game(players: Vec<PlayerOf<T>>) -> DispatchResult {
+	fn create_game(
+		game: GameKind,
+		registered_game: &RegisteredGame<PlayerOf<T>>,
+		players: Vec<PlayerOf<T>>,
+	) -> DispatchResult {
 		for player in &players {
 			ensure!(PlayerBoards::<T>::get(player).is_none(), Error::<T>::AlreadyInGame);
 		}
 
 		let board_id = NextBoardId::<T>::get();
 		let seed = Seed::<T>::get();
-		let state = T::Game::init(&players, seed).ok_or(Error::<T>::InvalidGameState)?;
-		Self::seed_for_next(&state);
+		let state = registered_game.init(&players, seed).ok_or(Error::<T>::InvalidGameState)?;
+		Self::seed_for_next(registered_game, &state);
+		let state = state.try_into().map_err(|_| Error::<T>::InvalidGameState)?;
 
 		let bounded_players = players.clone().try_into().map_err(|_| Error::<T>::InvalidPlayers)?;
 		let now = frame_system::Pallet::<T>::current_block_number();
-		let board_game = BoardGameOf::<T>::new(board_id, bounded_players, state, now);
+		let board_game = BoardGameOf::<T>::new(board_id, game, bounded_players, state, now);
+		let stake = Self::wager_stakes(&players)?;
+		if let Some(stake) = &stake {
+			BoardStakes::<T>::insert(board_id, stake);
+		}
 
 		players.iter().for_each(|player| PlayerBoards::<T>::insert(player, board_id));
 		BoardGames::<T>::insert(board_id, board_game);
+		IdleBoards::<T>::insert(Self::idle_deadline(now), board_id, ());
 		NextBoardId::<T>::mutate(|board_id| board_id.saturating_inc());
-		Self::deposit_event(Event::GameCreated { board_id, players });
+		Self::deposit_event(Event::GameCreated { board_id, game, players, stake });
 		Ok(())
 	}
 
-	fn seed_for_next(game_state: &T::GameState) {
-		match T::Game::seed(game_state) {
+	fn seed_for_next(registered_game: &RegisteredGame<PlayerOf<T>>, game_state: &[u8]) {
+		match registered_game.seed(game_state) {
 			Some(seed) => Seed::<T>::put(seed),
 			None => Seed::<T>::kill(),
 		}
 	}
 
-	fn finish_game(board_id: T::BoardId, winner: PlayerOf<T>) -> DispatchResult {
-		BoardGames::<T>::get(board_id)
-			.ok_or(Error::<T>::UnknownBoard)?
-			.players
-			.iter()
-			.for_each(PlayerBoards::<T>::remove);
-		Self::deposit_event(Event::GameFinished { board_id, winner });
+	/// Finishes a game, settling its stakes between all players, or only with the forfeiting
+	/// player if there is one.
+	///
+	/// Stakes that cannot be settled, such as a house fee below the minimum balance of an asset the
+	/// house account does not hold, are refunded instead of keeping the players in the game.
+	fn finish_game(
+		board_id: T::BoardId,
+		result: Finished<PlayerOf<T>>,
+		forfeiting_player: Option<&PlayerOf<T>>,
+	) -> DispatchResult {
+		ensure!(result != Finished::No, Error::<T>::InvalidGameState);
+
+		let board_game = BoardGames::<T>::get(board_id).ok_or(Error::<T>::UnknownBoard)?;
+		Self::free_players(board_id, &board_game.players);
+		IdleBoards::<T>::remove(Self::idle_deadline(board_game.last_turn), board_id);
+		if let Some(stake) = BoardStakes::<T>::take(board_id) {
+			let owed = Self::owed_stakes(&board_game.players, &result, forfeiting_player, &stake);
+			let settled = with_storage_layer(|| Self::settle_stakes(board_id, &stake, owed));
+			if settled.is_err() {
+				Self::refund_stakes(&board_game.players, &stake);
+				Self::deposit_event(Event::StakesRefunded { board_id });
+			}
+		}
+		Self::update_ratings(board_id, &board_game, &result, forfeiting_player);
+
+		match result {
+			Finished::Winner(winner) =>
+				Self::deposit_event(Event::GameFinished { board_id, winner }),
+			Finished::Draw => Self::deposit_event(Event::GameDrawn { board_id }),
+			Finished::Ranking(ranking) =>
+				Self::deposit_event(Event::GameRanked { board_id, ranking }),
+			Finished::No => {},
+		}
 		Ok(())
 	}
+
+	/// Updates the ratings of the players of a finished game, only rating the forfeiting player
+	/// against the winner if there is one.
+	fn update_ratings(
+		board_id: T::BoardId,
+		board_game: &BoardGameOf<T>,
+		result: &Finished<PlayerOf<T>>,
+		forfeiting_player: Option<&PlayerOf<T>>,
+	) {
+		let game = board_game.game;
+		let players = &board_game.players;
+		let ratings = players.iter().map(|player| Ratings::<T>::get(game, player));
+		let ratings = rating::updated_ratings(
+			players,
+			&ratings.collect::<Vec<_>>(),
+			result,
+			forfeiting_player,
+			T::RatingKFactor::get(),
+		);
+
+		let ratings = players.iter().cloned().zip(ratings).collect::<Vec<_>>();
+		Leaderboards::<T>::mutate(game, |leaderboard| {
+			for (player, rating) in &ratings {
+				Ratings::<T>::insert(game, player, rating);
+				Self::place_on_leaderboard(leaderboard, player, *rating);
+			}
+		});
+		Self::deposit_event(Event::RatingsUpdated { board_id, game, ratings });
+	}
+
+	/// The matchmaker brackets of a game, which is split into `RatingBrackets` consecutive
+	/// brackets.
+	fn game_brackets(game: GameKind) -> Option<RangeInclusive<Bracket>> {
+		let first = game.checked_mul(T::RatingBrackets::get())?;
+		let last = first.checked_add(T::RatingBrackets::get().checked_sub(1)?)?;
+		Some(first..=last)
+	}
+
+	/// The matchmaker bracket a player queues in for a game, which depends on their rating in it.
+	fn rating_bracket(game: GameKind, player: &PlayerOf<T>) -> Option<Bracket> {
+		let brackets = Self::game_brackets(game)?;
+		let rating_bracket = Ratings::<T>::get(game, player)
+			.checked_div(T::RatingBracketWidth::get())?
+			.min(T::RatingBrackets::get().saturating_sub(1).into()) as Bracket;
+		brackets.start().checked_add(rating_bracket)
+	}
+
+	/// The rating of `account` in games of the given kind.
+	pub fn rating_of(game: GameKind, account: &T::AccountId) -> Rating {
+		Ratings::<T>::get(game, account)
+	}
+
+	/// At most `limit` players with the highest ratings in games of the given kind, from best to
+	/// worst, out of the `LeaderboardSize` players kept on its leaderboard. Players are only listed
+	/// once they have finished a game.
+	pub fn leaderboard(game: GameKind, limit: u32) -> Vec<(T::AccountId, Rating)> {
+		let mut leaderboard = Leaderboards::<T>::get(game).into_inner();
+		leaderboard.truncate(limit as usize);
+		leaderboard
+	}
+
+	/// Places a player on a leaderboard by their new rating, behind the players rated the same.
+	///
+	/// The lowest rated player drops off a full leaderboard, only returning to it once their rating
+	/// changes again.
+	fn place_on_leaderboard(
+		leaderboard: &mut BoundedVec<(T::AccountId, Rating), T::LeaderboardSize>,
+		player: &T::AccountId,
+		rating: Rating,
+	) {
+		leaderboard.retain(|(ranked, _)| ranked != player);
+		let position = leaderboard.partition_point(|(_, ranked_rating)| *ranked_rating >= rating);
+		let _ = leaderboard.force_insert_keep_left(position, (player.clone(), rating));
+	}
+
+	/// Aborts a game whose `stalling_player` did not play their turn in time, forfeiting it.
+	///
+	/// The other player wins a game of two players, while in games of more players the others
+	/// draw, sharing what the stalling player forfeits. Their game state is left as it was, since
+	/// games can only be aborted with a single winner.
+	fn abort_game(
+		board_id: T::BoardId,
+		mut board_game: BoardGameOf<T>,
+		game: &RegisteredGame<PlayerOf<T>>,
+		stalling_player: PlayerOf<T>,
+	) -> DispatchResult {
+		let mut others = board_game.players.iter().filter(|player| *player != &stalling_player);
+		let result = match (others.next(), others.next()) {
+			(Some(winner), None) => Finished::Winner(winner.clone()),
+			(Some(_), Some(_)) => Finished::Draw,
+			(None, _) => return Err(Error::<T>::InvalidPlayers.into()),
+		};
+
+		if let Finished::Winner(winner) = &result {
+			let state = game
+				.abort(&board_game.state, winner.clone())
+				.ok_or(Error::<T>::InvalidGameState)?;
+			board_game.state = state.try_into().map_err(|_| Error::<T>::InvalidGameState)?;
+			BoardGames::<T>::insert(board_id, board_game);
+		}
+
+		Self::deposit_event(Event::TurnTimedOut { board_id, player: stalling_player.clone() });
+		Self::finish_game(board_id, result, Some(&stalling_player))
+	}
+
+	/// Takes the stakes of matched players, returning the stake each of them wagers on their game.
+	///
+	/// Players wager the lowest of their stakes if all of them staked the same asset, otherwise
+	/// the game is played without a wager. Anything not wagered is released.
+	fn wager_stakes(players: &[PlayerOf<T>]) -> Result<Option<StakeOf<T>>, DispatchError> {
+		let stakes = players.iter().map(QueuedStakes::<T>::take).collect::<Vec<_>>();
+		let wager = stakes
+			.iter()
+			.try_fold(None::<StakeOf<T>>, |wager, stake| {
+				let stake = stake.as_ref()?;
+				match wager {
+					None => Some(Some(stake.clone())),
+					Some(wager) if wager.asset == stake.asset =>
+						Some(Some(Stake { amount: wager.amount.min(stake.amount), ..wager })),
+					Some(_) => None,
+				}
+			})
+			.flatten();
+
+		let wagered = wager.as_ref().map_or(Zero::zero(), |wager| wager.amount);
+		for (player, stake) in players.iter().zip(stakes) {
+			if let Some(stake) = stake {
+				Self::release(&stake.asset, player, stake.amount.saturating_sub(wagered))?;
+			}
+		}
+		Ok(wager)
+	}
+
+	/// Splits the pot wagered on a finished game into the parts owed to each player.
+	///
+	/// A winner is owed the stakes of all losers, or only the stake of the forfeiting player if
+	/// there is one, in which case the other losers get their stakes back. A draw gives every
+	/// player their stake back, unless a player forfeited, whose stake is shared by the others
+	/// with any rounding dust left to the forfeiting player. A ranking splits the pot in decreasing
+	/// parts from the best to the worst player, who gets nothing.
+	fn owed_stakes(
+		players: &[PlayerOf<T>],
+		result: &Finished<PlayerOf<T>>,
+		forfeiting_player: Option<&PlayerOf<T>>,
+		stake: &StakeOf<T>,
+	) -> Vec<(PlayerOf<T>, BalanceOf<T>)> {
+		let stake = stake.amount;
+		let pot = stake.saturating_mul((players.len() as u32).into());
+		let owed_by = |owed: &dyn Fn(&PlayerOf<T>) -> BalanceOf<T>| {
+			players.iter().map(|player| (player.clone(), owed(player))).collect::<Vec<_>>()
+		};
+
+		match result {
+			Finished::Winner(winner) => owed_by(&|player| {
+				if player == winner {
+					forfeiting_player.map_or(pot, |_| stake.saturating_add(stake))
+				} else if forfeiting_player.is_some_and(|forfeiting| player != forfeiting) {
+					stake
+				} else {
+					Zero::zero()
+				}
+			}),
+			Finished::Draw if players.len() > 1 && forfeiting_player.is_some() => {
+				let share = stake / (players.len() as u32).saturating_sub(1).into();
+				let shared = share.saturating_mul((players.len() as u32).saturating_sub(1).into());
+				owed_by(&|player| {
+					if Some(player) == forfeiting_player {
+						stake.saturating_sub(shared)
+					} else {
+						stake.saturating_add(share)
+					}
+				})
+			},
+			Finished::Ranking(ranking) if players.len() > 1 => {
+				// The player ranked at position `i` of `n` players is owed `n - 1 - i` parts.
+				let parts = |player: &PlayerOf<T>| {
+					ranking.iter().position(|ranked| ranked == player).map_or(0, |position| {
+						(players.len() as u32).saturating_sub(1).saturating_sub(position as u32)
+					})
+				};
+				let total_parts = players.iter().map(parts).sum::<u32>().max(1);
+				let mut owed = owed_by(&|player| {
+					pot.saturating_mul(parts(player).into()) / total_parts.into()
+				});
+
+				// Rounding dust goes to the best player.
+				let dust = owed.iter().fold(pot, |dust, (_, owed)| dust.saturating_sub(*owed));
+				if let Some((_, best)) =
+					owed.iter_mut().find(|(player, _)| ranking.first() == Some(player))
+				{
+					best.saturating_accrue(dust);
+				}
+				owed
+			},
+			_ => owed_by(&|_| stake),
+		}
+	}
+
+	/// Settles the stakes wagered on a board, so that every player ends up with the part of the pot
+	/// they are owed. Players owed less than their stake pay the difference to the players owed
+	/// more, minus the house fee, and the remainder of every stake is released.
+	fn settle_stakes(
+		board_id: T::BoardId,
+		stake: &StakeOf<T>,
+		owed: Vec<(PlayerOf<T>, BalanceOf<T>)>,
+	) -> DispatchResult {
+		let house_account = T::HouseAccount::get();
+		let mut payers = owed
+			.iter()
+			.filter(|(_, owed)| *owed < stake.amount)
+			.map(|(player, owed)| (player, stake.amount.saturating_sub(*owed)));
+		let mut payer = payers.next();
+
+		for (winner, owed) in owed.iter().filter(|(_, owed)| *owed > stake.amount) {
+			let mut due = owed.saturating_sub(stake.amount);
+			let (mut winnings, mut fee) = (BalanceOf::<T>::zero(), BalanceOf::<T>::zero());
+			while let Some((loser, left)) = payer.as_mut().filter(|_| !due.is_zero()) {
+				let amount = due.min(*left);
+				let amount_fee = T::HouseFee::get().mul_floor(amount);
+				Self::pay(&stake.asset, *loser, winner, amount.saturating_sub(amount_fee))?;
+				Self::pay(&stake.asset, *loser, &house_account, amount_fee)?;
+
+				winnings.saturating_accrue(amount.saturating_sub(amount_fee));
+				fee.saturating_accrue(amount_fee);
+				due.saturating_reduce(amount);
+				left.saturating_reduce(amount);
+				if left.is_zero() {
+					payer = payers.next();
+				}
+			}
+			Self::deposit_event(Event::WinningsPaid {
+				board_id,
+				winner: winner.clone(),
+				winnings,
+				fee,
+			});
+		}
+
+		owed.iter().try_for_each(|(player, owed)| {
+			Self::release(&stake.asset, player, stake.amount.min(*owed))
+		})
+	}
+
+	/// Gives every player their stake back, as far as it is still escrowed.
+	fn refund_stakes(players: &[PlayerOf<T>], stake: &StakeOf<T>) {
+		for player in players {
+			let _ = with_storage_layer(|| Self::release(&stake.asset, player, stake.amount));
+		}
+	}
+
+	fn escrow_account() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	/// The identifier native stakes are reserved under.
+	fn reserve_id() -> [u8; 8] {
+		T::PalletId::get().0
+	}
+
+	/// Reserves native stakes or moves asset stakes to the escrow account.
+	fn escrow(
+		asset: &StakeAsset<T::AssetId>,
+		who: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		match asset {
+			StakeAsset::Native => T::Currency::reserve_named(&Self::reserve_id(), who, amount),
+			StakeAsset::Asset(asset_id) => <T::Fungibles as fungibles::Mutate<_>>::transfer(
+				asset_id.clone(),
+				who,
+				&Self::escrow_account(),
+				amount,
+				Expendable,
+			)
+			.map(|_| ()),
+		}
+	}
+
+	/// Gives an escrowed stake back to its owner, failing if not all of it is escrowed.
+	fn release(
+		asset: &StakeAsset<T::AssetId>,
+		who: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(())
+		}
+		match asset {
+			StakeAsset::Native => {
+				let left = T::Currency::unreserve_named(&Self::reserve_id(), who, amount);
+				ensure!(left.is_zero(), Error::<T>::StakeUnavailable);
+				Ok(())
+			},
+			StakeAsset::Asset(asset_id) => <T::Fungibles as fungibles::Mutate<_>>::transfer(
+				asset_id.clone(),
+				&Self::escrow_account(),
+				who,
+				amount,
+				Expendable,
+			)
+			.map(|_| ()),
+		}
+	}
+
+	/// Pays an escrowed stake of `from` to `to`, failing if not all of it is escrowed.
+	fn pay(
+		asset: &StakeAsset<T::AssetId>,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(())
+		}
+		match asset {
+			StakeAsset::Native => {
+				let unpaid = T::Currency::repatriate_reserved_named(
+					&Self::reserve_id(),
+					from,
+					to,
+					amount,
+					BalanceStatus::Free,
+				)?;
+				ensure!(unpaid.is_zero(), Error::<T>::StakeUnavailable);
+				Ok(())
+			},
+			StakeAsset::Asset(_) => Self::release(asset, to, amount),
+		}
+	}
+
+	fn idle_deadline(last_turn: BlockNumberFor<T>) -> BlockNumberFor<T> {
+		last_turn.saturating_add(T::IdleBoardTimeout::get())
+	}
+
+	/// Resolves the boards on which no turn has been played for `IdleBoardTimeout` blocks, with
+	/// the player whose turn it is forfeiting the game.
+	///
+	/// At most `MaxIdleBoardsPerBlock` boards are resolved in a block, starting with those left
+	/// over from earlier blocks. Catching up on them visits at most as many blocks.
+	fn resolve_idle_boards(now: BlockNumberFor<T>) -> Weight {
+		let db_weight = T::DbWeight::get();
+		let players = T::MaxPlayers::get() as u64;
+		// Each board reads and writes itself, its stake, the leaderboard and the ratings and
+		// balances of its players and the house account.
+		let board_weight = T::IdleBoardWeight::get()
+			.saturating_add(db_weight.reads_writes(4 + 2 * players, 6 + 3 * players));
+
+		let max_boards = T::MaxIdleBoardsPerBlock::get();
+		let mut budget = max_boards as usize;
+		let mut block = IdleBoardsBacklog::<T>::get().unwrap_or(now);
+		let mut backlog = None;
+		let mut weight = db_weight.reads_writes(1, 1);
+
+		for visited in 1..=max_boards {
+			let board_ids = IdleBoards::<T>::iter_key_prefix(block).take(budget + 1);
+			let board_ids = board_ids.collect::<Vec<_>>();
+			weight.saturating_accrue(db_weight.reads(1 + board_ids.len() as u64));
+
+			let left_over = board_ids.len() > budget;
+			for board_id in board_ids.into_iter().take(budget) {
+				IdleBoards::<T>::remove(block, board_id);
+				if with_storage_layer(|| Self::resolve_idle_board(board_id)).is_err() {
+					Self::cancel_game(board_id);
+				}
+				weight.saturating_accrue(board_weight);
+				budget.saturating_dec();
+			}
+
+			if left_over {
+				backlog = Some(block);
+				break
+			}
+			if block >= now {
+				break
+			}
+			block.saturating_inc();
+			if visited == max_boards {
+				backlog = Some(block);
+			}
+		}
+
+		IdleBoardsBacklog::<T>::set(backlog);
+		weight
+	}
+
+	fn resolve_idle_board(board_id: T::BoardId) -> DispatchResult {
+		let board_game = BoardGames::<T>::get(board_id).ok_or(Error::<T>::UnknownBoard)?;
+		let game = T::Games::game(board_game.game).ok_or(Error::<T>::UnknownGame)?;
+		let stalling_player =
+			game.next_player(&board_game.state).ok_or(Error::<T>::InvalidGameState)?;
+		Self::abort_game(board_id, board_game, &game, stalling_player)
+	}
+
+	/// Frees the players that are still on a board, leaving those that have moved on to another.
+	fn free_players(board_id: T::BoardId, players: &[PlayerOf<T>]) {
+		for player in players {
+			if PlayerBoards::<T>::get(player) == Some(board_id) {
+				PlayerBoards::<T>::remove(player);
+			}
+		}
+	}
+
+	/// Cancels a game that cannot be resolved, refunding its stakes and freeing its players, so
+	/// that the board can be cleared.
+	fn cancel_game(board_id: T::BoardId) {
+		let Some(board_game) = BoardGames::<T>::get(board_id) else { return };
+		Self::free_players(board_id, &board_game.players);
+		if let Some(stake) = BoardStakes::<T>::take(board_id) {
+			Self::refund_stakes(&board_game.players, &stake);
+		}
+		Self::deposit_event(Event::GameCancelled { board_id });
+	}
+}
+
+impl<T: Config> MatchHandler<T::AccountId> for Pallet<T> {
+	fn matching_brackets(bracket: Bracket) -> Option<(RangeInclusive<Bracket>, u8)> {
+		let game = bracket.checked_div(T::RatingBrackets::get())?;
+		let registered_game = T::Games::game(game)?;
+		Some((Self::game_brackets(game)?, registered_game.players))
+	}
+
+	fn on_match(bracket: Bracket, players: Vec<T::AccountId>) -> DispatchResult {
+		let game = bracket
+			.checked_div(T::RatingBrackets::get())
+			.ok_or(Error::<T>::InvalidBracket)?;
+		let registered_game = T::Games::game(game).ok_or(Error::<T>::UnknownGame)?;
+		ensure!(players.len() == registered_game.players as usize, Error::<T>::InvalidPlayers);
+		Self::create_game(game, &registered_game, players)
+	}
+
+	fn on_match_weight(players: u8) -> Weight {
+		// Creating a game reads the next board id and seed, writes them along with the board, its
+		// stake and deadline, and reads and writes the board, queued stake and balance of every
+		// player.
+		let players = players as u64;
+		T::DbWeight::get().reads_writes(2 + 3 * players, 5 + 3 * players)
+	}
 }
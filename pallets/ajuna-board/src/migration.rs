@@ -0,0 +1,106 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_support::{migrations::VersionedMigration, traits::UncheckedOnRuntimeUpgrade};
+use sp_std::marker::PhantomData;
+
+/// The current storage version.
+pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
+pub mod v2 {
+	use super::*;
+
+	/// A board game from before the game registry, when every board played the same game.
+	#[derive(Decode)]
+	struct OldBoardGame<BoardId, State, AccountId, BlockNumber> {
+		board_id: BoardId,
+		players: Vec<AccountId>,
+		state: State,
+		started: BlockNumber,
+	}
+
+	/// Registers the boards created before the game registry as boards of the game `Game`,
+	/// storing their `OldState` in its encoded form.
+	///
+	/// The turn timeout of every unfinished board whose players are still on it restarts at the
+	/// upgrade, after which idle boards are resolved as usual. Finished or abandoned boards are
+	/// kept for clearing but not scheduled. Boards that no longer fit the configured bounds are
+	/// dropped, freeing the players still on them.
+	pub struct UncheckedMigrateToV2<T, OldState, Game>(PhantomData<(T, OldState, Game)>);
+
+	impl<T, OldState, Game> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV2<T, OldState, Game>
+	where
+		T: Config,
+		OldState: Encode + Decode,
+		Game: Get<GameKind>,
+	{
+		fn on_runtime_upgrade() -> Weight {
+			let now = frame_system::Pallet::<T>::current_block_number();
+			let registered_game = T::Games::game(Game::get());
+			let (mut reads, mut writes) = (0_u64, 0_u64);
+
+			BoardGames::<T>::translate::<
+				OldBoardGame<T::BoardId, OldState, T::AccountId, BlockNumberFor<T>>,
+				_,
+			>(|board_id, old| {
+				reads.saturating_accrue(1 + old.players.len() as u64);
+				let on_board: Vec<_> = old
+					.players
+					.iter()
+					.map(|player| PlayerBoards::<T>::get(player) == Some(board_id))
+					.collect();
+
+				let players = BoundedPlayersOf::<T>::try_from(old.players.clone()).ok();
+				let state = BoundedStateOf::<T>::try_from(old.state.encode()).ok();
+				let (Some(players), Some(state)) = (players, state) else {
+					for (player, on_board) in old.players.iter().zip(on_board) {
+						if on_board {
+							PlayerBoards::<T>::remove(player);
+							writes.saturating_inc();
+						}
+					}
+					writes.saturating_inc();
+					return None
+				};
+
+				let unfinished = registered_game
+					.as_ref()
+					.and_then(|game| game.is_finished(&state))
+					.is_some_and(|finished| finished == Finished::No);
+				let mut board_game =
+					BoardGameOf::<T>::new(old.board_id, Game::get(), players, state, old.started);
+				board_game.last_turn = now;
+				if unfinished && on_board.into_iter().all(|on_board| on_board) {
+					IdleBoards::<T>::insert(Pallet::<T>::idle_deadline(now), board_id, ());
+					writes.saturating_inc();
+				}
+				writes.saturating_inc();
+				Some(board_game)
+			});
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+	}
+
+	pub type MigrateToV2<T, OldState, Game> = VersionedMigration<
+		1,
+		2,
+		UncheckedMigrateToV2<T, OldState, Game>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
@@ -14,16 +14,22 @@
 // You should have received a copy of the GNU Affero General Public License
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
-use crate::{self as pallet_ajuna_board};
+use crate::{
+	self as pallet_ajuna_board, Finished, GameKind, Rating, RegisteredGame, TurnBasedGame,
+};
 use frame_support::{
 	parameter_types,
-	traits::{ConstU16, ConstU64},
+	traits::{AsEnsureOriginWithArg, ConstU16, ConstU32, ConstU64, Hooks},
+	weights::Weight,
+	PalletId,
 };
+use frame_system::{EnsureRoot, EnsureSigned};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use sp_core::H256;
 use sp_runtime::{
 	testing::TestSignature,
 	traits::{BlakeTwo256, IdentifyAccount, IdentityLookup, Verify},
-	BuildStorage,
+	BuildStorage, Permill,
 };
 use sp_std::prelude::*;
 
@@ -32,10 +38,24 @@ pub type MockSignature = TestSignature;
 pub type MockAccountPublic = <MockSignature as Verify>::Signer;
 pub type MockAccountId = <MockAccountPublic as IdentifyAccount>::AccountId;
 pub type MockNonce = u64;
+pub type MockBalance = u64;
+pub type MockAssetId = u32;
+
+pub const ALICE: MockAccountId = 1;
+pub const BOB: MockAccountId = 2;
+pub const CHARLIE: MockAccountId = 3;
+pub const DAVE: MockAccountId = 4;
+pub const ERIN: MockAccountId = 5;
+pub const HOUSE: MockAccountId = 99;
+
+pub const ASSET_ID: MockAssetId = 1;
+pub const INITIAL_BALANCE: MockBalance = 1_000_000;
 
 frame_support::construct_runtime!(
 	pub struct Test {
 		System: frame_system,
+		Balances: pallet_balances,
+		Assets: pallet_assets,
 		AjunaMatchmaker: pallet_ajuna_matchmaker,
 		AjunaBoard: pallet_ajuna_board,
 	}
@@ -61,7 +81,7 @@ impl frame_system::Config for Test {
 	type BlockHashCount = ConstU64<250>;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<MockBalance>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -78,31 +98,253 @@ impl frame_system::Config for Test {
 	type PostTransactions = ();
 }
 
+parameter_types! {
+	pub const MockExistentialDeposit: MockBalance = 3;
+}
+
+impl pallet_balances::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeHoldReason = ();
+	type RuntimeFreezeReason = ();
+	type WeightInfo = ();
+	type Balance = MockBalance;
+	type DustRemoval = ();
+	type ExistentialDeposit = MockExistentialDeposit;
+	type AccountStore = System;
+	type ReserveIdentifier = [u8; 8];
+	type FreezeIdentifier = ();
+	type MaxLocks = ();
+	type MaxReserves = ConstU32<2>;
+	type MaxFreezes = ();
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct AssetHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl<AssetId: From<u32>> pallet_assets::BenchmarkHelper<AssetId> for AssetHelper {
+	fn create_asset_id_parameter(id: u32) -> AssetId {
+		id.into()
+	}
+}
+
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = MockBalance;
+	type RemoveItemsLimit = frame_support::traits::ConstU32<1000>;
+	type AssetId = MockAssetId;
+	type AssetIdParameter = parity_scale_codec::Compact<MockAssetId>;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<MockAccountId>>;
+	type ForceOrigin = EnsureRoot<MockAccountId>;
+	type AssetDeposit = ConstU64<1>;
+	type AssetAccountDeposit = ConstU64<1>;
+	type MetadataDepositBase = ConstU64<1>;
+	type MetadataDepositPerByte = ConstU64<1>;
+	type ApprovalDeposit = ConstU64<1>;
+	type StringLimit = frame_support::traits::ConstU32<20>;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = ();
+	pallet_assets::runtime_benchmarks_enabled! {
+		type BenchmarkHelper = AssetHelper;
+	}
+}
+
 parameter_types! {
 	pub const Players: u8 = 2;
-	pub const Brackets: u8 = 1;
+	pub const Brackets: u8 = 4;
+	pub const MaxQueueSize: u16 = 100;
+	pub const WideningPeriod: u64 = 10;
 }
 
 impl pallet_ajuna_matchmaker::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type AmountPlayers = Players;
 	type AmountBrackets = Brackets;
+	type MaxQueueSize = MaxQueueSize;
+	type WideningPeriod = WideningPeriod;
+	type MatchHandler = AjunaBoard;
+}
+
+pub const DOT4GRAVITY: GameKind = 0;
+pub const GUESSING: GameKind = 1;
+pub const THE_NUMBER: u32 = 42;
+pub const DRAW_NUMBER: u32 = 100;
+pub const RANKING_NUMBER: u32 = 200;
+
+/// Players take turns guessing a number, the first to guess it wins. Guessing the draw number
+/// ends the game in a draw, while guessing the ranking number ranks the players in turn order
+/// starting from the guesser.
+pub struct GuessingGame;
+
+#[derive(Encode, Decode)]
+pub struct GuessingState {
+	players: Vec<MockAccountId>,
+	next_player: u8,
+	winner: Option<MockAccountId>,
+	drawn: bool,
+	ranking: Vec<MockAccountId>,
+}
+
+impl TurnBasedGame for GuessingGame {
+	type Turn = u32;
+	type Player = MockAccountId;
+	type State = GuessingState;
+
+	fn init(players: &[Self::Player], _seed: Option<u32>) -> Option<Self::State> {
+		Some(GuessingState {
+			players: players.to_vec(),
+			next_player: 0,
+			winner: None,
+			drawn: false,
+			ranking: Vec::new(),
+		})
+	}
+
+	fn get_last_player(state: &Self::State) -> Self::Player {
+		let last_player = state.next_player as usize + state.players.len() - 1;
+		state.players[last_player % state.players.len()]
+	}
+
+	fn get_next_player(state: &Self::State) -> Self::Player {
+		state.players[state.next_player as usize]
+	}
+
+	fn play_turn(
+		player: Self::Player,
+		mut state: Self::State,
+		turn: Self::Turn,
+	) -> Option<Self::State> {
+		if Self::is_finished(&state) != Finished::No || Self::get_next_player(&state) != player {
+			return None
+		}
+		let guesser = state.next_player as usize;
+		state.next_player = (state.next_player + 1) % state.players.len() as u8;
+		match turn {
+			THE_NUMBER => state.winner = Some(player),
+			DRAW_NUMBER => state.drawn = true,
+			RANKING_NUMBER => {
+				state.ranking = state.players[guesser..].to_vec();
+				state.ranking.extend_from_slice(&state.players[..guesser]);
+			},
+			_ => {},
+		}
+		Some(state)
+	}
+
+	fn abort(mut state: Self::State, winner: Self::Player) -> Self::State {
+		state.winner = Some(winner);
+		state
+	}
+
+	fn is_finished(state: &Self::State) -> Finished<Self::Player> {
+		match state.winner {
+			Some(winner) => Finished::Winner(winner),
+			None if state.drawn => Finished::Draw,
+			None if !state.ranking.is_empty() => Finished::Ranking(state.ranking.clone()),
+			None => Finished::No,
+		}
+	}
+
+	fn seed(_state: &Self::State) -> Option<u32> {
+		None
+	}
+}
+
+pub struct MockGames;
+
+impl crate::GameRegistry<MockAccountId> for MockGames {
+	fn game(kind: GameKind) -> Option<RegisteredGame<MockAccountId>> {
+		match kind {
+			DOT4GRAVITY => Some(RegisteredGame::new::<crate::dot4gravity::Game<MockAccountId>>(2)),
+			GUESSING => Some(RegisteredGame::new::<GuessingGame>(3)),
+			_ => None,
+		}
+	}
+}
+
+parameter_types! {
+	pub const MaxPlayers: u32 = 3;
+	pub MaxStateLen: u32 =
+		crate::dot4gravity::GameState::<MockAccountId>::max_encoded_len() as u32;
+	pub const TurnTimeout: u64 = 10;
+	pub const IdleBoardTimeout: u64 = 20;
+	pub const MaxIdleBoardsPerBlock: u32 = 2;
+	pub const IdleBoardWeight: Weight = Weight::from_parts(10_000_000, 0);
+	pub const BoardPalletId: PalletId = PalletId(*b"aj/board");
+	pub const HouseFee: Permill = Permill::from_percent(10);
+	pub const HouseAccount: MockAccountId = HOUSE;
+	pub const InitialRating: Rating = 1_500;
+	pub const RatingKFactor: u32 = 32;
+	pub const RatingBrackets: u8 = 2;
+	pub const RatingBracketWidth: Rating = 1_600;
+	pub const LeaderboardSize: u32 = 2;
 }
 
 impl pallet_ajuna_board::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Matchmaker = AjunaMatchmaker;
+	type PalletId = BoardPalletId;
+	type Currency = Balances;
+	type AssetId = MockAssetId;
+	type Fungibles = Assets;
+	type HouseFee = HouseFee;
+	type HouseAccount = HouseAccount;
 	type BoardId = u32;
-	type PlayersTurn = crate::dot4gravity::Turn;
-	type GameState = crate::dot4gravity::GameState<MockAccountId>;
-	type Game = crate::dot4gravity::Game<MockAccountId>;
-	type Players = Players;
+	type Games = MockGames;
+	type MaxPlayers = MaxPlayers;
+	type MaxStateLen = MaxStateLen;
+	type TurnTimeout = TurnTimeout;
+	type IdleBoardTimeout = IdleBoardTimeout;
+	type MaxIdleBoardsPerBlock = MaxIdleBoardsPerBlock;
+	type IdleBoardWeight = IdleBoardWeight;
+	type InitialRating = InitialRating;
+	type RatingKFactor = RatingKFactor;
+	type RatingBrackets = RatingBrackets;
+	type RatingBracketWidth = RatingBracketWidth;
+	type LeaderboardSize = LeaderboardSize;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = BoardBenchmarkHelper;
+	type WeightInfo = ();
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct BoardBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper for BoardBenchmarkHelper {
+	fn dot4gravity() -> GameKind {
+		DOT4GRAVITY
+	}
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let players = [ALICE, BOB, CHARLIE, DAVE, ERIN];
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: players.iter().chain([&HOUSE]).map(|who| (*who, INITIAL_BALANCE)).collect(),
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
 	let mut ext = sp_io::TestExternalities::new(t);
-	ext.execute_with(|| System::set_block_number(1));
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		Assets::force_create(RuntimeOrigin::root(), ASSET_ID.into(), HOUSE, true, 1).unwrap();
+		for player in players {
+			Assets::mint(RuntimeOrigin::signed(HOUSE), ASSET_ID.into(), player, INITIAL_BALANCE)
+				.unwrap();
+		}
+	});
 	ext
 }
+
+pub fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		let next_block = System::block_number() + 1;
+		System::set_block_number(next_block);
+		AjunaMatchmaker::on_initialize(next_block);
+		AjunaBoard::on_initialize(next_block);
+	}
+}
@@ -0,0 +1,228 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Elo ratings of board game players.
+//!
+//! Scores are expressed in parts per million, so that ratings can be updated without floating
+//! point arithmetic.
+
+use crate::Finished;
+use sp_std::vec::Vec;
+
+/// The Elo rating of a player.
+pub type Rating = u32;
+
+/// Score of a won game, in parts per million.
+const WIN: i64 = 1_000_000;
+/// Score of a drawn game, in parts per million.
+const DRAW: i64 = 500_000;
+
+/// Rating difference between two entries of [`EXPECTED_SCORES`].
+const STEP: Rating = 25;
+
+/// Expected score of a player rated `STEP * i` above their opponent, in parts per million, which
+/// is `1 / (1 + 10^(-STEP * i / 400))`. Differences beyond the last entry are capped.
+const EXPECTED_SCORES: [i64; 33] = [
+	500_000, 535_916, 571_463, 606_288, 640_065, 672_510, 703_385, 732_507, 759_747, 785_027,
+	808_318, 829_633, 849_020, 866_557, 882_338, 896_477, 909_091, 920_305, 930_242, 939_022,
+	946_760, 953_565, 959_537, 964_769, 969_347, 973_346, 976_836, 979_878, 982_528, 984_834,
+	986_840, 988_584, 990_099,
+];
+
+/// Expected score of a player with `rating` against an opponent with `opponent_rating`, in parts
+/// per million.
+pub(crate) fn expected_score(rating: Rating, opponent_rating: Rating) -> i64 {
+	let difference = rating.abs_diff(opponent_rating);
+	let index = (difference / STEP) as usize;
+	let score = match (EXPECTED_SCORES.get(index), EXPECTED_SCORES.get(index + 1)) {
+		(Some(lower), Some(upper)) => {
+			let remainder = (difference % STEP) as i64;
+			lower + (upper - lower) * remainder / STEP as i64
+		},
+		_ => EXPECTED_SCORES[EXPECTED_SCORES.len() - 1],
+	};
+
+	if rating >= opponent_rating {
+		score
+	} else {
+		WIN - score
+	}
+}
+
+/// Computes the new ratings of `players` after a finished game, given their current `ratings` in
+/// the same order.
+///
+/// Players are rated on the pairings the game consists of, each of which moves the ratings of its
+/// two players by the same amount in opposite directions. The amounts are scaled down by the
+/// largest number of pairings any player has, so that no rating moves by more than the K-factor. A
+/// winner is paired with each loser, or only with the forfeiting player if there is one, a draw
+/// pairs all players with each other, unless a player forfeited, who then loses to each of the
+/// others, and a ranking pairs every player with all players ranked below them. Players missing
+/// from a ranking are ranked last, drawing with each other.
+pub(crate) fn updated_ratings<Player: PartialEq>(
+	players: &[Player],
+	ratings: &[Rating],
+	result: &Finished<Player>,
+	forfeiting_player: Option<&Player>,
+	k_factor: u32,
+) -> Vec<Rating> {
+	let position = |player: &Player| players.iter().position(|p| p == player);
+	let mut pairings = Vec::new();
+	match result {
+		Finished::No => {},
+		Finished::Winner(winner) => {
+			let Some(winner) = position(winner) else { return ratings.to_vec() };
+			for (loser, player) in players.iter().enumerate() {
+				let forfeited = match forfeiting_player {
+					Some(forfeiting) => player == forfeiting,
+					None => true,
+				};
+				if loser != winner && forfeited {
+					pairings.push((winner, loser, WIN));
+				}
+			}
+		},
+		Finished::Draw => match forfeiting_player.and_then(position) {
+			Some(forfeiting) =>
+				for other in (0..players.len()).filter(|other| *other != forfeiting) {
+					pairings.push((other, forfeiting, WIN));
+				},
+			None =>
+				for first in 0..players.len() {
+					for second in first + 1..players.len() {
+						pairings.push((first, second, DRAW));
+					}
+				},
+		},
+		Finished::Ranking(ranking) => {
+			let rank = |player: &Player| {
+				ranking.iter().position(|ranked| ranked == player).unwrap_or(ranking.len())
+			};
+			for first in 0..players.len() {
+				for second in first + 1..players.len() {
+					let score = match rank(&players[first]).cmp(&rank(&players[second])) {
+						core::cmp::Ordering::Less => WIN,
+						core::cmp::Ordering::Equal => DRAW,
+						core::cmp::Ordering::Greater => 0,
+					};
+					pairings.push((first, second, score));
+				}
+			}
+		},
+	}
+
+	let mut player_pairings = sp_std::vec![0_i64; players.len()];
+	for (first, second, _) in &pairings {
+		player_pairings[*first] += 1;
+		player_pairings[*second] += 1;
+	}
+	let scale = player_pairings.into_iter().max().unwrap_or_default().max(1) * WIN;
+
+	let mut changes = sp_std::vec![0_i64; players.len()];
+	for (first, second, score) in pairings {
+		let surprise = score - expected_score(ratings[first], ratings[second]);
+		let change = k_factor as i64 * surprise / scale;
+		changes[first] += change;
+		changes[second] -= change;
+	}
+
+	ratings
+		.iter()
+		.zip(changes)
+		.map(|(rating, change)| (*rating as i64 + change).clamp(0, Rating::MAX as i64) as Rating)
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const K: u32 = 32;
+
+	#[test]
+	fn expected_score_follows_the_elo_curve() {
+		assert_eq!(expected_score(1500, 1500), 500_000);
+		assert_eq!(expected_score(1600, 1500), 640_065);
+		assert_eq!(expected_score(1500, 1600), 359_935);
+		// in between two steps the score is interpolated
+		assert_eq!(expected_score(1510, 1500), 514_366);
+		// large differences are capped
+		assert_eq!(expected_score(3000, 1000), 990_099);
+		assert_eq!(expected_score(0, 2000), 9_901);
+	}
+
+	#[test]
+	fn winners_gain_what_losers_lose() {
+		let result = Finished::Winner(1);
+		assert_eq!(updated_ratings(&[1, 2], &[1500, 1500], &result, None, K), [1516, 1484]);
+		assert_eq!(updated_ratings(&[1, 2], &[1700, 1500], &result, None, K), [1707, 1493]);
+		assert_eq!(updated_ratings(&[1, 2], &[1500, 1700], &result, None, K), [1524, 1676]);
+		// ratings don't drop below zero
+		assert_eq!(updated_ratings(&[1, 2], &[10, 10], &Finished::Winner(2), None, K), [0, 26]);
+		// the winner of a game of more players gains what all losers lose together
+		assert_eq!(
+			updated_ratings(&[1, 2, 3], &[1500, 1500, 1500], &result, None, K),
+			[1516, 1492, 1492]
+		);
+		assert_eq!(
+			updated_ratings(&[1, 2, 3], &[1700, 1500, 1600], &result, None, K),
+			[1708, 1497, 1595]
+		);
+	}
+
+	#[test]
+	fn only_forfeiting_players_lose_rating() {
+		let result = Finished::Winner(1);
+		assert_eq!(
+			updated_ratings(&[1, 2, 3], &[1500, 1500, 1500], &result, Some(&2), K),
+			[1516, 1484, 1500]
+		);
+	}
+
+	#[test]
+	fn draws_move_ratings_towards_each_other() {
+		let result = Finished::Draw;
+		assert_eq!(updated_ratings(&[1, 2], &[1500, 1500], &result, None, K), [1500, 1500]);
+		assert_eq!(updated_ratings(&[1, 2], &[1600, 1500], &result, None, K), [1596, 1504]);
+	}
+
+	#[test]
+	fn forfeited_draws_pair_the_forfeiting_player_with_the_others() {
+		let result = Finished::Draw;
+		assert_eq!(
+			updated_ratings(&[1, 2, 3], &[1500, 1500, 1500], &result, Some(&2), K),
+			[1508, 1484, 1508]
+		);
+	}
+
+	#[test]
+	fn rankings_pair_all_players() {
+		let result = Finished::Ranking(sp_std::vec![3, 1]);
+		assert_eq!(
+			updated_ratings(&[1, 2, 3], &[1500, 1500, 1500], &result, None, K),
+			[1500, 1484, 1516]
+		);
+	}
+
+	#[test]
+	fn unfinished_games_keep_ratings() {
+		assert_eq!(updated_ratings(&[1, 2], &[1500, 1400], &Finished::No, None, K), [1500, 1400]);
+		assert_eq!(
+			updated_ratings(&[1, 2], &[1500, 1400], &Finished::Winner(3), None, K),
+			[1500, 1400]
+		);
+	}
+}
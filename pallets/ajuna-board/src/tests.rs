@@ -17,10 +17,6 @@
 use crate::{dot4gravity::*, mock::*, *};
 use frame_support::{assert_noop, assert_ok};
 
-const ALICE: MockAccountId = 1;
-const BOB: MockAccountId = 2;
-const ERIN: MockAccountId = 5;
-
 const BOARD_ID: u32 = 0;
 const TEST_COORD: Coordinates = Coordinates::new(0, 0);
 // The seed below generates the following board, where o is empty and x is block:
@@ -39,13 +35,86 @@ const TEST_SEED: u32 = 7357;
 #[test]
 fn queue_works() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(AjunaBoard::queue(RuntimeOrigin::signed(ALICE)));
+		assert_ok!(AjunaBoard::queue(RuntimeOrigin::signed(ALICE), DOT4GRAVITY, None));
 		System::assert_last_event(RuntimeEvent::AjunaMatchmaker(
 			pallet_ajuna_matchmaker::Event::Queued(pallet_ajuna_matchmaker::PlayerStruct {
 				account: ALICE,
+				queued_at: 1,
 			}),
 		));
-		assert_noop!(AjunaBoard::queue(RuntimeOrigin::signed(ALICE)), Error::<Test>::AlreadyQueued);
+		assert_noop!(
+			AjunaBoard::queue(RuntimeOrigin::signed(ALICE), DOT4GRAVITY, None),
+			Error::<Test>::AlreadyQueued
+		);
+	});
+}
+
+#[test]
+fn queue_reports_matchmaker_errors() {
+	new_test_ext().execute_with(|| {
+		pallet_ajuna_matchmaker::BracketsCount::<Test>::put(RatingBrackets::get());
+		assert_noop!(
+			AjunaBoard::queue(RuntimeOrigin::signed(ALICE), GUESSING, None),
+			pallet_ajuna_matchmaker::Error::<Test>::UnknownBracket
+		);
+	});
+}
+
+#[test]
+fn queue_rejects_unknown_games() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AjunaBoard::queue(RuntimeOrigin::signed(ALICE), 7, None),
+			Error::<Test>::UnknownGame
+		);
+	});
+}
+
+#[test]
+fn queue_matches_players_of_the_same_game() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AjunaBoard::queue(RuntimeOrigin::signed(ALICE), DOT4GRAVITY, None));
+		assert_ok!(AjunaBoard::queue(RuntimeOrigin::signed(BOB), GUESSING, None));
+		assert_ok!(AjunaBoard::queue(RuntimeOrigin::signed(CHARLIE), GUESSING, None));
+		assert!(BoardGames::<Test>::get(BOARD_ID).is_none());
+
+		// the guessing game starts once its third player is queued
+		assert_ok!(AjunaBoard::queue(RuntimeOrigin::signed(DAVE), GUESSING, None));
+		System::assert_last_event(RuntimeEvent::AjunaBoard(crate::Event::GameCreated {
+			board_id: BOARD_ID,
+			game: GUESSING,
+			players: vec![BOB, CHARLIE, DAVE],
+			stake: None,
+		}));
+		assert_eq!(BoardGames::<Test>::get(BOARD_ID).unwrap().game, GUESSING);
+		assert!(PlayerBoards::<Test>::get(ALICE).is_none());
+		assert_eq!(AjunaMatchmaker::queue_size(DOT4GRAVITY), 1);
+	});
+}
+
+#[test]
+fn play_decodes_turns_of_the_board_game() {
+	new_test_ext().execute_with(|| {
+		for player in [BOB, CHARLIE, DAVE] {
+			assert_ok!(AjunaBoard::queue(RuntimeOrigin::signed(player), GUESSING, None));
+		}
+
+		assert_noop!(
+			AjunaBoard::play(RuntimeOrigin::signed(BOB), Turn::DropBomb(TEST_COORD).encode()),
+			Error::<Test>::InvalidTurn
+		);
+		assert_noop!(
+			AjunaBoard::play(RuntimeOrigin::signed(CHARLIE), 1_u32.encode()),
+			Error::<Test>::InvalidTurn
+		);
+
+		assert_ok!(AjunaBoard::play(RuntimeOrigin::signed(BOB), 1_u32.encode()));
+		assert_ok!(AjunaBoard::play(RuntimeOrigin::signed(CHARLIE), THE_NUMBER.encode()));
+		System::assert_last_event(RuntimeEvent::AjunaBoard(crate::Event::GameFinished {
+			board_id: BOARD_ID,
+			winner: CHARLIE,
+		}));
+		assert!(PlayerBoards::<Test>::get(DAVE).is_none());
 	});
 }
 
@@ -59,23 +128,27 @@ fn queue_creates_game_on_successful_match() {
 		assert_eq!(NextBoardId::<Test>::get(), BOARD_ID);
 
 		// queue twice to matchmake
-		assert_ok!(AjunaBoard::queue(RuntimeOrigin::signed(ALICE)));
+		assert_ok!(AjunaBoard::queue(RuntimeOrigin::signed(ALICE), DOT4GRAVITY, None));
 		System::assert_has_event(RuntimeEvent::AjunaMatchmaker(
 			pallet_ajuna_matchmaker::Event::Queued(pallet_ajuna_matchmaker::PlayerStruct {
 				account: ALICE,
+				queued_at: 1,
 			}),
 		));
-		assert_ok!(AjunaBoard::queue(RuntimeOrigin::signed(BOB)));
+		assert_ok!(AjunaBoard::queue(RuntimeOrigin::signed(BOB), DOT4GRAVITY, None));
 		System::assert_has_event(RuntimeEvent::AjunaMatchmaker(
 			pallet_ajuna_matchmaker::Event::Queued(pallet_ajuna_matchmaker::PlayerStruct {
 				account: BOB,
+				queued_at: 1,
 			}),
 		));
 
 		let players = vec![ALICE, BOB];
 		System::assert_last_event(RuntimeEvent::AjunaBoard(crate::Event::GameCreated {
 			board_id: BOARD_ID,
+			game: DOT4GRAVITY,
 			players,
+			stake: None,
 		}));
 
 		assert!(PlayerBoards::<Test>::get(ALICE).is_some());
@@ -89,17 +162,17 @@ fn queue_creates_game_on_successful_match() {
 fn play_works() {
 	new_test_ext().execute_with(|| {
 		Seed::<Test>::put(TEST_SEED);
-		assert_ok!(AjunaBoard::queue(RuntimeOrigin::signed(BOB)));
-		assert_ok!(AjunaBoard::queue(RuntimeOrigin::signed(ERIN)));
+		assert_ok!(AjunaBoard::queue(RuntimeOrigin::signed(BOB), DOT4GRAVITY, None));
+		assert_ok!(AjunaBoard::queue(RuntimeOrigin::signed(ERIN), DOT4GRAVITY, None));
 		assert_noop!(
-			AjunaBoard::play(RuntimeOrigin::signed(ALICE), Turn::DropBomb(TEST_COORD)),
+			AjunaBoard::play(RuntimeOrigin::signed(ALICE), Turn::DropBomb(TEST_COORD).encode()),
 			Error::<Test>::NotPlaying
 		);
 
 		// Bomb phase
 		let drop_bomb = |coord: Coordinates| {
-			let _ = AjunaBoard::play(RuntimeOrigin::signed(BOB), Turn::DropBomb(coord));
-			let _ = AjunaBoard::play(RuntimeOrigin::signed(ERIN), Turn::DropBomb(coord));
+			let _ = AjunaBoard::play(RuntimeOrigin::signed(BOB), Turn::DropBomb(coord).encode());
+			let _ = AjunaBoard::play(RuntimeOrigin::signed(ERIN), Turn::DropBomb(coord).encode());
 		};
 		drop_bomb(Coordinates::new(9, 9));
 		drop_bomb(Coordinates::new(8, 8));
@@ -109,8 +182,8 @@ fn play_works() {
 		let drop_stone_1 = || {
 			let win = (Side::North, 0);
 			let loss = (Side::North, 9);
-			let _ = AjunaBoard::play(RuntimeOrigin::signed(BOB), Turn::DropStone(win));
-			let _ = AjunaBoard::play(RuntimeOrigin::signed(ERIN), Turn::DropStone(loss));
+			let _ = AjunaBoard::play(RuntimeOrigin::signed(BOB), Turn::DropStone(win).encode());
+			let _ = AjunaBoard::play(RuntimeOrigin::signed(ERIN), Turn::DropStone(loss).encode());
 		};
 		drop_stone_1();
 		drop_stone_1();
@@ -120,8 +193,8 @@ fn play_works() {
 		let drop_stone_2 = || {
 			let win = (Side::South, 1);
 			let loss = (Side::South, 7);
-			let _ = AjunaBoard::play(RuntimeOrigin::signed(BOB), Turn::DropStone(win));
-			let _ = AjunaBoard::play(RuntimeOrigin::signed(ERIN), Turn::DropStone(loss));
+			let _ = AjunaBoard::play(RuntimeOrigin::signed(BOB), Turn::DropStone(win).encode());
+			let _ = AjunaBoard::play(RuntimeOrigin::signed(ERIN), Turn::DropStone(loss).encode());
 		};
 		drop_stone_2();
 		drop_stone_2();
@@ -144,3 +217,602 @@ fn play_works() {
 		assert!(BoardGames::<Test>::get(BOARD_ID).is_none());
 	})
 }
+
+fn queue_guessing_game() {
+	for player in [BOB, CHARLIE, DAVE] {
+		assert_ok!(AjunaBoard::queue(RuntimeOrigin::signed(player), GUESSING, None));
+	}
+}
+
+#[test]
+fn claim_victory_works() {
+	new_test_ext().execute_with(|| {
+		queue_guessing_game();
+		assert_noop!(
+			AjunaBoard::claim_victory(RuntimeOrigin::signed(ALICE)),
+			Error::<Test>::NotPlaying
+		);
+
+		run_to_block(1 + TurnTimeout::get());
+		assert_noop!(
+			AjunaBoard::claim_victory(RuntimeOrigin::signed(CHARLIE)),
+			Error::<Test>::TurnNotExpired
+		);
+
+		run_to_block(2 + TurnTimeout::get());
+		assert_noop!(
+			AjunaBoard::claim_victory(RuntimeOrigin::signed(BOB)),
+			Error::<Test>::NotWaiting
+		);
+		assert_ok!(AjunaBoard::claim_victory(RuntimeOrigin::signed(CHARLIE)));
+		System::assert_has_event(RuntimeEvent::AjunaBoard(crate::Event::TurnTimedOut {
+			board_id: BOARD_ID,
+			player: BOB,
+		}));
+		// the players that did not stall draw
+		System::assert_last_event(RuntimeEvent::AjunaBoard(crate::Event::GameDrawn {
+			board_id: BOARD_ID,
+		}));
+		for player in [BOB, CHARLIE, DAVE] {
+			assert!(PlayerBoards::<Test>::get(player).is_none());
+		}
+		assert_eq!(IdleBoards::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn finished_games_keep_players_on_other_boards() {
+	new_test_ext().execute_with(|| {
+		queue_guessing_game();
+		// A stale board, such as one left by a migration, whose player has moved on.
+		PlayerBoards::<Test>::insert(DAVE, BOARD_ID + 1);
+
+		run_to_block(2 + TurnTimeout::get());
+		assert_ok!(AjunaBoard::claim_victory(RuntimeOrigin::signed(CHARLIE)));
+		for player in [BOB, CHARLIE] {
+			assert!(PlayerBoards::<Test>::get(player).is_none());
+		}
+		assert_eq!(PlayerBoards::<Test>::get(DAVE), Some(BOARD_ID + 1));
+	});
+}
+
+#[test]
+fn claim_victory_wins_two_player_games() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AjunaBoard::queue(RuntimeOrigin::signed(ALICE), DOT4GRAVITY, None));
+		assert_ok!(AjunaBoard::queue(RuntimeOrigin::signed(BOB), DOT4GRAVITY, None));
+
+		run_to_block(2 + TurnTimeout::get());
+		assert_ok!(AjunaBoard::claim_victory(RuntimeOrigin::signed(BOB)));
+		System::assert_last_event(RuntimeEvent::AjunaBoard(crate::Event::GameFinished {
+			board_id: BOARD_ID,
+			winner: BOB,
+		}));
+		let state = BoardGames::<Test>::get(BOARD_ID).unwrap().state;
+		let state = GameState::<MockAccountId>::decode(&mut &state[..]).unwrap();
+		assert_eq!(state.winner, Some(BOB));
+	});
+}
+
+#[test]
+fn play_resets_turn_timeout() {
+	new_test_ext().execute_with(|| {
+		queue_guessing_game();
+
+		run_to_block(5);
+		assert_ok!(AjunaBoard::play(RuntimeOrigin::signed(BOB), 1_u32.encode()));
+		assert_eq!(BoardGames::<Test>::get(BOARD_ID).unwrap().last_turn, 5);
+
+		run_to_block(5 + TurnTimeout::get());
+		assert_noop!(
+			AjunaBoard::claim_victory(RuntimeOrigin::signed(DAVE)),
+			Error::<Test>::TurnNotExpired
+		);
+		run_to_block(6 + TurnTimeout::get());
+		assert_ok!(AjunaBoard::claim_victory(RuntimeOrigin::signed(DAVE)));
+	});
+}
+
+#[test]
+fn idle_boards_are_resolved_automatically() {
+	new_test_ext().execute_with(|| {
+		queue_guessing_game();
+		run_to_block(5);
+		assert_ok!(AjunaBoard::play(RuntimeOrigin::signed(BOB), 1_u32.encode()));
+
+		// the deadline set when the game was created no longer applies
+		run_to_block(4 + IdleBoardTimeout::get());
+		assert_eq!(PlayerBoards::<Test>::get(CHARLIE), Some(BOARD_ID));
+
+		run_to_block(5 + IdleBoardTimeout::get());
+		System::assert_has_event(RuntimeEvent::AjunaBoard(crate::Event::TurnTimedOut {
+			board_id: BOARD_ID,
+			player: CHARLIE,
+		}));
+		System::assert_last_event(RuntimeEvent::AjunaBoard(crate::Event::GameDrawn {
+			board_id: BOARD_ID,
+		}));
+		assert!(PlayerBoards::<Test>::get(CHARLIE).is_none());
+		assert_eq!(IdleBoards::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn idle_boards_are_resolved_up_to_a_limit_per_block() {
+	new_test_ext().execute_with(|| {
+		let players = [ALICE, BOB, CHARLIE, DAVE, ERIN, 6];
+		for player in players {
+			assert_ok!(AjunaBoard::queue(RuntimeOrigin::signed(player), DOT4GRAVITY, None));
+		}
+		assert_eq!(IdleBoards::<Test>::iter_prefix(1 + IdleBoardTimeout::get()).count(), 3);
+
+		run_to_block(1 + IdleBoardTimeout::get());
+		let playing =
+			|| players.iter().filter(|player| PlayerBoards::<Test>::get(player).is_some());
+		assert_eq!(playing().count(), 2);
+		assert_eq!(IdleBoardsBacklog::<Test>::get(), Some(1 + IdleBoardTimeout::get()));
+
+		// left over boards are resolved in the next block
+		run_to_block(2 + IdleBoardTimeout::get());
+		assert_eq!(playing().count(), 0);
+		assert_eq!(IdleBoardsBacklog::<Test>::get(), None);
+		assert_eq!(IdleBoards::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn idle_boards_are_resolved_even_if_their_stakes_cannot_be_paid() {
+	new_test_ext().execute_with(|| {
+		queue_guessing_game_with([asset_stake(300), asset_stake(300), asset_stake(300)]);
+		// the escrowed stakes can no longer be paid out
+		let escrow = AjunaBoard::escrow_account();
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(HOUSE), ASSET_ID.into(), escrow, 900));
+
+		run_to_block(1 + IdleBoardTimeout::get());
+		System::assert_has_event(RuntimeEvent::AjunaBoard(crate::Event::StakesRefunded {
+			board_id: BOARD_ID,
+		}));
+		System::assert_last_event(RuntimeEvent::AjunaBoard(crate::Event::GameDrawn {
+			board_id: BOARD_ID,
+		}));
+		for player in [BOB, CHARLIE, DAVE] {
+			assert!(PlayerBoards::<Test>::get(player).is_none());
+		}
+		assert!(BoardStakes::<Test>::get(BOARD_ID).is_none());
+		assert_eq!(IdleBoards::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn idle_boards_failing_to_resolve_are_cancelled() {
+	new_test_ext().execute_with(|| {
+		queue_guessing_game_with([asset_stake(300), asset_stake(300), asset_stake(300)]);
+		// the game of the board is no longer registered
+		BoardGames::<Test>::mutate(BOARD_ID, |board_game| {
+			board_game.as_mut().unwrap().game = GameKind::MAX;
+		});
+
+		run_to_block(1 + IdleBoardTimeout::get());
+		System::assert_last_event(RuntimeEvent::AjunaBoard(crate::Event::GameCancelled {
+			board_id: BOARD_ID,
+		}));
+		for player in [BOB, CHARLIE, DAVE] {
+			assert!(PlayerBoards::<Test>::get(player).is_none());
+			assert_eq!(Assets::balance(ASSET_ID, player), INITIAL_BALANCE);
+		}
+		assert!(BoardStakes::<Test>::get(BOARD_ID).is_none());
+		assert_eq!(IdleBoards::<Test>::iter().count(), 0);
+
+		assert_ok!(AjunaBoard::clear_board(RuntimeOrigin::root(), BOARD_ID));
+		assert!(BoardGames::<Test>::get(BOARD_ID).is_none());
+	});
+}
+
+fn native_stake(amount: MockBalance) -> Option<StakeOf<Test>> {
+	Some(Stake { asset: StakeAsset::Native, amount })
+}
+
+fn asset_stake(amount: MockBalance) -> Option<StakeOf<Test>> {
+	Some(Stake { asset: StakeAsset::Asset(ASSET_ID), amount })
+}
+
+fn queue_guessing_game_with(stakes: [Option<StakeOf<Test>>; 3]) {
+	for (player, stake) in [BOB, CHARLIE, DAVE].into_iter().zip(stakes) {
+		assert_ok!(AjunaBoard::queue(RuntimeOrigin::signed(player), GUESSING, stake));
+	}
+}
+
+fn charlie_wins_guessing_game() {
+	assert_ok!(AjunaBoard::play(RuntimeOrigin::signed(BOB), 1_u32.encode()));
+	assert_ok!(AjunaBoard::play(RuntimeOrigin::signed(CHARLIE), THE_NUMBER.encode()));
+}
+
+#[test]
+fn queue_rejects_zero_stakes() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AjunaBoard::queue(RuntimeOrigin::signed(ALICE), DOT4GRAVITY, native_stake(0)),
+			Error::<Test>::InvalidStake
+		);
+	});
+}
+
+#[test]
+fn players_wager_their_lowest_stake() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AjunaBoard::queue(RuntimeOrigin::signed(BOB), GUESSING, native_stake(1_000)));
+		assert_eq!(Balances::reserved_balance(BOB), 1_000);
+		assert_eq!(QueuedStakes::<Test>::get(BOB), native_stake(1_000));
+
+		assert_ok!(AjunaBoard::queue(RuntimeOrigin::signed(CHARLIE), GUESSING, native_stake(500)));
+		assert_ok!(AjunaBoard::queue(RuntimeOrigin::signed(DAVE), GUESSING, native_stake(800)));
+		System::assert_last_event(RuntimeEvent::AjunaBoard(crate::Event::GameCreated {
+			board_id: BOARD_ID,
+			game: GUESSING,
+			players: vec![BOB, CHARLIE, DAVE],
+			stake: native_stake(500),
+		}));
+
+		assert_eq!(BoardStakes::<Test>::get(BOARD_ID), native_stake(500));
+		for player in [BOB, CHARLIE, DAVE] {
+			assert_eq!(Balances::reserved_balance(player), 500);
+			assert!(QueuedStakes::<Test>::get(player).is_none());
+		}
+	});
+}
+
+#[test]
+fn native_stakes_are_paid_to_the_winner() {
+	new_test_ext().execute_with(|| {
+		queue_guessing_game_with([native_stake(500), native_stake(500), native_stake(500)]);
+		charlie_wins_guessing_game();
+
+		// each loser pays their stake minus the 10% house fee
+		System::assert_has_event(RuntimeEvent::AjunaBoard(crate::Event::WinningsPaid {
+			board_id: BOARD_ID,
+			winner: CHARLIE,
+			winnings: 900,
+			fee: 100,
+		}));
+		assert_eq!(Balances::free_balance(CHARLIE), INITIAL_BALANCE + 900);
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE - 500);
+		assert_eq!(Balances::free_balance(DAVE), INITIAL_BALANCE - 500);
+		assert_eq!(Balances::free_balance(HOUSE), INITIAL_BALANCE + 100);
+		for player in [BOB, CHARLIE, DAVE] {
+			assert_eq!(Balances::reserved_balance(player), 0);
+		}
+		assert!(BoardStakes::<Test>::get(BOARD_ID).is_none());
+	});
+}
+
+#[test]
+fn native_stakes_are_reserved_under_the_pallet_id() {
+	new_test_ext().execute_with(|| {
+		let reserve_id = BoardPalletId::get().0;
+		queue_guessing_game_with([native_stake(500), native_stake(500), native_stake(500)]);
+		for player in [BOB, CHARLIE, DAVE] {
+			assert_eq!(Balances::reserved_balance_named(&reserve_id, &player), 500);
+		}
+
+		// stakes missing from the reserve are not paid, the others being refunded instead
+		assert_eq!(Balances::unreserve_named(&reserve_id, &DAVE, 500), 0);
+		charlie_wins_guessing_game();
+		System::assert_has_event(RuntimeEvent::AjunaBoard(crate::Event::StakesRefunded {
+			board_id: BOARD_ID,
+		}));
+		for player in [BOB, CHARLIE, DAVE] {
+			assert_eq!(Balances::free_balance(player), INITIAL_BALANCE);
+			assert_eq!(Balances::reserved_balance(player), 0);
+			assert!(PlayerBoards::<Test>::get(player).is_none());
+		}
+	});
+}
+
+#[test]
+fn leave_queue_releases_the_queued_stake() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AjunaBoard::leave_queue(RuntimeOrigin::signed(BOB)),
+			pallet_ajuna_matchmaker::Error::<Test>::NotQueued
+		);
+
+		assert_ok!(AjunaBoard::queue(RuntimeOrigin::signed(BOB), GUESSING, native_stake(500)));
+		assert_ok!(AjunaBoard::queue(RuntimeOrigin::signed(CHARLIE), GUESSING, asset_stake(300)));
+		for player in [BOB, CHARLIE] {
+			assert_ok!(AjunaBoard::leave_queue(RuntimeOrigin::signed(player)));
+			assert!(QueuedStakes::<Test>::get(player).is_none());
+			assert!(!AjunaMatchmaker::is_queued(player));
+		}
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(Assets::balance(ASSET_ID, CHARLIE), INITIAL_BALANCE);
+
+		// players that left are not matched
+		queue_guessing_game_with([None, None, None]);
+		assert_eq!(PlayerBoards::<Test>::get(BOB), Some(BOARD_ID));
+		assert_eq!(
+			BoardGames::<Test>::get(BOARD_ID).unwrap().players.to_vec(),
+			[BOB, CHARLIE, DAVE]
+		);
+	});
+}
+
+#[test]
+fn asset_stakes_are_paid_to_the_winner() {
+	new_test_ext().execute_with(|| {
+		queue_guessing_game_with([asset_stake(300), asset_stake(300), asset_stake(300)]);
+		assert_eq!(Assets::balance(ASSET_ID, AjunaBoard::escrow_account()), 900);
+
+		charlie_wins_guessing_game();
+		assert_eq!(Assets::balance(ASSET_ID, CHARLIE), INITIAL_BALANCE + 540);
+		assert_eq!(Assets::balance(ASSET_ID, BOB), INITIAL_BALANCE - 300);
+		assert_eq!(Assets::balance(ASSET_ID, DAVE), INITIAL_BALANCE - 300);
+		assert_eq!(Assets::balance(ASSET_ID, HOUSE), 60);
+		assert_eq!(Assets::balance(ASSET_ID, AjunaBoard::escrow_account()), 0);
+	});
+}
+
+#[test]
+fn house_fees_below_the_minimum_balance_refund_the_stakes() {
+	new_test_ext().execute_with(|| {
+		// the house account holds none of an asset whose minimum balance exceeds the house fees
+		let asset_id = ASSET_ID + 1;
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), asset_id.into(), HOUSE, true, 50));
+		for player in [BOB, CHARLIE, DAVE] {
+			assert_ok!(Assets::mint(
+				RuntimeOrigin::signed(HOUSE),
+				asset_id.into(),
+				player,
+				INITIAL_BALANCE
+			));
+		}
+		let stake = Some(Stake { asset: StakeAsset::Asset(asset_id), amount: 300 });
+		queue_guessing_game_with([stake.clone(), stake.clone(), stake]);
+
+		charlie_wins_guessing_game();
+		System::assert_has_event(RuntimeEvent::AjunaBoard(crate::Event::StakesRefunded {
+			board_id: BOARD_ID,
+		}));
+		System::assert_last_event(RuntimeEvent::AjunaBoard(crate::Event::GameFinished {
+			board_id: BOARD_ID,
+			winner: CHARLIE,
+		}));
+		for player in [BOB, CHARLIE, DAVE] {
+			assert_eq!(Assets::balance(asset_id, player), INITIAL_BALANCE);
+			assert!(PlayerBoards::<Test>::get(player).is_none());
+		}
+		assert_eq!(Assets::balance(asset_id, HOUSE), 0);
+		assert_eq!(Assets::balance(asset_id, AjunaBoard::escrow_account()), 0);
+	});
+}
+
+#[test]
+fn mismatched_stakes_are_released() {
+	new_test_ext().execute_with(|| {
+		queue_guessing_game_with([native_stake(500), asset_stake(300), None]);
+		System::assert_last_event(RuntimeEvent::AjunaBoard(crate::Event::GameCreated {
+			board_id: BOARD_ID,
+			game: GUESSING,
+			players: vec![BOB, CHARLIE, DAVE],
+			stake: None,
+		}));
+
+		assert!(BoardStakes::<Test>::get(BOARD_ID).is_none());
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(Assets::balance(ASSET_ID, CHARLIE), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn timed_out_players_forfeit_their_stake() {
+	new_test_ext().execute_with(|| {
+		queue_guessing_game_with([native_stake(500), native_stake(500), native_stake(500)]);
+
+		run_to_block(2 + TurnTimeout::get());
+		assert_ok!(AjunaBoard::claim_victory(RuntimeOrigin::signed(DAVE)));
+
+		// only the stalling player loses their stake, which the other players share
+		for winner in [CHARLIE, DAVE] {
+			System::assert_has_event(RuntimeEvent::AjunaBoard(crate::Event::WinningsPaid {
+				board_id: BOARD_ID,
+				winner,
+				winnings: 225,
+				fee: 25,
+			}));
+			assert_eq!(Balances::free_balance(winner), INITIAL_BALANCE + 225);
+			assert_eq!(Balances::reserved_balance(winner), 0);
+		}
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE - 500);
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+	});
+}
+
+#[test]
+fn drawn_games_refund_all_stakes() {
+	new_test_ext().execute_with(|| {
+		queue_guessing_game_with([native_stake(500), native_stake(500), native_stake(500)]);
+		assert_ok!(AjunaBoard::play(RuntimeOrigin::signed(BOB), DRAW_NUMBER.encode()));
+
+		System::assert_last_event(RuntimeEvent::AjunaBoard(crate::Event::GameDrawn {
+			board_id: BOARD_ID,
+		}));
+		for player in [BOB, CHARLIE, DAVE] {
+			assert_eq!(Balances::free_balance(player), INITIAL_BALANCE);
+			assert_eq!(Balances::reserved_balance(player), 0);
+			assert!(PlayerBoards::<Test>::get(player).is_none());
+		}
+		assert_eq!(Balances::free_balance(HOUSE), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn ranked_games_split_stakes_by_rank() {
+	new_test_ext().execute_with(|| {
+		queue_guessing_game_with([native_stake(600), native_stake(600), native_stake(600)]);
+		assert_ok!(AjunaBoard::play(RuntimeOrigin::signed(BOB), RANKING_NUMBER.encode()));
+
+		System::assert_last_event(RuntimeEvent::AjunaBoard(crate::Event::GameRanked {
+			board_id: BOARD_ID,
+			ranking: vec![BOB, CHARLIE, DAVE],
+		}));
+
+		// the pot is split in 2 parts for the first and 1 part for the second player
+		System::assert_has_event(RuntimeEvent::AjunaBoard(crate::Event::WinningsPaid {
+			board_id: BOARD_ID,
+			winner: BOB,
+			winnings: 540,
+			fee: 60,
+		}));
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE + 540);
+		assert_eq!(Balances::free_balance(CHARLIE), INITIAL_BALANCE);
+		assert_eq!(Balances::free_balance(DAVE), INITIAL_BALANCE - 600);
+		assert_eq!(Balances::free_balance(HOUSE), INITIAL_BALANCE + 60);
+		for player in [BOB, CHARLIE, DAVE] {
+			assert_eq!(Balances::reserved_balance(player), 0);
+		}
+	});
+}
+
+#[test]
+fn finished_games_update_ratings() {
+	new_test_ext().execute_with(|| {
+		queue_guessing_game_with([None, None, None]);
+		charlie_wins_guessing_game();
+
+		System::assert_has_event(RuntimeEvent::AjunaBoard(crate::Event::RatingsUpdated {
+			board_id: BOARD_ID,
+			game: GUESSING,
+			ratings: vec![(BOB, 1_492), (CHARLIE, 1_516), (DAVE, 1_492)],
+		}));
+		assert_eq!(AjunaBoard::rating_of(GUESSING, &CHARLIE), 1_516);
+		// ratings are kept per game kind
+		assert_eq!(AjunaBoard::rating_of(DOT4GRAVITY, &CHARLIE), InitialRating::get());
+		assert_eq!(AjunaBoard::rating_of(GUESSING, &ERIN), InitialRating::get());
+
+		assert_eq!(AjunaBoard::leaderboard(GUESSING, 1), vec![(CHARLIE, 1_516)]);
+		// only `LeaderboardSize` players are kept, with ties going to the player rated first
+		assert_eq!(AjunaBoard::leaderboard(GUESSING, 10), vec![(CHARLIE, 1_516), (BOB, 1_492)]);
+		assert!(AjunaBoard::leaderboard(DOT4GRAVITY, 10).is_empty());
+
+		// players rated higher push the lowest rated player off the leaderboard
+		queue_guessing_game_with([None, None, None]);
+		assert_ok!(AjunaBoard::play(RuntimeOrigin::signed(BOB), 1_u32.encode()));
+		assert_ok!(AjunaBoard::play(RuntimeOrigin::signed(CHARLIE), 2_u32.encode()));
+		assert_ok!(AjunaBoard::play(RuntimeOrigin::signed(DAVE), THE_NUMBER.encode()));
+		assert_eq!(AjunaBoard::leaderboard(GUESSING, 10), vec![(CHARLIE, 1_508), (DAVE, 1_508)]);
+	});
+}
+
+#[test]
+fn queue_matches_players_by_rating() {
+	new_test_ext().execute_with(|| {
+		Ratings::<Test>::insert(DOT4GRAVITY, ALICE, 1_700);
+		Ratings::<Test>::insert(DOT4GRAVITY, CHARLIE, 1_650);
+
+		assert_ok!(AjunaBoard::queue(RuntimeOrigin::signed(ALICE), DOT4GRAVITY, None));
+		assert_ok!(AjunaBoard::queue(RuntimeOrigin::signed(BOB), DOT4GRAVITY, None));
+		assert!(BoardGames::<Test>::get(BOARD_ID).is_none());
+		assert_eq!(AjunaMatchmaker::queue_size(0), 1);
+		assert_eq!(AjunaMatchmaker::queue_size(1), 1);
+
+		assert_ok!(AjunaBoard::queue(RuntimeOrigin::signed(CHARLIE), DOT4GRAVITY, None));
+		System::assert_last_event(RuntimeEvent::AjunaBoard(crate::Event::GameCreated {
+			board_id: BOARD_ID,
+			game: DOT4GRAVITY,
+			players: vec![ALICE, CHARLIE],
+			stake: None,
+		}));
+		assert!(PlayerBoards::<Test>::get(BOB).is_none());
+	});
+}
+
+#[test]
+fn waiting_players_are_matched_across_rating_brackets() {
+	new_test_ext().execute_with(|| {
+		Ratings::<Test>::insert(DOT4GRAVITY, ALICE, 1_700);
+		assert_ok!(AjunaBoard::queue(RuntimeOrigin::signed(ALICE), DOT4GRAVITY, None));
+		assert_ok!(AjunaBoard::queue(RuntimeOrigin::signed(BOB), DOT4GRAVITY, None));
+
+		run_to_block(WideningPeriod::get());
+		assert!(BoardGames::<Test>::get(BOARD_ID).is_none());
+
+		// once they waited long enough the matchmaker matches them on its own
+		run_to_block(1 + WideningPeriod::get());
+		System::assert_last_event(RuntimeEvent::AjunaBoard(crate::Event::GameCreated {
+			board_id: BOARD_ID,
+			game: DOT4GRAVITY,
+			players: vec![BOB, ALICE],
+			stake: None,
+		}));
+		assert_eq!(PlayerBoards::<Test>::get(ALICE), Some(BOARD_ID));
+		assert_eq!(AjunaMatchmaker::all_queue_size(), 0);
+
+		assert_noop!(
+			AjunaBoard::queue(RuntimeOrigin::signed(ALICE), DOT4GRAVITY, None),
+			Error::<Test>::AlreadyInGame
+		);
+	});
+}
+
+#[test]
+fn migrates_boards_to_the_game_registry() {
+	use crate::migration::v2::MigrateToV2;
+	use frame_support::traits::{ConstU8, GetStorageVersion, OnRuntimeUpgrade};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(1).put::<AjunaBoard>();
+		let state = Game::<MockAccountId>::init(&[ALICE, BOB], Some(TEST_SEED)).unwrap();
+		let old_board_game = (BOARD_ID, vec![ALICE, BOB], state.clone(), 1_u64);
+		frame_support::storage::unhashed::put(
+			&BoardGames::<Test>::hashed_key_for(BOARD_ID),
+			&old_board_game,
+		);
+		PlayerBoards::<Test>::insert(ALICE, BOARD_ID);
+		PlayerBoards::<Test>::insert(BOB, BOARD_ID);
+
+		// A board with more players than are allowed now.
+		let crowded_board_game =
+			(BOARD_ID + 1, vec![CHARLIE, DAVE, ERIN, HOUSE], state.clone(), 1_u64);
+		frame_support::storage::unhashed::put(
+			&BoardGames::<Test>::hashed_key_for(BOARD_ID + 1),
+			&crowded_board_game,
+		);
+		PlayerBoards::<Test>::insert(CHARLIE, BOARD_ID + 1);
+		PlayerBoards::<Test>::insert(DAVE, BOARD_ID + 2);
+
+		// A board whose game has been finished, and one its players have left.
+		let finished_state = Game::<MockAccountId>::abort(state.clone(), BOB);
+		let finished_board_game = (BOARD_ID + 2, vec![DAVE, ERIN], finished_state, 1_u64);
+		frame_support::storage::unhashed::put(
+			&BoardGames::<Test>::hashed_key_for(BOARD_ID + 2),
+			&finished_board_game,
+		);
+		PlayerBoards::<Test>::insert(ERIN, BOARD_ID + 2);
+		let abandoned_board_game = (BOARD_ID + 3, vec![ALICE, BOB], state.clone(), 1_u64);
+		frame_support::storage::unhashed::put(
+			&BoardGames::<Test>::hashed_key_for(BOARD_ID + 3),
+			&abandoned_board_game,
+		);
+
+		System::set_block_number(5);
+		MigrateToV2::<Test, GameState<MockAccountId>, ConstU8<DOT4GRAVITY>>::on_runtime_upgrade();
+
+		let board_game = BoardGames::<Test>::get(BOARD_ID).unwrap();
+		assert_eq!(board_game.game, DOT4GRAVITY);
+		assert_eq!(board_game.players.to_vec(), vec![ALICE, BOB]);
+		assert_eq!(board_game.state.to_vec(), state.encode());
+		assert_eq!(board_game.started, 1);
+		assert_eq!(board_game.last_turn, 5);
+		assert!(IdleBoards::<Test>::contains_key(5 + IdleBoardTimeout::get(), BOARD_ID));
+		assert_eq!(PlayerBoards::<Test>::get(ALICE), Some(BOARD_ID));
+
+		assert!(BoardGames::<Test>::get(BOARD_ID + 1).is_none());
+		assert_eq!(PlayerBoards::<Test>::get(CHARLIE), None);
+		assert_eq!(PlayerBoards::<Test>::get(DAVE), Some(BOARD_ID + 2));
+
+		for board_id in [BOARD_ID + 2, BOARD_ID + 3] {
+			assert!(BoardGames::<Test>::contains_key(board_id));
+			assert!(!IdleBoards::<Test>::contains_key(5 + IdleBoardTimeout::get(), board_id));
+		}
+		assert_eq!(AjunaBoard::on_chain_storage_version(), StorageVersion::new(2));
+	});
+}
@@ -16,39 +16,73 @@
 
 use super::*;
 
-pub(crate) type PlayerOf<T> = <<T as Config>::Game as TurnBasedGame>::Player;
+/// Identifies a game registered in [`Config::Games`].
+pub type GameKind = u8;
+
+pub(crate) type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+pub(crate) type StakeOf<T> = Stake<<T as Config>::AssetId, BalanceOf<T>>;
+
+pub(crate) type PlayerOf<T> = <T as frame_system::Config>::AccountId;
 pub(crate) type BoundedPlayersOf<T> =
-	BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::Players>;
-pub(crate) type BoardGameOf<T> = BoardGame<
-	<T as Config>::BoardId,
-	<T as Config>::GameState,
-	BoundedPlayersOf<T>,
-	BlockNumberFor<T>,
->;
+	BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxPlayers>;
+pub(crate) type BoundedStateOf<T> = BoundedVec<u8, <T as Config>::MaxStateLen>;
+pub(crate) type BoardGameOf<T> =
+	BoardGame<<T as Config>::BoardId, BoundedStateOf<T>, BoundedPlayersOf<T>, BlockNumberFor<T>>;
 
 /// The state of the board game
 #[derive(Clone, Debug, Encode, Decode, TypeInfo, MaxEncodedLen)]
 pub struct BoardGame<BoardId, State, Players, Start> {
 	board_id: BoardId,
+	/// The kind of game played on the board
+	pub game: GameKind,
 	/// Players in the game
 	pub(crate) players: Players,
 	/// The current state of the game
 	pub state: State,
 	/// When the game started
 	pub started: Start,
+	/// When the last turn was played, or the game started if no turn has been played yet
+	pub last_turn: Start,
 }
 
-impl<BoardId, State, Players, Start> BoardGame<BoardId, State, Players, Start> {
+impl<BoardId, State, Players, Start: Clone> BoardGame<BoardId, State, Players, Start> {
 	/// Create a BoardGame
-	pub(crate) fn new(board_id: BoardId, players: Players, state: State, started: Start) -> Self {
-		Self { board_id, players, state, started }
+	pub(crate) fn new(
+		board_id: BoardId,
+		game: GameKind,
+		players: Players,
+		state: State,
+		started: Start,
+	) -> Self {
+		Self { board_id, game, players, state, last_turn: started.clone(), started }
 	}
 }
 
+/// The currency a stake is paid in
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub enum StakeAsset<AssetId> {
+	/// The native currency
+	Native,
+	/// A fungible asset
+	Asset(AssetId),
+}
+
+/// A stake wagered by a player on a game
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct Stake<AssetId, Balance> {
+	pub asset: StakeAsset<AssetId>,
+	pub amount: Balance,
+}
+
 #[derive(Debug, PartialEq)]
 pub enum Finished<Player> {
 	No,
 	Winner(Player),
+	/// The game ended without a winner
+	Draw,
+	/// The game ended with players ranked from best to worst
+	Ranking(Vec<Player>),
 }
 
 pub trait TurnBasedGame {
@@ -75,3 +109,104 @@ pub trait TurnBasedGame {
 	/// Get seed if any
 	fn seed(state: &Self::State) -> Option<u32>;
 }
+
+/// Plays an encoded turn of a player on an encoded state, returning the encoded new state.
+type PlayTurnFn<Player> = fn(Player, &[u8], &[u8]) -> Option<Vec<u8>>;
+
+/// A [`TurnBasedGame`] registered under a [`GameKind`]. Turns and states are handled in their
+/// SCALE-encoded form, so that games with different types can share the same boards.
+pub struct RegisteredGame<Player> {
+	/// Number of players required to start a game
+	pub players: u8,
+	init: fn(&[Player], Option<u32>) -> Option<Vec<u8>>,
+	last_player: fn(&[u8]) -> Option<Player>,
+	next_player: fn(&[u8]) -> Option<Player>,
+	play_turn: PlayTurnFn<Player>,
+	abort: fn(&[u8], Player) -> Option<Vec<u8>>,
+	is_finished: fn(&[u8]) -> Option<Finished<Player>>,
+	seed: fn(&[u8]) -> Option<u32>,
+}
+
+impl<Player> RegisteredGame<Player> {
+	/// Register `Game` to be played by `players` players
+	pub fn new<Game>(players: u8) -> Self
+	where
+		Game: TurnBasedGame<Player = Player>,
+		Game::Turn: Decode,
+	{
+		Self {
+			players,
+			init: |players, seed| Game::init(players, seed).map(|state| state.encode()),
+			last_player: |state| {
+				Game::State::decode_all(&mut &*state)
+					.ok()
+					.map(|state| Game::get_last_player(&state))
+			},
+			next_player: |state| {
+				Game::State::decode_all(&mut &*state)
+					.ok()
+					.map(|state| Game::get_next_player(&state))
+			},
+			play_turn: |player, state, turn| {
+				let state = Game::State::decode_all(&mut &*state).ok()?;
+				let turn = Game::Turn::decode_all(&mut &*turn).ok()?;
+				Game::play_turn(player, state, turn).map(|state| state.encode())
+			},
+			abort: |state, winner| {
+				Game::State::decode_all(&mut &*state)
+					.ok()
+					.map(|state| Game::abort(state, winner).encode())
+			},
+			is_finished: |state| {
+				Game::State::decode_all(&mut &*state)
+					.ok()
+					.map(|state| Game::is_finished(&state))
+			},
+			seed: |state| {
+				Game::State::decode_all(&mut &*state).ok().and_then(|state| Game::seed(&state))
+			},
+		}
+	}
+
+	/// Initialise a game with players returning the encoded initial state
+	pub fn init(&self, players: &[Player], seed: Option<u32>) -> Option<Vec<u8>> {
+		(self.init)(players, seed)
+	}
+
+	/// Get the player that played its turn last, `None` if the state cannot be decoded
+	pub fn last_player(&self, state: &[u8]) -> Option<Player> {
+		(self.last_player)(state)
+	}
+
+	/// Get the player that should play its turn next, `None` if the state cannot be decoded
+	pub fn next_player(&self, state: &[u8]) -> Option<Player> {
+		(self.next_player)(state)
+	}
+
+	/// Play an encoded turn with player on the encoded state returning the new encoded state
+	pub fn play_turn(&self, player: Player, state: &[u8], turn: &[u8]) -> Option<Vec<u8>> {
+		(self.play_turn)(player, state, turn)
+	}
+
+	/// Force the termination of the game with a designated winner returning the new encoded
+	/// state, `None` if the state cannot be decoded
+	pub fn abort(&self, state: &[u8], winner: Player) -> Option<Vec<u8>> {
+		(self.abort)(state, winner)
+	}
+
+	/// Check if the game has finished with winner, `None` if the state cannot be decoded
+	pub fn is_finished(&self, state: &[u8]) -> Option<Finished<Player>> {
+		(self.is_finished)(state)
+	}
+
+	/// Get seed if any
+	pub fn seed(&self, state: &[u8]) -> Option<u32> {
+		(self.seed)(state)
+	}
+}
+
+/// The set of games that can be played on boards.
+pub trait GameRegistry<Player> {
+	/// Get the game registered under `kind`, if any
+	fn game(kind: GameKind) -> Option<RegisteredGame<Player>>;
+}
@@ -0,0 +1,98 @@
+//! Weights for pallet_ajuna_board
+//!
+//! The benchmarks behind these weights have never been run, as this workspace doesn't build
+//! without the node. The reads and writes are counted from the code for a two-player game with
+//! native stakes. The execution times are estimates, the one of `play` including the stalemate
+//! check of dot4gravity, which tries up to `2 * (BOARD_WIDTH + BOARD_HEIGHT)` stone drops on
+//! copies of the game state. The proof sizes assume 32-byte account ids, u128 balances, 50
+//! reserves per account, 100 leaderboard entries and a game state of at most 1 KB. Regenerate
+//! this file before using the pallet in a runtime.
+//!
+//! Command to regenerate it with:
+//!
+//! ./target/release/bajun-para
+//! benchmark
+//! pallet
+//! --chain=dev
+//! --steps=50
+//! --repeat=20
+//! --pallet=pallet-ajuna-board
+//! --extrinsic=*
+//! --wasm-execution=compiled
+//! --heap-pages=4096
+//! --template=./.maintain/frame-weight-template.hbs
+//! --output=./pallets/ajuna-board/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_ajuna_board.
+pub trait WeightInfo {
+	fn play() -> Weight;
+	fn claim_victory() -> Weight;
+}
+
+/// Weights for pallet_ajuna_board using the Substrate node and recommended hardware.
+pub struct AjunaWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
+	// Storage: Board PlayerBoards (r:2 w:2)
+	// Storage: Board BoardGames (r:1 w:1)
+	// Storage: Board IdleBoards (r:0 w:1)
+	// Storage: Board BoardStakes (r:1 w:1)
+	// Storage: Balances Reserves (r:2 w:2)
+	// Storage: System Account (r:3 w:3)
+	// Storage: Board Ratings (r:2 w:2)
+	// Storage: Board Leaderboards (r:1 w:1)
+	fn play() -> Weight {
+		Weight::from_parts(171_600_000, 38447)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
+	}
+	// Storage: Board PlayerBoards (r:2 w:2)
+	// Storage: Board BoardGames (r:1 w:1)
+	// Storage: Board IdleBoards (r:0 w:1)
+	// Storage: Board BoardStakes (r:1 w:1)
+	// Storage: Balances Reserves (r:2 w:2)
+	// Storage: System Account (r:3 w:3)
+	// Storage: Board Ratings (r:2 w:2)
+	// Storage: Board Leaderboards (r:1 w:1)
+	fn claim_victory() -> Weight {
+		Weight::from_parts(148_300_000, 38447)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: Board PlayerBoards (r:2 w:2)
+	// Storage: Board BoardGames (r:1 w:1)
+	// Storage: Board IdleBoards (r:0 w:1)
+	// Storage: Board BoardStakes (r:1 w:1)
+	// Storage: Balances Reserves (r:2 w:2)
+	// Storage: System Account (r:3 w:3)
+	// Storage: Board Ratings (r:2 w:2)
+	// Storage: Board Leaderboards (r:1 w:1)
+	fn play() -> Weight {
+		Weight::from_parts(171_600_000, 38447)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
+	}
+	// Storage: Board PlayerBoards (r:2 w:2)
+	// Storage: Board BoardGames (r:1 w:1)
+	// Storage: Board IdleBoards (r:0 w:1)
+	// Storage: Board BoardStakes (r:1 w:1)
+	// Storage: Balances Reserves (r:2 w:2)
+	// Storage: System Account (r:3 w:3)
+	// Storage: Board Ratings (r:2 w:2)
+	// Storage: Board Leaderboards (r:1 w:1)
+	fn claim_victory() -> Weight {
+		Weight::from_parts(148_300_000, 38447)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
+	}
+}
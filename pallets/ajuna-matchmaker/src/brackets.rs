@@ -30,6 +30,14 @@ where
 	///
 	/// Returns `None` if the queue is empty.
 	fn pop(&mut self, b: Bracket) -> Option<Item>;
+	/// Remove the item of an item key from the queue it is in, keeping the order of the others.
+	///
+	/// Returns `None` if the item key is not queued.
+	fn remove(&mut self, j: ItemKey) -> Option<Item>;
+	/// Return the item at the start of the queue without removing it.
+	///
+	/// Returns `None` if the queue is empty.
+	fn peek(&self, b: Bracket) -> Option<Item>;
 	/// Return whether the queue is empty.
 	fn is_empty(&self, b: Bracket) -> bool;
 	/// Return the size of the brackets queue.
@@ -192,6 +200,52 @@ where
 			})
 	}
 
+	/// Remove the item of the item_key from the bracket it is queued in.
+	///
+	/// Moves the items queued after it one place forward, but will not update the bounds in
+	/// storage.
+	fn remove(&mut self, item_key: ItemKey) -> Option<Item> {
+		let bracket = (0..self.index_vector.len())
+			.map(|i| i as Bracket)
+			.find(|bracket| N::contains_key(*bracket, &item_key))?;
+		let item = N::take(bracket, &item_key)?;
+
+		let (v_start, v_end) = self.index_vector[bracket as usize];
+		let Some(mut index) = (0..self.size(bracket))
+			.map(|offset| v_start.wrapping_add(offset))
+			.find(|index| {
+				M::get(bracket, index).is_some_and(|key| key.encode() == item_key.encode())
+			})
+		else {
+			return Some(item);
+		};
+
+		// close the gap left by the item
+		let mut next = index.wrapping_add(1);
+		while next != v_end {
+			if let Some(key) = M::take(bracket, next) {
+				M::insert(bracket, index, key);
+			}
+			index = next;
+			next = next.wrapping_add(1);
+		}
+		M::remove(bracket, index);
+
+		self.index_vector[bracket as usize] = (v_start, v_end.wrapping_sub(1));
+		Some(item)
+	}
+
+	/// Return the item at the start of the queue.
+	fn peek(&self, bracket: Bracket) -> Option<Item> {
+		if self.is_empty(bracket) {
+			return None;
+		}
+
+		let (v_start, _) = self.index_vector[bracket as usize];
+
+		M::get(bracket, v_start).and_then(|item_key| N::get(bracket, item_key))
+	}
+
 	/// Return whether to consider the queue empty.
 	fn is_empty(&self, bracket: Bracket) -> bool {
 		let (v_start, v_end) = self.index_vector[bracket as usize];
@@ -7,9 +7,14 @@ pub use pallet::*;
 
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
-use sp_std::{boxed::Box, vec::Vec};
+use sp_std::{boxed::Box, ops::RangeInclusive, vec::Vec};
 
-use frame_support::traits::Get;
+use frame_support::{pallet_prelude::Weight, storage::with_storage_layer, traits::Get};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	DispatchResult, SaturatedConversion,
+};
 
 #[cfg(test)]
 mod mock;
@@ -18,8 +23,10 @@ mod mock;
 mod tests;
 
 mod brackets;
+pub mod migration;
 
-use brackets::{Bracket, BracketsTrait, BracketsTransient, BufferIndex};
+pub use brackets::Bracket;
+use brackets::{BracketsTrait, BracketsTransient, BufferIndex};
 
 #[derive(Encode, Decode, Clone, PartialEq, TypeInfo)]
 pub enum MatchingType {
@@ -32,10 +39,15 @@ pub enum MatchingType {
 }
 
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
-pub struct PlayerStruct<AccountId> {
+pub struct PlayerStruct<AccountId, BlockNumber> {
 	pub account: AccountId,
+	/// Block in which the player has been queued.
+	pub queued_at: BlockNumber,
 }
 
+pub(crate) type PlayerStructOf<T> =
+	PlayerStruct<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use frame_support::pallet_prelude::*;
@@ -56,9 +68,24 @@ pub mod pallet {
 		/// Constant that indicates how many ranking brackets exist for players.
 		#[pallet::constant]
 		type AmountBrackets: Get<u8>;
+
+		/// Constant that indicates how many players can be queued in a bracket, which bounds the
+		/// number of players moved forward when one leaves the queue.
+		#[pallet::constant]
+		type MaxQueueSize: Get<BufferIndex>;
+
+		/// Number of blocks a queued player waits before being matched with players one bracket
+		/// further away. With zero, players are matched with players of any bracket right away,
+		/// preferring the closest brackets.
+		#[pallet::constant]
+		type WideningPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Handles the matches found automatically at the start of every block.
+		type MatchHandler: MatchHandler<Self::AccountId>;
 	}
 
 	#[pallet::pallet]
+	#[pallet::storage_version(migration::STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::type_value]
@@ -107,7 +134,7 @@ pub mod pallet {
 		Bracket,
 		Blake2_128Concat,
 		T::AccountId,
-		PlayerStruct<T::AccountId>,
+		PlayerStructOf<T>,
 		OptionQuery,
 	>;
 
@@ -117,9 +144,11 @@ pub mod pallet {
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// Queued event
-		Queued(PlayerStruct<T::AccountId>),
+		Queued(PlayerStructOf<T>),
 		/// Popped event
-		Popped(PlayerStruct<T::AccountId>),
+		Popped(PlayerStructOf<T>),
+		/// Removed event
+		Removed(PlayerStructOf<T>),
 	}
 
 	// Errors inform users that something went wrong.
@@ -127,6 +156,19 @@ pub mod pallet {
 	pub enum Error<T> {
 		/// Player has already queued, can not queue twice
 		AlreadyQueued,
+		/// Bracket is beyond the configured amount of brackets
+		UnknownBracket,
+		/// Player is not queued in any bracket
+		NotQueued,
+		/// Bracket queue already holds the maximum number of players
+		QueueFull,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_now: BlockNumberFor<T>) -> Weight {
+			Self::do_auto_match()
+		}
 	}
 
 	#[pallet::call]
@@ -138,10 +180,10 @@ impl<T: Config> Pallet<T> {
 	///
 	/// Constructs a ringbuffer transient and returns it as a boxed trait object.
 	/// See [this part of the Rust book](https://doc.rust-lang.org/book/ch17-02-trait-objects.html#trait-objects-perform-dynamic-dispatch)
-	fn queue_transient() -> Box<dyn BracketsTrait<T::AccountId, PlayerStruct<T::AccountId>>> {
+	fn queue_transient() -> Box<dyn BracketsTrait<T::AccountId, PlayerStructOf<T>>> {
 		Box::new(BracketsTransient::<
 			T::AccountId,
-			PlayerStruct<T::AccountId>,
+			PlayerStructOf<T>,
 			BracketsCount<T>,
 			BracketIndices<T>,
 			BracketIndexKeyMap<T>,
@@ -150,9 +192,17 @@ impl<T: Config> Pallet<T> {
 	}
 
 	fn do_add_queue(account: T::AccountId, bracket: u8) -> Result<(), sp_runtime::DispatchError> {
+		if bracket >= Self::brackets_count() {
+			return Err(Error::<T>::UnknownBracket.into());
+		}
+
 		let mut queue = Self::queue_transient();
+		if queue.size(bracket) >= T::MaxQueueSize::get() {
+			return Err(Error::<T>::QueueFull.into());
+		}
 
-		let player = PlayerStruct { account };
+		let queued_at = frame_system::Pallet::<T>::block_number();
+		let player = PlayerStruct { account, queued_at };
 		// duplicate check if we can add key to the queue
 		if !queue.push(bracket, player.account.clone(), player.clone()) {
 			return Err(Error::<T>::AlreadyQueued.into());
@@ -163,6 +213,27 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	fn do_remove_queue(account: T::AccountId) -> Result<(), sp_runtime::DispatchError> {
+		let player = Self::queue_transient().remove(account).ok_or(Error::<T>::NotQueued)?;
+		Self::deposit_event(Event::Removed(player));
+
+		Ok(())
+	}
+
+	/// Weight of removing a player from the front of a full bracket queue, which moves every
+	/// other player in it one place forward.
+	///
+	/// Every bracket in use is searched for the player, so the stored brackets count is used
+	/// rather than `AmountBrackets`, which only sets its default.
+	fn do_remove_queue_weight() -> Weight {
+		let brackets = Self::brackets_count() as u64;
+		let queue_size = T::MaxQueueSize::get() as u64;
+		// reads: brackets count, bounds and membership of every bracket, the removed player and
+		// the queue slots; writes: the removed player, the moved queue slots and the bounds
+		T::DbWeight::get()
+			.reads_writes(2 + brackets * 2 + queue_size, brackets + queue_size * 2)
+	}
+
 	fn do_empty_queue(bracket: u8) {
 		let mut queue = Self::queue_transient();
 
@@ -178,45 +249,127 @@ impl<T: Config> Pallet<T> {
 	}
 
 	fn do_try_match() -> Vec<T::AccountId> {
+		match Self::brackets_count().checked_sub(1) {
+			Some(last_bracket) => Self::do_try_match_in(0..=last_bracket, T::AmountPlayers::get()),
+			None => Vec::new(),
+		}
+	}
+
+	/// Try to match `max_players` players queued in the given brackets.
+	///
+	/// Starting with the player waiting the longest, players are matched with the players queued
+	/// in their own bracket first and in the closest neighbouring brackets after that. A player
+	/// only reaches one bracket further for every `WideningPeriod` blocks they have waited.
+	fn do_try_match_in(
+		brackets_range: RangeInclusive<Bracket>,
+		max_players: u8,
+	) -> Vec<T::AccountId> {
 		let mut queue = Self::queue_transient();
-		let max_players = T::AmountPlayers::get();
+		let brackets_count = Self::brackets_count();
+		let now = frame_system::Pallet::<T>::block_number();
+
+		// pass trough the given brackets, ignoring those that don't exist or are empty
+		let queued = brackets_range
+			.take_while(|bracket| *bracket < brackets_count)
+			.map(|bracket| (bracket, queue.size(bracket)))
+			.filter(|(_, size)| *size > 0)
+			.collect::<Vec<_>>();
+
+		// the first player of each bracket is the one waiting the longest in it
+		let mut longest_waiting = queued
+			.iter()
+			.filter_map(|(bracket, _)| queue.peek(*bracket).map(|p| (p.queued_at, *bracket)))
+			.collect::<Vec<_>>();
+		longest_waiting.sort();
 
 		let mut result: Vec<T::AccountId> = Vec::new();
-		let mut brackets: Vec<Bracket> = Vec::new();
-		// pass trough all brackets
-		for i in 0..Self::brackets_count() {
-			// skip if bracket is empty
-			if queue.size(i) == 0 {
-				continue;
-			}
-			// iterate for each slot occupied and fill, till player match size reached
-			for _j in 0..queue.size(i) {
-				if brackets.len() == max_players as usize {
-					break;
+		for (queued_at, bracket) in longest_waiting {
+			let reach = Self::reach(now.saturating_sub(queued_at));
+			let mut reachable = queued
+				.iter()
+				.filter(|(other, _)| other.abs_diff(bracket) <= reach)
+				.collect::<Vec<_>>();
+			reachable.sort_by_key(|(other, _)| (other.abs_diff(bracket), *other));
+
+			let mut brackets: Vec<Bracket> = Vec::new();
+			for (other, size) in reachable {
+				// iterate for each slot occupied and fill, till player match size reached
+				for _j in 0..*size {
+					if brackets.len() == max_players as usize {
+						break;
+					}
+					brackets.push(*other);
 				}
-				brackets.push(i);
 			}
-			// leave if brackets is filled with brackets
-			if brackets.len() == max_players as usize {
-				break;
+			// try the next player if not enough players are within reach
+			if brackets.len() < max_players as usize {
+				continue;
 			}
-		}
-		// vec not filled with enough brackets leave
-		if brackets.len() < max_players as usize {
-			return result;
-		}
 
-		// pop from the harvested brackets players
-		for bracket in brackets {
-			if let Some(p) = queue.pop(bracket) {
-				result.push(p.account.clone());
-				Self::deposit_event(Event::Popped(p));
+			// pop from the harvested brackets players
+			for bracket in brackets {
+				if let Some(p) = queue.pop(bracket) {
+					result.push(p.account.clone());
+					Self::deposit_event(Event::Popped(p));
+				}
 			}
+			break;
 		}
 		// return result
 		result
 	}
 
+	/// How many brackets away players can be matched after having waited for the given blocks.
+	fn reach(waited: BlockNumberFor<T>) -> Bracket {
+		let period = T::WideningPeriod::get();
+		if period.is_zero() {
+			Bracket::MAX
+		} else {
+			(waited / period).saturated_into()
+		}
+	}
+
+	/// Try to match the players of every group of brackets reported by the `MatchHandler`, handing
+	/// at most one match per group over to it.
+	fn do_auto_match() -> Weight {
+		let brackets_count = Self::brackets_count();
+		let (mut attempts, mut matches) = (0_u64, 0_u64);
+		let mut handled = Weight::zero();
+
+		let mut bracket: Bracket = 0;
+		while bracket < brackets_count {
+			let Some((brackets, players)) = T::MatchHandler::matching_brackets(bracket) else {
+				bracket.saturating_inc();
+				continue;
+			};
+			let next_bracket = brackets.end().saturating_add(1).max(bracket.saturating_add(1));
+
+			attempts.saturating_inc();
+			// a match that can't be handled is put back into the queue
+			let _ = with_storage_layer(|| -> DispatchResult {
+				let matched = Self::do_try_match_in(brackets, players);
+				if matched.is_empty() {
+					return Ok(());
+				}
+				// a rejected match costs as much as a handled one
+				handled.saturating_accrue(T::MatchHandler::on_match_weight(players));
+				T::MatchHandler::on_match(bracket, matched)?;
+				matches.saturating_inc();
+				Ok(())
+			});
+
+			bracket = next_bracket;
+		}
+
+		let brackets_count = brackets_count as u64;
+		T::DbWeight::get()
+			.reads_writes(
+				1 + attempts.saturating_mul(brackets_count * 3),
+				matches.saturating_mul(brackets_count * 3),
+			)
+			.saturating_add(handled)
+	}
+
 	fn do_is_queued(account: T::AccountId) -> bool {
 		Self::queue_transient().is_queued(account)
 	}
@@ -251,10 +404,22 @@ impl<T: Config> MatchFunc<T::AccountId> for Pallet<T> {
 		Self::do_add_queue(account, bracket)
 	}
 
+	fn remove_queue(account: T::AccountId) -> Result<(), sp_runtime::DispatchError> {
+		Self::do_remove_queue(account)
+	}
+
+	fn remove_queue_weight() -> Weight {
+		Self::do_remove_queue_weight()
+	}
+
 	fn try_match() -> Vec<T::AccountId> {
 		Self::do_try_match()
 	}
 
+	fn try_match_in(brackets: RangeInclusive<Bracket>, players: u8) -> Vec<T::AccountId> {
+		Self::do_try_match_in(brackets, players)
+	}
+
 	fn is_queued(account: T::AccountId) -> bool {
 		Self::do_is_queued(account)
 	}
@@ -266,6 +431,39 @@ impl<T: Config> MatchFunc<T::AccountId> for Pallet<T> {
 	fn all_queue_size() -> BufferIndex {
 		Self::do_all_queue_size()
 	}
+
+	fn brackets_count() -> u8 {
+		BracketsCount::<T>::get()
+	}
+}
+
+/// Handles matches found by the matchmaker on its own.
+pub trait MatchHandler<AccountId> {
+	/// The group of brackets players queued in `bracket` are matched within, and how many
+	/// players make a match, or `None` if they are not matched automatically.
+	fn matching_brackets(bracket: Bracket) -> Option<(RangeInclusive<Bracket>, u8)>;
+
+	/// Handle the players matched within the group of brackets `bracket` belongs to.
+	///
+	/// The players are put back into the queue if this fails.
+	fn on_match(bracket: Bracket, players: Vec<AccountId>) -> DispatchResult;
+
+	/// The weight of handling a match of `players` players with `on_match`.
+	fn on_match_weight(players: u8) -> Weight;
+}
+
+impl<AccountId> MatchHandler<AccountId> for () {
+	fn matching_brackets(_bracket: Bracket) -> Option<(RangeInclusive<Bracket>, u8)> {
+		None
+	}
+
+	fn on_match(_bracket: Bracket, _players: Vec<AccountId>) -> DispatchResult {
+		Ok(())
+	}
+
+	fn on_match_weight(_players: u8) -> Weight {
+		Weight::zero()
+	}
 }
 
 pub trait MatchFunc<AccountId> {
@@ -278,15 +476,27 @@ pub trait MatchFunc<AccountId> {
 	/// return true if adding account to bracket queue was successful
 	fn add_queue(account: AccountId, bracket: u8) -> Result<(), sp_runtime::DispatchError>;
 
+	/// remove account from the bracket queue it is in, keeping the order of the others
+	fn remove_queue(account: AccountId) -> Result<(), sp_runtime::DispatchError>;
+
+	/// weight of removing an account from a full bracket queue with `remove_queue`
+	fn remove_queue_weight() -> Weight;
+
 	/// try create a match
 	fn try_match() -> Vec<AccountId>;
 
+	/// try create a match of `players` accounts taken only from the given brackets
+	fn try_match_in(brackets: RangeInclusive<Bracket>, players: u8) -> Vec<AccountId>;
+
 	// return true if an account is queued in any bracket
 	fn is_queued(account: AccountId) -> bool;
 
 	// return size of a specific bracket queue
 	fn queue_size(bracket: u8) -> BufferIndex;
 
+	/// return the number of brackets players can be queued in
+	fn brackets_count() -> u8;
+
 	// return total size of all queued accounts in all brackets
 	fn all_queue_size() -> BufferIndex;
 }
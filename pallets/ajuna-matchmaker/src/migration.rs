@@ -0,0 +1,48 @@
+use super::*;
+use frame_support::{
+	migrations::VersionedMigration,
+	pallet_prelude::*,
+	traits::{Get, UncheckedOnRuntimeUpgrade},
+};
+use sp_std::marker::PhantomData;
+
+/// The current storage version.
+pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+pub mod v1 {
+	use super::*;
+
+	/// A queued player from before the time they were queued at was kept.
+	#[derive(Decode)]
+	struct OldPlayerStruct<AccountId> {
+		account: AccountId,
+	}
+
+	/// Treats the players queued before their wait time was tracked as queued at the upgrade, so
+	/// that their brackets start widening from then on.
+	pub struct UncheckedMigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let queued_at = frame_system::Pallet::<T>::block_number();
+			let mut players = 0_u64;
+
+			BracketKeyValueMap::<T>::translate::<OldPlayerStruct<T::AccountId>, _>(
+				|_bracket, _account, old| {
+					players.saturating_inc();
+					Some(PlayerStruct { account: old.account, queued_at })
+				},
+			);
+
+			T::DbWeight::get().reads_writes(players, players)
+		}
+	}
+
+	pub type MigrateToV1<T> = VersionedMigration<
+		0,
+		1,
+		UncheckedMigrateToV1<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
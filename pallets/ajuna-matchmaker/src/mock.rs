@@ -1,7 +1,8 @@
-use crate as pallet_matchmaker;
+use crate::{self as pallet_matchmaker, Bracket, MatchHandler};
 use frame_support::{
 	parameter_types,
 	traits::{ConstU16, ConstU64, Get},
+	weights::Weight,
 };
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
@@ -9,8 +10,9 @@ use sp_core::H256;
 use sp_runtime::{
 	testing::TestSignature,
 	traits::{BlakeTwo256, IdentifyAccount, IdentityLookup, Verify},
-	BuildStorage,
+	BuildStorage, DispatchError, DispatchResult,
 };
+use sp_std::ops::RangeInclusive;
 
 #[derive(Debug, PartialEq, Eq, Clone, Encode, Decode, MaxEncodedLen, TypeInfo)]
 pub struct ParameterGet<const N: u32>;
@@ -71,12 +73,40 @@ impl frame_system::Config for Test {
 parameter_types! {
 	pub const AmountPlayers: u8 = 2;
 	pub const AmountBrackets: u8 = 3;
+	pub const MaxQueueSize: u16 = 5;
+	pub static WideningPeriod: u64 = 0;
+	pub static Matches: Vec<(Bracket, Vec<MockAccountId>)> = Vec::new();
+	pub static RejectMatches: bool = false;
+}
+
+/// Matches players of all brackets automatically, recording the matches.
+pub struct MockMatchHandler;
+
+impl MatchHandler<MockAccountId> for MockMatchHandler {
+	fn matching_brackets(_bracket: Bracket) -> Option<(RangeInclusive<Bracket>, u8)> {
+		Some((0..=AmountBrackets::get() - 1, AmountPlayers::get()))
+	}
+
+	fn on_match(bracket: Bracket, players: Vec<MockAccountId>) -> DispatchResult {
+		if RejectMatches::get() {
+			return Err(DispatchError::Other("rejected"));
+		}
+		Matches::mutate(|matches| matches.push((bracket, players)));
+		Ok(())
+	}
+
+	fn on_match_weight(players: u8) -> Weight {
+		Weight::from_parts(1_000 * players as u64, 0)
+	}
 }
 
 impl pallet_matchmaker::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type AmountPlayers = AmountPlayers;
 	type AmountBrackets = AmountBrackets;
+	type MaxQueueSize = MaxQueueSize;
+	type WideningPeriod = WideningPeriod;
+	type MatchHandler = MockMatchHandler;
 }
 
 // Build genesis storage according to the mock runtime.
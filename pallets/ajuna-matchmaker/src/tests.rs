@@ -1,4 +1,5 @@
-use crate::{mock::*, Error};
+use crate::{mock::*, Error, Event, PlayerStruct};
+use frame_support::{traits::Hooks, weights::Weight};
 
 #[test]
 fn test_is_queued() {
@@ -61,6 +62,47 @@ fn test_add_queue() {
 	});
 }
 
+#[test]
+fn test_remove_queue() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(MatchMaker::do_add_queue(1, 0), Ok(()));
+		assert_eq!(MatchMaker::do_add_queue(2, 0), Ok(()));
+		assert_eq!(MatchMaker::do_add_queue(3, 0), Ok(()));
+
+		assert_eq!(MatchMaker::do_remove_queue(2), Ok(()));
+		System::assert_last_event(RuntimeEvent::MatchMaker(Event::Removed(PlayerStruct {
+			account: 2,
+			queued_at: 1,
+		})));
+		assert!(!MatchMaker::do_is_queued(2));
+		assert_eq!(MatchMaker::do_queue_size(0), 2);
+		assert_eq!(MatchMaker::do_remove_queue(2), Err(Error::<Test>::NotQueued.into()));
+
+		// the remaining players keep their order
+		assert_eq!(MatchMaker::do_add_queue(2, 0), Ok(()));
+		assert_eq!(MatchMaker::do_try_match(), [1, 3]);
+		assert_eq!(MatchMaker::do_queue_size(0), 1);
+		assert_eq!(MatchMaker::do_remove_queue(2), Ok(()));
+		assert_eq!(MatchMaker::do_all_queue_size(), 0);
+	});
+}
+
+#[test]
+fn test_full_queue() {
+	new_test_ext().execute_with(|| {
+		for player in 0..MaxQueueSize::get() as u64 {
+			assert_eq!(MatchMaker::do_add_queue(player, 0), Ok(()));
+		}
+		assert_eq!(MatchMaker::do_add_queue(10, 0), Err(Error::<Test>::QueueFull.into()));
+		// other brackets are not affected
+		assert_eq!(MatchMaker::do_add_queue(10, 1), Ok(()));
+
+		assert_eq!(MatchMaker::do_remove_queue(0), Ok(()));
+		assert_eq!(MatchMaker::do_add_queue(11, 0), Ok(()));
+	});
+}
+
 #[test]
 fn test_brackets_count() {
 	new_test_ext().execute_with(|| {
@@ -99,3 +141,150 @@ fn test_brackets() {
 		assert_eq!(MatchMaker::do_try_match(), [5, 6]);
 	});
 }
+
+#[test]
+fn test_try_match_in() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(MatchMaker::do_add_queue(1, 0), Ok(()));
+		assert_eq!(MatchMaker::do_add_queue(2, 1), Ok(()));
+		assert_eq!(MatchMaker::do_add_queue(3, 2), Ok(()));
+		assert_eq!(MatchMaker::do_add_queue(4, 2), Ok(()));
+		assert_eq!(MatchMaker::do_add_queue(5, 2), Ok(()));
+
+		// only players of the given brackets are matched
+		assert!(MatchMaker::do_try_match_in(1..=1, 2).is_empty());
+		assert_eq!(MatchMaker::do_try_match_in(2..=2, 3), [3, 4, 5]);
+		assert_eq!(MatchMaker::do_try_match_in(0..=1, 2), [1, 2]);
+		assert_eq!(MatchMaker::do_all_queue_size(), 0);
+	});
+}
+
+#[test]
+fn test_unknown_brackets() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(MatchMaker::do_add_queue(1, 3), Err(Error::<Test>::UnknownBracket.into()));
+		assert!(!MatchMaker::do_is_queued(1));
+
+		assert_eq!(MatchMaker::do_add_queue(1, 2), Ok(()));
+		assert_eq!(MatchMaker::do_add_queue(2, 2), Ok(()));
+		// brackets beyond the configured amount are ignored
+		assert_eq!(MatchMaker::do_try_match_in(2..=10, 2), [1, 2]);
+	});
+}
+
+#[test]
+fn test_queued_at() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(5);
+		assert_eq!(MatchMaker::do_add_queue(1, 0), Ok(()));
+
+		let player = PlayerStruct { account: 1, queued_at: 5 };
+		System::assert_last_event(RuntimeEvent::MatchMaker(Event::Queued(player.clone())));
+		assert_eq!(MatchMaker::key_value(0, 1), Some(player));
+	});
+}
+
+#[test]
+fn test_same_bracket_preferred() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(MatchMaker::do_add_queue(1, 1), Ok(()));
+		System::set_block_number(2);
+		assert_eq!(MatchMaker::do_add_queue(2, 0), Ok(()));
+		assert_eq!(MatchMaker::do_add_queue(3, 1), Ok(()));
+
+		// the player waiting the longest is matched within their own bracket first
+		assert_eq!(MatchMaker::do_try_match(), [1, 3]);
+		assert_eq!(MatchMaker::do_queue_size(0), 1);
+	});
+}
+
+#[test]
+fn test_brackets_widen_over_wait_time() {
+	new_test_ext().execute_with(|| {
+		WideningPeriod::set(10);
+
+		System::set_block_number(1);
+		assert_eq!(MatchMaker::do_add_queue(1, 0), Ok(()));
+		assert_eq!(MatchMaker::do_add_queue(2, 2), Ok(()));
+		assert!(MatchMaker::do_try_match().is_empty());
+
+		// after one period neighbouring brackets are reached
+		System::set_block_number(11);
+		assert!(MatchMaker::do_try_match().is_empty());
+
+		// after two periods the brackets next to the neighbouring ones are reached
+		System::set_block_number(21);
+		assert_eq!(MatchMaker::do_try_match(), [1, 2]);
+
+		// players who just queued are only matched within their own bracket
+		assert_eq!(MatchMaker::do_add_queue(3, 0), Ok(()));
+		assert_eq!(MatchMaker::do_add_queue(4, 1), Ok(()));
+		assert!(MatchMaker::do_try_match().is_empty());
+		System::set_block_number(31);
+		assert_eq!(MatchMaker::do_try_match(), [3, 4]);
+	});
+}
+
+#[test]
+fn test_auto_match() {
+	new_test_ext().execute_with(|| {
+		WideningPeriod::set(10);
+
+		System::set_block_number(1);
+		assert_eq!(MatchMaker::do_add_queue(1, 0), Ok(()));
+		assert_eq!(MatchMaker::do_add_queue(2, 1), Ok(()));
+		assert_eq!(MatchMaker::on_initialize(1), Weight::zero());
+		assert!(Matches::get().is_empty());
+
+		// handling the match is weighed as well
+		System::set_block_number(11);
+		assert_eq!(MatchMaker::on_initialize(11), Weight::from_parts(2_000, 0));
+		assert_eq!(Matches::get(), [(0, vec![1, 2])]);
+		assert_eq!(MatchMaker::do_all_queue_size(), 0);
+	});
+}
+
+#[test]
+fn test_rejected_auto_match_stays_queued() {
+	new_test_ext().execute_with(|| {
+		RejectMatches::set(true);
+
+		assert_eq!(MatchMaker::do_add_queue(1, 0), Ok(()));
+		assert_eq!(MatchMaker::do_add_queue(2, 0), Ok(()));
+		MatchMaker::on_initialize(0);
+
+		assert!(Matches::get().is_empty());
+		assert_eq!(MatchMaker::do_queue_size(0), 2);
+		assert!(MatchMaker::do_is_queued(1));
+		assert!(MatchMaker::do_is_queued(2));
+	});
+}
+
+#[test]
+fn test_migrate_to_v1() {
+	use crate::{migration::v1::MigrateToV1, BracketKeyValueMap};
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		// queued players only consisted of their account
+		assert_eq!(MatchMaker::do_add_queue(1, 0), Ok(()));
+		assert_eq!(MatchMaker::do_add_queue(2, 1), Ok(()));
+		for (bracket, account) in [(0, 1_u64), (1, 2)] {
+			frame_support::storage::unhashed::put(
+				&BracketKeyValueMap::<Test>::hashed_key_for(bracket, account),
+				&account,
+			);
+		}
+		StorageVersion::new(0).put::<MatchMaker>();
+
+		System::set_block_number(5);
+		MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(
+			BracketKeyValueMap::<Test>::get(1, 2),
+			Some(PlayerStruct { account: 2, queued_at: 5 })
+		);
+		assert_eq!(MatchMaker::on_chain_storage_version(), StorageVersion::new(1));
+		assert_eq!(MatchMaker::do_try_match(), [1, 2]);
+	});
+}
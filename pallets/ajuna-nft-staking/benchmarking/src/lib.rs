@@ -34,8 +34,8 @@ use pallet_ajuna_nft_staking::{
 use pallet_nfts::{BenchmarkHelper, ItemConfig};
 use sp_runtime::{
 	bounded_vec,
-	traits::{One, UniqueSaturatedFrom, UniqueSaturatedInto},
-	DispatchError,
+	traits::{One, Saturating, UniqueSaturatedFrom, UniqueSaturatedInto, Zero},
+	DispatchError, Perbill,
 };
 
 // Creator's collections.
@@ -49,6 +49,11 @@ const SNIPER_STAKE_COLLECTION: u16 = 4;
 const SNIPER_FEE_COLLECTION: u16 = 5;
 // Unified attribute value for all contracts.
 const ATTRIBUTE_VALUE: u8 = 10;
+// Longest decimal attribute value that still fits in a u128, the costliest to decode.
+const NUMERIC_ATTRIBUTE_VALUE: [u8; 38] = [b'9'; 38];
+// Unified duration and reward pot for all pools.
+const POOL_DURATION: u32 = 10;
+const POOL_REWARD_POT: u64 = 1_000_000;
 
 enum Mode {
 	Staker,
@@ -79,6 +84,11 @@ type ContractOf<T> = Contract<
 	<T as NftStakingConfig>::KeyLimit,
 	<T as NftStakingConfig>::ValueLimit,
 >;
+type ContractClauseOf<T> = ContractClause<
+	CollectionIdOf<T>,
+	<T as NftStakingConfig>::KeyLimit,
+	<T as NftStakingConfig>::ValueLimit,
+>;
 
 type NftCurrencyOf<T> = <T as pallet_nfts::Config>::Currency;
 type NftBalanceOf<T> = <NftCurrencyOf<T> as Currency<AccountIdFor<T>>>::Balance;
@@ -112,6 +122,25 @@ fn create_creator<T: Config>(reward_item: Option<Vec<u16>>) -> Result<T::Account
 	Ok(creator)
 }
 
+fn apply_as_creator<T: Config>() -> Result<T::AccountId, DispatchError> {
+	let creator = account::<T>("registered_creator");
+	pallet_ajuna_nft_staking::Pallet::<T>::apply_as_creator(
+		RawOrigin::Signed(creator.clone()).into(),
+		1_000_u64.unique_saturated_into(),
+	)?;
+	Ok(creator)
+}
+
+fn register_contract_creator<T: Config>() -> Result<T::AccountId, DispatchError> {
+	let creator = apply_as_creator::<T>()?;
+	pallet_ajuna_nft_staking::Pallet::<T>::register_creator(
+		RawOrigin::Root.into(),
+		creator.clone(),
+		10,
+	)?;
+	Ok(creator)
+}
+
 fn create_contract_collection<T: Config>(creator: &T::AccountId) -> DispatchResult {
 	create_collection::<T>(creator)?;
 	ContractCollectionId::<T>::put(CollectionIdOf::<T>::from(CONTRACT_COLLECTION));
@@ -197,6 +226,18 @@ fn set_attribute<T: Config>(
 	Ok(())
 }
 
+fn set_numeric_attribute<T: Config>(collection_id: u16, item_id: u16, key: u8) -> DispatchResult {
+	let collection_id = &T::Helper::collection(collection_id);
+	let item_id = &T::Helper::item(item_id);
+	<pallet_nfts::Pallet<T> as Mutate<T::AccountId, ItemConfig>>::set_attribute(
+		collection_id,
+		item_id,
+		&[key],
+		&NUMERIC_ATTRIBUTE_VALUE,
+	)?;
+	Ok(())
+}
+
 fn stakes_and_fees<T: Config>(
 	num_stake_clauses: u32,
 	num_fee_clauses: u32,
@@ -270,12 +311,97 @@ fn contract_with<T: Config>(
 		burn_fees: false,
 		rewards,
 		cancel_fee: 333_u64.unique_saturated_into(),
+		early_unstake: None,
 		nft_stake_amount: num_stake_clauses as u8,
 		nft_fee_amount: num_fee_clauses as u8,
 		is_snipeable: true,
 	}
 }
 
+fn staking_pool<T: Config>(num_stake_clauses: u32) -> StakingPoolOf<T> {
+	StakingPoolOf::<T> {
+		start: None,
+		duration: POOL_DURATION.unique_saturated_into(),
+		stake_clauses: (0..num_stake_clauses)
+			.map(|i| ContractClause {
+				namespace: AttributeNamespace::Pallet,
+				target_index: 0,
+				clause: Clause::HasAttributeWithValue(
+					CollectionIdOf::<T>::unique_saturated_from(STAKE_COLLECTION),
+					T::BenchmarkHelper::contract_key((i as u8) * 3),
+					AttributeValue::Equal(T::BenchmarkHelper::contract_value(ATTRIBUTE_VALUE)),
+				),
+			})
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap(),
+		reward_pot: POOL_REWARD_POT.unique_saturated_into(),
+	}
+}
+
+fn create_staking_pool<T: Config>(
+	num_stake_clauses: u32,
+) -> Result<(T::AccountId, ItemIdOf<T>), DispatchError> {
+	create_creator::<T>(None)?; // reserve CONTRACT_COLLECTION and REWARD_COLLECTION
+	let creator = register_contract_creator::<T>()?;
+	let pool_id = T::BenchmarkHelper::item_id(0_u16);
+	pallet_ajuna_nft_staking::Pallet::<T>::create_pool(
+		RawOrigin::Signed(creator.clone()).into(),
+		pool_id,
+		staking_pool::<T>(num_stake_clauses),
+	)?;
+	Ok((creator, pool_id))
+}
+
+fn pool_stakes<T: Config>(
+	who: &T::AccountId,
+	num_stakes: u32,
+) -> Result<BoundedVec<NftIdOf<T>, T::MaxPoolStakes>, DispatchError> {
+	let mut stakes = Vec::new();
+	for i in 0..num_stakes {
+		let item_id = i as u16;
+		mint_item::<T>(who, STAKE_COLLECTION, item_id)?;
+		stakes.push(NftId(
+			CollectionIdOf::<T>::unique_saturated_from(STAKE_COLLECTION),
+			T::BenchmarkHelper::item_id(item_id),
+		));
+	}
+	Ok(stakes.try_into().unwrap())
+}
+
+fn join_staking_pool<T: Config>(
+	pool_id: ItemIdOf<T>,
+	num_stakes: u32,
+) -> Result<T::AccountId, DispatchError> {
+	let staker = account::<T>("staker");
+	create_collections::<T>(&staker, 2)?;
+	let stakes = pool_stakes::<T>(&staker, num_stakes)?;
+	pallet_ajuna_nft_staking::Pallet::<T>::join_pool(
+		RawOrigin::Signed(staker.clone()).into(),
+		pool_id,
+		stakes,
+	)?;
+	Ok(staker)
+}
+
+fn reward_vesting<T: Config>() -> RewardVestingOf<T> {
+	RewardVesting { cliff: One::one(), period: One::one(), period_count: 2 }
+}
+
+fn vest_rewards<T: Config>(creator: T::AccountId, contract_id: ItemIdOf<T>) -> DispatchResult {
+	pallet_ajuna_nft_staking::Pallet::<T>::set_reward_vesting(
+		RawOrigin::Signed(creator).into(),
+		contract_id,
+		reward_vesting::<T>(),
+	)
+}
+
+fn advance_blocks<T: Config>(blocks: u32) {
+	frame_system::Pallet::<T>::set_block_number(
+		frame_system::Pallet::<T>::block_number() + blocks.unique_saturated_into(),
+	);
+}
+
 benchmarks! {
 	set_creator {
 		let creator = account::<T>("creator");
@@ -554,6 +680,225 @@ benchmarks! {
 		assert_last_event::<T>(Event::Sniped { by: sniper, contract_id, rewards })
 	}
 
+	apply_as_creator {
+		let creator = account::<T>("registered_creator");
+		let deposit: BalanceOf<T> = 1_000_u64.unique_saturated_into();
+	}: _(RawOrigin::Signed(creator.clone()), deposit)
+	verify {
+		assert_last_event::<T>(Event::CreatorApplied { creator, deposit })
+	}
+
+	register_creator {
+		let creator = apply_as_creator::<T>()?;
+		let deposit: BalanceOf<T> = 1_000_u64.unique_saturated_into();
+		let quota = 10;
+	}: _(RawOrigin::Root, creator.clone(), quota)
+	verify {
+		assert_last_event::<T>(Event::CreatorRegistered { creator, deposit, quota })
+	}
+
+	deregister_creator {
+		let creator = register_contract_creator::<T>()?;
+	}: _(RawOrigin::Signed(creator.clone()), creator.clone())
+	verify {
+		assert_last_event::<T>(Event::CreatorDeregistered { creator })
+	}
+
+	create_pool {
+		create_creator::<T>(None)?;
+		let creator = register_contract_creator::<T>()?;
+		let pool_id = T::BenchmarkHelper::item_id(0_u16);
+		let pool = staking_pool::<T>(T::MaxStakingClauses::get());
+	}: _(RawOrigin::Signed(creator.clone()), pool_id, pool)
+	verify {
+		assert_last_event::<T>(Event::PoolCreated { pool_id, creator })
+	}
+
+	join_pool {
+		let n in 1..T::MaxPoolStakes::get();
+		// Clause lookups are weighed separately by `evaluate_clauses`.
+		let (_, pool_id) = create_staking_pool::<T>(0)?;
+
+		let by = account::<T>("staker");
+		create_collections::<T>(&by, 2)?;
+		let stakes = pool_stakes::<T>(&by, n)?;
+	}: _(RawOrigin::Signed(by.clone()), pool_id, stakes)
+	verify {
+		assert_last_event::<T>(Event::PoolJoined { by, pool_id, stakes: n })
+	}
+
+	leave_pool {
+		let n in 1..T::MaxPoolStakes::get();
+		let (_, pool_id) = create_staking_pool::<T>(0)?;
+		let by = join_staking_pool::<T>(pool_id, n)?;
+
+		// Advance block halfway through the pool to accrue rewards.
+		advance_blocks::<T>(POOL_DURATION / 2);
+	}: _(RawOrigin::Signed(by.clone()), pool_id)
+	verify {
+		assert_last_event::<T>(Event::PoolLeft { by, pool_id })
+	}
+
+	claim_pool_rewards {
+		let (_, pool_id) = create_staking_pool::<T>(0)?;
+		let by = join_staking_pool::<T>(pool_id, T::MaxPoolStakes::get())?;
+
+		// Advance block halfway through the pool to accrue rewards.
+		advance_blocks::<T>(POOL_DURATION / 2);
+	}: _(RawOrigin::Signed(by.clone()), pool_id)
+	verify {
+		let amount = PoolStates::<T>::get(pool_id).unwrap().paid;
+		assert_last_event::<T>(Event::PoolRewardsClaimed { by, pool_id, amount })
+	}
+
+	close_pool {
+		let (creator, pool_id) = create_staking_pool::<T>(0)?;
+
+		// Advance block past pool end.
+		advance_blocks::<T>(POOL_DURATION);
+	}: _(RawOrigin::Signed(creator), pool_id)
+	verify {
+		let refund = POOL_REWARD_POT.unique_saturated_into();
+		assert_last_event::<T>(Event::PoolClosed { pool_id, refund })
+	}
+
+	evaluate_clauses {
+		// Every lookup after the first one is joined by an `And`, so a single expression holds at
+		// most half of MAX_EXPRESSION_OPS lookups, rounded up.
+		let n in 1..(MAX_EXPRESSION_OPS + 1) / 2;
+		let owner = account::<T>("staker");
+		create_collections::<T>(&owner, MAX_EXPRESSION_COLLECTIONS as usize)?;
+
+		// The staked item belongs to the last of the expression's collections.
+		let collection_id = (MAX_EXPRESSION_COLLECTIONS - 1) as u16;
+		let item_id = 0_u16;
+		mint_item::<T>(&owner, collection_id, item_id)?;
+
+		let mut ops = Vec::new();
+		for i in 0..n {
+			let key = i as u8;
+			set_numeric_attribute::<T>(collection_id, item_id, key)?;
+			ops.push(ClauseOp::HasNumericAttribute(
+				T::BenchmarkHelper::contract_key(key),
+				NumericEncoding::Decimal,
+				NumericComparison::GreaterOrEqual(0),
+			));
+			if i > 0 {
+				ops.push(ClauseOp::And);
+			}
+		}
+		let clause: ContractClauseOf<T> = ContractClause {
+			namespace: AttributeNamespace::Pallet,
+			target_index: 0,
+			clause: Clause::Expression(
+				(0..MAX_EXPRESSION_COLLECTIONS)
+					.map(CollectionIdOf::<T>::unique_saturated_from)
+					.collect::<Vec<_>>()
+					.try_into()
+					.unwrap(),
+				ops.try_into().unwrap(),
+			),
+		};
+		let stake = NftId(
+			CollectionIdOf::<T>::unique_saturated_from(collection_id),
+			T::BenchmarkHelper::item_id(item_id),
+		);
+	}: {
+		assert!(clause.evaluate_for::<T::AccountId, T::NftHelper, ItemIdOf<T>>(&stake));
+	}
+
+	unstake_early {
+		let m = T::MaxStakingClauses::get();
+		let n = T::MaxFeeClauses::get();
+		let amount: BalanceOf<T> = 123_u64.unique_saturated_into();
+		let rewards: BoundedRewardsOf<T> = bounded_vec![Reward::Tokens(amount)];
+		let terms = EarlyUnstake {
+			cliff: Zero::zero(),
+			curve: VestingCurve::Linear,
+			penalty: Perbill::from_percent(10),
+			burn_penalty: false,
+		};
+		let mut contract = contract_with::<T>(m, n, rewards, Mode::Staker);
+		contract.stake_duration = 100_u32.unique_saturated_into();
+		contract.early_unstake = Some(terms);
+		let stake_duration = contract.stake_duration;
+		let contract_id = T::BenchmarkHelper::item_id(0_u16);
+
+		let creator = create_creator::<T>(None)?;
+		create_contract::<T>(creator.clone(), contract_id, contract)?;
+		vest_rewards::<T>(creator, contract_id)?;
+
+		let by = account::<T>("staker");
+		create_collections::<T>(&by, 2)?;
+		accept_contract::<T>(m, n, by.clone(), contract_id, Mode::Staker)?;
+
+		// Advance block halfway through the stake duration to vest part of the rewards.
+		let elapsed = 50_u32;
+		advance_blocks::<T>(elapsed);
+	}: _(RawOrigin::Signed(by.clone()), contract_id)
+	verify {
+		let vested = terms.vested(elapsed.unique_saturated_into(), stake_duration) * amount;
+		let penalty = terms.penalty * vested;
+		let reward = vested.saturating_sub(penalty);
+		assert_last_event::<T>(Event::UnstakedEarly { by, contract_id, reward, penalty })
+	}
+
+	set_contract_renewal {
+		let rewards: BoundedRewardsOf<T> = bounded_vec![Reward::Tokens(123_u64.unique_saturated_into())];
+		let contract = contract_with::<T>(0, 0, rewards, Mode::Staker);
+		let contract_id = T::BenchmarkHelper::item_id(0_u16);
+
+		let creator = create_creator::<T>(None)?;
+		create_contract::<T>(creator.clone(), contract_id, contract)?;
+
+		let mode = RenewalMode::Restake;
+		let max_rounds = 10;
+	}: _(RawOrigin::Signed(creator), contract_id, mode, max_rounds)
+	verify {
+		let budget = (123_u64 * (max_rounds as u64 - 1)).unique_saturated_into();
+		assert_last_event::<T>(Event::RenewalSet { contract_id, mode, max_rounds, budget })
+	}
+
+	renew {
+		let rewards: BoundedRewardsOf<T> = bounded_vec![Reward::Tokens(123_u64.unique_saturated_into())];
+		let contract = contract_with::<T>(0, 0, rewards, Mode::Staker);
+		let contract_id = T::BenchmarkHelper::item_id(0_u16);
+
+		let creator = create_creator::<T>(None)?;
+		create_contract::<T>(creator.clone(), contract_id, contract)?;
+		pallet_ajuna_nft_staking::Pallet::<T>::set_contract_renewal(
+			RawOrigin::Signed(creator.clone()).into(),
+			contract_id,
+			RenewalMode::Restake,
+			2,
+		)?;
+		vest_rewards::<T>(creator, contract_id)?;
+
+		let by = account::<T>("staker");
+		create_collections::<T>(&by, 2)?;
+		accept_contract::<T>(0, 0, by.clone(), contract_id, Mode::Staker)?;
+
+		// Advance block past the stake duration.
+		advance_blocks::<T>(1);
+	}: _(RawOrigin::Signed(by.clone()), contract_id)
+	verify {
+		assert_last_event::<T>(Event::Renewed { by, contract_id, round: 2 })
+	}
+
+	set_reward_vesting {
+		let rewards: BoundedRewardsOf<T> = bounded_vec![Reward::Tokens(123_u64.unique_saturated_into())];
+		let contract = contract_with::<T>(0, 0, rewards, Mode::Staker);
+		let contract_id = T::BenchmarkHelper::item_id(0_u16);
+
+		let creator = create_creator::<T>(None)?;
+		create_contract::<T>(creator.clone(), contract_id, contract)?;
+
+		let vesting = reward_vesting::<T>();
+	}: _(RawOrigin::Signed(creator), contract_id, vesting)
+	verify {
+		assert_last_event::<T>(Event::RewardVestingSet { contract_id, vesting })
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::new_test_ext(),
@@ -43,6 +43,7 @@ frame_support::construct_runtime!(
 		System: frame_system,
 		Balances: pallet_balances,
 		Nft: pallet_nfts,
+		Vesting: orml_vesting,
 		NftStake: pallet_ajuna_nft_staking,
 	}
 );
@@ -191,10 +192,21 @@ impl pallet_nfts::Config for Runtime {
 	type WeightInfo = ();
 }
 
+impl orml_vesting::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type MinVestedTransfer = MockExistentialDeposit;
+	type VestedTransferOrigin = EnsureSigned<MockAccountId>;
+	type WeightInfo = ();
+	type MaxVestingSchedules = frame_support::traits::ConstU32<100>;
+	type BlockNumberProvider = System;
+}
+
 parameter_types! {
 	pub const NftStakingPalletId: PalletId = PalletId(*b"aj/nftst");
 	pub const MaxContracts: u32 = 100;
 	pub const MaxStakingClauses: u32 = 10;
+	pub const MaxPoolStakes: u32 = 5;
 	pub const MaxFeeClauses: u32 = 1;
 	pub const MaxMetadataLenght: u32 = 100;
 }
@@ -207,8 +219,10 @@ impl pallet_ajuna_nft_staking::Config for Runtime {
 	type ItemId = H256;
 	type ItemConfig = pallet_nfts::ItemConfig;
 	type NftHelper = Nft;
+	type RewardVesting = pallet_ajuna_nft_staking::OrmlVesting<Runtime>;
 	type MaxContracts = MaxContracts;
 	type MaxStakingClauses = MaxStakingClauses;
+	type MaxPoolStakes = MaxPoolStakes;
 	type MaxFeeClauses = MaxFeeClauses;
 	type MaxMetadataLength = MaxMetadataLenght;
 	type KeyLimit = KeyLimit;
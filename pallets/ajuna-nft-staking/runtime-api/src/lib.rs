@@ -0,0 +1,42 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API definition for the NFT staking pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use pallet_ajuna_nft_staking::{ContractMatch, NftId};
+use parity_scale_codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait NftStakingApi<AccountId, CollectionId, ItemId>
+	where
+		AccountId: Codec,
+		CollectionId: Codec,
+		ItemId: Codec,
+	{
+		/// Returns the contracts that `account` can currently accept with the NFTs it owns, along
+		/// with the stakes and fees to accept each of them with.
+		fn acceptable_contracts(account: AccountId) -> Vec<ContractMatch<CollectionId, ItemId>>;
+
+		/// Returns the contracts that can currently be accepted with the given NFTs, along with
+		/// the stakes and fees to accept each of them with.
+		fn matching_contracts(
+			nfts: Vec<NftId<CollectionId, ItemId>>,
+		) -> Vec<ContractMatch<CollectionId, ItemId>>;
+	}
+}
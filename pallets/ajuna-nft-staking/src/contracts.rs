@@ -18,7 +18,7 @@ use frame_support::traits::{tokens::nonfungibles_v2::Inspect, ConstU32};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_core::Get;
-use sp_runtime::BoundedVec;
+use sp_runtime::{traits::AtLeast32BitUnsigned, BoundedVec, Perbill, SaturatedConversion};
 use sp_std::{fmt::Debug, vec::Vec};
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Default, Encode, Decode, MaxEncodedLen, TypeInfo)]
@@ -30,6 +30,24 @@ pub struct ContractStats {
 	pub contracts_lost: u32,
 }
 
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct CreatorStats {
+	pub contracts_created: u32,
+	pub contracts_removed: u32,
+	pub contracts_accepted: u32,
+}
+
+/// Registration details of an account allowed to author staking contracts.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct CreatorInfo<Balance> {
+	/// The amount reserved from the creator for as long as it stays registered.
+	pub deposit: Balance,
+	/// The maximum number of contracts the creator can have at once.
+	pub quota: u32,
+	/// The number of contracts created by the creator that are still in storage.
+	pub contracts: u32,
+}
+
 /// Attribute namespaces for non-fungible tokens.
 /// Based on the logic for
 /// https://github.com/paritytech/substrate/blob/polkadot-v0.9.42/frame/nfts/src/types.rs#L326
@@ -135,6 +153,117 @@ where
 	}
 }
 
+/// The maximum number of collections an expression clause can match items from.
+pub const MAX_EXPRESSION_COLLECTIONS: u32 = 10;
+/// The maximum number of operations of an expression clause.
+pub const MAX_EXPRESSION_OPS: u32 = 16;
+/// The maximum number of attribute lookups needed to evaluate any single clause.
+pub const MAX_CLAUSE_LOOKUPS: u32 = MAX_EXPRESSION_OPS;
+
+/// Encoding used to interpret an attribute value as an unsigned number.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub enum NumericEncoding {
+	/// ASCII decimal digits, such as `b"150"`.
+	Decimal,
+	/// Little-endian bytes of an unsigned integer of up to 16 bytes.
+	LittleEndian,
+}
+
+impl NumericEncoding {
+	pub fn decode_value(&self, value: &[u8]) -> Option<u128> {
+		if value.is_empty() {
+			return None
+		}
+		match self {
+			NumericEncoding::Decimal => value.iter().try_fold(0_u128, |acc, byte| {
+				let digit = (*byte as char).to_digit(10)?;
+				acc.checked_mul(10)?.checked_add(digit as u128)
+			}),
+			NumericEncoding::LittleEndian => {
+				if value.len() > 16 {
+					return None
+				}
+				let mut bytes = [0_u8; 16];
+				bytes[..value.len()].copy_from_slice(value);
+				Some(u128::from_le_bytes(bytes))
+			},
+		}
+	}
+}
+
+/// Comparison of a numeric attribute value against a constant, in the form of
+/// `attribute <comparison> constant`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub enum NumericComparison {
+	Equal(u128),
+	Greater(u128),
+	Lower(u128),
+	GreaterOrEqual(u128),
+	LowerOrEqual(u128),
+}
+
+impl NumericComparison {
+	pub fn evaluate_for(&self, value: u128) -> bool {
+		match self {
+			NumericComparison::Equal(expected) => value == *expected,
+			NumericComparison::Greater(expected) => value > *expected,
+			NumericComparison::Lower(expected) => value < *expected,
+			NumericComparison::GreaterOrEqual(expected) => value >= *expected,
+			NumericComparison::LowerOrEqual(expected) => value <= *expected,
+		}
+	}
+}
+
+/// A single operation of an expression clause, evaluated in postfix order: attribute checks push
+/// their outcome onto a stack, while `And`, `Or` and `Not` pop their operands from it and push
+/// their result. For example `[A, B, Or, C, Not, And]` evaluates `(A || B) && !C`.
+#[derive(Debug, Clone, Eq, PartialEq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub enum ClauseOp<KL, VL>
+where
+	KL: Get<u32>,
+	VL: Get<u32>,
+{
+	HasAttribute(Attribute<KL>),
+	HasAttributeWithValue(Attribute<KL>, AttributeValue<VL>),
+	HasNumericAttribute(Attribute<KL>, NumericEncoding, NumericComparison),
+	And,
+	Or,
+	Not,
+}
+
+pub type ClauseOps<KL, VL> = BoundedVec<ClauseOp<KL, VL>, ConstU32<MAX_EXPRESSION_OPS>>;
+
+impl<KL, VL> ClauseOp<KL, VL>
+where
+	KL: Get<u32>,
+	VL: Get<u32>,
+{
+	/// Returns the resulting stack depth of applying the operation to a stack of the given
+	/// depth, or `None` if the stack doesn't hold enough operands.
+	fn apply_depth(&self, depth: usize) -> Option<usize> {
+		match self {
+			ClauseOp::HasAttribute(..) |
+			ClauseOp::HasAttributeWithValue(..) |
+			ClauseOp::HasNumericAttribute(..) => Some(depth + 1),
+			ClauseOp::And | ClauseOp::Or => depth.checked_sub(2).map(|depth| depth + 1),
+			ClauseOp::Not => depth.checked_sub(1).map(|depth| depth + 1),
+		}
+	}
+
+	fn is_lookup(&self) -> bool {
+		!matches!(self, ClauseOp::And | ClauseOp::Or | ClauseOp::Not)
+	}
+}
+
+/// Checks that the given operations form a single well-formed expression.
+pub fn is_well_formed<KL, VL>(ops: &[ClauseOp<KL, VL>]) -> bool
+where
+	KL: Get<u32>,
+	VL: Get<u32>,
+{
+	ops.iter().try_fold(0, |depth, op| op.apply_depth(depth)) == Some(1)
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Encode, Decode, MaxEncodedLen, TypeInfo)]
 pub enum Clause<CollectionId, KL, VL>
 where
@@ -153,6 +282,9 @@ where
 		CollectionId,
 		BoundedVec<(Attribute<KL>, AttributeValue<VL>), ConstU32<10>>,
 	),
+	HasNumericAttribute(CollectionId, Attribute<KL>, NumericEncoding, NumericComparison),
+	/// Matches items of any of the given collections for which the expression holds.
+	Expression(BoundedVec<CollectionId, ConstU32<MAX_EXPRESSION_COLLECTIONS>>, ClauseOps<KL, VL>),
 }
 
 impl<CollectionId, KL, VL> Clause<CollectionId, KL, VL>
@@ -172,16 +304,19 @@ where
 		F: Fn(&'b CollectionId, &'b ItemId, &'b [u8]) -> Option<Vec<u8>>,
 		'a: 'b,
 	{
-		let clause_collection_id = match self {
-			Clause::HasAttribute(collection_id, _) => collection_id,
-			Clause::HasAttributeWithValue(collection_id, _, _) => collection_id,
-			Clause::HasAllAttributes(collection_id, _) => collection_id,
-			Clause::HasAnyAttributes(collection_id, _) => collection_id,
-			Clause::HasAllAttributesWithValues(collection_id, _) => collection_id,
-			Clause::HasAnyAttributesWithValues(collection_id, _) => collection_id,
-		};
 		let NftId(collection_id, item_id) = address;
-		clause_collection_id == collection_id &&
+		let matches_collection = match self {
+			Clause::HasAttribute(clause_collection_id, _) |
+			Clause::HasAttributeWithValue(clause_collection_id, _, _) |
+			Clause::HasAllAttributes(clause_collection_id, _) |
+			Clause::HasAnyAttributes(clause_collection_id, _) |
+			Clause::HasAllAttributesWithValues(clause_collection_id, _) |
+			Clause::HasAnyAttributesWithValues(clause_collection_id, _) |
+			Clause::HasNumericAttribute(clause_collection_id, _, _, _) =>
+				clause_collection_id == collection_id,
+			Clause::Expression(collection_ids, _) => collection_ids.contains(collection_id),
+		};
+		matches_collection &&
 			(match self {
 				Clause::HasAttribute(_, key) =>
 					evaluate_fn(collection_id, item_id, key.as_slice()).is_some(),
@@ -214,8 +349,68 @@ where
 							false
 						}
 					}),
+				Clause::HasNumericAttribute(_, key, encoding, comparison) =>
+					evaluate_fn(collection_id, item_id, key.as_slice())
+						.and_then(|value| encoding.decode_value(value.as_slice()))
+						.map(|value| comparison.evaluate_for(value))
+						.unwrap_or(false),
+				Clause::Expression(_, ops) => {
+					if !is_well_formed(ops) {
+						return false
+					}
+					let mut stack = Vec::with_capacity(ops.len());
+					for op in ops.iter() {
+						let result = match op {
+							ClauseOp::HasAttribute(key) =>
+								evaluate_fn(collection_id, item_id, key.as_slice()).is_some(),
+							ClauseOp::HasAttributeWithValue(key, expected_value) =>
+								evaluate_fn(collection_id, item_id, key.as_slice())
+									.map(|value| expected_value.evaluate_for(value.as_slice()))
+									.unwrap_or(false),
+							ClauseOp::HasNumericAttribute(key, encoding, comparison) =>
+								evaluate_fn(collection_id, item_id, key.as_slice())
+									.and_then(|value| encoding.decode_value(value.as_slice()))
+									.map(|value| comparison.evaluate_for(value))
+									.unwrap_or(false),
+							ClauseOp::And => {
+								let (rhs, lhs) = (stack.pop(), stack.pop());
+								lhs.unwrap_or(false) && rhs.unwrap_or(false)
+							},
+							ClauseOp::Or => {
+								let (rhs, lhs) = (stack.pop(), stack.pop());
+								lhs.unwrap_or(false) || rhs.unwrap_or(false)
+							},
+							ClauseOp::Not => !stack.pop().unwrap_or(true),
+						};
+						stack.push(result);
+					}
+					stack.pop().unwrap_or(false)
+				},
 			})
 	}
+
+	/// Returns the number of attribute lookups needed to evaluate the clause.
+	pub fn lookups(&self) -> u32 {
+		match self {
+			Clause::HasAttribute(..) |
+			Clause::HasAttributeWithValue(..) |
+			Clause::HasNumericAttribute(..) => 1,
+			Clause::HasAllAttributes(_, attributes) | Clause::HasAnyAttributes(_, attributes) =>
+				attributes.len() as u32,
+			Clause::HasAllAttributesWithValues(_, attributes) |
+			Clause::HasAnyAttributesWithValues(_, attributes) => attributes.len() as u32,
+			Clause::Expression(_, ops) => ops.iter().filter(|op| op.is_lookup()).count() as u32,
+		}
+	}
+
+	/// Checks that the clause can be evaluated, which only fails for malformed expressions.
+	pub fn is_valid(&self) -> bool {
+		match self {
+			Clause::Expression(collection_ids, ops) =>
+				!collection_ids.is_empty() && is_well_formed(ops),
+			_ => true,
+		}
+	}
 }
 
 pub(crate) type BoundedClauses<CollectionId, KL, VL> =
@@ -224,6 +419,74 @@ pub(crate) type BoundedClauses<CollectionId, KL, VL> =
 pub(crate) type BoundedRewards<Balance, CollectionId, ItemId> =
 	BoundedVec<Reward<Balance, CollectionId, ItemId>, ConstU32<5>>;
 
+/// Shape of the curve along which token rewards vest over a contract's stake duration.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub enum VestingCurve {
+	/// Rewards vest at a constant rate.
+	Linear,
+	/// Rewards vest slowly at first and faster towards the end, rewarding longer stakes.
+	Quadratic,
+}
+
+/// Terms under which a staker can leave an accepted contract before its stake duration ends,
+/// receiving the vested share of its token rewards minus a penalty.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct EarlyUnstake<BlockNumber> {
+	/// The number of blocks after acceptance before any rewards vest.
+	pub cliff: BlockNumber,
+	/// The curve along which rewards vest after acceptance.
+	pub curve: VestingCurve,
+	/// The share of the vested rewards withheld from the staker.
+	pub penalty: Perbill,
+	/// If true the penalty is burned instead of being transferred to the creator.
+	pub burn_penalty: bool,
+}
+
+impl<BlockNumber> EarlyUnstake<BlockNumber>
+where
+	BlockNumber: AtLeast32BitUnsigned + Copy,
+{
+	/// Returns the share of rewards vested after staking for `elapsed` out of `duration` blocks.
+	pub fn vested(&self, elapsed: BlockNumber, duration: BlockNumber) -> Perbill {
+		if duration.is_zero() {
+			return Perbill::one()
+		}
+		if elapsed < self.cliff {
+			return Perbill::zero()
+		}
+		let share = Perbill::from_rational(
+			elapsed.min(duration).saturated_into::<u64>(),
+			duration.saturated_into::<u64>(),
+		);
+		match self.curve {
+			VestingCurve::Linear => share,
+			VestingCurve::Quadratic => share.square(),
+		}
+	}
+}
+
+/// How a renewable contract starts a new round once its stake duration ends.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub enum RenewalMode {
+	/// The staker can claim the round's rewards and re-stake the same NFTs for another round.
+	Restake,
+	/// The contract is listed again for anyone to accept once claimed or sniped.
+	Relist,
+}
+
+/// Renewal terms of a contract whose token rewards are funded for several rounds.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct ContractRenewal<Balance> {
+	/// How the contract is renewed.
+	pub mode: RenewalMode,
+	/// The maximum number of rounds of the contract, including the first one.
+	pub max_rounds: u32,
+	/// The current round of the contract, starting from 1.
+	pub round: u32,
+	/// The amount deposited by the creator to fund the rewards of the remaining rounds.
+	pub budget: Balance,
+}
+
 /// Specification for a staking contract, in short it's a list of criteria to be fulfilled,
 /// with a given reward after the duration is complete.
 #[derive(Debug, Clone, Eq, PartialEq, Encode, Decode, MaxEncodedLen, TypeInfo)]
@@ -259,6 +522,9 @@ where
 	/// The fee required to cancel the given contract. Any staked NFTs for the contract will be
 	/// immediately returned to the staker upon cancellation.
 	pub cancel_fee: Balance,
+	/// The terms to leave the given contract early with a share of its token rewards. If it is
+	/// not set, the contract can only be left early via cancellation.
+	pub early_unstake: Option<EarlyUnstake<BlockNumber>>,
 
 	/// Amount of NFT to stake for the contract.
 	pub nft_stake_amount: u8,
@@ -0,0 +1,132 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::contracts::{Contract, ContractClause, NftId};
+use frame_support::traits::tokens::nonfungibles_v2::Inspect;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_core::Get;
+use sp_std::{vec, vec::Vec};
+
+/// The stakes and fees assigned to the stake and fee indices of a contract, in that order.
+pub type NftAssignment<CollectionId, ItemId> =
+	(Vec<NftId<CollectionId, ItemId>>, Vec<NftId<CollectionId, ItemId>>);
+
+/// A contract that can be accepted with a given set of NFTs, along with the stakes and fees to
+/// accept it with.
+#[derive(Debug, Clone, Eq, PartialEq, Encode, Decode, TypeInfo)]
+pub struct ContractMatch<CollectionId, ItemId> {
+	/// The identifier of the matched contract.
+	pub contract_id: ItemId,
+	/// The NFTs to stake, where each one fulfils the stake clauses targeting its index.
+	pub stakes: Vec<NftId<CollectionId, ItemId>>,
+	/// The NFTs to pay as fees, where each one fulfils the fee clauses targeting its index.
+	pub fees: Vec<NftId<CollectionId, ItemId>>,
+}
+
+impl<Balance, CollectionId, ItemId, BlockNumber, KL, VL>
+	Contract<Balance, CollectionId, ItemId, BlockNumber, KL, VL>
+where
+	CollectionId: Clone + PartialEq,
+	ItemId: Clone + PartialEq,
+	KL: Get<u32>,
+	VL: Get<u32>,
+{
+	/// Assigns distinct `nfts` to the stake and fee indices of the contract, such that every NFT
+	/// fulfils the clauses targeting its index. Returns the stakes and fees of the assignment, or
+	/// `None` if the contract cannot be fulfilled with the given NFTs.
+	pub fn match_nfts<AccountId, NftInspector>(
+		&self,
+		nfts: &[NftId<CollectionId, ItemId>],
+	) -> Option<NftAssignment<CollectionId, ItemId>>
+	where
+		NftInspector: Inspect<AccountId, CollectionId = CollectionId, ItemId = ItemId>,
+	{
+		let stake_amount = self.nft_stake_amount as usize;
+		let fee_amount = self.nft_fee_amount as usize;
+
+		// Stake indices come first, followed by fee indices.
+		let mut slot_clauses: Vec<Vec<&ContractClause<CollectionId, KL, VL>>> =
+			vec![Vec::new(); stake_amount + fee_amount];
+		for (offset, amount, clauses) in
+			[(0, stake_amount, &self.stake_clauses), (stake_amount, fee_amount, &self.fee_clauses)]
+		{
+			for clause in clauses.iter() {
+				let index = clause.target_index as usize;
+				if index >= amount {
+					return None
+				}
+				slot_clauses[offset + index].push(clause);
+			}
+		}
+
+		let candidates = slot_clauses
+			.iter()
+			.map(|clauses| {
+				(0..nfts.len())
+					.filter(|nft| {
+						clauses.iter().all(|clause| {
+							clause.evaluate_for::<AccountId, NftInspector, ItemId>(&nfts[*nft])
+						})
+					})
+					.collect::<Vec<_>>()
+			})
+			.collect::<Vec<_>>();
+
+		let mut nft_slots = vec![None; nfts.len()];
+		for slot in 0..candidates.len() {
+			if !assign_slot(slot, &candidates, &mut nft_slots, &mut vec![false; nfts.len()]) {
+				return None
+			}
+		}
+
+		let mut assignment = vec![0; candidates.len()];
+		for (nft, slot) in nft_slots.into_iter().enumerate() {
+			if let Some(slot) = slot {
+				assignment[slot] = nft;
+			}
+		}
+		let mut matched = assignment.into_iter().map(|nft| nfts[nft].clone());
+		let stakes = matched.by_ref().take(stake_amount).collect();
+		let fees = matched.collect();
+		Some((stakes, fees))
+	}
+}
+
+/// Assigns one of its candidate NFTs to `slot`, moving NFTs assigned to other slots to their
+/// remaining candidates if needed. Returns false if no such assignment exists.
+fn assign_slot(
+	slot: usize,
+	candidates: &[Vec<usize>],
+	nft_slots: &mut [Option<usize>],
+	visited: &mut [bool],
+) -> bool {
+	for &nft in &candidates[slot] {
+		if visited[nft] {
+			continue
+		}
+		visited[nft] = true;
+		let reassigned = match nft_slots[nft] {
+			Some(other_slot) => assign_slot(other_slot, candidates, nft_slots, visited),
+			None => true,
+		};
+		if reassigned {
+			nft_slots[nft] = Some(slot);
+			return true
+		}
+	}
+	false
+}
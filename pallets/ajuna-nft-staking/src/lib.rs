@@ -20,28 +20,35 @@
 mod tests;
 
 pub mod contracts;
+pub mod discovery;
+pub mod migration;
+pub mod pools;
+pub mod vesting;
 pub mod weights;
 
 use frame_support::{
 	pallet_prelude::*,
 	traits::{
-		tokens::nonfungibles_v2::{Destroy, Inspect, Mutate, Transfer},
+		tokens::nonfungibles_v2::{Destroy, Inspect, InspectEnumerable, Mutate, Transfer},
 		Currency,
 		ExistenceRequirement::AllowDeath,
-		Get, Imbalance, WithdrawReasons,
+		Get, Imbalance, ReservableCurrency, WithdrawReasons,
 	},
 	PalletId,
 };
 use frame_system::pallet_prelude::*;
 use scale_info::prelude::string::String as Str;
 use sp_runtime::{
-	traits::{AccountIdConversion, AtLeast32BitUnsigned, CheckedAdd, Zero},
-	ArithmeticError,
+	traits::{AccountIdConversion, AtLeast32BitUnsigned, CheckedAdd, CheckedMul, Saturating, Zero},
+	ArithmeticError, FixedPointNumber, FixedU128, Perbill, SaturatedConversion,
 };
 use sp_std::prelude::*;
 
 pub use contracts::*;
+pub use discovery::*;
 pub use pallet::*;
+pub use pools::*;
+pub use vesting::*;
 pub use weights::*;
 
 #[frame_support::pallet]
@@ -62,12 +69,27 @@ pub mod pallet {
 		<T as Config>::ValueLimit,
 	>;
 	pub type NftIdOf<T> = NftId<CollectionIdOf<T>, ItemIdOf<T>>;
+	pub type ContractMatchOf<T> = ContractMatch<CollectionIdOf<T>, ItemIdOf<T>>;
+	pub(crate) type EarlyUnstakeOf<T> = EarlyUnstake<BlockNumberFor<T>>;
+	pub type ContractRenewalOf<T> = ContractRenewal<BalanceOf<T>>;
+	pub type RewardVestingOf<T> = RewardVesting<BlockNumberFor<T>>;
+	pub type CreatorInfoOf<T> = CreatorInfo<BalanceOf<T>>;
+	pub type StakingPoolOf<T> = StakingPool<
+		BalanceOf<T>,
+		CollectionIdOf<T>,
+		BlockNumberFor<T>,
+		<T as Config>::KeyLimit,
+		<T as Config>::ValueLimit,
+	>;
+	pub type PoolStateOf<T> = PoolState<BalanceOf<T>, BlockNumberFor<T>>;
+	pub type PoolStakeOf<T> = PoolStake<BalanceOf<T>>;
 
 	pub type BoundedRewardsOf<T> = BoundedRewards<BalanceOf<T>, CollectionIdOf<T>, ItemIdOf<T>>;
 	pub type RewardOf<T> = Reward<BalanceOf<T>, CollectionIdOf<T>, ItemIdOf<T>>;
 
 	pub(crate) type ContractIdsOf<T> = BoundedVec<ItemIdOf<T>, <T as Config>::MaxContracts>;
 	pub(crate) type StakedItemsOf<T> = BoundedVec<NftIdOf<T>, <T as Config>::MaxStakingClauses>;
+	pub(crate) type PoolStakedItemsOf<T> = BoundedVec<NftIdOf<T>, <T as Config>::MaxPoolStakes>;
 
 	#[derive(
 		Encode, Decode, MaxEncodedLen, TypeInfo, Copy, Clone, Debug, Default, Eq, PartialEq,
@@ -81,9 +103,11 @@ pub mod pallet {
 		Claim,
 		Cancel,
 		Snipe,
+		Unstake,
 	}
 
 	#[pallet::pallet]
+	#[pallet::storage_version(migration::STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[cfg(feature = "runtime-benchmarks")]
@@ -120,7 +144,7 @@ pub mod pallet {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
 		/// The staking balance.
-		type Currency: Currency<Self::AccountId>;
+		type Currency: ReservableCurrency<Self::AccountId>;
 
 		/// Identifier for the collection of an Nft.
 		type CollectionId: Member + Parameter + MaxEncodedLen + Copy + AtLeast32BitUnsigned;
@@ -132,10 +156,14 @@ pub mod pallet {
 		type ItemConfig: Default + MaxEncodedLen + TypeInfo;
 
 		type NftHelper: Inspect<Self::AccountId, CollectionId = Self::CollectionId, ItemId = Self::ItemId>
+			+ InspectEnumerable<Self::AccountId>
 			+ Mutate<Self::AccountId, Self::ItemConfig>
 			+ Destroy<Self::AccountId>
 			+ Transfer<Self::AccountId>;
 
+		/// Handler used to pay token rewards under a vesting schedule.
+		type RewardVesting: VestedTransfer<Self::AccountId, BalanceOf<Self>, BlockNumberFor<Self>>;
+
 		/// The maximum number of contracts an account can have.
 		#[pallet::constant]
 		type MaxContracts: Get<u32>;
@@ -148,6 +176,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxFeeClauses: Get<u32>;
 
+		/// The maximum number of NFTs an account can stake in a staking pool.
+		#[pallet::constant]
+		type MaxPoolStakes: Get<u32>;
+
 		/// The maximum number of bytes used for a contract's metadata.
 		#[pallet::constant]
 		type MaxMetadataLength: Get<u32>;
@@ -171,6 +203,16 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type Creator<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
 
+	#[pallet::storage]
+	pub type Creators<T: Config> = StorageMap<_, Identity, T::AccountId, CreatorInfoOf<T>>;
+
+	#[pallet::storage]
+	pub type CreatorApplications<T: Config> = StorageMap<_, Identity, T::AccountId, BalanceOf<T>>;
+
+	#[pallet::storage]
+	pub type CreatorsStats<T: Config> =
+		StorageMap<_, Identity, T::AccountId, CreatorStats, ValueQuery>;
+
 	#[pallet::storage]
 	pub type GlobalConfigs<T: Config> = StorageValue<_, GlobalConfig, ValueQuery>;
 
@@ -180,6 +222,9 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type Contracts<T: Config> = StorageMap<_, Identity, T::ItemId, ContractOf<T>>;
 
+	#[pallet::storage]
+	pub type ContractCreators<T: Config> = StorageMap<_, Identity, T::ItemId, T::AccountId>;
+
 	#[pallet::storage]
 	pub type ContractsMetadata<T: Config> =
 		StorageMap<_, Identity, T::ItemId, BoundedVec<u8, T::MaxMetadataLength>>;
@@ -205,11 +250,52 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type ContractIds<T: Config> = StorageMap<_, Identity, T::AccountId, ContractIdsOf<T>>;
 
+	#[pallet::storage]
+	pub type ContractRenewals<T: Config> = StorageMap<_, Identity, T::ItemId, ContractRenewalOf<T>>;
+
+	#[pallet::storage]
+	pub type ContractRewardVestings<T: Config> =
+		StorageMap<_, Identity, T::ItemId, RewardVestingOf<T>>;
+
+	#[pallet::storage]
+	pub type Pools<T: Config> = StorageMap<_, Identity, T::ItemId, StakingPoolOf<T>>;
+
+	#[pallet::storage]
+	pub type PoolCreators<T: Config> = StorageMap<_, Identity, T::ItemId, T::AccountId>;
+
+	#[pallet::storage]
+	pub type PoolStates<T: Config> = StorageMap<_, Identity, T::ItemId, PoolStateOf<T>>;
+
+	#[pallet::storage]
+	pub type PoolStakes<T: Config> =
+		StorageDoubleMap<_, Identity, T::ItemId, Identity, T::AccountId, PoolStakeOf<T>>;
+
+	#[pallet::storage]
+	pub type PoolStakedItems<T: Config> =
+		StorageDoubleMap<_, Identity, T::ItemId, Identity, T::AccountId, PoolStakedItemsOf<T>>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// An creator has been set.
 		CreatorSet { creator: T::AccountId },
+		/// An account has applied to become a contract creator.
+		CreatorApplied { creator: T::AccountId, deposit: BalanceOf<T> },
+		/// An account has been registered as a contract creator.
+		CreatorRegistered { creator: T::AccountId, deposit: BalanceOf<T>, quota: u32 },
+		/// An account has been removed from the contract creators, or its application has been
+		/// withdrawn.
+		CreatorDeregistered { creator: T::AccountId },
+		/// A new staking pool has been created.
+		PoolCreated { pool_id: T::ItemId, creator: T::AccountId },
+		/// NFTs have been staked into a staking pool.
+		PoolJoined { by: T::AccountId, pool_id: T::ItemId, stakes: u32 },
+		/// All NFTs of an account have been withdrawn from a staking pool.
+		PoolLeft { by: T::AccountId, pool_id: T::ItemId },
+		/// Rewards accrued in a staking pool have been claimed.
+		PoolRewardsClaimed { by: T::AccountId, pool_id: T::ItemId, amount: BalanceOf<T> },
+		/// A staking pool has been closed, refunding its undistributed rewards to its creator.
+		PoolClosed { pool_id: T::ItemId, refund: BalanceOf<T> },
 		/// The collection holding the staking contracts has been set.
 		ContractCollectionSet { collection_id: T::CollectionId },
 		/// The pallet's global config has been set.
@@ -226,6 +312,27 @@ pub mod pallet {
 		Cancelled { by: T::AccountId, contract_id: T::ItemId },
 		/// A staking contract has been sniped.
 		Sniped { by: T::AccountId, contract_id: T::ItemId, rewards: BoundedRewardsOf<T> },
+		/// A staking contract has been left early, paying out its vested token rewards minus the
+		/// penalty.
+		UnstakedEarly {
+			by: T::AccountId,
+			contract_id: T::ItemId,
+			reward: BalanceOf<T>,
+			penalty: BalanceOf<T>,
+		},
+		/// The renewal terms of a staking contract have been set.
+		RenewalSet {
+			contract_id: T::ItemId,
+			mode: RenewalMode,
+			max_rounds: u32,
+			budget: BalanceOf<T>,
+		},
+		/// A staking contract has been re-staked for a new round.
+		Renewed { by: T::AccountId, contract_id: T::ItemId, round: u32 },
+		/// A staking contract has been listed again for a new round.
+		Relisted { contract_id: T::ItemId, round: u32 },
+		/// The token rewards of a staking contract will be paid under a vesting schedule.
+		RewardVestingSet { contract_id: T::ItemId, vesting: RewardVestingOf<T> },
 	}
 
 	/// Error for the treasury pallet.
@@ -295,6 +402,52 @@ pub mod pallet {
 		CannotSnipeOwnContract,
 		/// Cannot claim contract with unknown owner, only sniping is possible.
 		CannotClaimUnknownContract,
+		/// The given account is already registered as a contract creator.
+		CreatorAlreadyRegistered,
+		/// The given account is not registered as a contract creator.
+		UnregisteredCreator,
+		/// The creator has reached the maximum number of contracts it can have.
+		CreatorQuotaReached,
+		/// The creator still has contracts in storage.
+		CreatorHasContracts,
+		/// The given account has already applied to become a contract creator.
+		CreatorAlreadyApplied,
+		/// The given account hasn't applied to become a contract creator.
+		NoCreatorApplication,
+		/// A clause of the given contract or pool is malformed.
+		InvalidClause,
+		/// The given pool doesn't exist.
+		UnknownPool,
+		/// A pool with the given ID already exists.
+		PoolAlreadyExists,
+		/// The given pool's duration is zero.
+		ZeroPoolDuration,
+		/// The given pool has ended and can no longer be joined.
+		PoolEnded,
+		/// The given pool is still distributing rewards.
+		PoolNotEnded,
+		/// The given pool still has NFTs staked.
+		PoolHasStakers,
+		/// The given account has no NFTs staked in the pool.
+		NotPoolStaker,
+		/// The number of NFTs staked by the account in the pool exceeds maximum allowed.
+		MaxPoolStakes,
+		/// The contract doesn't allow leaving early other than by cancellation.
+		EarlyUnstakeDisabled,
+		/// The renewal terms need at least two rounds.
+		InvalidRenewal,
+		/// Only contracts with token rewards can be renewed.
+		NonRenewableContract,
+		/// The contract already has renewal terms.
+		RenewalAlreadySet,
+		/// The contract cannot be re-staked.
+		NotRestakeable,
+		/// The contract has no rounds left.
+		NoRoundsLeft,
+		/// The vesting schedule needs a non-zero period and period count.
+		InvalidRewardVesting,
+		/// The vesting backend cannot vest the contract's token rewards.
+		RewardVestingUnsupported,
 	}
 
 	#[pallet::call]
@@ -350,7 +503,8 @@ pub mod pallet {
 		/// sets the parameters of the contract, such as the assets involved, the staking period,
 		/// and any other requirements. The creator will also transfer the necessary reward NFTs or
 		/// tokens to the provider, which will be locked until a staker claims them, or the contract
-		/// is removed in an unaccepted state.
+		/// is removed in an unaccepted state. It can be called by the root-set creator or by any
+		/// registered creator within its quota.
 		#[pallet::weight(
 			T::WeightInfo::create_token_reward()
 				.max(T::WeightInfo::create_nft_reward())
@@ -363,7 +517,7 @@ pub mod pallet {
 			metadata: Option<Str>,
 			contract_attrs: Option<BoundedVec<(Str, Str), ConstU32<10>>>,
 		) -> DispatchResult {
-			let creator = Self::ensure_creator(origin)?;
+			let creator = Self::ensure_contract_creator(origin)?;
 			Self::ensure_pallet_unlocked()?;
 			Self::ensure_contract_clauses(&contract)?;
 			Self::create_contract(creator, contract_id, contract, metadata, contract_attrs)
@@ -373,14 +527,15 @@ pub mod pallet {
 		///
 		/// This call enables the creator to remove inactive staking contracts that haven't been
 		/// accepted by any staker. This can be done to clean up the available staking contracts or
-		/// to adjust the parameters before re-creating the contract.
+		/// to adjust the parameters before re-creating the contract. The root-set creator can
+		/// remove any contract, in which case its rewards return to the contract's creator.
 		#[pallet::weight(
 			T::WeightInfo::remove_token_reward()
 				.max(T::WeightInfo::remove_nft_reward())
 		)]
 		#[pallet::call_index(4)]
 		pub fn remove(origin: OriginFor<T>, contract_id: T::ItemId) -> DispatchResult {
-			let _ = Self::ensure_creator(origin)?;
+			Self::ensure_contract_remover(origin, &contract_id)?;
 			Self::ensure_pallet_unlocked()?;
 			Self::ensure_removable(&contract_id)?;
 			Self::remove_non_staked_contract(contract_id)
@@ -392,7 +547,13 @@ pub mod pallet {
 		/// executing this call, the staker will transfer the required stake and fee NFTs to the
 		/// provider, thus engaging in the contract. The provider will issue a contract NFT to the
 		/// staker, acknowledging their participation in the staking contract.
-		#[pallet::weight(T::WeightInfo::accept())]
+		#[pallet::weight(
+			T::WeightInfo::accept().saturating_add(T::WeightInfo::evaluate_clauses(
+				T::MaxStakingClauses::get()
+					.saturating_add(T::MaxFeeClauses::get())
+					.saturating_mul(MAX_CLAUSE_LOOKUPS)
+			))
+		)]
 		#[pallet::call_index(5)]
 		pub fn accept(
 			origin: OriginFor<T>,
@@ -465,6 +626,249 @@ pub mod pallet {
 			Self::process_contract(Operation::Snipe, contract_id, sniper, None)?;
 			Ok(())
 		}
+
+		/// Apply to become a contract creator.
+		///
+		/// This call reserves the given `deposit` from the caller until its application is
+		/// withdrawn or, once registered with `register_creator`, until it is deregistered.
+		#[pallet::weight(T::WeightInfo::apply_as_creator())]
+		#[pallet::call_index(20)]
+		pub fn apply_as_creator(origin: OriginFor<T>, deposit: BalanceOf<T>) -> DispatchResult {
+			let creator = ensure_signed(origin)?;
+			ensure!(!Creators::<T>::contains_key(&creator), Error::<T>::CreatorAlreadyRegistered);
+			ensure!(
+				!CreatorApplications::<T>::contains_key(&creator),
+				Error::<T>::CreatorAlreadyApplied
+			);
+			T::Currency::reserve(&creator, deposit)?;
+			CreatorApplications::<T>::insert(&creator, deposit);
+
+			Self::deposit_event(Event::CreatorApplied { creator, deposit });
+			Ok(())
+		}
+
+		/// Register a contract creator.
+		///
+		/// This call approves the application of an account, allowing it to create staking
+		/// contracts funded from its own balance, up to `quota` contracts at once. The deposit
+		/// reserved by the application is kept until the creator is deregistered. It must be
+		/// called with root privilege.
+		#[pallet::weight(T::WeightInfo::register_creator())]
+		#[pallet::call_index(9)]
+		pub fn register_creator(
+			origin: OriginFor<T>,
+			creator: T::AccountId,
+			quota: u32,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(!Creators::<T>::contains_key(&creator), Error::<T>::CreatorAlreadyRegistered);
+			let deposit =
+				CreatorApplications::<T>::take(&creator).ok_or(Error::<T>::NoCreatorApplication)?;
+			Creators::<T>::insert(&creator, CreatorInfo { deposit, quota, contracts: 0 });
+
+			Self::deposit_event(Event::CreatorRegistered { creator, deposit, quota });
+			Ok(())
+		}
+
+		/// Deregister a contract creator.
+		///
+		/// This call unreserves the creator's deposit once all of its contracts have been removed
+		/// or concluded. A pending application is withdrawn, or rejected, the same way. It must be
+		/// called with root privilege or by the creator itself.
+		#[pallet::weight(T::WeightInfo::deregister_creator())]
+		#[pallet::call_index(10)]
+		pub fn deregister_creator(origin: OriginFor<T>, creator: T::AccountId) -> DispatchResult {
+			if ensure_root(origin.clone()).is_err() {
+				let who = ensure_signed(origin)?;
+				ensure!(who == creator, DispatchError::BadOrigin);
+			}
+			if let Some(deposit) = CreatorApplications::<T>::take(&creator) {
+				T::Currency::unreserve(&creator, deposit);
+				Self::deposit_event(Event::CreatorDeregistered { creator });
+				return Ok(())
+			}
+			let CreatorInfo { deposit, contracts, .. } =
+				Creators::<T>::get(&creator).ok_or(Error::<T>::UnregisteredCreator)?;
+			ensure!(contracts.is_zero(), Error::<T>::CreatorHasContracts);
+
+			T::Currency::unreserve(&creator, deposit);
+			Creators::<T>::remove(&creator);
+
+			Self::deposit_event(Event::CreatorDeregistered { creator });
+			Ok(())
+		}
+
+		/// Create a staking pool.
+		///
+		/// This call allows a creator to open a pool that any number of stakers can join by
+		/// staking NFTs that satisfy its clauses. The reward pot is transferred from the creator
+		/// to the provider and distributed over the pool's duration, pro-rata to the number of
+		/// NFTs each staker has staked at any point in time.
+		#[pallet::weight(T::WeightInfo::create_pool())]
+		#[pallet::call_index(11)]
+		pub fn create_pool(
+			origin: OriginFor<T>,
+			pool_id: T::ItemId,
+			pool: StakingPoolOf<T>,
+		) -> DispatchResult {
+			let creator = Self::ensure_contract_creator(origin)?;
+			Self::ensure_pallet_unlocked()?;
+			ensure!(
+				pool.stake_clauses.len() as u32 <= T::MaxStakingClauses::get(),
+				Error::<T>::MaxStakingClauses
+			);
+			ensure!(
+				pool.stake_clauses.iter().all(|clause| clause.clause.is_valid()),
+				Error::<T>::InvalidClause
+			);
+			Self::create_staking_pool(creator, pool_id, pool)
+		}
+
+		/// Stake NFTs into a staking pool.
+		///
+		/// The staked NFTs start accruing rewards immediately, or once the pool starts. Rewards
+		/// accrued by previously staked NFTs are kept until claimed.
+		#[pallet::weight(
+			T::WeightInfo::join_pool(stakes.len() as u32).saturating_add(T::WeightInfo::evaluate_clauses(
+				(stakes.len() as u32)
+					.saturating_mul(T::MaxStakingClauses::get())
+					.saturating_mul(MAX_CLAUSE_LOOKUPS)
+			))
+		)]
+		#[pallet::call_index(12)]
+		pub fn join_pool(
+			origin: OriginFor<T>,
+			pool_id: T::ItemId,
+			stakes: BoundedVec<NftIdOf<T>, T::MaxPoolStakes>,
+		) -> DispatchResult {
+			let staker = ensure_signed(origin)?;
+			Self::ensure_pallet_unlocked()?;
+			Self::join_staking_pool(pool_id, staker, stakes)
+		}
+
+		/// Withdraw all NFTs staked into a staking pool, claiming any accrued rewards.
+		#[pallet::weight(T::WeightInfo::leave_pool(T::MaxPoolStakes::get()))]
+		#[pallet::call_index(13)]
+		pub fn leave_pool(origin: OriginFor<T>, pool_id: T::ItemId) -> DispatchResult {
+			let staker = ensure_signed(origin)?;
+			Self::ensure_pallet_unlocked()?;
+			Self::leave_staking_pool(pool_id, staker)
+		}
+
+		/// Claim the rewards accrued so far in a staking pool, keeping the NFTs staked.
+		#[pallet::weight(T::WeightInfo::claim_pool_rewards())]
+		#[pallet::call_index(14)]
+		pub fn claim_pool_rewards(origin: OriginFor<T>, pool_id: T::ItemId) -> DispatchResult {
+			let staker = ensure_signed(origin)?;
+			Self::ensure_pallet_unlocked()?;
+			Self::claim_staking_pool_rewards(pool_id, staker)
+		}
+
+		/// Leave an active staking contract early.
+		///
+		/// This call allows the staker, holding a contract NFT, to leave a contract before its
+		/// stake duration ends, provided the contract defines early unstake terms. The staker
+		/// receives the share of token rewards vested so far minus a penalty, and gets the stake
+		/// NFTs back. The unvested rewards return to the creator, as does the penalty unless it
+		/// is burned.
+		#[pallet::weight(T::WeightInfo::unstake_early())]
+		#[pallet::call_index(16)]
+		pub fn unstake_early(origin: OriginFor<T>, contract_id: T::ItemId) -> DispatchResult {
+			let staker = ensure_signed(origin)?;
+			Self::ensure_pallet_unlocked()?;
+			Self::ensure_contract(Operation::Unstake, &contract_id, &staker)?;
+			Self::process_contract(Operation::Unstake, contract_id, staker, None)
+		}
+
+		/// Make a staking contract renewable.
+		///
+		/// This call allows the creator of a contract that hasn't been accepted yet to fund its
+		/// token rewards for up to `max_rounds` rounds. The rewards of the additional rounds are
+		/// transferred from the creator to the provider as a budget, and any leftover budget is
+		/// returned once the contract is removed or stops being renewed.
+		#[pallet::weight(T::WeightInfo::set_contract_renewal())]
+		#[pallet::call_index(17)]
+		pub fn set_contract_renewal(
+			origin: OriginFor<T>,
+			contract_id: T::ItemId,
+			mode: RenewalMode,
+			max_rounds: u32,
+		) -> DispatchResult {
+			let creator = ensure_signed(origin)?;
+			Self::ensure_pallet_unlocked()?;
+			ensure!(Self::contract_creator(&contract_id)? == creator, DispatchError::BadOrigin);
+			Self::ensure_removable(&contract_id)?;
+			Self::set_renewal(contract_id, creator, mode, max_rounds)
+		}
+
+		/// Re-stake a fulfilled staking contract for another round.
+		///
+		/// The staker, holding the contract NFT of a contract renewable by re-staking, can call
+		/// this function instead of claiming. The rewards of the fulfilled round are transferred
+		/// to the staker, and the stake NFTs remain locked for a new round funded from the
+		/// creator's budget.
+		#[pallet::weight(T::WeightInfo::renew())]
+		#[pallet::call_index(18)]
+		pub fn renew(origin: OriginFor<T>, contract_id: T::ItemId) -> DispatchResult {
+			let staker = ensure_signed(origin)?;
+			Self::ensure_pallet_unlocked()?;
+			Self::ensure_contract(Operation::Claim, &contract_id, &staker)?;
+			Self::restake_contract(contract_id, staker)
+		}
+
+		/// Pay the token rewards of a staking contract under a vesting schedule.
+		///
+		/// This call allows the creator of a contract that hasn't been accepted yet to release
+		/// its token rewards gradually upon claiming, sniping or unstaking early, instead of paying
+		/// them in full. Any amount that cannot be split evenly across the periods is paid
+		/// immediately, as are rewards whose beneficiary has no vesting schedule left to hold them.
+		#[pallet::weight(T::WeightInfo::set_reward_vesting())]
+		#[pallet::call_index(19)]
+		pub fn set_reward_vesting(
+			origin: OriginFor<T>,
+			contract_id: T::ItemId,
+			vesting: RewardVestingOf<T>,
+		) -> DispatchResult {
+			let creator = ensure_signed(origin)?;
+			Self::ensure_pallet_unlocked()?;
+			ensure!(Self::contract_creator(&contract_id)? == creator, DispatchError::BadOrigin);
+			Self::ensure_removable(&contract_id)?;
+			ensure!(
+				!vesting.period.is_zero() && vesting.period_count > 0,
+				Error::<T>::InvalidRewardVesting
+			);
+			let rewards = Self::contract(&contract_id)?.rewards;
+			ensure!(
+				T::RewardVesting::max_schedules() > 0 &&
+					rewards.iter().all(|reward| match reward {
+						Reward::Tokens(amount) =>
+							Self::vested_per_period(*amount, &vesting).is_some(),
+						Reward::Nft(_) => true,
+					}),
+				Error::<T>::RewardVestingUnsupported
+			);
+			ContractRewardVestings::<T>::insert(contract_id, vesting);
+			Self::deposit_event(Event::<T>::RewardVestingSet { contract_id, vesting });
+			Ok(())
+		}
+
+		/// Close an ended staking pool without stakers.
+		///
+		/// Any rewards left undistributed, for instance during periods without stakers, are
+		/// returned to the pool's creator. It can be called by the pool's creator or by the
+		/// root-set creator.
+		#[pallet::weight(T::WeightInfo::close_pool())]
+		#[pallet::call_index(15)]
+		pub fn close_pool(origin: OriginFor<T>, pool_id: T::ItemId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_pallet_unlocked()?;
+			ensure!(
+				Self::is_root_creator(&who) ||
+					PoolCreators::<T>::get(pool_id).as_ref() == Some(&who),
+				DispatchError::BadOrigin
+			);
+			Self::close_staking_pool(pool_id)
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -485,6 +889,8 @@ pub mod pallet {
 			metadata: Option<Str>,
 			contract_attrs: Option<BoundedVec<(Str, Str), ConstU32<10>>>,
 		) -> DispatchResult {
+			Self::acquire_creator_slot(&creator)?;
+
 			// Lock contract rewards in pallet account.
 			let pallet_account_id = Self::account_id();
 
@@ -572,6 +978,11 @@ pub mod pallet {
 				ContractsAttributes::<T>::insert(contract_id, contract_attrs);
 			}
 
+			ContractCreators::<T>::insert(contract_id, &creator);
+			CreatorsStats::<T>::mutate(&creator, |stats| {
+				stats.contracts_created.saturating_inc();
+			});
+
 			Self::deposit_event(Event::<T>::Created { contract_id });
 			Ok(())
 		}
@@ -579,7 +990,7 @@ pub mod pallet {
 		fn remove_non_staked_contract(contract_id: T::ItemId) -> DispatchResult {
 			let Contract { rewards, .. } =
 				Contracts::<T>::get(contract_id).ok_or(Error::<T>::UnknownContract)?;
-			let creator = Self::creator()?;
+			let creator = Self::contract_creator(&contract_id)?;
 
 			for reward in &rewards {
 				match reward {
@@ -592,6 +1003,12 @@ pub mod pallet {
 
 			Contracts::<T>::remove(contract_id);
 			ContractsMetadata::<T>::remove(contract_id);
+			Self::refund_renewal_budget(&contract_id, &creator)?;
+			ContractRewardVestings::<T>::remove(contract_id);
+			Self::release_contract_creator(&contract_id);
+			CreatorsStats::<T>::mutate(&creator, |stats| {
+				stats.contracts_removed.saturating_inc();
+			});
 
 			Self::burn_and_clear_contract_attributes(&contract_id, None)?;
 
@@ -613,13 +1030,14 @@ pub mod pallet {
 
 			let Contract { burn_fees, .. } =
 				Contracts::<T>::get(contract_id).ok_or(Error::<T>::UnknownContract)?;
+			let creator = Self::contract_creator(&contract_id)?;
 
 			if burn_fees {
 				for NftId(collection_id, item_id) in fee_addresses {
 					T::NftHelper::burn(collection_id, item_id, None)?;
 				}
 			} else {
-				Self::transfer_items(fee_addresses, &Self::creator()?)?;
+				Self::transfer_items(fee_addresses, &creator)?;
 			}
 
 			// Record staked NFTs' addresses.
@@ -639,6 +1057,9 @@ pub mod pallet {
 			ContractsStats::<T>::mutate(&who, |stats| {
 				stats.contracts_staked = stats.contracts_staked.saturating_add(1);
 			});
+			CreatorsStats::<T>::mutate(&creator, |stats| {
+				stats.contracts_accepted.saturating_inc();
+			});
 
 			// Emit events.
 			Self::deposit_event(Event::<T>::Accepted { by: who, contract_id });
@@ -653,31 +1074,72 @@ pub mod pallet {
 			reward_beneficiary: Option<T::AccountId>,
 		) -> DispatchResult {
 			// Transfer rewards.
-			let creator = Self::creator()?;
-			let Contract { cancel_fee, rewards, .. } = Self::contract(&contract_id)?;
+			let creator = Self::contract_creator(&contract_id)?;
+			let Contract { cancel_fee, rewards, stake_duration, early_unstake, .. } =
+				Self::contract(&contract_id)?;
 			let beneficiary = match op {
 				Operation::Claim => reward_beneficiary.as_ref().unwrap_or(&who),
 				Operation::Snipe => &who,
-				Operation::Cancel => {
-					T::Currency::transfer(&who, &creator, cancel_fee, AllowDeath)?;
+				Operation::Cancel | Operation::Unstake => {
+					if op == Operation::Cancel {
+						T::Currency::transfer(&who, &creator, cancel_fee, AllowDeath)?;
+					}
 					&creator
 				},
 			};
+			let vesting = match op {
+				Operation::Unstake => {
+					let terms = early_unstake.ok_or(Error::<T>::EarlyUnstakeDisabled)?;
+					let now = <frame_system::Pallet<T>>::block_number();
+					let elapsed = now.saturating_sub(Self::contract_accepted(&contract_id)?);
+					Some((terms.vested(elapsed, stake_duration), terms))
+				},
+				_ => None,
+			};
 
+			let (mut vested_reward, mut vested_penalty): (BalanceOf<T>, BalanceOf<T>) =
+				(Zero::zero(), Zero::zero());
 			for reward in &rewards {
-				match reward {
-					Reward::Tokens(amount) =>
+				match (reward, &vesting) {
+					(Reward::Tokens(amount), Some((vested, terms))) => {
+						let (reward, penalty) = Self::split_vested_reward(
+							&contract_id,
+							&who,
+							&creator,
+							*amount,
+							*vested,
+							terms,
+						)?;
+						vested_reward.saturating_accrue(reward);
+						vested_penalty.saturating_accrue(penalty);
+						Ok(())
+					},
+					(Reward::Tokens(amount), None) if op == Operation::Cancel =>
 						T::Currency::transfer(&Self::account_id(), beneficiary, *amount, AllowDeath),
-					Reward::Nft(NftId(collection_id, item_id)) =>
+					(Reward::Tokens(amount), None) =>
+						Self::pay_token_reward(&contract_id, beneficiary, *amount),
+					(Reward::Nft(NftId(collection_id, item_id)), _) =>
 						T::NftHelper::transfer(collection_id, item_id, beneficiary),
 				}?;
 			}
 
 			// Return staked items, if the owner is not present the staked items may go to a
 			// different party.
-			let stake_beneficiary = if let Ok(contract_owner) = Self::contract_owner(&contract_id) {
-				// Burn contract NFT.
-				Self::burn_and_clear_contract_attributes(&contract_id, Some(&contract_owner))?;
+			let contract_owner = Self::contract_owner(&contract_id).ok();
+			let relisted_round = match op {
+				Operation::Claim | Operation::Snipe if contract_owner.is_some() =>
+					Self::take_renewal_round(&contract_id, RenewalMode::Relist),
+				_ => None,
+			};
+			let stake_beneficiary = if let Some(contract_owner) = contract_owner {
+				if relisted_round.is_some() {
+					// Return contract NFT to be accepted again.
+					let contract_address = NftId(Self::contract_collection_id()?, contract_id);
+					Self::transfer_items(&[contract_address], &Self::account_id())?;
+				} else {
+					// Burn contract NFT.
+					Self::burn_and_clear_contract_attributes(&contract_id, Some(&contract_owner))?;
+				}
 
 				// Retain contract IDs held.
 				let mut contract_ids = Self::contract_ids(&contract_owner)?;
@@ -692,8 +1154,8 @@ pub mod pallet {
 			} else {
 				match op {
 					Operation::Claim => return Err(Error::<T>::CannotClaimUnknownContract.into()),
-					Operation::Cancel => creator,
-					Operation::Snipe => who.clone(),
+					Operation::Cancel => creator.clone(),
+					Operation::Snipe | Operation::Unstake => who.clone(),
 				}
 			};
 
@@ -702,8 +1164,21 @@ pub mod pallet {
 			// Clean up storage.
 			ContractStakedItems::<T>::remove(contract_id);
 			ContractAccepted::<T>::remove(contract_id);
-			Contracts::<T>::remove(contract_id);
-			ContractsMetadata::<T>::remove(contract_id);
+			if let Some(round) = relisted_round {
+				let now = <frame_system::Pallet<T>>::block_number();
+				Contracts::<T>::mutate(contract_id, |maybe_contract| {
+					if let Some(contract) = maybe_contract {
+						contract.activation = Some(now);
+					}
+				});
+				Self::deposit_event(Event::<T>::Relisted { contract_id, round });
+			} else {
+				Contracts::<T>::remove(contract_id);
+				ContractsMetadata::<T>::remove(contract_id);
+				Self::refund_renewal_budget(&contract_id, &creator)?;
+				ContractRewardVestings::<T>::remove(contract_id);
+				Self::release_contract_creator(&contract_id);
+			}
 
 			// Emit events and update stats.
 			match op {
@@ -728,16 +1203,445 @@ pub mod pallet {
 					});
 					Self::deposit_event(Event::<T>::Sniped { by: who, contract_id, rewards })
 				},
+				Operation::Unstake => Self::deposit_event(Event::<T>::UnstakedEarly {
+					by: who,
+					contract_id,
+					reward: vested_reward,
+					penalty: vested_penalty,
+				}),
 			};
 
 			Ok(())
 		}
 
+		fn set_renewal(
+			contract_id: T::ItemId,
+			creator: T::AccountId,
+			mode: RenewalMode,
+			max_rounds: u32,
+		) -> DispatchResult {
+			ensure!(
+				!ContractRenewals::<T>::contains_key(contract_id),
+				Error::<T>::RenewalAlreadySet
+			);
+			ensure!(max_rounds > 1, Error::<T>::InvalidRenewal);
+
+			let Contract { rewards, .. } = Self::contract(&contract_id)?;
+			let round_rewards = Self::round_token_rewards(&rewards)?;
+			let budget = round_rewards
+				.checked_mul(&(max_rounds - 1).into())
+				.ok_or(ArithmeticError::Overflow)?;
+			T::Currency::transfer(&creator, &Self::account_id(), budget, AllowDeath)?;
+
+			ContractRenewals::<T>::insert(
+				contract_id,
+				ContractRenewal { mode, max_rounds, round: 1, budget },
+			);
+			Self::deposit_event(Event::<T>::RenewalSet { contract_id, mode, max_rounds, budget });
+			Ok(())
+		}
+
+		fn restake_contract(contract_id: T::ItemId, staker: T::AccountId) -> DispatchResult {
+			let Contract { rewards, .. } = Self::contract(&contract_id)?;
+			let renewal =
+				ContractRenewals::<T>::get(contract_id).ok_or(Error::<T>::NotRestakeable)?;
+			ensure!(renewal.mode == RenewalMode::Restake, Error::<T>::NotRestakeable);
+			let round = Self::take_renewal_round(&contract_id, RenewalMode::Restake)
+				.ok_or(Error::<T>::NoRoundsLeft)?;
+
+			let round_rewards = Self::round_token_rewards(&rewards)?;
+			Self::pay_token_reward(&contract_id, &staker, round_rewards)?;
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			ContractAccepted::<T>::insert(contract_id, now);
+			ContractsStats::<T>::mutate(&staker, |stats| {
+				stats.contracts_claimed.saturating_inc();
+			});
+
+			Self::deposit_event(Event::<T>::Renewed { by: staker, contract_id, round });
+			Ok(())
+		}
+
+		/// Pays a token reward of a contract, under the contract's vesting schedule if it has one
+		/// and the beneficiary can still be vested under another schedule.
+		fn pay_token_reward(
+			contract_id: &T::ItemId,
+			beneficiary: &T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let pallet_account_id = Self::account_id();
+			let Some(vesting) = ContractRewardVestings::<T>::get(contract_id)
+				.filter(|_| T::RewardVesting::has_free_schedule(beneficiary))
+			else {
+				return T::Currency::transfer(&pallet_account_id, beneficiary, amount, AllowDeath)
+			};
+
+			let Some(per_period) = Self::vested_per_period(amount, &vesting) else {
+				return T::Currency::transfer(&pallet_account_id, beneficiary, amount, AllowDeath)
+			};
+			// Pay the remainder first, as the vested transfer may reap a pallet account left with
+			// less than the existential deposit.
+			let vested_amount =
+				per_period.saturating_mul(BalanceOf::<T>::from(vesting.period_count));
+			let remainder = amount.saturating_sub(vested_amount);
+			T::Currency::transfer(&pallet_account_id, beneficiary, remainder, AllowDeath)?;
+			T::RewardVesting::vested_transfer(
+				&pallet_account_id,
+				beneficiary,
+				vesting.cliff,
+				vesting.period,
+				vesting.period_count,
+				per_period,
+			)
+		}
+
+		/// Returns the amount released per period when vesting `amount`, or `None` if it is too
+		/// small to be vested and must be paid directly.
+		fn vested_per_period(
+			amount: BalanceOf<T>,
+			vesting: &RewardVestingOf<T>,
+		) -> Option<BalanceOf<T>> {
+			let period_count = BalanceOf::<T>::from(vesting.period_count);
+			let per_period = amount / period_count;
+			let vested_amount = per_period.saturating_mul(period_count);
+			(!per_period.is_zero() && vested_amount >= T::RewardVesting::min_vested_transfer())
+				.then_some(per_period)
+		}
+
+		/// Returns the sum of token rewards of a single round, failing for contracts with NFT
+		/// rewards since those cannot be renewed.
+		fn round_token_rewards(
+			rewards: &BoundedRewardsOf<T>,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			rewards.iter().try_fold(BalanceOf::<T>::zero(), |total, reward| match reward {
+				Reward::Tokens(amount) =>
+					total.checked_add(amount).ok_or(ArithmeticError::Overflow.into()),
+				Reward::Nft(_) => Err(Error::<T>::NonRenewableContract.into()),
+			})
+		}
+
+		/// Starts a new round of a contract renewable with the given mode, funding its rewards from
+		/// the budget. Returns the new round, or `None` if the contract cannot be renewed.
+		fn take_renewal_round(contract_id: &T::ItemId, mode: RenewalMode) -> Option<u32> {
+			let rewards = Contracts::<T>::get(contract_id)?.rewards;
+			let round_rewards = Self::round_token_rewards(&rewards).ok()?;
+			ContractRenewals::<T>::mutate(contract_id, |maybe_renewal| {
+				let renewal = maybe_renewal
+					.as_mut()
+					.filter(|renewal| renewal.mode == mode && renewal.round < renewal.max_rounds)?;
+				renewal.round.saturating_inc();
+				renewal.budget.saturating_reduce(round_rewards);
+				Some(renewal.round)
+			})
+		}
+
+		/// Returns the budget left for the remaining rounds of a contract to its creator.
+		fn refund_renewal_budget(
+			contract_id: &T::ItemId,
+			creator: &T::AccountId,
+		) -> DispatchResult {
+			if let Some(ContractRenewal { budget, .. }) = ContractRenewals::<T>::take(contract_id) {
+				T::Currency::transfer(&Self::account_id(), creator, budget, AllowDeath)?;
+			}
+			Ok(())
+		}
+
+		/// Pays the staker the vested share of a token reward minus the penalty, under the
+		/// contract's vesting schedule if it has one, returning the rest to the creator. Returns
+		/// the amounts paid to the staker and withheld as penalty.
+		fn split_vested_reward(
+			contract_id: &T::ItemId,
+			staker: &T::AccountId,
+			creator: &T::AccountId,
+			amount: BalanceOf<T>,
+			vested: Perbill,
+			terms: &EarlyUnstakeOf<T>,
+		) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+			let vested_amount = vested * amount;
+			let penalty = terms.penalty * vested_amount;
+			let reward = vested_amount.saturating_sub(penalty);
+			let mut refund = amount.saturating_sub(vested_amount);
+
+			let pallet_account_id = Self::account_id();
+			Self::pay_token_reward(contract_id, staker, reward)?;
+			if terms.burn_penalty {
+				let _ = T::Currency::withdraw(
+					&pallet_account_id,
+					penalty,
+					WithdrawReasons::TRANSFER,
+					AllowDeath,
+				)?;
+			} else {
+				refund.saturating_accrue(penalty);
+			}
+			T::Currency::transfer(&pallet_account_id, creator, refund, AllowDeath)?;
+			Ok((reward, penalty))
+		}
+
 		fn transfer_items(addresses: &[NftIdOf<T>], to: &T::AccountId) -> DispatchResult {
 			addresses.iter().try_for_each(|NftId(collection_id, item_id)| {
 				T::NftHelper::transfer(collection_id, item_id, to)
 			})
 		}
+
+		/// Counts a new contract or pool towards the creator's quota, if registered.
+		fn acquire_creator_slot(creator: &T::AccountId) -> DispatchResult {
+			Creators::<T>::try_mutate_exists(creator, |maybe_info| {
+				if let Some(info) = maybe_info {
+					ensure!(info.contracts < info.quota, Error::<T>::CreatorQuotaReached);
+					info.contracts.saturating_inc();
+				}
+				Ok(())
+			})
+		}
+
+		/// Frees a slot of the creator's quota, if registered.
+		fn release_creator_slot(creator: &T::AccountId) {
+			Creators::<T>::mutate(creator, |maybe_info| {
+				if let Some(info) = maybe_info {
+					info.contracts.saturating_dec();
+				}
+			});
+		}
+
+		/// Removes the creator record of a contract, freeing a slot of its quota.
+		fn release_contract_creator(contract_id: &T::ItemId) {
+			if let Some(creator) = ContractCreators::<T>::take(contract_id) {
+				Self::release_creator_slot(&creator);
+			}
+		}
+	}
+
+	// Implementation of staking pools.
+	impl<T: Config> Pallet<T> {
+		pub(crate) fn create_staking_pool(
+			creator: T::AccountId,
+			pool_id: T::ItemId,
+			mut pool: StakingPoolOf<T>,
+		) -> DispatchResult {
+			ensure!(!Pools::<T>::contains_key(pool_id), Error::<T>::PoolAlreadyExists);
+			ensure!(pool.duration > Zero::zero(), Error::<T>::ZeroPoolDuration);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			pool.start = match pool.start {
+				Some(block_number) => {
+					ensure!(block_number > now, Error::<T>::IncorrectActivation);
+					Some(block_number)
+				},
+				None => Some(now),
+			};
+
+			Self::acquire_creator_slot(&creator)?;
+			T::Currency::transfer(&creator, &Self::account_id(), pool.reward_pot, AllowDeath)?;
+
+			PoolStates::<T>::insert(pool_id, PoolState { last_update: now, ..Default::default() });
+			Pools::<T>::insert(pool_id, pool);
+			PoolCreators::<T>::insert(pool_id, &creator);
+			CreatorsStats::<T>::mutate(&creator, |stats| {
+				stats.contracts_created.saturating_inc();
+			});
+
+			Self::deposit_event(Event::<T>::PoolCreated { pool_id, creator });
+			Ok(())
+		}
+
+		fn join_staking_pool(
+			pool_id: T::ItemId,
+			staker: T::AccountId,
+			stakes: PoolStakedItemsOf<T>,
+		) -> DispatchResult {
+			let pool = Self::pool(&pool_id)?;
+			ensure!(
+				!stakes.is_empty() &&
+					Self::count_unique_nft(&stakes) == stakes.len(),
+				Error::<T>::InvalidNFTStakeAmount
+			);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now < Self::pool_end(&pool)?, Error::<T>::PoolEnded);
+
+			for NftId(collection_id, item_id) in &stakes {
+				Self::ensure_item_ownership(collection_id, item_id, &staker)?;
+			}
+			ensure!(
+				stakes.iter().all(|stake| {
+					pool.evaluate_stake::<T::AccountId, T::NftHelper, T::ItemId>(stake)
+				}),
+				Error::<T>::UnfulfilledStakingClause
+			);
+
+			let mut state = Self::updated_pool_state(&pool_id, &pool)?;
+			let mut stake = PoolStakes::<T>::get(pool_id, &staker).unwrap_or_default();
+			Self::settle_pool_stake(&state, &mut stake);
+
+			let mut items = PoolStakedItems::<T>::get(pool_id, &staker).unwrap_or_default();
+			for address in &stakes {
+				items.try_push(address.clone()).map_err(|_| Error::<T>::MaxPoolStakes)?;
+			}
+			Self::transfer_items(&stakes, &Self::account_id())?;
+
+			let joined = stakes.len() as u32;
+			stake.stakes.saturating_accrue(joined);
+			state.total_stakes.saturating_accrue(joined);
+
+			PoolStakedItems::<T>::insert(pool_id, &staker, items);
+			PoolStakes::<T>::insert(pool_id, &staker, stake);
+			PoolStates::<T>::insert(pool_id, state);
+
+			Self::deposit_event(Event::<T>::PoolJoined { by: staker, pool_id, stakes: joined });
+			Ok(())
+		}
+
+		fn leave_staking_pool(pool_id: T::ItemId, staker: T::AccountId) -> DispatchResult {
+			let pool = Self::pool(&pool_id)?;
+			let mut state = Self::updated_pool_state(&pool_id, &pool)?;
+			let mut stake =
+				PoolStakes::<T>::take(pool_id, &staker).ok_or(Error::<T>::NotPoolStaker)?;
+			Self::settle_pool_stake(&state, &mut stake);
+
+			let items = PoolStakedItems::<T>::take(pool_id, &staker).unwrap_or_default();
+			Self::transfer_items(&items, &staker)?;
+			state.total_stakes.saturating_reduce(stake.stakes);
+
+			Self::pay_pool_rewards(pool_id, &staker, stake.pending, &mut state)?;
+			PoolStates::<T>::insert(pool_id, state);
+
+			Self::deposit_event(Event::<T>::PoolLeft { by: staker, pool_id });
+			Ok(())
+		}
+
+		fn claim_staking_pool_rewards(pool_id: T::ItemId, staker: T::AccountId) -> DispatchResult {
+			let pool = Self::pool(&pool_id)?;
+			let mut state = Self::updated_pool_state(&pool_id, &pool)?;
+			let mut stake =
+				PoolStakes::<T>::get(pool_id, &staker).ok_or(Error::<T>::NotPoolStaker)?;
+			Self::settle_pool_stake(&state, &mut stake);
+
+			let amount = sp_std::mem::take(&mut stake.pending);
+			Self::pay_pool_rewards(pool_id, &staker, amount, &mut state)?;
+			PoolStakes::<T>::insert(pool_id, &staker, stake);
+			PoolStates::<T>::insert(pool_id, state);
+			Ok(())
+		}
+
+		fn close_staking_pool(pool_id: T::ItemId) -> DispatchResult {
+			let pool = Self::pool(&pool_id)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now >= Self::pool_end(&pool)?, Error::<T>::PoolNotEnded);
+			let state = PoolStates::<T>::get(pool_id).ok_or(Error::<T>::UnknownPool)?;
+			ensure!(state.total_stakes.is_zero(), Error::<T>::PoolHasStakers);
+
+			let creator = PoolCreators::<T>::take(pool_id).ok_or(Error::<T>::UnknownPool)?;
+			let refund = pool.reward_pot.saturating_sub(state.paid);
+			if !refund.is_zero() {
+				T::Currency::transfer(&Self::account_id(), &creator, refund, AllowDeath)?;
+			}
+			Self::release_creator_slot(&creator);
+
+			Pools::<T>::remove(pool_id);
+			PoolStates::<T>::remove(pool_id);
+			let _ = PoolStakes::<T>::clear_prefix(pool_id, u32::MAX, None);
+
+			Self::deposit_event(Event::<T>::PoolClosed { pool_id, refund });
+			Ok(())
+		}
+
+		fn pool_end(pool: &StakingPoolOf<T>) -> Result<BlockNumberFor<T>, DispatchError> {
+			let start = pool.start.ok_or(Error::<T>::UnknownActivation)?;
+			let end = start.checked_add(&pool.duration).ok_or(ArithmeticError::Overflow)?;
+			Ok(end)
+		}
+
+		/// Returns the state of the pool with its rewards per stake accumulated up to the current
+		/// block, or the end of the pool if it has already ended.
+		fn updated_pool_state(
+			pool_id: &T::ItemId,
+			pool: &StakingPoolOf<T>,
+		) -> Result<PoolStateOf<T>, DispatchError> {
+			let mut state = PoolStates::<T>::get(pool_id).ok_or(Error::<T>::UnknownPool)?;
+			let start = pool.start.ok_or(Error::<T>::UnknownActivation)?;
+			let now = frame_system::Pallet::<T>::block_number().min(Self::pool_end(pool)?);
+			let from = state.last_update.max(start);
+
+			if now > from && !state.total_stakes.is_zero() {
+				let elapsed = now.saturating_sub(from).saturated_into::<u128>();
+				let reward = pool.reward_pot.saturated_into::<u128>().saturating_mul(elapsed);
+				let shares = pool
+					.duration
+					.saturated_into::<u128>()
+					.saturating_mul(state.total_stakes as u128);
+				state.reward_per_stake = state
+					.reward_per_stake
+					.saturating_add(FixedU128::saturating_from_rational(reward, shares));
+			}
+			state.last_update = state.last_update.max(now);
+			Ok(state)
+		}
+
+		/// Accrues the rewards of a stake up to the pool's current rewards per stake.
+		fn settle_pool_stake(state: &PoolStateOf<T>, stake: &mut PoolStakeOf<T>) {
+			let accrued = state
+				.reward_per_stake
+				.saturating_sub(stake.reward_per_stake_paid)
+				.saturating_mul_int(stake.stakes as u128);
+			stake.pending.saturating_accrue(accrued.saturated_into());
+			stake.reward_per_stake_paid = state.reward_per_stake;
+		}
+
+		fn pay_pool_rewards(
+			pool_id: T::ItemId,
+			staker: &T::AccountId,
+			amount: BalanceOf<T>,
+			state: &mut PoolStateOf<T>,
+		) -> DispatchResult {
+			if !amount.is_zero() {
+				T::Currency::transfer(&Self::account_id(), staker, amount, AllowDeath)?;
+				state.paid.saturating_accrue(amount);
+				Self::deposit_event(Event::<T>::PoolRewardsClaimed {
+					by: staker.clone(),
+					pool_id,
+					amount,
+				});
+			}
+			Ok(())
+		}
+	}
+
+	// Implementation of contract discovery.
+	impl<T: Config> Pallet<T> {
+		/// Returns the contracts that `account` can currently accept with the NFTs it owns.
+		pub fn acceptable_contracts(account: &T::AccountId) -> Vec<ContractMatchOf<T>> {
+			let nfts = T::NftHelper::owned(account)
+				.map(|(collection_id, item_id)| NftId(collection_id, item_id))
+				.collect();
+			Self::matching_contracts(nfts)
+		}
+
+		/// Returns the contracts that can currently be accepted with the given NFTs, together with
+		/// the stakes and fees to accept each of them with. Ownership of the NFTs is not checked.
+		pub fn matching_contracts(mut nfts: Vec<NftIdOf<T>>) -> Vec<ContractMatchOf<T>> {
+			let mut seen = sp_std::collections::btree_set::BTreeSet::new();
+			nfts.retain(|nft| seen.insert(nft.encode()));
+
+			Contracts::<T>::iter()
+				.filter(|(contract_id, contract)| Self::is_available(contract_id, contract))
+				.filter_map(|(contract_id, contract)| {
+					let (stakes, fees) =
+						contract.match_nfts::<T::AccountId, T::NftHelper>(&nfts)?;
+					Some(ContractMatch { contract_id, stakes, fees })
+				})
+				.collect()
+		}
+
+		/// Whether a contract is listed and within its active period.
+		fn is_available(contract_id: &T::ItemId, contract: &ContractOf<T>) -> bool {
+			let now = <frame_system::Pallet<T>>::block_number();
+			let is_active = contract.activation.is_some_and(|activation| {
+				activation
+					.checked_add(&contract.active_duration)
+					.is_some_and(|inactive| now >= activation && now <= inactive)
+			});
+			is_active && Self::contract_owner(contract_id).ok() == Some(Self::account_id())
+		}
 	}
 
 	// Implementation of ensure checks.
@@ -749,6 +1653,29 @@ pub mod pallet {
 			Ok(maybe_creator)
 		}
 
+		fn ensure_contract_creator(origin: OriginFor<T>) -> Result<T::AccountId, DispatchError> {
+			let maybe_creator = ensure_signed(origin)?;
+			ensure!(
+				Self::is_root_creator(&maybe_creator) ||
+					Creators::<T>::contains_key(&maybe_creator),
+				DispatchError::BadOrigin
+			);
+			Ok(maybe_creator)
+		}
+
+		fn ensure_contract_remover(
+			origin: OriginFor<T>,
+			contract_id: &T::ItemId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				Self::is_root_creator(&who) ||
+					ContractCreators::<T>::get(contract_id).as_ref() == Some(&who),
+				DispatchError::BadOrigin
+			);
+			Ok(())
+		}
+
 		fn ensure_pallet_unlocked() -> DispatchResult {
 			ensure!(!GlobalConfigs::<T>::get().pallet_locked, Error::<T>::PalletLocked);
 			Ok(())
@@ -804,6 +1731,14 @@ pub mod pallet {
 				contract.fee_clauses.len() as u32 <= T::MaxFeeClauses::get(),
 				Error::<T>::MaxFeeClauses
 			);
+			ensure!(
+				contract
+					.stake_clauses
+					.iter()
+					.chain(contract.fee_clauses.iter())
+					.all(|clause| clause.clause.is_valid()),
+				Error::<T>::InvalidClause
+			);
 			Ok(())
 		}
 
@@ -876,7 +1811,7 @@ pub mod pallet {
 			contract_id: &T::ItemId,
 			who: &T::AccountId,
 		) -> DispatchResult {
-			let Contract { claim_duration, stake_duration, is_snipeable, .. } =
+			let Contract { claim_duration, stake_duration, is_snipeable, early_unstake, .. } =
 				Self::ensure_contract_ownership(contract_id, who, op == Operation::Snipe)?;
 			let now = <frame_system::Pallet<T>>::block_number();
 			let accepted = Self::contract_accepted(contract_id)?;
@@ -890,6 +1825,10 @@ pub mod pallet {
 				Operation::Cancel => {
 					ensure!(now < end, Error::<T>::Claimable);
 				},
+				Operation::Unstake => {
+					ensure!(early_unstake.is_some(), Error::<T>::EarlyUnstakeDisabled);
+					ensure!(now < end, Error::<T>::Claimable);
+				},
 				Operation::Snipe => {
 					ensure!(is_snipeable, Error::<T>::UnSnipeable);
 					ensure!(now >= expiry, Error::<T>::Claimable);
@@ -922,11 +1861,16 @@ pub mod pallet {
 
 		#[inline]
 		fn count_consecutive_unique_nft(nft_set: &[NftIdOf<T>]) -> u8 {
+			Self::count_unique_nft(nft_set) as u8
+		}
+
+		#[inline]
+		fn count_unique_nft(nft_set: &[NftIdOf<T>]) -> usize {
 			nft_set
 				.iter()
 				.map(|i| i.encode())
 				.collect::<sp_std::collections::btree_set::BTreeSet<_>>()
-				.len() as u8
+				.len()
 		}
 	}
 
@@ -941,6 +1885,18 @@ pub mod pallet {
 			let creator = Creator::<T>::get().ok_or(Error::<T>::UnknownCreator)?;
 			Ok(creator)
 		}
+		fn is_root_creator(who: &T::AccountId) -> bool {
+			Creator::<T>::get().as_ref() == Some(who)
+		}
+		/// Returns the creator of a contract, which defaults to the root-set creator for contracts
+		/// created before creators were recorded.
+		fn contract_creator(contract_id: &T::ItemId) -> Result<T::AccountId, DispatchError> {
+			ContractCreators::<T>::get(contract_id).map_or_else(Self::creator, Ok)
+		}
+		fn pool(pool_id: &T::ItemId) -> Result<StakingPoolOf<T>, DispatchError> {
+			let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::UnknownPool)?;
+			Ok(pool)
+		}
 		fn contract(contract_id: &T::ItemId) -> Result<ContractOf<T>, DispatchError> {
 			let contract = Contracts::<T>::get(contract_id).ok_or(Error::<T>::UnknownContract)?;
 			Ok(contract)
@@ -0,0 +1,101 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_support::{migrations::VersionedMigration, traits::UncheckedOnRuntimeUpgrade};
+
+/// The current storage version.
+pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+pub mod v1 {
+	use super::*;
+
+	#[derive(Decode)]
+	pub struct OldContract<Balance, CollectionId, ItemId, BlockNumber, KL, VL>
+	where
+		KL: Get<u32>,
+		VL: Get<u32>,
+	{
+		pub activation: Option<BlockNumber>,
+		pub active_duration: BlockNumber,
+		pub claim_duration: BlockNumber,
+		pub stake_duration: BlockNumber,
+		pub stake_clauses: BoundedClauses<CollectionId, KL, VL>,
+		pub fee_clauses: BoundedClauses<CollectionId, KL, VL>,
+		pub burn_fees: bool,
+		pub rewards: BoundedRewards<Balance, CollectionId, ItemId>,
+		pub cancel_fee: Balance,
+		pub nft_stake_amount: u8,
+		pub nft_fee_amount: u8,
+		pub is_snipeable: bool,
+	}
+
+	impl<Balance, CollectionId, ItemId, BlockNumber, KL, VL>
+		OldContract<Balance, CollectionId, ItemId, BlockNumber, KL, VL>
+	where
+		KL: Get<u32>,
+		VL: Get<u32>,
+	{
+		fn migrate_to_v1(self) -> Contract<Balance, CollectionId, ItemId, BlockNumber, KL, VL> {
+			Contract {
+				activation: self.activation,
+				active_duration: self.active_duration,
+				claim_duration: self.claim_duration,
+				stake_duration: self.stake_duration,
+				stake_clauses: self.stake_clauses,
+				fee_clauses: self.fee_clauses,
+				burn_fees: self.burn_fees,
+				rewards: self.rewards,
+				cancel_fee: self.cancel_fee,
+				early_unstake: None,
+				nft_stake_amount: self.nft_stake_amount,
+				nft_fee_amount: self.nft_fee_amount,
+				is_snipeable: self.is_snipeable,
+			}
+		}
+	}
+
+	pub type OldContractOf<T> = OldContract<
+		BalanceOf<T>,
+		CollectionIdOf<T>,
+		ItemIdOf<T>,
+		BlockNumberFor<T>,
+		<T as Config>::KeyLimit,
+		<T as Config>::ValueLimit,
+	>;
+
+	/// Adds the early unstake terms to existing contracts, disabling early unstake for them.
+	pub struct UncheckedMigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut translated = 0_u64;
+			Contracts::<T>::translate::<OldContractOf<T>, _>(|_, old_contract| {
+				translated.saturating_inc();
+				Some(old_contract.migrate_to_v1())
+			});
+			T::DbWeight::get().reads_writes(translated, translated)
+		}
+	}
+
+	pub type MigrateToV1<T> = VersionedMigration<
+		0,
+		1,
+		UncheckedMigrateToV1<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
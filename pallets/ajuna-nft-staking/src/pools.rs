@@ -0,0 +1,89 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::contracts::{BoundedClauses, NftId};
+use frame_support::traits::tokens::nonfungibles_v2::Inspect;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_core::Get;
+use sp_runtime::FixedU128;
+use sp_std::fmt::Debug;
+
+/// Specification for a staking pool, in which any number of stakers lock NFTs to share a token
+/// reward pot, distributed evenly over the pool's duration among all NFTs staked at the time.
+#[derive(Debug, Clone, Eq, PartialEq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct StakingPool<Balance, CollectionId, BlockNumber, KL, VL>
+where
+	KL: Get<u32>,
+	VL: Get<u32>,
+{
+	/// The block number at which the pool starts distributing rewards. If it is not set, the
+	/// pool starts immediately upon creation.
+	pub start: Option<BlockNumber>,
+	/// The number of blocks over which the reward pot is distributed. When the block number
+	/// advances beyond it, stakers stop accruing rewards.
+	pub duration: BlockNumber,
+	/// The list of conditions each staked NFT must satisfy. The target index of each clause is
+	/// ignored since every NFT is evaluated on its own.
+	pub stake_clauses: BoundedClauses<CollectionId, KL, VL>,
+	/// The amount of tokens distributed among stakers over the pool's duration.
+	pub reward_pot: Balance,
+}
+
+impl<Balance, CollectionId, BlockNumber, KL, VL>
+	StakingPool<Balance, CollectionId, BlockNumber, KL, VL>
+where
+	CollectionId: PartialEq,
+	KL: Get<u32>,
+	VL: Get<u32>,
+{
+	pub fn evaluate_stake<AccountId, NftInspector, ItemId>(
+		&self,
+		stake: &NftId<CollectionId, ItemId>,
+	) -> bool
+	where
+		NftInspector: Inspect<AccountId, CollectionId = CollectionId, ItemId = ItemId>,
+		ItemId: PartialEq,
+	{
+		self.stake_clauses
+			.iter()
+			.all(|clause| clause.evaluate_for::<AccountId, NftInspector, ItemId>(stake))
+	}
+}
+
+/// The reward accounting of a staking pool.
+#[derive(Debug, Clone, Eq, PartialEq, Default, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct PoolState<Balance, BlockNumber> {
+	/// The number of NFTs currently staked in the pool.
+	pub total_stakes: u32,
+	/// The accumulated rewards per staked NFT since the pool started.
+	pub reward_per_stake: FixedU128,
+	/// The block number up to which `reward_per_stake` has been accumulated.
+	pub last_update: BlockNumber,
+	/// The amount of tokens paid out to stakers so far.
+	pub paid: Balance,
+}
+
+/// The reward accounting of an account staking in a staking pool.
+#[derive(Debug, Clone, Eq, PartialEq, Default, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct PoolStake<Balance> {
+	/// The number of NFTs staked by the account.
+	pub stakes: u32,
+	/// The pool's `reward_per_stake` at the time rewards were last settled for the account.
+	pub reward_per_stake_paid: FixedU128,
+	/// The rewards accrued by the account that haven't been claimed yet.
+	pub pending: Balance,
+}
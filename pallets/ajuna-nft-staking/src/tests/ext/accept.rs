@@ -508,3 +508,167 @@ fn rejects_inactive_contracts() {
 			));
 		});
 }
+
+#[test]
+fn works_with_numeric_clauses() {
+	let soul_points = Clause::HasNumericAttribute(
+		RESERVED_COLLECTION_0,
+		bounded_vec![12],
+		NumericEncoding::Decimal,
+		NumericComparison::Greater(100),
+	);
+	let contract = Contract::default()
+		.stake_clauses(AttributeNamespace::Pallet, vec![(0, soul_points)])
+		.stake_amt(1)
+		.fee_amt(0);
+	let contract_id = H256::random();
+
+	// Lexicographically, "99" is greater than "100" and "150" is lower than "1000".
+	let mint = |value: &[u8]| {
+		(
+			NftId(RESERVED_COLLECTION_0, H256::random()),
+			bounded_vec![12],
+			AttributeValue::Equal(value.to_vec().try_into().unwrap()),
+		)
+	};
+	let (low_stake, high_stake) = (mint(b"99"), mint(b"150"));
+
+	ExtBuilder::default()
+		.set_creator(ALICE)
+		.create_contract_collection()
+		.create_contract_with_funds(contract_id, contract)
+		.mint_stakes(vec![(BOB, vec![low_stake.clone(), high_stake.clone()])])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				NftStake::accept(
+					RuntimeOrigin::signed(BOB),
+					contract_id,
+					vec![low_stake.0],
+					Default::default()
+				),
+				Error::<Test>::UnfulfilledStakingClause
+			);
+			assert_ok!(NftStake::accept(
+				RuntimeOrigin::signed(BOB),
+				contract_id,
+				vec![high_stake.0],
+				Default::default()
+			));
+		});
+}
+
+#[test]
+fn works_with_expression_clauses() {
+	// Matches items from either collection with attribute 1 or 2, but without attribute 3.
+	let expression = Clause::Expression(
+		bounded_vec![RESERVED_COLLECTION_0, RESERVED_COLLECTION_1],
+		bounded_vec![
+			ClauseOp::HasAttribute(bounded_vec![1]),
+			ClauseOp::HasAttribute(bounded_vec![2]),
+			ClauseOp::Or,
+			ClauseOp::HasAttribute(bounded_vec![3]),
+			ClauseOp::Not,
+			ClauseOp::And,
+		],
+	);
+	let contract = Contract::default()
+		.stake_clauses(AttributeNamespace::Pallet, vec![(0, expression)])
+		.stake_amt(1)
+		.fee_amt(0);
+	let contract_id = H256::random();
+
+	let mint = |collection_id, key: u8| {
+		(
+			NftId(collection_id, H256::random()),
+			bounded_vec![key],
+			AttributeValue::Equal(bounded_vec![0]),
+		)
+	};
+	let unmatched_stakes = vec![mint(RESERVED_COLLECTION_0, 3), mint(RESERVED_COLLECTION_2, 1)];
+	let matched_stake = mint(RESERVED_COLLECTION_1, 2);
+
+	let mut stakes = unmatched_stakes.clone();
+	stakes.push(matched_stake.clone());
+
+	ExtBuilder::default()
+		.set_creator(ALICE)
+		.create_contract_collection()
+		.create_contract_with_funds(contract_id, contract)
+		.mint_stakes(vec![(BOB, stakes)])
+		.build()
+		.execute_with(|| {
+			for (address, _, _) in unmatched_stakes {
+				assert_noop!(
+					NftStake::accept(
+						RuntimeOrigin::signed(BOB),
+						contract_id,
+						vec![address],
+						Default::default()
+					),
+					Error::<Test>::UnfulfilledStakingClause
+				);
+			}
+
+			// An item with both attributes 1 and 3 doesn't match either.
+			let (NftId(collection_id, item_id), _, _) = mint(RESERVED_COLLECTION_0, 1);
+			mint_item(&BOB, &collection_id, &item_id);
+			for key in [1, 3] {
+				set_attribute(
+					&collection_id,
+					&item_id,
+					&bounded_vec![key],
+					&AttributeValue::Equal(bounded_vec![0]),
+				);
+			}
+			assert_noop!(
+				NftStake::accept(
+					RuntimeOrigin::signed(BOB),
+					contract_id,
+					vec![NftId(collection_id, item_id)],
+					Default::default()
+				),
+				Error::<Test>::UnfulfilledStakingClause
+			);
+
+			assert_ok!(NftStake::accept(
+				RuntimeOrigin::signed(BOB),
+				contract_id,
+				vec![matched_stake.0],
+				Default::default()
+			));
+		});
+}
+
+#[test]
+fn works_with_minted_expression_clauses() {
+	let expression = Clause::Expression(
+		bounded_vec![RESERVED_COLLECTION_1],
+		bounded_vec![
+			ClauseOp::HasAttribute(bounded_vec![4]),
+			ClauseOp::HasAttribute(bounded_vec![5]),
+			ClauseOp::Or,
+		],
+	);
+	let contract = Contract::default()
+		.stake_clauses(AttributeNamespace::Pallet, vec![(0, expression.clone())])
+		.stake_amt(1)
+		.fee_amt(0);
+	let contract_id = H256::random();
+	let stakes = MockMints::from(MockClauses(vec![expression]));
+
+	ExtBuilder::default()
+		.set_creator(ALICE)
+		.create_contract_collection()
+		.create_contract_with_funds(contract_id, contract)
+		.mint_stakes(vec![(BOB, stakes.clone())])
+		.build()
+		.execute_with(|| {
+			assert_ok!(NftStake::accept(
+				RuntimeOrigin::signed(BOB),
+				contract_id,
+				vec![stakes[0].0.clone()],
+				Default::default()
+			));
+		});
+}
@@ -0,0 +1,78 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+
+#[test]
+fn works() {
+	ExtBuilder::default().build().execute_with(|| {
+		// BOB already holds the deposits of the collections reserved by the builder.
+		let (initial_balance, initial_reserve) =
+			(Balances::free_balance(BOB), Balances::reserved_balance(BOB));
+		assert_ok!(NftStake::apply_as_creator(RuntimeOrigin::signed(BOB), 100));
+		assert_eq!(CreatorApplications::<Test>::get(BOB), Some(100));
+		assert_eq!(Creators::<Test>::get(BOB), None);
+		assert_eq!(Balances::free_balance(BOB), initial_balance - 100);
+		assert_eq!(Balances::reserved_balance(BOB), initial_reserve + 100);
+		System::assert_last_event(RuntimeEvent::NftStake(crate::Event::CreatorApplied {
+			creator: BOB,
+			deposit: 100,
+		}));
+	});
+}
+
+#[test]
+fn rejects_unsigned_calls() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			NftStake::apply_as_creator(RuntimeOrigin::root(), 100),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn rejects_pending_applications() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(NftStake::apply_as_creator(RuntimeOrigin::signed(BOB), 100));
+		assert_noop!(
+			NftStake::apply_as_creator(RuntimeOrigin::signed(BOB), 100),
+			Error::<Test>::CreatorAlreadyApplied
+		);
+	});
+}
+
+#[test]
+fn rejects_registered_creators() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(NftStake::apply_as_creator(RuntimeOrigin::signed(BOB), 100));
+		assert_ok!(NftStake::register_creator(RuntimeOrigin::root(), BOB, 2));
+		assert_noop!(
+			NftStake::apply_as_creator(RuntimeOrigin::signed(BOB), 100),
+			Error::<Test>::CreatorAlreadyRegistered
+		);
+	});
+}
+
+#[test]
+fn rejects_insufficient_balance() {
+	ExtBuilder::default().balances(vec![(CHARLIE, 99)]).build().execute_with(|| {
+		assert_noop!(
+			NftStake::apply_as_creator(RuntimeOrigin::signed(CHARLIE), 100),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+	});
+}
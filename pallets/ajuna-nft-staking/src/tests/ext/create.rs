@@ -92,6 +92,39 @@ fn works_with_nft_reward() {
 		});
 }
 
+#[test]
+fn works_with_registered_creators() {
+	ExtBuilder::default()
+		.set_creator(ALICE)
+		.create_contract_collection()
+		.build()
+		.execute_with(|| {
+			let reward_amount = 1_000;
+			let contract = Contract::default().rewards(bounded_vec![Reward::Tokens(reward_amount)]);
+			assert_ok!(NftStake::apply_as_creator(RuntimeOrigin::signed(BOB), 100));
+			assert_ok!(NftStake::register_creator(RuntimeOrigin::root(), BOB, 1));
+			let initial_balance = Balances::free_balance(BOB);
+
+			let contract_id = H256::random();
+			assert_ok!(NftStake::create(
+				RuntimeOrigin::signed(BOB),
+				contract_id,
+				contract.clone(),
+				None,
+				None
+			));
+			assert_eq!(ContractCreators::<Test>::get(contract_id), Some(BOB));
+			assert_eq!(Creators::<Test>::get(BOB).unwrap().contracts, 1);
+			assert_eq!(CreatorsStats::<Test>::get(BOB).contracts_created, 1);
+			assert_eq!(Balances::free_balance(BOB), initial_balance - reward_amount);
+
+			assert_noop!(
+				NftStake::create(RuntimeOrigin::signed(BOB), H256::random(), contract, None, None),
+				Error::<Test>::CreatorQuotaReached
+			);
+		});
+}
+
 #[test]
 fn rejects_non_creator_calls() {
 	ExtBuilder::default()
@@ -284,3 +317,38 @@ fn rejects_incorrect_claim_duration() {
 			);
 		});
 }
+
+#[test]
+fn rejects_malformed_expression_clauses() {
+	ExtBuilder::default().set_creator(ALICE).build().execute_with(|| {
+		for (collection_ids, ops) in [
+			(bounded_vec![], bounded_vec![ClauseOp::HasAttribute(bounded_vec![1])]),
+			(bounded_vec![RESERVED_COLLECTION_0], bounded_vec![]),
+			(
+				bounded_vec![RESERVED_COLLECTION_0],
+				bounded_vec![ClauseOp::HasAttribute(bounded_vec![1]), ClauseOp::And],
+			),
+			(
+				bounded_vec![RESERVED_COLLECTION_0],
+				bounded_vec![
+					ClauseOp::HasAttribute(bounded_vec![1]),
+					ClauseOp::HasAttribute(bounded_vec![2]),
+				],
+			),
+		] {
+			assert_noop!(
+				NftStake::create(
+					RuntimeOrigin::signed(ALICE),
+					H256::random(),
+					Contract::default().rewards(bounded_vec![Reward::Tokens(1)]).stake_clauses(
+						AttributeNamespace::Pallet,
+						vec![(0, Clause::Expression(collection_ids, ops))]
+					),
+					None,
+					None
+				),
+				Error::<Test>::InvalidClause
+			);
+		}
+	});
+}
@@ -0,0 +1,101 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+
+#[test]
+fn works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let initial_balance = Balances::free_balance(BOB);
+		assert_ok!(NftStake::apply_as_creator(RuntimeOrigin::signed(BOB), 100));
+		assert_ok!(NftStake::register_creator(RuntimeOrigin::root(), BOB, 2));
+		assert_ok!(NftStake::deregister_creator(RuntimeOrigin::signed(BOB), BOB));
+		assert_eq!(Creators::<Test>::get(BOB), None);
+		assert_eq!(Balances::free_balance(BOB), initial_balance);
+		System::assert_last_event(RuntimeEvent::NftStake(crate::Event::CreatorDeregistered {
+			creator: BOB,
+		}));
+
+		assert_ok!(NftStake::apply_as_creator(RuntimeOrigin::signed(BOB), 100));
+		assert_ok!(NftStake::register_creator(RuntimeOrigin::root(), BOB, 2));
+		assert_ok!(NftStake::deregister_creator(RuntimeOrigin::root(), BOB));
+		assert_eq!(Creators::<Test>::get(BOB), None);
+	});
+}
+
+#[test]
+fn withdraws_pending_applications() {
+	ExtBuilder::default().build().execute_with(|| {
+		let initial_balance = Balances::free_balance(BOB);
+		assert_ok!(NftStake::apply_as_creator(RuntimeOrigin::signed(BOB), 100));
+		assert_ok!(NftStake::deregister_creator(RuntimeOrigin::signed(BOB), BOB));
+		assert_eq!(CreatorApplications::<Test>::get(BOB), None);
+		assert_eq!(Balances::free_balance(BOB), initial_balance);
+		System::assert_last_event(RuntimeEvent::NftStake(crate::Event::CreatorDeregistered {
+			creator: BOB,
+		}));
+
+		assert_ok!(NftStake::apply_as_creator(RuntimeOrigin::signed(BOB), 100));
+		assert_ok!(NftStake::deregister_creator(RuntimeOrigin::root(), BOB));
+		assert_eq!(CreatorApplications::<Test>::get(BOB), None);
+		assert_eq!(Balances::free_balance(BOB), initial_balance);
+	});
+}
+
+#[test]
+fn rejects_other_accounts() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(NftStake::apply_as_creator(RuntimeOrigin::signed(BOB), 100));
+		assert_ok!(NftStake::register_creator(RuntimeOrigin::root(), BOB, 2));
+		assert_noop!(
+			NftStake::deregister_creator(RuntimeOrigin::signed(CHARLIE), BOB),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn rejects_unregistered_creators() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			NftStake::deregister_creator(RuntimeOrigin::root(), BOB),
+			Error::<Test>::UnregisteredCreator
+		);
+	});
+}
+
+#[test]
+fn rejects_creators_with_contracts() {
+	ExtBuilder::default()
+		.set_creator(ALICE)
+		.create_contract_collection()
+		.build()
+		.execute_with(|| {
+			assert_ok!(NftStake::apply_as_creator(RuntimeOrigin::signed(BOB), 100));
+			assert_ok!(NftStake::register_creator(RuntimeOrigin::root(), BOB, 2));
+			assert_ok!(NftStake::create(
+				RuntimeOrigin::signed(BOB),
+				H256::random(),
+				Contract::default(),
+				None,
+				None
+			));
+			assert_noop!(
+				NftStake::deregister_creator(RuntimeOrigin::signed(BOB), BOB),
+				Error::<Test>::CreatorHasContracts
+			);
+		});
+}
@@ -0,0 +1,151 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+
+fn mint_with_attributes(owner: &MockAccountId, keys: &[u8]) -> NftIdOf<Test> {
+	let nft = mint_item(owner, &RESERVED_COLLECTION_0, &H256::random());
+	for key in keys {
+		set_attribute(&nft.0, &nft.1, &bounded_vec![*key], &AttributeValue::Equal(bounded_vec![1]));
+	}
+	nft
+}
+
+fn has_attribute(key: u8) -> MockClause {
+	Clause::HasAttribute(RESERVED_COLLECTION_0, bounded_vec![key])
+}
+
+fn contract_with(
+	stake_clauses: Vec<(u8, MockClause)>,
+	fee_clauses: Vec<(u8, MockClause)>,
+) -> ContractOf<Test> {
+	let (stake_amount, fee_amount) = (
+		stake_clauses.iter().map(|(index, _)| index + 1).max().unwrap_or_default(),
+		fee_clauses.iter().map(|(index, _)| index + 1).max().unwrap_or_default(),
+	);
+	Contract::default()
+		.rewards(bounded_vec![Reward::Tokens(123)])
+		.active_duration(100)
+		.stake_duration(10)
+		.claim_duration(10)
+		.stake_amt(stake_amount)
+		.stake_clauses(AttributeNamespace::Pallet, stake_clauses)
+		.fee_amt(fee_amount)
+		.fee_clauses(AttributeNamespace::Pallet, fee_clauses)
+}
+
+#[test]
+fn returns_contracts_acceptable_with_owned_nfts() {
+	let contract_id = H256::random();
+	let contract = contract_with(vec![(0, has_attribute(12))], vec![(0, has_attribute(34))]);
+	ExtBuilder::default()
+		.set_creator(ALICE)
+		.create_contract_collection()
+		.create_contract_with_funds(contract_id, contract)
+		.build()
+		.execute_with(|| {
+			let fee = mint_with_attributes(&BOB, &[34]);
+			let stake = mint_with_attributes(&BOB, &[12]);
+			let _ = mint_with_attributes(&BOB, &[56]);
+
+			let matches = NftStake::acceptable_contracts(&BOB);
+			assert_eq!(
+				matches,
+				vec![ContractMatch { contract_id, stakes: vec![stake], fees: vec![fee] }]
+			);
+			assert_eq!(NftStake::acceptable_contracts(&CHARLIE), vec![]);
+
+			let ContractMatch { stakes, fees, .. } = matches[0].clone();
+			assert_ok!(NftStake::accept(RuntimeOrigin::signed(BOB), contract_id, stakes, fees));
+			assert_eq!(NftStake::acceptable_contracts(&BOB), vec![]);
+		});
+}
+
+#[test]
+fn assigns_nfts_to_target_indices() {
+	let contract_id = H256::random();
+	let contract = contract_with(
+		vec![(0, has_attribute(12)), (1, has_attribute(12)), (1, has_attribute(34))],
+		vec![],
+	);
+	ExtBuilder::default()
+		.set_creator(ALICE)
+		.create_contract_collection()
+		.create_contract_with_funds(contract_id, contract)
+		.build()
+		.execute_with(|| {
+			// The NFT fulfilling both indices must be moved to the second one.
+			let both = mint_with_attributes(&BOB, &[12, 34]);
+			let first = mint_with_attributes(&BOB, &[12]);
+
+			assert_eq!(
+				NftStake::matching_contracts(vec![both.clone(), first.clone(), both.clone()]),
+				vec![ContractMatch {
+					contract_id,
+					stakes: vec![first.clone(), both.clone()],
+					fees: vec![]
+				}]
+			);
+			assert_eq!(NftStake::matching_contracts(vec![both.clone()]), vec![]);
+			assert_eq!(NftStake::matching_contracts(vec![first.clone()]), vec![]);
+		});
+}
+
+#[test]
+fn does_not_reuse_nfts_across_stakes_and_fees() {
+	let contract_id = H256::random();
+	let contract = contract_with(vec![(0, has_attribute(12))], vec![(0, has_attribute(12))]);
+	ExtBuilder::default()
+		.set_creator(ALICE)
+		.create_contract_collection()
+		.create_contract_with_funds(contract_id, contract)
+		.build()
+		.execute_with(|| {
+			let stake = mint_with_attributes(&BOB, &[12]);
+			assert_eq!(NftStake::acceptable_contracts(&BOB), vec![]);
+
+			let fee = mint_with_attributes(&BOB, &[12]);
+			let matches = NftStake::matching_contracts(vec![stake, fee]);
+			assert_eq!(matches.len(), 1);
+			assert_ne!(matches[0].stakes, matches[0].fees);
+		});
+}
+
+#[test]
+fn skips_inactive_contracts() {
+	let contract_id = H256::random();
+	let contract = contract_with(vec![(0, has_attribute(12))], vec![])
+		.activation(10)
+		.active_duration(5);
+	ExtBuilder::default()
+		.set_creator(ALICE)
+		.create_contract_collection()
+		.create_contract_with_funds(contract_id, contract)
+		.build()
+		.execute_with(|| {
+			let stake = mint_with_attributes(&BOB, &[12]);
+			assert_eq!(NftStake::acceptable_contracts(&BOB), vec![]);
+
+			run_to_block(10);
+			assert_eq!(
+				NftStake::acceptable_contracts(&BOB),
+				vec![ContractMatch { contract_id, stakes: vec![stake], fees: vec![] }]
+			);
+
+			run_to_block(16);
+			assert_eq!(NftStake::acceptable_contracts(&BOB), vec![]);
+		});
+}
@@ -15,14 +15,22 @@
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
 mod accept;
+mod apply_as_creator;
 mod cancel;
 mod claim;
 mod create;
+mod deregister_creator;
+mod discovery;
+mod pool;
+mod register_creator;
 mod remove;
+mod renewal;
+mod reward_vesting;
 mod set_contract_collection_id;
 mod set_creator;
 mod set_global_config;
 mod snipe;
+mod unstake_early;
 
 use crate::{tests::mock::*, *};
 use frame_support::{assert_noop, assert_ok};
@@ -0,0 +1,490 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use sp_runtime::{FixedPointNumber, FixedU128};
+
+fn pool(duration: BlockNumberFor<Test>, reward_pot: MockBalance) -> StakingPoolOf<Test> {
+	StakingPool { start: None, duration, stake_clauses: Default::default(), reward_pot }
+}
+
+fn mint_stakes(owner: MockAccountId, amount: usize) -> PoolStakedItemsOf<Test> {
+	(0..amount)
+		.map(|_| mint_item(&owner, &RESERVED_COLLECTION_0, &H256::random()))
+		.collect::<Vec<_>>()
+		.try_into()
+		.unwrap()
+}
+
+mod create_pool {
+	use super::*;
+
+	#[test]
+	fn works() {
+		ExtBuilder::default().set_creator(ALICE).build().execute_with(|| {
+			let pool_id = H256::random();
+			let initial_balance = Balances::free_balance(ALICE);
+			assert_ok!(NftStake::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				pool(10, 1_000)
+			));
+
+			assert_eq!(
+				Pools::<Test>::get(pool_id),
+				Some(StakingPool { start: Some(1), ..pool(10, 1_000) })
+			);
+			assert_eq!(PoolCreators::<Test>::get(pool_id), Some(ALICE));
+			assert_eq!(
+				PoolStates::<Test>::get(pool_id),
+				Some(PoolState { last_update: 1, ..Default::default() })
+			);
+			assert_eq!(Balances::free_balance(ALICE), initial_balance - 1_000);
+			assert_eq!(Balances::free_balance(NftStake::account_id()), 1_000);
+			assert_eq!(CreatorsStats::<Test>::get(ALICE).contracts_created, 1);
+			System::assert_last_event(RuntimeEvent::NftStake(crate::Event::PoolCreated {
+				pool_id,
+				creator: ALICE,
+			}));
+		});
+	}
+
+	#[test]
+	fn counts_towards_creator_quota() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(NftStake::apply_as_creator(RuntimeOrigin::signed(BOB), 100));
+			assert_ok!(NftStake::register_creator(RuntimeOrigin::root(), BOB, 1));
+			assert_ok!(NftStake::create_pool(
+				RuntimeOrigin::signed(BOB),
+				H256::random(),
+				pool(10, 1_000)
+			));
+			assert_eq!(Creators::<Test>::get(BOB).unwrap().contracts, 1);
+			assert_noop!(
+				NftStake::create_pool(RuntimeOrigin::signed(BOB), H256::random(), pool(10, 1_000)),
+				Error::<Test>::CreatorQuotaReached
+			);
+		});
+	}
+
+	#[test]
+	fn rejects_non_creator_calls() {
+		ExtBuilder::default().set_creator(ALICE).build().execute_with(|| {
+			assert_noop!(
+				NftStake::create_pool(RuntimeOrigin::signed(BOB), H256::random(), pool(10, 1_000)),
+				DispatchError::BadOrigin
+			);
+		});
+	}
+
+	#[test]
+	fn rejects_when_pallet_is_locked() {
+		ExtBuilder::default().set_creator(ALICE).build().execute_with(|| {
+			GlobalConfigs::<Test>::mutate(|config| config.pallet_locked = true);
+			assert_noop!(
+				NftStake::create_pool(
+					RuntimeOrigin::signed(ALICE),
+					H256::random(),
+					pool(10, 1_000)
+				),
+				Error::<Test>::PalletLocked
+			);
+		});
+	}
+
+	#[test]
+	fn rejects_existing_pools() {
+		ExtBuilder::default().set_creator(ALICE).build().execute_with(|| {
+			let pool_id = H256::random();
+			assert_ok!(NftStake::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				pool(10, 1_000)
+			));
+			assert_noop!(
+				NftStake::create_pool(RuntimeOrigin::signed(ALICE), pool_id, pool(10, 1_000)),
+				Error::<Test>::PoolAlreadyExists
+			);
+		});
+	}
+
+	#[test]
+	fn rejects_zero_duration() {
+		ExtBuilder::default().set_creator(ALICE).build().execute_with(|| {
+			assert_noop!(
+				NftStake::create_pool(RuntimeOrigin::signed(ALICE), H256::random(), pool(0, 1_000)),
+				Error::<Test>::ZeroPoolDuration
+			);
+		});
+	}
+
+	#[test]
+	fn rejects_past_start() {
+		ExtBuilder::default().set_creator(ALICE).build().execute_with(|| {
+			run_to_block(5);
+			let pool = StakingPool { start: Some(3), ..pool(10, 1_000) };
+			assert_noop!(
+				NftStake::create_pool(RuntimeOrigin::signed(ALICE), H256::random(), pool),
+				Error::<Test>::IncorrectActivation
+			);
+		});
+	}
+}
+
+mod join_pool {
+	use super::*;
+
+	#[test]
+	fn works() {
+		ExtBuilder::default().set_creator(ALICE).build().execute_with(|| {
+			let pool_id = H256::random();
+			assert_ok!(NftStake::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				pool(10, 1_000)
+			));
+
+			let stakes = mint_stakes(BOB, 2);
+			assert_ok!(NftStake::join_pool(RuntimeOrigin::signed(BOB), pool_id, stakes.clone()));
+
+			for NftId(collection_id, item_id) in stakes.clone() {
+				assert_eq!(Nft::owner(collection_id, item_id), Some(NftStake::account_id()));
+			}
+			assert_eq!(PoolStakedItems::<Test>::get(pool_id, BOB).unwrap(), stakes);
+			assert_eq!(PoolStakes::<Test>::get(pool_id, BOB).unwrap().stakes, 2);
+			assert_eq!(PoolStates::<Test>::get(pool_id).unwrap().total_stakes, 2);
+			System::assert_last_event(RuntimeEvent::NftStake(crate::Event::PoolJoined {
+				by: BOB,
+				pool_id,
+				stakes: 2,
+			}));
+		});
+	}
+
+	#[test]
+	fn rejects_unknown_pools() {
+		ExtBuilder::default().build().execute_with(|| {
+			let stakes = mint_stakes(BOB, 1);
+			assert_noop!(
+				NftStake::join_pool(RuntimeOrigin::signed(BOB), H256::random(), stakes),
+				Error::<Test>::UnknownPool
+			);
+		});
+	}
+
+	#[test]
+	fn rejects_empty_stakes() {
+		ExtBuilder::default().set_creator(ALICE).build().execute_with(|| {
+			let pool_id = H256::random();
+			assert_ok!(NftStake::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				pool(10, 1_000)
+			));
+			assert_noop!(
+				NftStake::join_pool(RuntimeOrigin::signed(BOB), pool_id, bounded_vec![]),
+				Error::<Test>::InvalidNFTStakeAmount
+			);
+		});
+	}
+
+	#[test]
+	fn rejects_unowned_stakes() {
+		ExtBuilder::default().set_creator(ALICE).build().execute_with(|| {
+			let pool_id = H256::random();
+			assert_ok!(NftStake::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				pool(10, 1_000)
+			));
+			let stakes = mint_stakes(CHARLIE, 1);
+			assert_noop!(
+				NftStake::join_pool(RuntimeOrigin::signed(BOB), pool_id, stakes),
+				Error::<Test>::Ownership
+			);
+		});
+	}
+
+	#[test]
+	fn rejects_unfulfilled_stake_clauses() {
+		ExtBuilder::default().set_creator(ALICE).build().execute_with(|| {
+			let pool_id = H256::random();
+			let stake_clauses = bounded_vec![MockContractClause {
+				namespace: AttributeNamespace::Pallet,
+				target_index: 0,
+				clause: Clause::HasAttribute(RESERVED_COLLECTION_0, bounded_vec![4]),
+			}];
+			let pool = StakingPool { stake_clauses, ..pool(10, 1_000) };
+			assert_ok!(NftStake::create_pool(RuntimeOrigin::signed(ALICE), pool_id, pool));
+			let stakes = mint_stakes(BOB, 1);
+			assert_noop!(
+				NftStake::join_pool(RuntimeOrigin::signed(BOB), pool_id, stakes),
+				Error::<Test>::UnfulfilledStakingClause
+			);
+		});
+	}
+
+	#[test]
+	fn rejects_ended_pools() {
+		ExtBuilder::default().set_creator(ALICE).build().execute_with(|| {
+			let pool_id = H256::random();
+			assert_ok!(NftStake::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				pool(10, 1_000)
+			));
+			run_to_block(11);
+			let stakes = mint_stakes(BOB, 1);
+			assert_noop!(
+				NftStake::join_pool(RuntimeOrigin::signed(BOB), pool_id, stakes),
+				Error::<Test>::PoolEnded
+			);
+		});
+	}
+
+	#[test]
+	fn rejects_exceeding_max_pool_stakes() {
+		ExtBuilder::default().set_creator(ALICE).build().execute_with(|| {
+			let pool_id = H256::random();
+			assert_ok!(NftStake::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				pool(10, 1_000)
+			));
+			let stakes = mint_stakes(BOB, MaxPoolStakes::get() as usize);
+			assert_ok!(NftStake::join_pool(RuntimeOrigin::signed(BOB), pool_id, stakes));
+			let stakes = mint_stakes(BOB, 1);
+			assert_noop!(
+				NftStake::join_pool(RuntimeOrigin::signed(BOB), pool_id, stakes),
+				Error::<Test>::MaxPoolStakes
+			);
+		});
+	}
+}
+
+mod pool_rewards {
+	use super::*;
+
+	#[test]
+	fn accrue_pro_rata_to_stakes_and_duration() {
+		ExtBuilder::default().set_creator(ALICE).build().execute_with(|| {
+			let pool_id = H256::random();
+			assert_ok!(NftStake::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				pool(10, 1_000)
+			));
+			let (bob_balance, charlie_balance) =
+				(Balances::free_balance(BOB), Balances::free_balance(CHARLIE));
+
+			// BOB is the only staker for the first half of the pool.
+			assert_ok!(NftStake::join_pool(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				mint_stakes(BOB, 1)
+			));
+			run_to_block(6);
+			assert_ok!(NftStake::claim_pool_rewards(RuntimeOrigin::signed(BOB), pool_id));
+			System::assert_last_event(RuntimeEvent::NftStake(crate::Event::PoolRewardsClaimed {
+				by: BOB,
+				pool_id,
+				amount: 500,
+			}));
+
+			// CHARLIE stakes twice as many NFTs as BOB for the second half.
+			assert_ok!(NftStake::join_pool(
+				RuntimeOrigin::signed(CHARLIE),
+				pool_id,
+				mint_stakes(CHARLIE, 2)
+			));
+			run_to_block(20);
+			assert_ok!(NftStake::leave_pool(RuntimeOrigin::signed(BOB), pool_id));
+			assert_ok!(NftStake::leave_pool(RuntimeOrigin::signed(CHARLIE), pool_id));
+
+			// Each minted stake leaves its owner with 999 extra free balance.
+			let minting_costs = 999;
+			assert_eq!(Balances::free_balance(BOB), bob_balance + minting_costs + 500 + 166);
+			assert_eq!(Balances::free_balance(CHARLIE), charlie_balance + 2 * minting_costs + 333);
+			assert_eq!(PoolStates::<Test>::get(pool_id).unwrap().paid, 999);
+		});
+	}
+
+	#[test]
+	fn are_not_claimable_by_non_stakers() {
+		ExtBuilder::default().set_creator(ALICE).build().execute_with(|| {
+			let pool_id = H256::random();
+			assert_ok!(NftStake::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				pool(10, 1_000)
+			));
+			assert_noop!(
+				NftStake::claim_pool_rewards(RuntimeOrigin::signed(BOB), pool_id),
+				Error::<Test>::NotPoolStaker
+			);
+			assert_noop!(
+				NftStake::leave_pool(RuntimeOrigin::signed(BOB), pool_id),
+				Error::<Test>::NotPoolStaker
+			);
+		});
+	}
+}
+
+mod leave_pool {
+	use super::*;
+
+	#[test]
+	fn works() {
+		ExtBuilder::default().set_creator(ALICE).build().execute_with(|| {
+			let pool_id = H256::random();
+			assert_ok!(NftStake::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				pool(10, 1_000)
+			));
+			let stakes = mint_stakes(BOB, 2);
+			assert_ok!(NftStake::join_pool(RuntimeOrigin::signed(BOB), pool_id, stakes.clone()));
+
+			run_to_block(3);
+			assert_ok!(NftStake::leave_pool(RuntimeOrigin::signed(BOB), pool_id));
+
+			for NftId(collection_id, item_id) in stakes.clone() {
+				assert_eq!(Nft::owner(collection_id, item_id), Some(BOB));
+			}
+			assert!(PoolStakes::<Test>::get(pool_id, BOB).is_none());
+			assert!(PoolStakedItems::<Test>::get(pool_id, BOB).is_none());
+			assert_eq!(
+				PoolStates::<Test>::get(pool_id),
+				Some(PoolState {
+					total_stakes: 0,
+					reward_per_stake: FixedU128::saturating_from_integer(100),
+					last_update: 3,
+					paid: 200
+				})
+			);
+			System::assert_last_event(RuntimeEvent::NftStake(crate::Event::PoolLeft {
+				by: BOB,
+				pool_id,
+			}));
+		});
+	}
+}
+
+mod close_pool {
+	use super::*;
+
+	#[test]
+	fn refunds_undistributed_rewards() {
+		ExtBuilder::default().set_creator(ALICE).build().execute_with(|| {
+			let pool_id = H256::random();
+			assert_ok!(NftStake::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				pool(10, 1_000)
+			));
+			let alice_balance = Balances::free_balance(ALICE);
+
+			// Nobody stakes during the first half of the pool.
+			run_to_block(6);
+			assert_ok!(NftStake::join_pool(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				mint_stakes(BOB, 1)
+			));
+			run_to_block(11);
+			assert_ok!(NftStake::leave_pool(RuntimeOrigin::signed(BOB), pool_id));
+
+			assert_ok!(NftStake::close_pool(RuntimeOrigin::signed(ALICE), pool_id));
+			assert_eq!(Balances::free_balance(ALICE), alice_balance + 500);
+			assert!(Pools::<Test>::get(pool_id).is_none());
+			assert!(PoolStates::<Test>::get(pool_id).is_none());
+			assert!(PoolCreators::<Test>::get(pool_id).is_none());
+			System::assert_last_event(RuntimeEvent::NftStake(crate::Event::PoolClosed {
+				pool_id,
+				refund: 500,
+			}));
+		});
+	}
+
+	#[test]
+	fn releases_creator_quota() {
+		ExtBuilder::default().build().execute_with(|| {
+			let pool_id = H256::random();
+			assert_ok!(NftStake::apply_as_creator(RuntimeOrigin::signed(BOB), 100));
+			assert_ok!(NftStake::register_creator(RuntimeOrigin::root(), BOB, 1));
+			assert_ok!(NftStake::create_pool(RuntimeOrigin::signed(BOB), pool_id, pool(10, 1_000)));
+			run_to_block(11);
+			assert_ok!(NftStake::close_pool(RuntimeOrigin::signed(BOB), pool_id));
+			assert_eq!(Creators::<Test>::get(BOB).unwrap().contracts, 0);
+		});
+	}
+
+	#[test]
+	fn rejects_non_creator_calls() {
+		ExtBuilder::default().set_creator(ALICE).build().execute_with(|| {
+			let pool_id = H256::random();
+			assert_ok!(NftStake::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				pool(10, 1_000)
+			));
+			run_to_block(11);
+			assert_noop!(
+				NftStake::close_pool(RuntimeOrigin::signed(BOB), pool_id),
+				DispatchError::BadOrigin
+			);
+		});
+	}
+
+	#[test]
+	fn rejects_ongoing_pools() {
+		ExtBuilder::default().set_creator(ALICE).build().execute_with(|| {
+			let pool_id = H256::random();
+			assert_ok!(NftStake::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				pool(10, 1_000)
+			));
+			run_to_block(10);
+			assert_noop!(
+				NftStake::close_pool(RuntimeOrigin::signed(ALICE), pool_id),
+				Error::<Test>::PoolNotEnded
+			);
+		});
+	}
+
+	#[test]
+	fn rejects_pools_with_stakers() {
+		ExtBuilder::default().set_creator(ALICE).build().execute_with(|| {
+			let pool_id = H256::random();
+			assert_ok!(NftStake::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				pool(10, 1_000)
+			));
+			assert_ok!(NftStake::join_pool(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				mint_stakes(BOB, 1)
+			));
+			run_to_block(11);
+			assert_noop!(
+				NftStake::close_pool(RuntimeOrigin::signed(ALICE), pool_id),
+				Error::<Test>::PoolHasStakers
+			);
+		});
+	}
+}
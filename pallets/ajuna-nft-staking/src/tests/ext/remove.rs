@@ -76,6 +76,49 @@ fn rejects_non_creator_calls() {
 		});
 }
 
+#[test]
+fn works_with_registered_creators() {
+	let reward_amount = 1_000;
+
+	ExtBuilder::default()
+		.set_creator(ALICE)
+		.create_contract_collection()
+		.build()
+		.execute_with(|| {
+			let contract = Contract::default().rewards(bounded_vec![Reward::Tokens(reward_amount)]);
+			assert_ok!(NftStake::apply_as_creator(RuntimeOrigin::signed(BOB), 100));
+			assert_ok!(NftStake::register_creator(RuntimeOrigin::root(), BOB, 2));
+			let initial_balance = Balances::free_balance(BOB);
+
+			let (contract_id_1, contract_id_2) = (H256::random(), H256::random());
+			for contract_id in [contract_id_1, contract_id_2] {
+				assert_ok!(NftStake::create(
+					RuntimeOrigin::signed(BOB),
+					contract_id,
+					contract.clone(),
+					None,
+					None
+				));
+			}
+
+			assert_noop!(
+				NftStake::remove(RuntimeOrigin::signed(CHARLIE), contract_id_1),
+				DispatchError::BadOrigin
+			);
+
+			// Both the contract's creator and the root-set creator can remove it, with the
+			// rewards going back to the contract's creator.
+			assert_ok!(NftStake::remove(RuntimeOrigin::signed(BOB), contract_id_1));
+			assert_ok!(NftStake::remove(RuntimeOrigin::signed(ALICE), contract_id_2));
+			assert_eq!(Balances::free_balance(BOB), initial_balance);
+
+			assert_eq!(ContractCreators::<Test>::get(contract_id_1), None);
+			assert_eq!(ContractCreators::<Test>::get(contract_id_2), None);
+			assert_eq!(Creators::<Test>::get(BOB).unwrap().contracts, 0);
+			assert_eq!(CreatorsStats::<Test>::get(BOB).contracts_removed, 2);
+		});
+}
+
 #[test]
 fn rejects_when_pallet_is_locked() {
 	let contract_id = H256::random();
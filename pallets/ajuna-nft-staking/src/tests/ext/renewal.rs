@@ -0,0 +1,342 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+
+const REWARD: MockBalance = 100;
+const STAKE_DURATION: BlockNumberFor<Test> = 5;
+
+fn build_with(
+	contract_id: MockItemId,
+	rewards: BoundedRewardsOf<Test>,
+	stakes: Vec<(MockAccountId, MockMints)>,
+) -> sp_io::TestExternalities {
+	let contract = Contract::default()
+		.rewards(rewards)
+		.active_duration(100)
+		.stake_duration(STAKE_DURATION)
+		.claim_duration(100);
+	single_stake_builder(contract_id, contract).mint_stakes(stakes).build()
+}
+
+mod set_contract_renewal {
+	use super::*;
+
+	#[test]
+	fn works() {
+		let contract_id = H256::random();
+		build_with(contract_id, bounded_vec![Reward::Tokens(REWARD)], vec![]).execute_with(|| {
+			let initial_balance = Balances::free_balance(ALICE);
+			assert_ok!(NftStake::set_contract_renewal(
+				RuntimeOrigin::signed(ALICE),
+				contract_id,
+				RenewalMode::Restake,
+				3
+			));
+			assert_eq!(Balances::free_balance(ALICE), initial_balance - 2 * REWARD);
+			assert_eq!(NftStake::account_balance(), 3 * REWARD);
+			assert_eq!(
+				ContractRenewals::<Test>::get(contract_id),
+				Some(ContractRenewal {
+					mode: RenewalMode::Restake,
+					max_rounds: 3,
+					round: 1,
+					budget: 2 * REWARD
+				})
+			);
+			System::assert_last_event(RuntimeEvent::NftStake(crate::Event::RenewalSet {
+				contract_id,
+				mode: RenewalMode::Restake,
+				max_rounds: 3,
+				budget: 2 * REWARD,
+			}));
+		});
+	}
+
+	#[test]
+	fn rejects_non_creator_calls() {
+		let contract_id = H256::random();
+		build_with(contract_id, bounded_vec![Reward::Tokens(REWARD)], vec![]).execute_with(|| {
+			assert_noop!(
+				NftStake::set_contract_renewal(
+					RuntimeOrigin::signed(BOB),
+					contract_id,
+					RenewalMode::Restake,
+					3
+				),
+				DispatchError::BadOrigin
+			);
+		});
+	}
+
+	#[test]
+	fn rejects_accepted_contracts() {
+		let contract_id = H256::random();
+		let (staker, stakes) = single_stake_for(BOB);
+		build_with(
+			contract_id,
+			bounded_vec![Reward::Tokens(REWARD)],
+			vec![(staker, stakes.clone())],
+		)
+		.execute_with(|| {
+			accept_with_stakes(BOB, contract_id, &stakes);
+			assert_noop!(
+				NftStake::set_contract_renewal(
+					RuntimeOrigin::signed(ALICE),
+					contract_id,
+					RenewalMode::Restake,
+					3
+				),
+				Error::<Test>::Staking
+			);
+		});
+	}
+
+	#[test]
+	fn rejects_single_round() {
+		let contract_id = H256::random();
+		build_with(contract_id, bounded_vec![Reward::Tokens(REWARD)], vec![]).execute_with(|| {
+			assert_noop!(
+				NftStake::set_contract_renewal(
+					RuntimeOrigin::signed(ALICE),
+					contract_id,
+					RenewalMode::Restake,
+					1
+				),
+				Error::<Test>::InvalidRenewal
+			);
+		});
+	}
+
+	#[test]
+	fn rejects_nft_rewards() {
+		let contract_id = H256::random();
+		let reward = Reward::Nft(NftId(RESERVED_COLLECTION_2, H256::random()));
+		build_with(contract_id, bounded_vec![reward], vec![]).execute_with(|| {
+			assert_noop!(
+				NftStake::set_contract_renewal(
+					RuntimeOrigin::signed(ALICE),
+					contract_id,
+					RenewalMode::Relist,
+					2
+				),
+				Error::<Test>::NonRenewableContract
+			);
+		});
+	}
+
+	#[test]
+	fn rejects_renewable_contracts() {
+		let contract_id = H256::random();
+		build_with(contract_id, bounded_vec![Reward::Tokens(REWARD)], vec![]).execute_with(|| {
+			assert_ok!(NftStake::set_contract_renewal(
+				RuntimeOrigin::signed(ALICE),
+				contract_id,
+				RenewalMode::Restake,
+				2
+			));
+			assert_noop!(
+				NftStake::set_contract_renewal(
+					RuntimeOrigin::signed(ALICE),
+					contract_id,
+					RenewalMode::Relist,
+					2
+				),
+				Error::<Test>::RenewalAlreadySet
+			);
+		});
+	}
+
+	#[test]
+	fn refunds_budget_on_removal() {
+		let contract_id = H256::random();
+		build_with(contract_id, bounded_vec![Reward::Tokens(REWARD)], vec![]).execute_with(|| {
+			let initial_balance = Balances::free_balance(ALICE);
+			assert_ok!(NftStake::set_contract_renewal(
+				RuntimeOrigin::signed(ALICE),
+				contract_id,
+				RenewalMode::Relist,
+				3
+			));
+			run_to_block(102);
+			assert_ok!(NftStake::remove(RuntimeOrigin::signed(ALICE), contract_id));
+			assert_eq!(
+				Balances::free_balance(ALICE),
+				initial_balance + REWARD + ItemDeposit::get()
+			);
+			assert_eq!(ContractRenewals::<Test>::get(contract_id), None);
+			assert_eq!(NftStake::account_balance(), 0);
+		});
+	}
+}
+
+mod renew {
+	use super::*;
+
+	#[test]
+	fn works() {
+		let contract_id = H256::random();
+		let (staker, stakes) = single_stake_for(BOB);
+		build_with(
+			contract_id,
+			bounded_vec![Reward::Tokens(REWARD)],
+			vec![(staker, stakes.clone())],
+		)
+		.execute_with(|| {
+			assert_ok!(NftStake::set_contract_renewal(
+				RuntimeOrigin::signed(ALICE),
+				contract_id,
+				RenewalMode::Restake,
+				2
+			));
+			accept_with_stakes(BOB, contract_id, &stakes);
+			let initial_balance = Balances::free_balance(BOB);
+
+			run_to_block(1 + STAKE_DURATION);
+			assert_ok!(NftStake::renew(RuntimeOrigin::signed(BOB), contract_id));
+			assert_eq!(Balances::free_balance(BOB), initial_balance + REWARD);
+			assert_eq!(ContractAccepted::<Test>::get(contract_id), Some(1 + STAKE_DURATION));
+			assert_eq!(ContractRenewals::<Test>::get(contract_id).unwrap().round, 2);
+			for (NftId(collection_id, item_id), _, _) in stakes.clone() {
+				assert_eq!(Nft::owner(collection_id, item_id), Some(NftStake::account_id()));
+			}
+			System::assert_last_event(RuntimeEvent::NftStake(crate::Event::Renewed {
+				by: BOB,
+				contract_id,
+				round: 2,
+			}));
+
+			// The last round can only be claimed.
+			run_to_block(1 + 2 * STAKE_DURATION);
+			assert_noop!(
+				NftStake::renew(RuntimeOrigin::signed(BOB), contract_id),
+				Error::<Test>::NoRoundsLeft
+			);
+			assert_ok!(NftStake::claim(RuntimeOrigin::signed(BOB), contract_id, None));
+			assert_eq!(Balances::free_balance(BOB), initial_balance + 2 * REWARD);
+			assert_eq!(ContractRenewals::<Test>::get(contract_id), None);
+			assert_eq!(NftStake::account_balance(), 0);
+			for (NftId(collection_id, item_id), _, _) in stakes {
+				assert_eq!(Nft::owner(collection_id, item_id), Some(BOB));
+			}
+		});
+	}
+
+	#[test]
+	fn rejects_staking_contracts() {
+		let contract_id = H256::random();
+		let (staker, stakes) = single_stake_for(BOB);
+		build_with(
+			contract_id,
+			bounded_vec![Reward::Tokens(REWARD)],
+			vec![(staker, stakes.clone())],
+		)
+		.execute_with(|| {
+			assert_ok!(NftStake::set_contract_renewal(
+				RuntimeOrigin::signed(ALICE),
+				contract_id,
+				RenewalMode::Restake,
+				2
+			));
+			accept_with_stakes(BOB, contract_id, &stakes);
+			assert_noop!(
+				NftStake::renew(RuntimeOrigin::signed(BOB), contract_id),
+				Error::<Test>::Staking
+			);
+		});
+	}
+
+	#[test]
+	fn rejects_non_restakeable_contracts() {
+		let contract_id = H256::random();
+		let (staker, stakes) = single_stake_for(BOB);
+		build_with(
+			contract_id,
+			bounded_vec![Reward::Tokens(REWARD)],
+			vec![(staker, stakes.clone())],
+		)
+		.execute_with(|| {
+			assert_ok!(NftStake::set_contract_renewal(
+				RuntimeOrigin::signed(ALICE),
+				contract_id,
+				RenewalMode::Relist,
+				2
+			));
+			accept_with_stakes(BOB, contract_id, &stakes);
+			run_to_block(1 + STAKE_DURATION);
+			assert_noop!(
+				NftStake::renew(RuntimeOrigin::signed(BOB), contract_id),
+				Error::<Test>::NotRestakeable
+			);
+		});
+	}
+}
+
+mod relist {
+	use super::*;
+
+	#[test]
+	fn works() {
+		let contract_id = H256::random();
+		let (bob, bob_stakes) = single_stake_for(BOB);
+		let (charlie, charlie_stakes) = single_stake_for(CHARLIE);
+		build_with(
+			contract_id,
+			bounded_vec![Reward::Tokens(REWARD)],
+			vec![(bob, bob_stakes.clone()), (charlie, charlie_stakes.clone())],
+		)
+		.execute_with(|| {
+			assert_ok!(NftStake::set_contract_renewal(
+				RuntimeOrigin::signed(ALICE),
+				contract_id,
+				RenewalMode::Relist,
+				2
+			));
+			accept_with_stakes(BOB, contract_id, &bob_stakes);
+
+			run_to_block(1 + STAKE_DURATION);
+			assert_ok!(NftStake::claim(RuntimeOrigin::signed(BOB), contract_id, None));
+			System::assert_has_event(RuntimeEvent::NftStake(crate::Event::Relisted {
+				contract_id,
+				round: 2,
+			}));
+
+			// The contract is available again with the reward funded from the budget.
+			let contract_collection_id = ContractCollectionId::<Test>::get().unwrap();
+			assert_eq!(
+				Nft::owner(contract_collection_id, contract_id),
+				Some(NftStake::account_id())
+			);
+			assert_eq!(
+				Contracts::<Test>::get(contract_id).unwrap().activation,
+				Some(1 + STAKE_DURATION)
+			);
+			assert_eq!(ContractAccepted::<Test>::get(contract_id), None);
+			assert_eq!(ContractIds::<Test>::get(BOB), None);
+			assert_eq!(NftStake::account_balance(), REWARD);
+
+			accept_with_stakes(CHARLIE, contract_id, &charlie_stakes);
+			run_to_block(1 + 2 * STAKE_DURATION);
+			assert_ok!(NftStake::claim(RuntimeOrigin::signed(CHARLIE), contract_id, None));
+
+			// The contract is removed after its last round.
+			assert_eq!(Nft::owner(contract_collection_id, contract_id), None);
+			assert_eq!(Contracts::<Test>::get(contract_id), None);
+			assert_eq!(ContractRenewals::<Test>::get(contract_id), None);
+			assert_eq!(NftStake::account_balance(), 0);
+		});
+	}
+}
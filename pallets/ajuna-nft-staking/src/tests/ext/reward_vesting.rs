@@ -0,0 +1,295 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+
+const REWARD: MockBalance = 100;
+const STAKE_DURATION: BlockNumberFor<Test> = 5;
+
+fn build_with(
+	contract_id: MockItemId,
+	rewards: BoundedRewardsOf<Test>,
+	stakes: Vec<(MockAccountId, MockMints)>,
+) -> sp_io::TestExternalities {
+	build_with_contract(contract_id, Contract::default().rewards(rewards), stakes)
+}
+
+fn build_with_contract(
+	contract_id: MockItemId,
+	contract: ContractOf<Test>,
+	stakes: Vec<(MockAccountId, MockMints)>,
+) -> sp_io::TestExternalities {
+	let contract = contract.active_duration(100).stake_duration(STAKE_DURATION).claim_duration(100);
+	single_stake_builder(contract_id, contract).mint_stakes(stakes).build()
+}
+
+fn locked_balance(who: MockAccountId) -> MockBalance {
+	pallet_balances::Locks::<Test>::get(who).iter().map(|lock| lock.amount).sum()
+}
+
+const VESTING: RewardVesting<BlockNumberFor<Test>> =
+	RewardVesting { cliff: 2, period: 10, period_count: 3 };
+
+mod set_reward_vesting {
+	use super::*;
+
+	#[test]
+	fn works() {
+		let contract_id = H256::random();
+		build_with(contract_id, bounded_vec![Reward::Tokens(REWARD)], vec![]).execute_with(|| {
+			assert_ok!(NftStake::set_reward_vesting(
+				RuntimeOrigin::signed(ALICE),
+				contract_id,
+				VESTING
+			));
+			assert_eq!(ContractRewardVestings::<Test>::get(contract_id), Some(VESTING));
+			System::assert_last_event(RuntimeEvent::NftStake(crate::Event::RewardVestingSet {
+				contract_id,
+				vesting: VESTING,
+			}));
+		});
+	}
+
+	#[test]
+	fn rejects_non_creator_calls() {
+		let contract_id = H256::random();
+		build_with(contract_id, bounded_vec![Reward::Tokens(REWARD)], vec![]).execute_with(|| {
+			assert_noop!(
+				NftStake::set_reward_vesting(RuntimeOrigin::signed(BOB), contract_id, VESTING),
+				DispatchError::BadOrigin
+			);
+		});
+	}
+
+	#[test]
+	fn rejects_accepted_contracts() {
+		let contract_id = H256::random();
+		let (staker, stakes) = single_stake_for(BOB);
+		build_with(
+			contract_id,
+			bounded_vec![Reward::Tokens(REWARD)],
+			vec![(staker, stakes.clone())],
+		)
+		.execute_with(|| {
+			accept_with_stakes(BOB, contract_id, &stakes);
+			assert_noop!(
+				NftStake::set_reward_vesting(RuntimeOrigin::signed(ALICE), contract_id, VESTING),
+				Error::<Test>::Staking
+			);
+		});
+	}
+
+	#[test]
+	fn rejects_empty_schedules() {
+		let contract_id = H256::random();
+		build_with(contract_id, bounded_vec![Reward::Tokens(REWARD)], vec![]).execute_with(|| {
+			for vesting in [
+				RewardVesting { period: 0, ..VESTING },
+				RewardVesting { period_count: 0, ..VESTING },
+			] {
+				assert_noop!(
+					NftStake::set_reward_vesting(
+						RuntimeOrigin::signed(ALICE),
+						contract_id,
+						vesting
+					),
+					Error::<Test>::InvalidRewardVesting
+				);
+			}
+		});
+	}
+
+	#[test]
+	fn rejects_rewards_too_small_to_vest() {
+		let contract_id = H256::random();
+		build_with(contract_id, bounded_vec![Reward::Tokens(REWARD)], vec![]).execute_with(|| {
+			let vesting = RewardVesting { period_count: REWARD as u32 + 1, ..VESTING };
+			assert_noop!(
+				NftStake::set_reward_vesting(RuntimeOrigin::signed(ALICE), contract_id, vesting),
+				Error::<Test>::RewardVestingUnsupported
+			);
+		});
+	}
+
+	#[test]
+	fn is_cleared_on_removal() {
+		let contract_id = H256::random();
+		build_with(contract_id, bounded_vec![Reward::Tokens(REWARD)], vec![]).execute_with(|| {
+			assert_ok!(NftStake::set_reward_vesting(
+				RuntimeOrigin::signed(ALICE),
+				contract_id,
+				VESTING
+			));
+			run_to_block(102);
+			assert_ok!(NftStake::remove(RuntimeOrigin::signed(ALICE), contract_id));
+			assert_eq!(ContractRewardVestings::<Test>::get(contract_id), None);
+		});
+	}
+}
+
+mod vested_payout {
+	use super::*;
+
+	#[test]
+	fn claim_pays_rewards_under_vesting_schedule() {
+		let contract_id = H256::random();
+		let (staker, stakes) = single_stake_for(BOB);
+		build_with(
+			contract_id,
+			bounded_vec![Reward::Tokens(REWARD)],
+			vec![(staker, stakes.clone())],
+		)
+		.execute_with(|| {
+			assert_ok!(NftStake::set_reward_vesting(
+				RuntimeOrigin::signed(ALICE),
+				contract_id,
+				VESTING
+			));
+			accept_with_stakes(BOB, contract_id, &stakes);
+			let initial_balance = Balances::free_balance(BOB);
+
+			run_to_block(1 + STAKE_DURATION);
+			assert_ok!(NftStake::claim(RuntimeOrigin::signed(BOB), contract_id, None));
+			assert_eq!(Balances::free_balance(BOB), initial_balance + REWARD);
+			assert_eq!(NftStake::account_balance(), 0);
+			assert_eq!(ContractRewardVestings::<Test>::get(contract_id), None);
+
+			// The indivisible remainder is paid out immediately.
+			let per_period = REWARD / 3;
+			assert_eq!(
+				orml_vesting::VestingSchedules::<Test>::get(BOB).into_inner(),
+				vec![orml_vesting::VestingSchedule {
+					start: 1 + STAKE_DURATION + VESTING.cliff,
+					period: VESTING.period,
+					period_count: VESTING.period_count,
+					per_period,
+				}]
+			);
+			assert_eq!(locked_balance(BOB), 3 * per_period);
+
+			run_to_block(1 + STAKE_DURATION + VESTING.cliff + 3 * VESTING.period);
+			assert_ok!(Vesting::claim(RuntimeOrigin::signed(BOB)));
+			assert!(orml_vesting::VestingSchedules::<Test>::get(BOB).is_empty());
+			assert_eq!(locked_balance(BOB), 0);
+		});
+	}
+
+	#[test]
+	fn pays_rewards_directly_without_vesting_schedule() {
+		let contract_id = H256::random();
+		let (staker, stakes) = single_stake_for(BOB);
+		build_with(
+			contract_id,
+			bounded_vec![Reward::Tokens(REWARD)],
+			vec![(staker, stakes.clone())],
+		)
+		.execute_with(|| {
+			accept_with_stakes(BOB, contract_id, &stakes);
+			let initial_balance = Balances::free_balance(BOB);
+
+			run_to_block(1 + STAKE_DURATION);
+			assert_ok!(NftStake::claim(RuntimeOrigin::signed(BOB), contract_id, None));
+			assert_eq!(Balances::free_balance(BOB), initial_balance + REWARD);
+			assert!(orml_vesting::VestingSchedules::<Test>::get(BOB).is_empty());
+			assert_eq!(locked_balance(BOB), 0);
+		});
+	}
+
+	#[test]
+	fn claim_pays_rewards_directly_once_vesting_schedules_are_full() {
+		let contract_id = H256::random();
+		let (staker, stakes) = single_stake_for(BOB);
+		build_with(
+			contract_id,
+			bounded_vec![Reward::Tokens(REWARD)],
+			vec![(staker, stakes.clone())],
+		)
+		.execute_with(|| {
+			assert_ok!(NftStake::set_reward_vesting(
+				RuntimeOrigin::signed(ALICE),
+				contract_id,
+				VESTING
+			));
+			accept_with_stakes(BOB, contract_id, &stakes);
+			let initial_balance = Balances::free_balance(BOB);
+
+			let schedule = orml_vesting::VestingSchedule {
+				start: 0,
+				period: 1,
+				period_count: 1,
+				per_period: 1,
+			};
+			let max_schedules =
+				<<Test as orml_vesting::Config>::MaxVestingSchedules as Get<u32>>::get();
+			let schedules = vec![schedule; max_schedules as usize];
+			orml_vesting::VestingSchedules::<Test>::insert(
+				BOB,
+				BoundedVec::try_from(schedules).unwrap(),
+			);
+
+			run_to_block(1 + STAKE_DURATION);
+			assert_ok!(NftStake::claim(RuntimeOrigin::signed(BOB), contract_id, None));
+			assert_eq!(Balances::free_balance(BOB), initial_balance + REWARD);
+			assert_eq!(locked_balance(BOB), 0);
+			assert_eq!(
+				orml_vesting::VestingSchedules::<Test>::decode_len(BOB),
+				Some(max_schedules as usize)
+			);
+			assert_eq!(NftStake::account_balance(), 0);
+		});
+	}
+
+	#[test]
+	fn early_unstake_pays_vested_share_under_vesting_schedule() {
+		let contract_id = H256::random();
+		let (staker, stakes) = single_stake_for(BOB);
+		let contract = Contract::default()
+			.rewards(bounded_vec![Reward::Tokens(REWARD)])
+			.early_unstake(EarlyUnstake {
+				cliff: 0,
+				curve: VestingCurve::Linear,
+				penalty: Perbill::from_percent(10),
+				burn_penalty: false,
+			});
+		build_with_contract(contract_id, contract, vec![(staker, stakes.clone())]).execute_with(
+			|| {
+				assert_ok!(NftStake::set_reward_vesting(
+					RuntimeOrigin::signed(ALICE),
+					contract_id,
+					VESTING
+				));
+				accept_with_stakes(BOB, contract_id, &stakes);
+				let initial_balance = Balances::free_balance(BOB);
+
+				// 80% of the reward has vested, of which 10% is withheld as penalty.
+				run_to_block(5);
+				assert_ok!(NftStake::unstake_early(RuntimeOrigin::signed(BOB), contract_id));
+				assert_eq!(Balances::free_balance(BOB), initial_balance + 72);
+				assert_eq!(locked_balance(BOB), 72);
+				assert_eq!(
+					orml_vesting::VestingSchedules::<Test>::get(BOB).into_inner(),
+					vec![orml_vesting::VestingSchedule {
+						start: 5 + VESTING.cliff,
+						period: VESTING.period,
+						period_count: VESTING.period_count,
+						per_period: 24,
+					}]
+				);
+				assert_eq!(NftStake::account_balance(), 0);
+			},
+		);
+	}
+}
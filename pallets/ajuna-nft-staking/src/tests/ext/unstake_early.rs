@@ -0,0 +1,181 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+
+const REWARD: MockBalance = 1_000;
+
+fn early_unstake(curve: VestingCurve, burn_penalty: bool) -> EarlyUnstake<BlockNumberFor<Test>> {
+	EarlyUnstake { cliff: 2, curve, penalty: Perbill::from_percent(10), burn_penalty }
+}
+
+fn build_with(contract: ContractOf<Test>, contract_id: MockItemId) -> sp_io::TestExternalities {
+	single_stake_builder(contract_id, contract.stake_duration(10))
+		.accept_contract(vec![single_stake_for(BOB)], vec![], contract_id, BOB)
+		.build()
+}
+
+#[test]
+fn works() {
+	let reward_addr = NftId(RESERVED_COLLECTION_2, H256::random());
+	let contract = Contract::default()
+		.rewards(bounded_vec![Reward::Tokens(REWARD), Reward::Nft(reward_addr.clone())])
+		.early_unstake(early_unstake(VestingCurve::Linear, false));
+	let contract_id = H256::random();
+
+	build_with(contract, contract_id).execute_with(|| {
+		let stake_addresses = ContractStakedItems::<Test>::get(contract_id).unwrap();
+		let (initial_balance_alice, initial_balance_bob) =
+			(Balances::free_balance(ALICE), Balances::free_balance(BOB));
+
+		// Half of the stake duration has passed, so half of the reward has vested. The creator
+		// also gets the deposit of the burned contract NFT back.
+		run_to_block(6);
+		assert_ok!(NftStake::unstake_early(RuntimeOrigin::signed(BOB), contract_id));
+
+		assert_eq!(Balances::free_balance(BOB), initial_balance_bob + 450);
+		assert_eq!(Balances::free_balance(ALICE), initial_balance_alice + 550 + ItemDeposit::get());
+		assert_eq!(NftStake::account_balance(), 0);
+		assert_eq!(Nft::owner(reward_addr.0, reward_addr.1), Some(ALICE));
+		for NftId(collection_id, item_id) in stake_addresses {
+			assert_eq!(Nft::owner(collection_id, item_id), Some(BOB));
+		}
+
+		let contract_collection_id = ContractCollectionId::<Test>::get().unwrap();
+		assert_eq!(Nft::owner(contract_collection_id, contract_id), None);
+		assert_eq!(Contracts::<Test>::get(contract_id), None);
+		assert_eq!(ContractAccepted::<Test>::get(contract_id), None);
+		assert_eq!(ContractStakedItems::<Test>::get(contract_id), None);
+		assert_eq!(ContractIds::<Test>::get(BOB), None);
+
+		System::assert_last_event(RuntimeEvent::NftStake(crate::Event::UnstakedEarly {
+			by: BOB,
+			contract_id,
+			reward: 450,
+			penalty: 50,
+		}));
+	});
+}
+
+#[test]
+fn burns_penalty() {
+	let contract = Contract::default()
+		.rewards(bounded_vec![Reward::Tokens(REWARD)])
+		.early_unstake(early_unstake(VestingCurve::Quadratic, true));
+	let contract_id = H256::random();
+
+	build_with(contract, contract_id).execute_with(|| {
+		let (initial_balance_alice, initial_balance_bob) =
+			(Balances::free_balance(ALICE), Balances::free_balance(BOB));
+		let initial_issuance = Balances::total_issuance();
+
+		// Half of the stake duration has passed, so a quarter of the reward has vested.
+		run_to_block(6);
+		assert_ok!(NftStake::unstake_early(RuntimeOrigin::signed(BOB), contract_id));
+
+		assert_eq!(Balances::free_balance(BOB), initial_balance_bob + 225);
+		assert_eq!(Balances::free_balance(ALICE), initial_balance_alice + 750 + ItemDeposit::get());
+		assert_eq!(Balances::total_issuance(), initial_issuance - 25);
+		assert_eq!(NftStake::account_balance(), 0);
+	});
+}
+
+#[test]
+fn pays_nothing_before_cliff() {
+	let contract = Contract::default()
+		.rewards(bounded_vec![Reward::Tokens(REWARD)])
+		.early_unstake(early_unstake(VestingCurve::Linear, false));
+	let contract_id = H256::random();
+
+	build_with(contract, contract_id).execute_with(|| {
+		let (initial_balance_alice, initial_balance_bob) =
+			(Balances::free_balance(ALICE), Balances::free_balance(BOB));
+
+		run_to_block(2);
+		assert_ok!(NftStake::unstake_early(RuntimeOrigin::signed(BOB), contract_id));
+
+		assert_eq!(Balances::free_balance(BOB), initial_balance_bob);
+		assert_eq!(
+			Balances::free_balance(ALICE),
+			initial_balance_alice + REWARD + ItemDeposit::get()
+		);
+	});
+}
+
+#[test]
+fn rejects_unsigned_calls() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			NftStake::unstake_early(RuntimeOrigin::none(), Default::default()),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn rejects_when_pallet_is_locked() {
+	ExtBuilder::default().build().execute_with(|| {
+		GlobalConfigs::<Test>::mutate(|config| config.pallet_locked = true);
+		assert_noop!(
+			NftStake::unstake_early(RuntimeOrigin::signed(BOB), Default::default()),
+			Error::<Test>::PalletLocked
+		);
+	});
+}
+
+#[test]
+fn rejects_when_contract_is_not_owned() {
+	let contract = Contract::default()
+		.rewards(bounded_vec![Reward::Tokens(REWARD)])
+		.early_unstake(early_unstake(VestingCurve::Linear, false));
+	let contract_id = H256::random();
+
+	build_with(contract, contract_id).execute_with(|| {
+		assert_noop!(
+			NftStake::unstake_early(RuntimeOrigin::signed(CHARLIE), contract_id),
+			Error::<Test>::ContractOwnership
+		);
+	});
+}
+
+#[test]
+fn rejects_contracts_without_early_unstake() {
+	let contract = Contract::default().rewards(bounded_vec![Reward::Tokens(REWARD)]);
+	let contract_id = H256::random();
+
+	build_with(contract, contract_id).execute_with(|| {
+		assert_noop!(
+			NftStake::unstake_early(RuntimeOrigin::signed(BOB), contract_id),
+			Error::<Test>::EarlyUnstakeDisabled
+		);
+	});
+}
+
+#[test]
+fn rejects_claimable_contracts() {
+	let contract = Contract::default()
+		.rewards(bounded_vec![Reward::Tokens(REWARD)])
+		.early_unstake(early_unstake(VestingCurve::Linear, false));
+	let contract_id = H256::random();
+
+	build_with(contract, contract_id).execute_with(|| {
+		run_to_block(11);
+		assert_noop!(
+			NftStake::unstake_early(RuntimeOrigin::signed(BOB), contract_id),
+			Error::<Test>::Claimable
+		);
+	});
+}
@@ -52,6 +52,7 @@ frame_support::construct_runtime!(
 		System: frame_system,
 		Balances: pallet_balances,
 		Nft: pallet_nfts,
+		Vesting: orml_vesting,
 		NftStake: pallet_nft_staking,
 	}
 );
@@ -198,10 +199,21 @@ impl pallet_nfts::Config for Test {
 	type WeightInfo = ();
 }
 
+impl orml_vesting::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type MinVestedTransfer = MockExistentialDeposit;
+	type VestedTransferOrigin = EnsureSigned<MockAccountId>;
+	type WeightInfo = ();
+	type MaxVestingSchedules = ConstU32<100>;
+	type BlockNumberProvider = System;
+}
+
 parameter_types! {
 	pub const NftStakingPalletId: PalletId = PalletId(*b"aj/nftst");
 	pub const MaxContracts: u32 = 5;
 	pub const MaxStakingClauses: u32 = 10;
+	pub const MaxPoolStakes: u32 = 5;
 	pub const MaxFeeClauses: u32 = 1;
 	pub const MaxMetadataLenght: u32 = 100;
 	pub const AttributeMaxBytes: u32 = 32;
@@ -218,8 +230,10 @@ impl pallet_nft_staking::Config for Test {
 	type ItemId = MockItemId;
 	type ItemConfig = pallet_nfts::ItemConfig;
 	type NftHelper = Nft;
+	type RewardVesting = OrmlVesting<Test>;
 	type MaxContracts = MaxContracts;
 	type MaxStakingClauses = MaxStakingClauses;
+	type MaxPoolStakes = MaxPoolStakes;
 	type MaxFeeClauses = MaxFeeClauses;
 	type MaxMetadataLength = MaxMetadataLenght;
 	type KeyLimit = KeyLimit;
@@ -246,6 +260,7 @@ impl Default for ContractOf<Test> {
 			burn_fees: Default::default(),
 			rewards: Default::default(),
 			cancel_fee: Default::default(),
+			early_unstake: Default::default(),
 			nft_stake_amount: 1,
 			nft_fee_amount: 1,
 			is_snipeable: true,
@@ -303,6 +318,10 @@ impl ContractOf<Test> {
 		self.cancel_fee = cancel_fee;
 		self
 	}
+	pub fn early_unstake(mut self, early_unstake: EarlyUnstake<BlockNumberFor<Test>>) -> Self {
+		self.early_unstake = Some(early_unstake);
+		self
+	}
 	pub fn stake_amt(mut self, stake_amount: u8) -> Self {
 		self.nft_stake_amount = stake_amount;
 		self
@@ -357,11 +376,52 @@ impl From<MockClauses> for MockMints {
 					attrs.clone().pop().unwrap().0,
 					attrs.pop().unwrap().1,
 				),
+				MockClause::HasNumericAttribute(collection_id, key, encoding, comparison) => (
+					NftId(collection_id, H256::random()),
+					key,
+					AttributeValue::Equal(numeric_value(encoding, comparison)),
+				),
+				// Only the leading operand is minted, which fulfils expressions holding whenever
+				// it does, such as disjunctions.
+				MockClause::Expression(collection_ids, ops) => {
+					let address = NftId(collection_ids[0], H256::random());
+					match ops.into_inner().remove(0) {
+						ClauseOp::HasAttribute(key) =>
+							(address, key, AttributeValue::Equal(bounded_vec![i as u8])),
+						ClauseOp::HasAttributeWithValue(key, value) => (address, key, value),
+						ClauseOp::HasNumericAttribute(key, encoding, comparison) => (
+							address,
+							key,
+							AttributeValue::Equal(numeric_value(encoding, comparison)),
+						),
+						ClauseOp::And | ClauseOp::Or | ClauseOp::Not =>
+							unreachable!("expressions start with an operand"),
+					}
+				},
 			})
 			.collect()
 	}
 }
 
+/// Encodes a value fulfilling the given numeric comparison.
+pub fn numeric_value(
+	encoding: NumericEncoding,
+	comparison: NumericComparison,
+) -> Attribute<ValueLimit> {
+	let value = match comparison {
+		NumericComparison::Equal(value) |
+		NumericComparison::GreaterOrEqual(value) |
+		NumericComparison::LowerOrEqual(value) => value,
+		NumericComparison::Greater(value) => value + 1,
+		NumericComparison::Lower(value) => value - 1,
+	};
+	let bytes = match encoding {
+		NumericEncoding::Decimal => value.to_string().into_bytes(),
+		NumericEncoding::LittleEndian => value.to_le_bytes().to_vec(),
+	};
+	bytes.try_into().unwrap()
+}
+
 pub struct ExtBuilder {
 	creator: Option<MockAccountId>,
 	balances: Vec<(MockAccountId, MockBalance)>,
@@ -441,6 +501,7 @@ impl ExtBuilder {
 		let config = RuntimeGenesisConfig {
 			system: Default::default(),
 			balances: BalancesConfig { balances: self.balances },
+			vesting: Default::default(),
 		};
 
 		let mut ext: sp_io::TestExternalities = config.build_storage().unwrap().into();
@@ -553,7 +614,7 @@ pub fn mint_item(
 	NftId(*collection_id, *item_id)
 }
 
-fn set_attribute(
+pub fn set_attribute(
 	collection_id: &MockCollectionId,
 	item_id: &MockItemId,
 	key: &Attribute<KeyLimit>,
@@ -579,3 +640,38 @@ pub fn run_to_block(n: u64) {
 		NftStake::on_initialize(System::block_number());
 	}
 }
+
+fn single_stake_clause() -> MockClause {
+	Clause::HasAttribute(RESERVED_COLLECTION_0, bounded_vec![12])
+}
+
+/// Returns the stake NFTs to mint for `staker` to fulfil a contract set up by
+/// `single_stake_builder`.
+pub fn single_stake_for(staker: MockAccountId) -> (MockAccountId, MockMints) {
+	(staker, MockMints::from(MockClauses(vec![single_stake_clause()])))
+}
+
+/// Returns a builder in which ALICE creates `contract`, requiring a single stake NFT as minted
+/// by `single_stake_for` and no fees.
+pub fn single_stake_builder(contract_id: MockItemId, contract: ContractOf<Test>) -> ExtBuilder {
+	let contract = contract
+		.stake_amt(1)
+		.stake_clauses(AttributeNamespace::Pallet, vec![(0, single_stake_clause())])
+		.fee_amt(0);
+
+	ExtBuilder::default()
+		.set_creator(ALICE)
+		.create_contract_collection()
+		.create_contract(contract_id, contract)
+}
+
+pub fn accept_with_stakes(staker: MockAccountId, contract_id: MockItemId, stakes: &MockMints) {
+	let stake_addresses = stakes.iter().map(|(address, _, _)| address.clone()).collect();
+	NftStake::accept(
+		RuntimeOrigin::signed(staker),
+		contract_id,
+		stake_addresses,
+		Default::default(),
+	)
+	.unwrap();
+}
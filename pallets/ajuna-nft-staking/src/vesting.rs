@@ -0,0 +1,123 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use frame_support::{pallet_prelude::*, sp_runtime::traits::StaticLookup};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::traits::{BlockNumberProvider, Saturating, Zero};
+use sp_std::marker::PhantomData;
+
+type OrmlBalanceOf<T> =
+	<<T as orml_vesting::Config>::Currency as frame_support::traits::Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::Balance;
+
+/// Schedule along which the token rewards of a contract are released to its beneficiary.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct RewardVesting<BlockNumber> {
+	/// The number of blocks after the payout before the first period starts.
+	pub cliff: BlockNumber,
+	/// The number of blocks of each period.
+	pub period: BlockNumber,
+	/// The number of periods over which the rewards are released evenly.
+	pub period_count: u32,
+}
+
+/// Transfers funds locked under a vesting schedule.
+pub trait VestedTransfer<AccountId, Balance, BlockNumber> {
+	/// The maximum number of schedules an account can be vested under, zero if vested transfers
+	/// are not supported.
+	fn max_schedules() -> u32;
+
+	/// Whether `to` has room for another vesting schedule.
+	fn has_free_schedule(to: &AccountId) -> bool;
+
+	/// The minimum amount a single vested transfer must move.
+	fn min_vested_transfer() -> Balance;
+
+	/// Transfers `per_period * period_count` from `from` to `to`, releasing `per_period` at the
+	/// end of each `period`, starting after `cliff` blocks.
+	fn vested_transfer(
+		from: &AccountId,
+		to: &AccountId,
+		cliff: BlockNumber,
+		period: BlockNumber,
+		period_count: u32,
+		per_period: Balance,
+	) -> DispatchResult;
+}
+
+impl<AccountId, Balance: Zero, BlockNumber> VestedTransfer<AccountId, Balance, BlockNumber> for () {
+	fn max_schedules() -> u32 {
+		0
+	}
+
+	fn has_free_schedule(_to: &AccountId) -> bool {
+		false
+	}
+
+	fn min_vested_transfer() -> Balance {
+		Zero::zero()
+	}
+
+	fn vested_transfer(
+		_from: &AccountId,
+		_to: &AccountId,
+		_cliff: BlockNumber,
+		_period: BlockNumber,
+		_period_count: u32,
+		_per_period: Balance,
+	) -> DispatchResult {
+		Err(DispatchError::Other("Vested transfers are not supported"))
+	}
+}
+
+/// Vested transfers backed by `orml-vesting`. The runtime's `VestedTransferOrigin` must accept
+/// signed origins from the pallet's account.
+pub struct OrmlVesting<T>(PhantomData<T>);
+
+impl<T: orml_vesting::Config> VestedTransfer<T::AccountId, OrmlBalanceOf<T>, BlockNumberFor<T>>
+	for OrmlVesting<T>
+{
+	fn max_schedules() -> u32 {
+		T::MaxVestingSchedules::get()
+	}
+
+	fn has_free_schedule(to: &T::AccountId) -> bool {
+		let schedules = orml_vesting::VestingSchedules::<T>::decode_len(to).unwrap_or_default();
+		schedules < T::MaxVestingSchedules::get() as usize
+	}
+
+	fn min_vested_transfer() -> OrmlBalanceOf<T> {
+		T::MinVestedTransfer::get()
+	}
+
+	fn vested_transfer(
+		from: &T::AccountId,
+		to: &T::AccountId,
+		cliff: BlockNumberFor<T>,
+		period: BlockNumberFor<T>,
+		period_count: u32,
+		per_period: OrmlBalanceOf<T>,
+	) -> DispatchResult {
+		let start = T::BlockNumberProvider::current_block_number().saturating_add(cliff);
+		let schedule = orml_vesting::VestingSchedule { start, period, period_count, per_period };
+		orml_vesting::Pallet::<T>::vested_transfer(
+			frame_system::RawOrigin::Signed(from.clone()).into(),
+			T::Lookup::unlookup(to.clone()),
+			schedule,
+		)
+	}
+}
@@ -14,12 +14,14 @@
 // You should have received a copy of the GNU Affero General Public License
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
-//! Autogenerated weights for pallet_ajuna_nft_staking
+//! Weights for pallet_ajuna_nft_staking
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2023-03-22, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! HOSTNAME: `weight-calculation-didac`, CPU: `DO-Regular`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//! The weights up to `snipe_nft_reward` were generated by the benchmark CLI on 2023-03-22, with
+//! the command below. The weights from `apply_as_creator` on are not: their benchmarks have never
+//! been run, since this workspace doesn't build without the node. Their execution times are
+//! estimates, their reads and writes are counted from the code and their proof sizes assume
+//! 32-byte ids, u128 balances and the attribute limits of the benchmark mock. Regenerate this
+//! file before using these calls in a runtime.
 
 // Executed Command:
 // ./target/release/bajun-para
@@ -60,6 +62,19 @@ pub trait WeightInfo {
 	fn cancel_nft_reward() -> Weight;
 	fn snipe_token_reward() -> Weight;
 	fn snipe_nft_reward() -> Weight;
+	fn apply_as_creator() -> Weight;
+	fn register_creator() -> Weight;
+	fn deregister_creator() -> Weight;
+	fn create_pool() -> Weight;
+	fn join_pool(n: u32) -> Weight;
+	fn leave_pool(n: u32) -> Weight;
+	fn claim_pool_rewards() -> Weight;
+	fn close_pool() -> Weight;
+	fn evaluate_clauses(n: u32) -> Weight;
+	fn unstake_early() -> Weight;
+	fn set_contract_renewal() -> Weight;
+	fn renew() -> Weight;
+	fn set_reward_vesting() -> Weight;
 }
 
 /// Weights for pallet_ajuna_nft_staking using the Substrate node and recommended hardware.
@@ -214,6 +229,182 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 	fn snipe_nft_reward() -> Weight {
 		Default::default()
 	}
+	// Storage: NftStake Creators (r:1 w:0)
+	// Storage: NftStake CreatorApplications (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn apply_as_creator() -> Weight {
+		Weight::from_parts(47_820_000, 8647)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: NftStake Creators (r:1 w:1)
+	// Storage: NftStake CreatorApplications (r:1 w:1)
+	fn register_creator() -> Weight {
+		Weight::from_parts(21_460_000, 6044)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: NftStake CreatorApplications (r:1 w:1)
+	// Storage: NftStake Creators (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn deregister_creator() -> Weight {
+		Weight::from_parts(44_905_000, 8647)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: NftStake LockedState (r:1 w:0)
+	// Storage: NftStake Creator (r:1 w:0)
+	// Storage: NftStake Creators (r:1 w:1)
+	// Storage: NftStake Pools (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: NftStake CreatorsStats (r:1 w:1)
+	// Storage: NftStake PoolStates (r:0 w:1)
+	// Storage: NftStake PoolCreators (r:0 w:1)
+	fn create_pool() -> Weight {
+		Weight::from_parts(78_940_000, 180903)
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
+	}
+	// Storage: NftStake LockedState (r:1 w:0)
+	// Storage: NftStake Pools (r:1 w:0)
+	// Storage: Nft Item (r:5 w:5)
+	// Storage: Nft Collection (r:1 w:0)
+	// Storage: Nft CollectionConfigOf (r:1 w:0)
+	// Storage: Nft ItemConfigOf (r:5 w:0)
+	// Storage: NftStake PoolStates (r:1 w:1)
+	// Storage: NftStake PoolStakes (r:1 w:1)
+	// Storage: NftStake PoolStakedItems (r:1 w:1)
+	// Storage: Nft Account (r:0 w:10)
+	// Storage: Nft ItemPriceOf (r:0 w:5)
+	// Storage: Nft PendingSwapOf (r:0 w:5)
+	fn join_pool(n: u32) -> Weight {
+		Weight::from_parts(62_300_000, 183069)
+			.saturating_add(Weight::from_parts(31_270_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+			.saturating_add(T::DbWeight::get().writes((5 as u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5212).saturating_mul(n.into()))
+	}
+	// Storage: NftStake LockedState (r:1 w:0)
+	// Storage: NftStake Pools (r:1 w:0)
+	// Storage: NftStake PoolStates (r:1 w:1)
+	// Storage: NftStake PoolStakes (r:1 w:1)
+	// Storage: NftStake PoolStakedItems (r:1 w:1)
+	// Storage: Nft Collection (r:1 w:0)
+	// Storage: Nft CollectionConfigOf (r:1 w:0)
+	// Storage: Nft ItemConfigOf (r:5 w:0)
+	// Storage: Nft Item (r:5 w:5)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Nft Account (r:0 w:10)
+	// Storage: Nft ItemPriceOf (r:0 w:5)
+	// Storage: Nft PendingSwapOf (r:0 w:5)
+	fn leave_pool(n: u32) -> Weight {
+		Weight::from_parts(42_390_000, 188275)
+			.saturating_add(Weight::from_parts(31_270_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(9 as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+			.saturating_add(T::DbWeight::get().writes((5 as u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5212).saturating_mul(n.into()))
+	}
+	// Storage: NftStake LockedState (r:1 w:0)
+	// Storage: NftStake Pools (r:1 w:0)
+	// Storage: NftStake PoolStates (r:1 w:1)
+	// Storage: NftStake PoolStakes (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn claim_pool_rewards() -> Weight {
+		Weight::from_parts(64_150_000, 180448)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// Storage: NftStake LockedState (r:1 w:0)
+	// Storage: NftStake Creator (r:1 w:0)
+	// Storage: NftStake PoolCreators (r:1 w:1)
+	// Storage: NftStake Pools (r:1 w:1)
+	// Storage: NftStake PoolStates (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: NftStake Creators (r:1 w:1)
+	// Storage: NftStake PoolStakes (r:1 w:0)
+	fn close_pool() -> Weight {
+		Weight::from_parts(71_620_000, 186045)
+			.saturating_add(T::DbWeight::get().reads(9 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
+	// Storage: Nft Attribute (r:8 w:0)
+	fn evaluate_clauses(n: u32) -> Weight {
+		Weight::from_parts(2_184_000, 990)
+			.saturating_add(Weight::from_parts(12_950_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2757).saturating_mul(n.into()))
+	}
+	// Storage: NftStake LockedState (r:1 w:0)
+	// Storage: NftStake ContractCollectionId (r:1 w:0)
+	// Storage: Nft Item (r:11 w:11)
+	// Storage: NftStake Contracts (r:1 w:1)
+	// Storage: NftStake ContractAccepted (r:1 w:1)
+	// Storage: NftStake ContractCreators (r:1 w:1)
+	// Storage: NftStake Creators (r:1 w:1)
+	// Storage: System Account (r:3 w:3)
+	// Storage: NftStake ContractRewardVestings (r:1 w:1)
+	// Storage: NftStake ContractIds (r:1 w:1)
+	// Storage: NftStake ContractStakedItems (r:1 w:1)
+	// Storage: NftStake ContractRenewals (r:1 w:1)
+	// Storage: NftStake ContractsAttributes (r:1 w:1)
+	// Storage: Nft Collection (r:2 w:1)
+	// Storage: Nft CollectionConfigOf (r:2 w:0)
+	// Storage: Nft ItemConfigOf (r:11 w:1)
+	// Storage: Vesting VestingSchedules (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: NftStake ContractsMetadata (r:0 w:1)
+	// Storage: Nft Account (r:0 w:21)
+	// Storage: Nft ItemPriceOf (r:0 w:11)
+	// Storage: Nft ItemAttributesApprovalsOf (r:0 w:1)
+	// Storage: Nft PendingSwapOf (r:0 w:11)
+	fn unstake_early() -> Weight {
+		Weight::from_parts(634_910_000, 445479)
+			.saturating_add(T::DbWeight::get().reads(42 as u64))
+			.saturating_add(T::DbWeight::get().writes(72 as u64))
+	}
+	// Storage: NftStake LockedState (r:1 w:0)
+	// Storage: NftStake ContractCreators (r:1 w:0)
+	// Storage: NftStake ContractCollectionId (r:1 w:0)
+	// Storage: Nft Item (r:1 w:0)
+	// Storage: NftStake ContractRenewals (r:1 w:1)
+	// Storage: NftStake Contracts (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	fn set_contract_renewal() -> Weight {
+		Weight::from_parts(66_830_000, 349868)
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: NftStake LockedState (r:1 w:0)
+	// Storage: NftStake ContractCollectionId (r:1 w:0)
+	// Storage: Nft Item (r:1 w:0)
+	// Storage: NftStake Contracts (r:1 w:0)
+	// Storage: NftStake ContractAccepted (r:1 w:1)
+	// Storage: NftStake ContractRenewals (r:1 w:1)
+	// Storage: NftStake ContractRewardVestings (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: NftStake ContractsStats (r:1 w:1)
+	// Storage: Vesting VestingSchedules (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	fn renew() -> Weight {
+		Weight::from_parts(118_260_000, 364033)
+			.saturating_add(T::DbWeight::get().reads(12 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
+	}
+	// Storage: NftStake LockedState (r:1 w:0)
+	// Storage: NftStake ContractCreators (r:1 w:0)
+	// Storage: NftStake ContractCollectionId (r:1 w:0)
+	// Storage: Nft Item (r:1 w:0)
+	// Storage: NftStake Contracts (r:1 w:0)
+	// Storage: NftStake ContractRewardVestings (r:0 w:1)
+	fn set_reward_vesting() -> Weight {
+		Weight::from_parts(35_940_000, 342130)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -367,4 +558,180 @@ impl WeightInfo for () {
 	fn snipe_nft_reward() -> Weight {
 		Default::default()
 	}
+	// Storage: NftStake Creators (r:1 w:0)
+	// Storage: NftStake CreatorApplications (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn apply_as_creator() -> Weight {
+		Weight::from_parts(47_820_000, 8647)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: NftStake Creators (r:1 w:1)
+	// Storage: NftStake CreatorApplications (r:1 w:1)
+	fn register_creator() -> Weight {
+		Weight::from_parts(21_460_000, 6044)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: NftStake CreatorApplications (r:1 w:1)
+	// Storage: NftStake Creators (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn deregister_creator() -> Weight {
+		Weight::from_parts(44_905_000, 8647)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: NftStake LockedState (r:1 w:0)
+	// Storage: NftStake Creator (r:1 w:0)
+	// Storage: NftStake Creators (r:1 w:1)
+	// Storage: NftStake Pools (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: NftStake CreatorsStats (r:1 w:1)
+	// Storage: NftStake PoolStates (r:0 w:1)
+	// Storage: NftStake PoolCreators (r:0 w:1)
+	fn create_pool() -> Weight {
+		Weight::from_parts(78_940_000, 180903)
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
+			.saturating_add(RocksDbWeight::get().writes(7 as u64))
+	}
+	// Storage: NftStake LockedState (r:1 w:0)
+	// Storage: NftStake Pools (r:1 w:0)
+	// Storage: Nft Item (r:5 w:5)
+	// Storage: Nft Collection (r:1 w:0)
+	// Storage: Nft CollectionConfigOf (r:1 w:0)
+	// Storage: Nft ItemConfigOf (r:5 w:0)
+	// Storage: NftStake PoolStates (r:1 w:1)
+	// Storage: NftStake PoolStakes (r:1 w:1)
+	// Storage: NftStake PoolStakedItems (r:1 w:1)
+	// Storage: Nft Account (r:0 w:10)
+	// Storage: Nft ItemPriceOf (r:0 w:5)
+	// Storage: Nft PendingSwapOf (r:0 w:5)
+	fn join_pool(n: u32) -> Weight {
+		Weight::from_parts(62_300_000, 183069)
+			.saturating_add(Weight::from_parts(31_270_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes((5 as u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5212).saturating_mul(n.into()))
+	}
+	// Storage: NftStake LockedState (r:1 w:0)
+	// Storage: NftStake Pools (r:1 w:0)
+	// Storage: NftStake PoolStates (r:1 w:1)
+	// Storage: NftStake PoolStakes (r:1 w:1)
+	// Storage: NftStake PoolStakedItems (r:1 w:1)
+	// Storage: Nft Collection (r:1 w:0)
+	// Storage: Nft CollectionConfigOf (r:1 w:0)
+	// Storage: Nft ItemConfigOf (r:5 w:0)
+	// Storage: Nft Item (r:5 w:5)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Nft Account (r:0 w:10)
+	// Storage: Nft ItemPriceOf (r:0 w:5)
+	// Storage: Nft PendingSwapOf (r:0 w:5)
+	fn leave_pool(n: u32) -> Weight {
+		Weight::from_parts(42_390_000, 188275)
+			.saturating_add(Weight::from_parts(31_270_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(9 as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes((5 as u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5212).saturating_mul(n.into()))
+	}
+	// Storage: NftStake LockedState (r:1 w:0)
+	// Storage: NftStake Pools (r:1 w:0)
+	// Storage: NftStake PoolStates (r:1 w:1)
+	// Storage: NftStake PoolStakes (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn claim_pool_rewards() -> Weight {
+		Weight::from_parts(64_150_000, 180448)
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
+	// Storage: NftStake LockedState (r:1 w:0)
+	// Storage: NftStake Creator (r:1 w:0)
+	// Storage: NftStake PoolCreators (r:1 w:1)
+	// Storage: NftStake Pools (r:1 w:1)
+	// Storage: NftStake PoolStates (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: NftStake Creators (r:1 w:1)
+	// Storage: NftStake PoolStakes (r:1 w:0)
+	fn close_pool() -> Weight {
+		Weight::from_parts(71_620_000, 186045)
+			.saturating_add(RocksDbWeight::get().reads(9 as u64))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+	}
+	// Storage: Nft Attribute (r:8 w:0)
+	fn evaluate_clauses(n: u32) -> Weight {
+		Weight::from_parts(2_184_000, 990)
+			.saturating_add(Weight::from_parts(12_950_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2757).saturating_mul(n.into()))
+	}
+	// Storage: NftStake LockedState (r:1 w:0)
+	// Storage: NftStake ContractCollectionId (r:1 w:0)
+	// Storage: Nft Item (r:11 w:11)
+	// Storage: NftStake Contracts (r:1 w:1)
+	// Storage: NftStake ContractAccepted (r:1 w:1)
+	// Storage: NftStake ContractCreators (r:1 w:1)
+	// Storage: NftStake Creators (r:1 w:1)
+	// Storage: System Account (r:3 w:3)
+	// Storage: NftStake ContractRewardVestings (r:1 w:1)
+	// Storage: NftStake ContractIds (r:1 w:1)
+	// Storage: NftStake ContractStakedItems (r:1 w:1)
+	// Storage: NftStake ContractRenewals (r:1 w:1)
+	// Storage: NftStake ContractsAttributes (r:1 w:1)
+	// Storage: Nft Collection (r:2 w:1)
+	// Storage: Nft CollectionConfigOf (r:2 w:0)
+	// Storage: Nft ItemConfigOf (r:11 w:1)
+	// Storage: Vesting VestingSchedules (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: NftStake ContractsMetadata (r:0 w:1)
+	// Storage: Nft Account (r:0 w:21)
+	// Storage: Nft ItemPriceOf (r:0 w:11)
+	// Storage: Nft ItemAttributesApprovalsOf (r:0 w:1)
+	// Storage: Nft PendingSwapOf (r:0 w:11)
+	fn unstake_early() -> Weight {
+		Weight::from_parts(634_910_000, 445479)
+			.saturating_add(RocksDbWeight::get().reads(42 as u64))
+			.saturating_add(RocksDbWeight::get().writes(72 as u64))
+	}
+	// Storage: NftStake LockedState (r:1 w:0)
+	// Storage: NftStake ContractCreators (r:1 w:0)
+	// Storage: NftStake ContractCollectionId (r:1 w:0)
+	// Storage: Nft Item (r:1 w:0)
+	// Storage: NftStake ContractRenewals (r:1 w:1)
+	// Storage: NftStake Contracts (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	fn set_contract_renewal() -> Weight {
+		Weight::from_parts(66_830_000, 349868)
+			.saturating_add(RocksDbWeight::get().reads(8 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: NftStake LockedState (r:1 w:0)
+	// Storage: NftStake ContractCollectionId (r:1 w:0)
+	// Storage: Nft Item (r:1 w:0)
+	// Storage: NftStake Contracts (r:1 w:0)
+	// Storage: NftStake ContractAccepted (r:1 w:1)
+	// Storage: NftStake ContractRenewals (r:1 w:1)
+	// Storage: NftStake ContractRewardVestings (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: NftStake ContractsStats (r:1 w:1)
+	// Storage: Vesting VestingSchedules (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	fn renew() -> Weight {
+		Weight::from_parts(118_260_000, 364033)
+			.saturating_add(RocksDbWeight::get().reads(12 as u64))
+			.saturating_add(RocksDbWeight::get().writes(7 as u64))
+	}
+	// Storage: NftStake LockedState (r:1 w:0)
+	// Storage: NftStake ContractCreators (r:1 w:0)
+	// Storage: NftStake ContractCollectionId (r:1 w:0)
+	// Storage: Nft Item (r:1 w:0)
+	// Storage: NftStake Contracts (r:1 w:0)
+	// Storage: NftStake ContractRewardVestings (r:0 w:1)
+	fn set_reward_vesting() -> Weight {
+		Weight::from_parts(35_940_000, 342130)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
 }
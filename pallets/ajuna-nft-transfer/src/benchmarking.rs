@@ -17,11 +17,21 @@
 #![cfg(feature = "runtime-benchmarks")]
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use crate::{mock::Test, traits::IpfsUrl, *};
+use crate::{
+	mock::Test,
+	traits::{ExternalReference, IpfsUrl, NftCategorized},
+	*,
+};
 use ajuna_primitives::{account_manager::AccountManager, asset_manager::AssetManager};
 use frame_benchmarking::benchmarks;
-use frame_support::{pallet_prelude::DispatchError, traits::Currency};
-use frame_system::RawOrigin;
+use frame_support::{
+	pallet_prelude::DispatchError,
+	traits::{
+		tokens::nonfungibles_v2::{Create, Mutate},
+		Currency, Get,
+	},
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 use sp_runtime::{BuildStorage, SaturatedConversion};
 
 type CurrencyOf<T> = <T as pallet_nfts::Config>::Currency;
@@ -33,7 +43,7 @@ pub struct Pallet<T: Config>(crate::Pallet<T>);
 // Todo: If we can't get rid of the pallet-nfts constraint here
 // we can just as well make the pallet-ajuna-nft-transfer depend
 // on it.
-pub trait Config: pallet_nfts::Config + crate::Config {}
+pub trait Config: pallet_nfts::Config<CollectionId = CollectionIdOf<Self>> + crate::Config {}
 
 impl Config for Test {}
 
@@ -68,6 +78,58 @@ fn enable_fee_payment<T: Config>(player: &T::AccountId) {
 	CurrencyOf::<T>::make_free_balance_be(player, prepare_fee.saturated_into());
 }
 
+fn create_stored_nft<T: Config>(
+	owner: T::AccountId,
+) -> Result<(CollectionIdOf<T>, ItemIdOf<T>), DispatchError> {
+	enable_fee_payment::<T>(&owner);
+	let collection_id = pallet_nfts::Pallet::<T>::create_collection(
+		&owner,
+		&crate::Pallet::<T>::account_id(),
+		&pallet_nfts::CollectionConfig {
+			settings: pallet_nfts::CollectionSettings::all_enabled(),
+			max_supply: None,
+			mint_settings: pallet_nfts::MintSettings {
+				mint_type: pallet_nfts::MintType::Issuer,
+				price: None,
+				start_block: None,
+				end_block: None,
+				default_item_settings: pallet_nfts::ItemSettings::all_enabled(),
+			},
+		},
+	)?;
+	let item_id = create_assets::<T>(owner.clone(), 1)[0];
+	T::NftHelper::mint_into(&collection_id, &item_id, &owner, &T::ItemConfig::default(), false)?;
+	NftStatuses::<T>::insert(collection_id, item_id, NftStatus::Stored);
+	Ok((collection_id, item_id))
+}
+
+fn create_uploaded_nft<T: Config>(
+	owner: T::AccountId,
+	service_account: T::AccountId,
+) -> Result<(CollectionIdOf<T>, ItemIdOf<T>), DispatchError> {
+	let (collection_id, item_id) = create_stored_nft::<T>(owner.clone())?;
+	crate::Pallet::<T>::schedule_nft_upload(
+		RawOrigin::Signed(owner).into(),
+		collection_id,
+		item_id,
+	)?;
+	crate::Pallet::<T>::confirm_nft_upload(
+		RawOrigin::Signed(service_account).into(),
+		collection_id,
+		item_id,
+		max_external_reference(),
+	)?;
+	Ok((collection_id, item_id))
+}
+
+fn max_external_reference() -> ExternalReference {
+	ExternalReference::try_from(vec![u8::MAX; ExternalReference::bound()]).unwrap()
+}
+
+fn transit_deadline<T: Config>() -> BlockNumberFor<T> {
+	frame_system::Pallet::<T>::block_number() + T::TransitTimeout::get()
+}
+
 fn assert_last_event<T: Config>(avatars_event: Event<T>) {
 	let event = <T as crate::Config>::RuntimeEvent::from(avatars_event);
 	frame_system::Pallet::<T>::assert_last_event(event.into());
@@ -83,6 +145,20 @@ benchmarks! {
 		assert_last_event::<T>(Event::CollectionIdSet { collection_id })
 	}
 
+	set_category_collection_id {
+		let organizer = account::<T>("organizer");
+		T::AccountManager::set_organizer(organizer.clone());
+		let category = T::AssetManager::ensure_ownership(
+			&organizer,
+			&create_assets::<T>(organizer.clone(), 1)[0],
+		)?
+		.nft_category();
+		let collection_id = Some(CollectionIdOf::<T>::from(u32::MAX));
+	}: _(RawOrigin::Signed(organizer), category, collection_id)
+	verify {
+		assert_last_event::<T>(Event::CategoryCollectionIdSet { category, collection_id })
+	}
+
 	set_service_account {
 		let service_account = account::<T>("sa");
 	}: _(RawOrigin::Root, service_account.clone())
@@ -108,6 +184,7 @@ benchmarks! {
 		let _ = create_service_account_and_prepare_avatar::<T>(player.clone(), asset_id)?;
 	}: _(RawOrigin::Signed(player), asset_id)
 	verify {
+		assert!(T::AssetManager::is_locked(&asset_id).is_none());
 		assert_last_event::<T>(Event::<T>::UnpreparedAvatar { asset_id })
 	}
 
@@ -122,6 +199,95 @@ benchmarks! {
 		assert_last_event::<T>(Event::<T>::PreparedIpfsUrl { url })
 	}
 
+	cancel_preparation {
+		let name = "player";
+		let player = account::<T>(name);
+		let asset_id = create_assets::<T>(player.clone(), 1)[0];
+		let _ = create_service_account_and_prepare_avatar::<T>(player.clone(), asset_id)?;
+		let deadline = PreparationDeadlines::<T>::get(asset_id).unwrap().deadline;
+		frame_system::Pallet::<T>::set_block_number(deadline + 1_u32.into());
+	}: _(RawOrigin::Signed(player), asset_id)
+	verify {
+		assert!(PreparationDeadlines::<T>::get(asset_id).is_none());
+		assert!(T::AssetManager::is_locked(&asset_id).is_none());
+	}
+
+	schedule_nft_upload {
+		let player = account::<T>("player");
+		let (collection_id, item_id) = create_stored_nft::<T>(player.clone())?;
+	}: _(RawOrigin::Signed(player.clone()), collection_id, item_id)
+	verify {
+		let deadline = transit_deadline::<T>();
+		assert_last_event::<T>(Event::<T>::UploadScheduled {
+			collection_id,
+			item_id,
+			owner: player,
+			deadline,
+		})
+	}
+
+	confirm_nft_upload {
+		let player = account::<T>("player");
+		let service_account = create_service_account::<T>();
+		let (collection_id, item_id) = create_stored_nft::<T>(player.clone())?;
+		crate::Pallet::<T>::schedule_nft_upload(
+			RawOrigin::Signed(player).into(),
+			collection_id,
+			item_id,
+		)?;
+		let reference = max_external_reference();
+	}: _(RawOrigin::Signed(service_account), collection_id, item_id, reference.clone())
+	verify {
+		assert_last_event::<T>(Event::<T>::UploadConfirmed { collection_id, item_id, reference })
+	}
+
+	schedule_nft_return {
+		let player = account::<T>("player");
+		let service_account = create_service_account::<T>();
+		let (collection_id, item_id) = create_uploaded_nft::<T>(player.clone(), service_account)?;
+	}: _(RawOrigin::Signed(player.clone()), collection_id, item_id)
+	verify {
+		let deadline = transit_deadline::<T>();
+		assert_last_event::<T>(Event::<T>::ReturnScheduled {
+			collection_id,
+			item_id,
+			owner: player,
+			deadline,
+		})
+	}
+
+	confirm_nft_return {
+		let player = account::<T>("player");
+		let service_account = create_service_account::<T>();
+		let (collection_id, item_id) =
+			create_uploaded_nft::<T>(player.clone(), service_account.clone())?;
+		crate::Pallet::<T>::schedule_nft_return(
+			RawOrigin::Signed(player).into(),
+			collection_id,
+			item_id,
+		)?;
+	}: _(RawOrigin::Signed(service_account), collection_id, item_id)
+	verify {
+		assert_last_event::<T>(Event::<T>::ReturnConfirmed { collection_id, item_id })
+	}
+
+	rollback_nft_transit {
+		let player = account::<T>("player");
+		let service_account = create_service_account::<T>();
+		let (collection_id, item_id) = create_uploaded_nft::<T>(player.clone(), service_account)?;
+		crate::Pallet::<T>::schedule_nft_return(
+			RawOrigin::Signed(player.clone()).into(),
+			collection_id,
+			item_id,
+		)?;
+		let deadline = NftTransits::<T>::get(collection_id, item_id).unwrap().deadline;
+		frame_system::Pallet::<T>::set_block_number(deadline + 1_u32.into());
+	}: _(RawOrigin::Signed(player), collection_id, item_id)
+	verify {
+		let status = NftStatus::Uploaded;
+		assert_last_event::<T>(Event::<T>::TransitRolledBack { collection_id, item_id, status })
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::benchmarking::new_test_ext(),
@@ -26,6 +26,7 @@ mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 pub mod benchmarking;
+pub mod migration;
 pub mod traits;
 pub mod weights;
 
@@ -35,7 +36,9 @@ use crate::weights::WeightInfo;
 pub mod pallet {
 	use super::*;
 	use crate::traits::*;
-	use ajuna_primitives::{account_manager::AccountManager, asset_manager::AssetManager};
+	use ajuna_primitives::{
+		account_manager::AccountManager, asset_manager::AssetManager, fee_router::FeeReceipt,
+	};
 	use frame_support::{
 		pallet_prelude::*,
 		traits::{
@@ -44,10 +47,21 @@ pub mod pallet {
 		},
 		PalletId,
 	};
-	use frame_system::{ensure_root, ensure_signed, pallet_prelude::OriginFor};
-	use sp_runtime::traits::{AccountIdConversion, AtLeast32BitUnsigned};
+	use frame_system::{
+		ensure_root, ensure_signed,
+		pallet_prelude::{BlockNumberFor, OriginFor},
+	};
+	use sp_runtime::traits::{AccountIdConversion, AtLeast32BitUnsigned, Saturating};
 
 	pub(crate) type AccountIdFor<T> = <T as frame_system::Config>::AccountId;
+	pub(crate) type NftTransitOf<T> = NftTransit<AccountIdFor<T>, BlockNumberFor<T>>;
+	pub(crate) type BalanceOf<T> = <<T as Config>::AssetManager as AssetManager>::Balance;
+	pub(crate) type PendingPreparationOf<T> = PendingPreparation<
+		AccountIdFor<T>,
+		BlockNumberFor<T>,
+		BalanceOf<T>,
+		<T as Config>::MaxPrepareFeeTransfers,
+	>;
 
 	#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, Eq, PartialEq)]
 	pub enum NftStatus {
@@ -55,9 +69,59 @@ pub mod pallet {
 		Stored,
 		/// The NFT has been uploaded outside the chain
 		Uploaded,
+		/// The NFT is waiting for the service account to confirm its upload outside the chain
+		UploadScheduled,
+		/// The NFT is waiting for the service account to confirm its return into the chain
+		ReturnScheduled,
+	}
+
+	#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, Eq, PartialEq)]
+	pub struct NftTransit<AccountId, BlockNumber> {
+		/// The account that scheduled the transit and owns the NFT.
+		pub owner: AccountId,
+		/// The last block in which the service account can confirm the transit.
+		pub deadline: BlockNumber,
+	}
+
+	#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Debug, Eq, PartialEq)]
+	#[scale_info(skip_type_params(MaxTransfers))]
+	#[codec(mel_bound(
+		AccountId: MaxEncodedLen,
+		BlockNumber: MaxEncodedLen,
+		Balance: MaxEncodedLen
+	))]
+	pub struct PendingPreparation<AccountId, BlockNumber, Balance, MaxTransfers: Get<u32>> {
+		/// The last block in which the service account can prepare the IPFS URL of the asset.
+		pub deadline: BlockNumber,
+		/// The account the preparation fee was paid to, which refunds its deposited part even if
+		/// the service account has changed since.
+		pub fees_recipient: AccountId,
+		/// The fee before any cut was applied.
+		pub base_fee: Balance,
+		/// The amounts of the fee transferred to other accounts.
+		pub transfers: BoundedVec<(AccountId, Balance), MaxTransfers>,
+		/// The amount of the fee that has been burned.
+		pub burned: Balance,
+		/// The amount of the fee deposited into the fees recipient.
+		pub deposited: Balance,
+	}
+
+	impl<AccountId, BlockNumber, Balance, MaxTransfers: Get<u32>>
+		PendingPreparation<AccountId, BlockNumber, Balance, MaxTransfers>
+	{
+		/// Where the preparation fee went, so that exactly that can be refunded.
+		pub fn fee(self) -> FeeReceipt<AccountId, Balance> {
+			FeeReceipt {
+				base_fee: self.base_fee,
+				transfers: self.transfers.into_inner(),
+				burned: self.burned,
+				deposited: self.deposited,
+			}
+		}
 	}
 
 	#[pallet::pallet]
+	#[pallet::storage_version(migration::STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -72,7 +136,11 @@ pub mod pallet {
 		/// Identifier for the collection of item.
 		type CollectionId: Member + Parameter + MaxEncodedLen + Copy + AtLeast32BitUnsigned;
 
-		type Item: NftConvertible<Self::KeyLimit, Self::ValueLimit>;
+		type Item: NftConvertible<Self::KeyLimit, Self::ValueLimit>
+			+ NftCategorized<Category = Self::ItemCategory>;
+
+		/// The category of an item, used to store each category in a different collection.
+		type ItemCategory: Member + Parameter + MaxEncodedLen + Copy;
 
 		/// The type used to identify a unique item within a collection.
 		type ItemId: Member + Parameter + MaxEncodedLen + Copy;
@@ -100,39 +168,122 @@ pub mod pallet {
 		type NftHelper: Inspect<Self::AccountId, CollectionId = Self::CollectionId, ItemId = Self::ItemId>
 			+ Mutate<Self::AccountId, Self::ItemConfig>;
 
+		/// The number of blocks the service account has to confirm a scheduled upload or return of
+		/// an NFT, after which the owner can roll it back.
+		#[pallet::constant]
+		type TransitTimeout: Get<BlockNumberFor<Self>>;
+
+		/// The number of blocks the service account has to prepare the IPFS URL of an asset,
+		/// after which the owner can cancel the preparation and get the fee refunded.
+		#[pallet::constant]
+		type PreparationTimeout: Get<BlockNumberFor<Self>>;
+
+		/// The maximum number of accounts a preparation fee can be transferred to, besides the
+		/// service account.
+		#[pallet::constant]
+		type MaxPrepareFeeTransfers: Get<u32>;
+
 		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::storage]
 	pub type CollectionId<T: Config> = StorageValue<_, T::CollectionId, OptionQuery>;
 
+	#[pallet::storage]
+	pub type CategoryCollections<T: Config> =
+		StorageMap<_, Identity, T::ItemCategory, T::CollectionId, OptionQuery>;
+
+	/// The collection each item has been stored in as an NFT, so that it can be recovered from
+	/// there even if the collection of its category changes in the meantime.
+	#[pallet::storage]
+	pub type ItemCollections<T: Config> =
+		StorageMap<_, Identity, T::ItemId, T::CollectionId, OptionQuery>;
+
 	#[pallet::storage]
 	pub type ServiceAccount<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
 
 	#[pallet::storage]
 	pub type Preparation<T: Config> = StorageMap<_, Identity, T::ItemId, IpfsUrl, OptionQuery>;
 
+	#[pallet::storage]
+	pub type PreparationDeadlines<T: Config> =
+		StorageMap<_, Identity, T::ItemId, PendingPreparationOf<T>, OptionQuery>;
+
 	#[pallet::storage]
 	pub type NftStatuses<T: Config> =
 		StorageDoubleMap<_, Identity, T::CollectionId, Identity, T::ItemId, NftStatus, OptionQuery>;
 
+	#[pallet::storage]
+	pub type NftTransits<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		T::CollectionId,
+		Identity,
+		T::ItemId,
+		NftTransitOf<T>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	pub type NftReferences<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		T::CollectionId,
+		Identity,
+		T::ItemId,
+		ExternalReference,
+		OptionQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// A collection ID has been set.
 		CollectionIdSet { collection_id: T::CollectionId },
+		/// The collection ID of an item category has been set or cleared.
+		CategoryCollectionIdSet {
+			category: T::ItemCategory,
+			collection_id: Option<T::CollectionId>,
+		},
 		/// A service account has been set.
 		ServiceAccountSet { service_account: T::AccountId },
 		/// Avatar prepared.
 		PreparedAvatar { asset_id: T::ItemId },
 		/// Avatar unprepared.
 		UnpreparedAvatar { asset_id: T::ItemId },
+		/// An expired preparation has been cancelled and as much of its fee as could be paid back
+		/// has been refunded.
+		PreparationCancelled { asset_id: T::ItemId, refunded: BalanceOf<T> },
 		/// IPFS URL prepared.
 		PreparedIpfsUrl { url: IpfsUrl },
 		/// Item has been stored as an NFT [collection_id, item_id, owner]
 		ItemStored { collection_id: T::CollectionId, item_id: T::ItemId, owner: T::AccountId },
 		/// Item has been restored back from its NFT representation [collection_id, item_id, owner]
 		ItemRestored { collection_id: T::CollectionId, item_id: T::ItemId, owner: T::AccountId },
+		/// The upload of an NFT outside the chain has been scheduled.
+		UploadScheduled {
+			collection_id: T::CollectionId,
+			item_id: T::ItemId,
+			owner: T::AccountId,
+			deadline: BlockNumberFor<T>,
+		},
+		/// The upload of an NFT has been confirmed by the service account.
+		UploadConfirmed {
+			collection_id: T::CollectionId,
+			item_id: T::ItemId,
+			reference: ExternalReference,
+		},
+		/// The return of an uploaded NFT into the chain has been scheduled.
+		ReturnScheduled {
+			collection_id: T::CollectionId,
+			item_id: T::ItemId,
+			owner: T::AccountId,
+			deadline: BlockNumberFor<T>,
+		},
+		/// The return of an NFT has been confirmed by the service account.
+		ReturnConfirmed { collection_id: T::CollectionId, item_id: T::ItemId },
+		/// An unconfirmed transit of an NFT has been rolled back to its previous status.
+		TransitRolledBack { collection_id: T::CollectionId, item_id: T::ItemId, status: NftStatus },
 	}
 
 	#[pallet::error]
@@ -162,6 +313,24 @@ pub mod pallet {
 		NftTransferClosed,
 		/// The process of restoring an NFT into an item has failed.
 		ItemRestoreFailure,
+		/// The given NFT is not in the status required by the operation.
+		InvalidNftStatus,
+		/// There is no pending transit for the given NFT.
+		UnknownTransit,
+		/// The pending transit has passed its deadline and can only be rolled back.
+		TransitExpired,
+		/// The pending transit is still within its deadline.
+		TransitNotExpired,
+		/// External reference must not be empty.
+		EmptyExternalReference,
+		/// The asset has no pending preparation awaiting its IPFS URL.
+		NoPendingPreparation,
+		/// The pending preparation is still within its deadline.
+		PreparationNotExpired,
+		/// The preparation fee has been transferred to more accounts than can be refunded.
+		TooManyPrepareFeeTransfers,
+		/// The given NFT has no collection recorded for it.
+		UnknownItemCollection,
 	}
 
 	#[pallet::call]
@@ -212,8 +381,9 @@ pub mod pallet {
 
 			let asset = T::AssetManager::ensure_ownership(&player, &asset_id)?;
 
-			let collection_id = CollectionId::<T>::get().ok_or(Error::<T>::CollectionIdNotSet)?;
+			let collection_id = Self::collection_id_for(&asset)?;
 			let url = Preparation::<T>::take(asset_id).ok_or(Error::<T>::AssetUnprepared)?;
+			PreparationDeadlines::<T>::remove(asset_id);
 
 			Self::store_as_nft(player, collection_id, asset_id, asset, url)?;
 
@@ -226,10 +396,10 @@ pub mod pallet {
 		pub fn recover_asset_from_nft(origin: OriginFor<T>, asset_id: T::ItemId) -> DispatchResult {
 			let player = ensure_signed(origin)?;
 
-			let _asset =
-				T::AssetManager::unlock_asset(T::PalletId::get().0, player.clone(), asset_id)?;
+			let _ = T::AssetManager::unlock_asset(T::PalletId::get().0, player.clone(), asset_id)?;
 
-			let collection_id = CollectionId::<T>::get().ok_or(Error::<T>::CollectionIdNotSet)?;
+			let collection_id =
+				ItemCollections::<T>::get(asset_id).ok_or(Error::<T>::UnknownItemCollection)?;
 
 			// need to specify explicit Item type.
 			let _ = <Self as NftHandler<_, _, _, _, <T as Config>::Item>>::recover_from_nft(
@@ -252,14 +422,33 @@ pub mod pallet {
 				T::AssetManager::lock_asset(T::PalletId::get().0, player.clone(), asset_id)?;
 
 			let service_account = ServiceAccount::<T>::get().ok_or(Error::<T>::NoServiceAccount)?;
-			T::AssetManager::handle_asset_prepare_fee(&asset, &player, &service_account)?;
+			let FeeReceipt { base_fee, transfers, burned, deposited } =
+				T::AssetManager::handle_asset_prepare_fee(&asset, &player, &service_account)?;
+			let transfers =
+				transfers.try_into().map_err(|_| Error::<T>::TooManyPrepareFeeTransfers)?;
 
+			let deadline = <frame_system::Pallet<T>>::block_number()
+				.saturating_add(T::PreparationTimeout::get());
 			Preparation::<T>::insert(asset_id, IpfsUrl::default());
+			PreparationDeadlines::<T>::insert(
+				asset_id,
+				PendingPreparation {
+					deadline,
+					fees_recipient: service_account,
+					base_fee,
+					transfers,
+					burned,
+					deposited,
+				},
+			);
 			Self::deposit_event(Event::PreparedAvatar { asset_id });
 			Ok(())
 		}
 
 		/// Unprepare an asset owned by the origin to be detached from IPFS.
+		///
+		/// The asset is unlocked and any pending preparation is dropped. The preparation fee is
+		/// not refunded, which only `cancel_preparation` does once the preparation has expired.
 		#[pallet::call_index(5)]
 		#[pallet::weight(T::WeightInfo::unprepare_asset())]
 		pub fn unprepare_asset(origin: OriginFor<T>, asset_id: T::ItemId) -> DispatchResult {
@@ -268,7 +457,10 @@ pub mod pallet {
 			ensure!(T::AssetManager::nft_transfer_open(), Error::<T>::NftTransferClosed);
 			ensure!(Preparation::<T>::contains_key(asset_id), Error::<T>::AssetUnprepared);
 
+			let _ = T::AssetManager::unlock_asset(T::PalletId::get().0, player, asset_id)?;
+
 			Preparation::<T>::remove(asset_id);
+			PreparationDeadlines::<T>::remove(asset_id);
 			Self::deposit_event(Event::UnpreparedAvatar { asset_id });
 			Ok(())
 		}
@@ -292,9 +484,183 @@ pub mod pallet {
 			ensure!(Preparation::<T>::contains_key(asset_id), Error::<T>::AssetUnprepared);
 			ensure!(!url.is_empty(), Error::<T>::EmptyIpfsUrl);
 			Preparation::<T>::insert(asset_id, &url);
+			PreparationDeadlines::<T>::remove(asset_id);
 			Self::deposit_event(Event::PreparedIpfsUrl { url });
 			Ok(())
 		}
+
+		/// Schedule the upload of an NFT owned by the origin outside the chain.
+		///
+		/// The NFT is locked from this point on. A third-party service subscribes for the
+		/// `UploadScheduled` events and confirms the upload with `confirm_nft_upload` before the
+		/// deadline, otherwise the owner can roll it back with `rollback_nft_transit`.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::schedule_nft_upload())]
+		pub fn schedule_nft_upload(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			item_id: T::ItemId,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			// need to specify explicit Item type.
+			<Self as NftHandler<_, _, _, _, <T as Config>::Item>>::schedule_upload(
+				owner,
+				collection_id,
+				item_id,
+			)
+		}
+
+		/// Confirm the upload of an NFT with the reference under which it is stored outside the
+		/// chain.
+		///
+		/// The origin of this call must be signed by the service account.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::confirm_nft_upload())]
+		pub fn confirm_nft_upload(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			item_id: T::ItemId,
+			reference: ExternalReference,
+		) -> DispatchResult {
+			let _ = Self::ensure_service_account(origin)?;
+			ensure!(!reference.is_empty(), Error::<T>::EmptyExternalReference);
+
+			Self::complete_transit(
+				collection_id,
+				item_id,
+				NftStatus::UploadScheduled,
+				NftStatus::Uploaded,
+			)?;
+
+			NftReferences::<T>::insert(collection_id, item_id, &reference);
+			Self::deposit_event(Event::UploadConfirmed { collection_id, item_id, reference });
+			Ok(())
+		}
+
+		/// Schedule the return of an uploaded NFT owned by the origin back into the chain.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::schedule_nft_return())]
+		pub fn schedule_nft_return(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			item_id: T::ItemId,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let deadline = Self::begin_transit(
+				&owner,
+				collection_id,
+				item_id,
+				NftStatus::Uploaded,
+				NftStatus::ReturnScheduled,
+			)?;
+			Self::deposit_event(Event::ReturnScheduled { collection_id, item_id, owner, deadline });
+			Ok(())
+		}
+
+		/// Confirm the return of an NFT into the chain, making it available as stored again.
+		///
+		/// The origin of this call must be signed by the service account.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::confirm_nft_return())]
+		pub fn confirm_nft_return(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			item_id: T::ItemId,
+		) -> DispatchResult {
+			let _ = Self::ensure_service_account(origin)?;
+
+			Self::complete_transit(
+				collection_id,
+				item_id,
+				NftStatus::ReturnScheduled,
+				NftStatus::Stored,
+			)?;
+
+			NftReferences::<T>::remove(collection_id, item_id);
+			Self::deposit_event(Event::ReturnConfirmed { collection_id, item_id });
+			Ok(())
+		}
+
+		/// Roll back an NFT transit that hasn't been confirmed before its deadline.
+		///
+		/// A scheduled upload reverts the NFT to `Stored`, while a scheduled return reverts it to
+		/// `Uploaded`. Only the owner that scheduled the transit can roll it back.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::rollback_nft_transit())]
+		pub fn rollback_nft_transit(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			item_id: T::ItemId,
+		) -> DispatchResult {
+			let account = ensure_signed(origin)?;
+
+			let transit =
+				NftTransits::<T>::get(collection_id, item_id).ok_or(Error::<T>::UnknownTransit)?;
+			ensure!(transit.owner == account, Error::<T>::NftNotOwned);
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() > transit.deadline,
+				Error::<T>::TransitNotExpired
+			);
+
+			let status = match NftStatuses::<T>::get(collection_id, item_id) {
+				Some(NftStatus::UploadScheduled) => NftStatus::Stored,
+				Some(NftStatus::ReturnScheduled) => NftStatus::Uploaded,
+				_ => return Err(Error::<T>::InvalidNftStatus.into()),
+			};
+
+			NftStatuses::<T>::insert(collection_id, item_id, &status);
+			NftTransits::<T>::remove(collection_id, item_id);
+			Self::deposit_event(Event::TransitRolledBack { collection_id, item_id, status });
+			Ok(())
+		}
+
+		/// Set the collection ID to associate assets of the given category with.
+		///
+		/// Assets whose category has no collection ID fall back to the one in `CollectionId`.
+		/// Passing `None` removes the collection ID of the category.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::set_category_collection_id())]
+		pub fn set_category_collection_id(
+			origin: OriginFor<T>,
+			category: T::ItemCategory,
+			collection_id: Option<T::CollectionId>,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			T::AccountManager::is_organizer(&signer)?;
+			CategoryCollections::<T>::set(category, collection_id);
+			Self::deposit_event(Event::CategoryCollectionIdSet { category, collection_id });
+			Ok(())
+		}
+
+		/// Cancel the preparation of an asset owned by the origin whose IPFS URL hasn't been
+		/// prepared before its deadline.
+		///
+		/// The asset is unlocked and the part of the preparation fee deposited into the fees
+		/// recipient is refunded by it, if it can still afford it.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::cancel_preparation())]
+		pub fn cancel_preparation(origin: OriginFor<T>, asset_id: T::ItemId) -> DispatchResult {
+			let player = ensure_signed(origin)?;
+
+			let pending =
+				PreparationDeadlines::<T>::get(asset_id).ok_or(Error::<T>::NoPendingPreparation)?;
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() > pending.deadline,
+				Error::<T>::PreparationNotExpired
+			);
+
+			let _ = T::AssetManager::unlock_asset(T::PalletId::get().0, player.clone(), asset_id)?;
+			let fees_recipient = pending.fees_recipient.clone();
+			let refunded =
+				T::AssetManager::refund_asset_prepare_fee(&pending.fee(), &player, &fees_recipient);
+
+			Preparation::<T>::remove(asset_id);
+			PreparationDeadlines::<T>::remove(asset_id);
+			Self::deposit_event(Event::PreparationCancelled { asset_id, refunded });
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -312,10 +678,70 @@ pub mod pallet {
 			Ok(maybe_sa)
 		}
 
+		/// Returns the collection ID in which the given asset is stored as an NFT.
+		pub fn collection_id_for(asset: &T::Item) -> Result<T::CollectionId, DispatchError> {
+			CategoryCollections::<T>::get(asset.nft_category())
+				.or_else(CollectionId::<T>::get)
+				.ok_or_else(|| Error::<T>::CollectionIdNotSet.into())
+		}
+
 		fn ensure_unprepared(asset_id: &T::ItemId) -> DispatchResult {
 			ensure!(!Preparation::<T>::contains_key(asset_id), Error::<T>::AssetAlreadyPrepared);
 			Ok(())
 		}
+
+		/// Moves an NFT owned by `owner` from the `from` status into the `to` transit status,
+		/// returning the deadline for its confirmation.
+		fn begin_transit(
+			owner: &T::AccountId,
+			collection_id: T::CollectionId,
+			item_id: T::ItemId,
+			from: NftStatus,
+			to: NftStatus,
+		) -> Result<BlockNumberFor<T>, DispatchError> {
+			ensure!(
+				T::NftHelper::owner(&collection_id, &item_id).as_ref() == Some(owner),
+				Error::<T>::NftNotOwned
+			);
+			NftStatuses::<T>::try_mutate(collection_id, item_id, |maybe_status| {
+				ensure!(maybe_status.as_ref() == Some(&from), Error::<T>::InvalidNftStatus);
+				*maybe_status = Some(to);
+				Ok::<_, DispatchError>(())
+			})?;
+
+			let deadline =
+				<frame_system::Pallet<T>>::block_number().saturating_add(T::TransitTimeout::get());
+			NftTransits::<T>::insert(
+				collection_id,
+				item_id,
+				NftTransit { owner: owner.clone(), deadline },
+			);
+			Ok(deadline)
+		}
+
+		/// Moves an NFT in the `from` transit status into the `to` status, provided its transit
+		/// has not expired yet.
+		fn complete_transit(
+			collection_id: T::CollectionId,
+			item_id: T::ItemId,
+			from: NftStatus,
+			to: NftStatus,
+		) -> DispatchResult {
+			let transit =
+				NftTransits::<T>::get(collection_id, item_id).ok_or(Error::<T>::UnknownTransit)?;
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() <= transit.deadline,
+				Error::<T>::TransitExpired
+			);
+			ensure!(
+				NftStatuses::<T>::get(collection_id, item_id) == Some(from),
+				Error::<T>::InvalidNftStatus
+			);
+
+			NftStatuses::<T>::insert(collection_id, item_id, to);
+			NftTransits::<T>::remove(collection_id, item_id);
+			Ok(())
+		}
 	}
 
 	impl<T: Config, Item: NftConvertible<T::KeyLimit, T::ValueLimit>>
@@ -358,6 +784,7 @@ pub mod pallet {
 				})?;
 
 			NftStatuses::<T>::insert(collection_id, item_id, NftStatus::Stored);
+			ItemCollections::<T>::insert(item_id, collection_id);
 
 			Self::deposit_event(Event::<T>::ItemStored { collection_id, item_id, owner });
 			Ok(())
@@ -384,16 +811,41 @@ pub mod pallet {
 			}
 
 			NftStatuses::<T>::remove(collection_id, item_id);
+			ItemCollections::<T>::remove(item_id);
 			T::NftHelper::burn(&collection_id, &item_id, Some(&owner))?;
 
 			Self::deposit_event(Event::<T>::ItemRestored { collection_id, item_id, owner });
 			Item::decode(&mut item.as_slice()).map_err(|_| Error::<T>::ItemRestoreFailure.into())
 		}
+
+		fn schedule_upload(
+			owner: T::AccountId,
+			collection_id: Self::CollectionId,
+			item_id: T::ItemId,
+		) -> DispatchResult {
+			let deadline = Self::begin_transit(
+				&owner,
+				collection_id,
+				item_id,
+				NftStatus::Stored,
+				NftStatus::UploadScheduled,
+			)?;
+			Self::deposit_event(Event::<T>::UploadScheduled {
+				collection_id,
+				item_id,
+				owner,
+				deadline,
+			});
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Locker<T::CollectionId, T::ItemId> for Pallet<T> {
 		fn is_locked(collection_id: T::CollectionId, item_id: T::ItemId) -> bool {
-			matches!(NftStatuses::<T>::get(collection_id, item_id), Some(NftStatus::Uploaded))
+			matches!(
+				NftStatuses::<T>::get(collection_id, item_id),
+				Some(NftStatus::Uploaded | NftStatus::UploadScheduled | NftStatus::ReturnScheduled)
+			)
 		}
 	}
 }
@@ -0,0 +1,85 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_support::{
+	migrations::VersionedMigration,
+	pallet_prelude::*,
+	traits::{Get, UncheckedOnRuntimeUpgrade},
+};
+use sp_runtime::traits::{Saturating, Zero};
+use sp_std::marker::PhantomData;
+
+/// The current storage version.
+pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+pub mod v1 {
+	use super::*;
+
+	/// Records the collection of every item that has been stored as an NFT, based on the
+	/// existing [`NftStatuses`], and gives every preparation still awaiting its IPFS URL a
+	/// deadline so that it can be cancelled. The fee of those preparations wasn't recorded, so
+	/// their receipt is empty and cancelling them only unlocks the asset.
+	pub struct UncheckedMigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut items = 0_u64;
+
+			for (collection_id, item_id) in NftStatuses::<T>::iter_keys() {
+				ItemCollections::<T>::insert(item_id, collection_id);
+				items += 1;
+			}
+
+			let mut preparations = 0_u64;
+			let mut pending_preparations = 0_u64;
+			let deadline = <frame_system::Pallet<T>>::block_number()
+				.saturating_add(T::PreparationTimeout::get());
+			let fees_recipient = ServiceAccount::<T>::get().unwrap_or_else(Pallet::<T>::account_id);
+
+			for (asset_id, url) in Preparation::<T>::iter() {
+				preparations += 1;
+				if url.is_empty() && !PreparationDeadlines::<T>::contains_key(asset_id) {
+					PreparationDeadlines::<T>::insert(
+						asset_id,
+						PendingPreparation {
+							deadline,
+							fees_recipient: fees_recipient.clone(),
+							base_fee: Zero::zero(),
+							transfers: BoundedVec::default(),
+							burned: Zero::zero(),
+							deposited: Zero::zero(),
+						},
+					);
+					pending_preparations += 1;
+				}
+			}
+
+			T::DbWeight::get()
+				.reads_writes(items, items)
+				.saturating_add(T::DbWeight::get().reads(preparations.saturating_mul(2) + 1))
+				.saturating_add(T::DbWeight::get().writes(pending_preparations))
+		}
+	}
+
+	pub type MigrateToV1<T> = VersionedMigration<
+		0,
+		1,
+		UncheckedMigrateToV1<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
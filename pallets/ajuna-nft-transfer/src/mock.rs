@@ -16,16 +16,18 @@
 
 use crate::{
 	self as pallet_ajuna_nft_transfer,
-	traits::{NFTAttribute, NftConvertible},
+	traits::{NFTAttribute, NftCategorized, NftConvertible},
 };
 use ajuna_primitives::{
 	account_manager::WhitelistKey,
 	asset_manager::{AssetManager, Lock},
+	fee_router::FeeReceipt,
 };
 use frame_support::{
 	ensure, parameter_types,
 	traits::{
-		AsEnsureOriginWithArg, ConstU16, ConstU64, Currency, ExistenceRequirement, LockIdentifier,
+		AsEnsureOriginWithArg, ConstU16, ConstU32, ConstU64, Currency, ExistenceRequirement,
+		LockIdentifier,
 	},
 	BoundedVec, PalletId,
 };
@@ -204,6 +206,8 @@ impl pallet_nfts::Config for Test {
 
 parameter_types! {
 	pub const NftTransferPalletId: PalletId = PalletId(*b"aj/nfttr");
+	pub const TransitTimeout: u64 = 10;
+	pub const PreparationTimeout: u64 = 20;
 }
 
 impl pallet_ajuna_nft_transfer::Config for Test {
@@ -211,6 +215,7 @@ impl pallet_ajuna_nft_transfer::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type CollectionId = MockCollectionId;
 	type Item = MockItem;
+	type ItemCategory = u32;
 	type ItemId = ItemId;
 	type ItemConfig = pallet_nfts::ItemConfig;
 	type AssetManager = MockAssetManager;
@@ -218,6 +223,9 @@ impl pallet_ajuna_nft_transfer::Config for Test {
 	type KeyLimit = KeyLimit;
 	type ValueLimit = ValueLimit;
 	type NftHelper = Nft;
+	type TransitTimeout = TransitTimeout;
+	type PreparationTimeout = PreparationTimeout;
+	type MaxPrepareFeeTransfers = ConstU32<2>;
 	type WeightInfo = ();
 }
 
@@ -257,6 +265,14 @@ impl NftConvertible<KeyLimit, ValueLimit> for MockItem {
 	}
 }
 
+impl NftCategorized for MockItem {
+	type Category = u32;
+
+	fn nft_category(&self) -> Self::Category {
+		self.field_2
+	}
+}
+
 pub const ALICE: MockAccountId = 1;
 
 thread_local! {
@@ -266,6 +282,7 @@ thread_local! {
 	pub static ORGANIZER: RefCell<Option<MockAccountId>> = RefCell::new(Some(ALICE));
 	pub static NFT_TRANSFER_OPEN: RefCell<bool> = RefCell::new(true);
 	pub static PREPARE_FEE: RefCell<MockBalance> = RefCell::new(999);
+	pub static PREPARE_FEE_TRANSFERS: RefCell<Vec<(MockAccountId, MockBalance)>> = RefCell::new(vec![]);
 }
 
 /// In the future we might want to use the `pallet-awesome-ajuna-avatars`, but currently this
@@ -298,6 +315,11 @@ impl MockAssetManager {
 	pub fn set_prepare_fee(fee: MockBalance) {
 		PREPARE_FEE.with(|current| *current.borrow_mut() = fee)
 	}
+
+	/// Transfers the given parts of the prepare fee to other accounts, depositing the rest.
+	pub fn set_prepare_fee_transfers(transfers: Vec<(MockAccountId, MockBalance)>) {
+		PREPARE_FEE_TRANSFERS.with(|current| *current.borrow_mut() = transfers)
+	}
 }
 
 pub const NOT_OWNER_ERR: &str = "NOT_OWNER";
@@ -309,6 +331,7 @@ impl AssetManager for MockAssetManager {
 	type AccountId = MockAccountId;
 	type AssetId = ItemId;
 	type Asset = MockItem;
+	type Balance = MockBalance;
 
 	fn ensure_ownership(
 		owner: &Self::AccountId,
@@ -377,18 +400,45 @@ impl AssetManager for MockAssetManager {
 		_asset: &Self::Asset,
 		from: &Self::AccountId,
 		fees_recipient: &Self::AccountId,
-	) -> Result<(), DispatchError> {
-		PREPARE_FEE.with(|fee| {
-			let f = *fee.borrow();
+	) -> Result<FeeReceipt<Self::AccountId, Self::Balance>, DispatchError> {
+		let fee = PREPARE_FEE.with(|fee| *fee.borrow());
+		let transfers = PREPARE_FEE_TRANSFERS.with(|transfers| transfers.borrow().clone());
+		let mut deposited = fee;
+		for (account, amount) in &transfers {
 			<Balances as Currency<MockAccountId>>::transfer(
 				from,
-				fees_recipient,
-				f,
+				account,
+				*amount,
 				ExistenceRequirement::AllowDeath,
-			)
-		})?;
+			)?;
+			deposited -= amount;
+		}
+		<Balances as Currency<MockAccountId>>::transfer(
+			from,
+			fees_recipient,
+			deposited,
+			ExistenceRequirement::AllowDeath,
+		)?;
+
+		Ok(FeeReceipt { base_fee: fee, transfers, burned: 0, deposited })
+	}
 
-		Ok(())
+	fn refund_asset_prepare_fee(
+		receipt: &FeeReceipt<Self::AccountId, Self::Balance>,
+		to: &Self::AccountId,
+		fees_recipient: &Self::AccountId,
+	) -> Self::Balance {
+		let refunded = <Balances as Currency<MockAccountId>>::transfer(
+			fees_recipient,
+			to,
+			receipt.deposited,
+			ExistenceRequirement::AllowDeath,
+		);
+		if refunded.is_ok() {
+			receipt.deposited
+		} else {
+			0
+		}
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
@@ -15,6 +15,7 @@
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
 use crate::{mock::*, traits::*, Error, *};
+use ajuna_primitives::fee_router::FeeReceipt;
 use frame_support::{
 	assert_err, assert_noop, assert_ok,
 	traits::tokens::nonfungibles_v2::{Create, Inspect},
@@ -37,6 +38,7 @@ fn create_collection(organizer: MockAccountId) -> MockCollectionId {
 
 pub const ALICE: MockAccountId = 1;
 pub const BOB: MockAccountId = 2;
+pub const CHARLIE: MockAccountId = 3;
 
 #[derive(Default)]
 pub struct ExtBuilder {
@@ -86,6 +88,131 @@ mod set_collection_id {
 	}
 }
 
+mod set_category_collection_id {
+	use super::*;
+	use ajuna_primitives::asset_manager::AssetManager;
+
+	#[test]
+	fn set_category_collection_id_works() {
+		ExtBuilder::default().build().execute_with(|| {
+			let (category, collection_id) = (7, 369);
+			assert_ok!(NftTransfer::set_category_collection_id(
+				RuntimeOrigin::signed(ALICE),
+				category,
+				Some(collection_id)
+			));
+			assert_eq!(CategoryCollections::<Test>::get(category), Some(collection_id));
+			System::assert_last_event(mock::RuntimeEvent::NftTransfer(
+				crate::Event::CategoryCollectionIdSet {
+					category,
+					collection_id: Some(collection_id),
+				},
+			));
+
+			assert_ok!(NftTransfer::set_category_collection_id(
+				RuntimeOrigin::signed(ALICE),
+				category,
+				None
+			));
+			assert_eq!(CategoryCollections::<Test>::get(category), None);
+		});
+	}
+
+	#[test]
+	fn set_category_collection_id_rejects_non_organizer_calls() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_noop!(
+				NftTransfer::set_category_collection_id(RuntimeOrigin::signed(BOB), 7, Some(333)),
+				DispatchError::Other(ACCOUNT_IS_NOT_ORGANIZER)
+			);
+		});
+	}
+
+	#[test]
+	fn collection_id_is_resolved_by_category() {
+		ExtBuilder::default().build().execute_with(|| {
+			let item = MockItem::new_with_field2(7);
+			assert_noop!(NftTransfer::collection_id_for(&item), Error::<Test>::CollectionIdNotSet);
+
+			CollectionId::<Test>::put(1);
+			assert_eq!(NftTransfer::collection_id_for(&item), Ok(1));
+
+			CategoryCollections::<Test>::insert(7, 2);
+			assert_eq!(NftTransfer::collection_id_for(&item), Ok(2));
+			assert_eq!(NftTransfer::collection_id_for(&MockItem::new_with_field2(8)), Ok(1));
+		});
+	}
+
+	#[test]
+	fn store_and_recover_use_category_collection() {
+		ExtBuilder::default()
+			.balances(&[
+				(ALICE, 2 * CollectionDeposit::get() + 999),
+				(BOB, ItemDeposit::get() + 9_999),
+			])
+			.build()
+			.execute_with(|| {
+				let default_collection_id = create_collection(ALICE);
+				let collection_id = create_collection(ALICE);
+				CollectionId::<Test>::put(default_collection_id);
+				ServiceAccount::<Test>::put(ALICE);
+
+				let asset_id = MockAssetManager::create_assets(BOB, 1)[0];
+				let category = MockItem::new_with_field2(0).nft_category();
+				assert_ok!(NftTransfer::set_category_collection_id(
+					RuntimeOrigin::signed(ALICE),
+					category,
+					Some(collection_id)
+				));
+
+				assert_ok!(NftTransfer::prepare_asset(RuntimeOrigin::signed(BOB), asset_id));
+				Preparation::<Test>::insert(
+					asset_id,
+					IpfsUrl::try_from(b"ipfs://test".to_vec()).unwrap(),
+				);
+				assert_ok!(NftTransfer::store_prepared_as_nft(
+					RuntimeOrigin::signed(BOB),
+					asset_id
+				));
+				assert_eq!(
+					NftStatuses::<Test>::get(collection_id, asset_id),
+					Some(NftStatus::Stored)
+				);
+				assert_eq!(NftStatuses::<Test>::get(default_collection_id, asset_id), None);
+				assert_eq!(ItemCollections::<Test>::get(asset_id), Some(collection_id));
+
+				// changing the category's collection doesn't affect already stored items
+				assert_ok!(NftTransfer::set_category_collection_id(
+					RuntimeOrigin::signed(ALICE),
+					category,
+					None
+				));
+				assert_ok!(NftTransfer::recover_asset_from_nft(
+					RuntimeOrigin::signed(BOB),
+					asset_id
+				));
+				System::assert_last_event(mock::RuntimeEvent::NftTransfer(
+					crate::Event::ItemRestored { collection_id, item_id: asset_id, owner: BOB },
+				));
+				assert_eq!(ItemCollections::<Test>::get(asset_id), None);
+			});
+	}
+
+	#[test]
+	fn recover_asset_from_nft_rejects_unknown_item_collection() {
+		ExtBuilder::default().build().execute_with(|| {
+			let asset_id = MockAssetManager::create_assets(BOB, 1)[0];
+			MockAssetManager::lock_asset(<Test as Config>::PalletId::get().0, BOB, asset_id)
+				.unwrap();
+
+			assert_noop!(
+				NftTransfer::recover_asset_from_nft(RuntimeOrigin::signed(BOB), asset_id),
+				Error::<Test>::UnknownItemCollection
+			);
+		});
+	}
+}
+
 mod set_service_account {
 	use super::*;
 	#[test]
@@ -131,6 +258,18 @@ mod prepare_asset {
 				assert_ok!(NftTransfer::prepare_asset(RuntimeOrigin::signed(ALICE), asset_id));
 				assert_eq!(Balances::free_balance(ALICE), initial_balance - prepare_fee);
 				assert_eq!(Preparation::<Test>::get(asset_id).unwrap().to_vec(), Vec::<u8>::new());
+				let pending = PreparationDeadlines::<Test>::get(asset_id).unwrap();
+				assert_eq!(pending.deadline, 1 + PreparationTimeout::get());
+				assert_eq!(pending.fees_recipient, BOB);
+				assert_eq!(
+					pending.fee(),
+					FeeReceipt {
+						base_fee: prepare_fee,
+						transfers: vec![],
+						burned: 0,
+						deposited: prepare_fee,
+					}
+				);
 				System::assert_last_event(mock::RuntimeEvent::NftTransfer(
 					crate::Event::PreparedAvatar { asset_id },
 				));
@@ -216,6 +355,7 @@ mod prepare_asset {
 
 mod unprepare_asset {
 	use super::*;
+	use ajuna_primitives::asset_manager::AssetManager;
 	use sp_runtime::DispatchError;
 
 	#[test]
@@ -232,6 +372,58 @@ mod unprepare_asset {
 		});
 	}
 
+	#[test]
+	fn unprepare_avatar_unlocks_without_refund_before_the_deadline() {
+		let prepare_fee = 999;
+		let initial_balance = 2 * prepare_fee + MockExistentialDeposit::get();
+
+		ExtBuilder::default()
+			.balances(&[(ALICE, initial_balance), (BOB, MockExistentialDeposit::get())])
+			.build()
+			.execute_with(|| {
+				let asset_id = MockAssetManager::create_assets(ALICE, 1)[0];
+				assert_ok!(NftTransfer::set_service_account(RuntimeOrigin::root(), BOB));
+				assert_ok!(NftTransfer::prepare_asset(RuntimeOrigin::signed(ALICE), asset_id));
+				assert_eq!(Balances::free_balance(ALICE), initial_balance - prepare_fee);
+
+				assert_ok!(NftTransfer::unprepare_asset(RuntimeOrigin::signed(ALICE), asset_id));
+				assert_eq!(Balances::free_balance(ALICE), initial_balance - prepare_fee);
+				assert_eq!(
+					Balances::free_balance(BOB),
+					MockExistentialDeposit::get() + prepare_fee
+				);
+				assert!(MockAssetManager::is_locked(&asset_id).is_none());
+				assert!(PreparationDeadlines::<Test>::get(asset_id).is_none());
+
+				// the asset can be prepared again
+				assert_ok!(NftTransfer::prepare_asset(RuntimeOrigin::signed(ALICE), asset_id));
+			});
+	}
+
+	#[test]
+	fn unprepare_avatar_unlocks_without_refund_once_ipfs_is_prepared() {
+		let prepare_fee = 999;
+		let initial_balance = prepare_fee + MockExistentialDeposit::get();
+
+		ExtBuilder::default()
+			.balances(&[(ALICE, initial_balance), (BOB, MockExistentialDeposit::get())])
+			.build()
+			.execute_with(|| {
+				let asset_id = MockAssetManager::create_assets(ALICE, 1)[0];
+				assert_ok!(NftTransfer::set_service_account(RuntimeOrigin::root(), BOB));
+				assert_ok!(NftTransfer::prepare_asset(RuntimeOrigin::signed(ALICE), asset_id));
+				assert_ok!(NftTransfer::prepare_ipfs(
+					RuntimeOrigin::signed(BOB),
+					asset_id,
+					IpfsUrl::try_from(b"ipfs://test".to_vec()).unwrap()
+				));
+
+				assert_ok!(NftTransfer::unprepare_asset(RuntimeOrigin::signed(ALICE), asset_id));
+				assert_eq!(Balances::free_balance(ALICE), initial_balance - prepare_fee);
+				assert!(MockAssetManager::is_locked(&asset_id).is_none());
+			});
+	}
+
 	#[test]
 	fn unprepare_avatar_rejects_unsigned_calls() {
 		ExtBuilder::default().build().execute_with(|| {
@@ -330,6 +522,215 @@ mod prepare_ipfs {
 	}
 }
 
+mod cancel_preparation {
+	use super::*;
+	use ajuna_primitives::asset_manager::AssetManager;
+
+	const PREPARE_FEE: MockBalance = 999;
+
+	fn prepare(asset_id: ItemId) {
+		assert_ok!(NftTransfer::set_service_account(RuntimeOrigin::root(), BOB));
+		assert_ok!(NftTransfer::prepare_asset(RuntimeOrigin::signed(ALICE), asset_id));
+	}
+
+	#[test]
+	fn cancel_preparation_works() {
+		let initial_balance = PREPARE_FEE + MockExistentialDeposit::get();
+
+		ExtBuilder::default()
+			.balances(&[(ALICE, initial_balance), (BOB, MockExistentialDeposit::get())])
+			.build()
+			.execute_with(|| {
+				let asset_id = MockAssetManager::create_assets(ALICE, 1)[0];
+				prepare(asset_id);
+				assert_eq!(Balances::free_balance(ALICE), initial_balance - PREPARE_FEE);
+
+				System::set_block_number(System::block_number() + PreparationTimeout::get() + 1);
+				assert_ok!(NftTransfer::cancel_preparation(RuntimeOrigin::signed(ALICE), asset_id));
+
+				assert_eq!(Balances::free_balance(ALICE), initial_balance);
+				assert_eq!(Balances::free_balance(BOB), MockExistentialDeposit::get());
+				assert!(MockAssetManager::is_locked(&asset_id).is_none());
+				assert!(Preparation::<Test>::get(asset_id).is_none());
+				assert!(PreparationDeadlines::<Test>::get(asset_id).is_none());
+				System::assert_last_event(mock::RuntimeEvent::NftTransfer(
+					crate::Event::PreparationCancelled { asset_id, refunded: PREPARE_FEE },
+				));
+			});
+	}
+
+	#[test]
+	fn cancel_preparation_refunds_the_fee_paid() {
+		let initial_balance = PREPARE_FEE + MockExistentialDeposit::get();
+		let service_balance = 10 * PREPARE_FEE;
+
+		ExtBuilder::default()
+			.balances(&[(ALICE, initial_balance), (BOB, service_balance)])
+			.build()
+			.execute_with(|| {
+				let asset_id = MockAssetManager::create_assets(ALICE, 1)[0];
+				prepare(asset_id);
+
+				// the fee changing in the meantime doesn't affect the refund
+				MockAssetManager::set_prepare_fee(2 * PREPARE_FEE);
+				System::set_block_number(System::block_number() + PreparationTimeout::get() + 1);
+				assert_ok!(NftTransfer::cancel_preparation(RuntimeOrigin::signed(ALICE), asset_id));
+
+				assert_eq!(Balances::free_balance(ALICE), initial_balance);
+				assert_eq!(Balances::free_balance(BOB), service_balance);
+			});
+	}
+
+	#[test]
+	fn cancel_preparation_refunds_only_from_the_fees_recipient() {
+		let initial_balance = PREPARE_FEE + MockExistentialDeposit::get();
+		let transferred = 100;
+
+		ExtBuilder::default()
+			.balances(&[
+				(ALICE, initial_balance),
+				(BOB, MockExistentialDeposit::get()),
+				(CHARLIE, MockExistentialDeposit::get()),
+			])
+			.build()
+			.execute_with(|| {
+				let asset_id = MockAssetManager::create_assets(ALICE, 1)[0];
+				MockAssetManager::set_prepare_fee_transfers(vec![(CHARLIE, transferred)]);
+				prepare(asset_id);
+
+				// the service account changing in the meantime doesn't affect the refund
+				assert_ok!(NftTransfer::set_service_account(RuntimeOrigin::root(), CHARLIE));
+				System::set_block_number(System::block_number() + PreparationTimeout::get() + 1);
+				assert_ok!(NftTransfer::cancel_preparation(RuntimeOrigin::signed(ALICE), asset_id));
+
+				// the part transferred to another account is not refunded
+				assert_eq!(Balances::free_balance(ALICE), initial_balance - transferred);
+				assert_eq!(Balances::free_balance(BOB), MockExistentialDeposit::get());
+				assert_eq!(
+					Balances::free_balance(CHARLIE),
+					MockExistentialDeposit::get() + transferred
+				);
+			});
+	}
+
+	#[test]
+	fn cancel_preparation_unlocks_the_asset_when_the_fee_cannot_be_refunded() {
+		let initial_balance = PREPARE_FEE + MockExistentialDeposit::get();
+		let transferred = 100;
+
+		ExtBuilder::default()
+			.balances(&[
+				(ALICE, initial_balance),
+				(BOB, MockExistentialDeposit::get()),
+				(CHARLIE, MockExistentialDeposit::get()),
+			])
+			.build()
+			.execute_with(|| {
+				let asset_id = MockAssetManager::create_assets(ALICE, 1)[0];
+				MockAssetManager::set_prepare_fee_transfers(vec![(CHARLIE, transferred)]);
+				prepare(asset_id);
+
+				// the service account has been emptied
+				assert_ok!(Balances::transfer_all(RuntimeOrigin::signed(BOB), CHARLIE, false));
+				System::set_block_number(System::block_number() + PreparationTimeout::get() + 1);
+				assert_ok!(NftTransfer::cancel_preparation(RuntimeOrigin::signed(ALICE), asset_id));
+
+				assert_eq!(Balances::free_balance(ALICE), MockExistentialDeposit::get());
+				assert!(MockAssetManager::is_locked(&asset_id).is_none());
+				assert!(Preparation::<Test>::get(asset_id).is_none());
+				assert!(PreparationDeadlines::<Test>::get(asset_id).is_none());
+				System::assert_last_event(mock::RuntimeEvent::NftTransfer(
+					crate::Event::PreparationCancelled { asset_id, refunded: 0 },
+				));
+			});
+	}
+
+	#[test]
+	fn prepare_asset_rejects_more_fee_transfers_than_can_be_refunded() {
+		ExtBuilder::default()
+			.balances(&[
+				(ALICE, PREPARE_FEE + MockExistentialDeposit::get()),
+				(BOB, MockExistentialDeposit::get()),
+				(CHARLIE, MockExistentialDeposit::get()),
+			])
+			.build()
+			.execute_with(|| {
+				let asset_id = MockAssetManager::create_assets(ALICE, 1)[0];
+				MockAssetManager::set_prepare_fee_transfers(vec![(CHARLIE, 1); 3]);
+				assert_ok!(NftTransfer::set_service_account(RuntimeOrigin::root(), BOB));
+				assert_noop!(
+					NftTransfer::prepare_asset(RuntimeOrigin::signed(ALICE), asset_id),
+					Error::<Test>::TooManyPrepareFeeTransfers
+				);
+			});
+	}
+
+	#[test]
+	fn cancel_preparation_rejects_before_deadline() {
+		ExtBuilder::default()
+			.balances(&[
+				(ALICE, PREPARE_FEE + MockExistentialDeposit::get()),
+				(BOB, MockExistentialDeposit::get()),
+			])
+			.build()
+			.execute_with(|| {
+				let asset_id = MockAssetManager::create_assets(ALICE, 1)[0];
+				prepare(asset_id);
+
+				System::set_block_number(System::block_number() + PreparationTimeout::get());
+				assert_noop!(
+					NftTransfer::cancel_preparation(RuntimeOrigin::signed(ALICE), asset_id),
+					Error::<Test>::PreparationNotExpired
+				);
+			});
+	}
+
+	#[test]
+	fn cancel_preparation_rejects_prepared_ipfs_url() {
+		ExtBuilder::default()
+			.balances(&[
+				(ALICE, PREPARE_FEE + MockExistentialDeposit::get()),
+				(BOB, MockExistentialDeposit::get()),
+			])
+			.build()
+			.execute_with(|| {
+				let asset_id = MockAssetManager::create_assets(ALICE, 1)[0];
+				prepare(asset_id);
+				assert_ok!(NftTransfer::prepare_ipfs(
+					RuntimeOrigin::signed(BOB),
+					asset_id,
+					b"ipfs://test".to_vec().try_into().unwrap()
+				));
+
+				System::set_block_number(System::block_number() + PreparationTimeout::get() + 1);
+				assert_noop!(
+					NftTransfer::cancel_preparation(RuntimeOrigin::signed(ALICE), asset_id),
+					Error::<Test>::NoPendingPreparation
+				);
+			});
+	}
+
+	#[test]
+	fn cancel_preparation_rejects_non_owner() {
+		ExtBuilder::default()
+			.balances(&[
+				(ALICE, PREPARE_FEE + MockExistentialDeposit::get()),
+				(BOB, MockExistentialDeposit::get()),
+			])
+			.build()
+			.execute_with(|| {
+				let asset_id = MockAssetManager::create_assets(ALICE, 1)[0];
+				prepare(asset_id);
+
+				System::set_block_number(System::block_number() + PreparationTimeout::get() + 1);
+				assert_noop!(
+					NftTransfer::cancel_preparation(RuntimeOrigin::signed(BOB), asset_id),
+					DispatchError::Other(NOT_OWNER_ERR)
+				);
+			});
+	}
+}
+
 mod store_as_nft {
 	use super::*;
 	use sp_runtime::traits::Get;
@@ -625,3 +1026,377 @@ mod recover_from_nft {
 			});
 	}
 }
+
+mod nft_transit {
+	use super::*;
+
+	const SERVICE_ACCOUNT: MockAccountId = 3;
+
+	fn setup_stored_nft() -> (MockCollectionId, ItemId) {
+		let collection_id = create_collection(ALICE);
+		let item_id = H256::random();
+		let url = b"ipfs://test".to_vec();
+		assert_ok!(NftTransfer::store_as_nft(
+			BOB,
+			collection_id,
+			item_id,
+			MockItem::default(),
+			url.try_into().unwrap()
+		));
+		ServiceAccount::<Test>::put(SERVICE_ACCOUNT);
+		(collection_id, item_id)
+	}
+
+	fn reference() -> ExternalReference {
+		b"external://item".to_vec().try_into().unwrap()
+	}
+
+	fn upload(collection_id: MockCollectionId, item_id: ItemId) {
+		assert_ok!(NftTransfer::schedule_nft_upload(
+			RuntimeOrigin::signed(BOB),
+			collection_id,
+			item_id
+		));
+		assert_ok!(NftTransfer::confirm_nft_upload(
+			RuntimeOrigin::signed(SERVICE_ACCOUNT),
+			collection_id,
+			item_id,
+			reference()
+		));
+	}
+
+	#[test]
+	fn can_upload_and_return_nft() {
+		ExtBuilder::default()
+			.balances(&[(ALICE, CollectionDeposit::get() + 999), (BOB, ItemDeposit::get() + 999)])
+			.build()
+			.execute_with(|| {
+				let (collection_id, item_id) = setup_stored_nft();
+				let deadline = 1 + TransitTimeout::get();
+
+				assert_ok!(NftTransfer::schedule_nft_upload(
+					RuntimeOrigin::signed(BOB),
+					collection_id,
+					item_id
+				));
+				assert_eq!(
+					NftStatuses::<Test>::get(collection_id, item_id),
+					Some(NftStatus::UploadScheduled)
+				);
+				assert_eq!(
+					NftTransits::<Test>::get(collection_id, item_id),
+					Some(NftTransit { owner: BOB, deadline })
+				);
+				System::assert_last_event(mock::RuntimeEvent::NftTransfer(
+					crate::Event::UploadScheduled { collection_id, item_id, owner: BOB, deadline },
+				));
+
+				assert_ok!(NftTransfer::confirm_nft_upload(
+					RuntimeOrigin::signed(SERVICE_ACCOUNT),
+					collection_id,
+					item_id,
+					reference()
+				));
+				assert_eq!(
+					NftStatuses::<Test>::get(collection_id, item_id),
+					Some(NftStatus::Uploaded)
+				);
+				assert_eq!(NftReferences::<Test>::get(collection_id, item_id), Some(reference()));
+				assert!(NftTransits::<Test>::get(collection_id, item_id).is_none());
+				System::assert_last_event(mock::RuntimeEvent::NftTransfer(
+					crate::Event::UploadConfirmed {
+						collection_id,
+						item_id,
+						reference: reference(),
+					},
+				));
+
+				assert_ok!(NftTransfer::schedule_nft_return(
+					RuntimeOrigin::signed(BOB),
+					collection_id,
+					item_id
+				));
+				assert_eq!(
+					NftStatuses::<Test>::get(collection_id, item_id),
+					Some(NftStatus::ReturnScheduled)
+				);
+				System::assert_last_event(mock::RuntimeEvent::NftTransfer(
+					crate::Event::ReturnScheduled { collection_id, item_id, owner: BOB, deadline },
+				));
+
+				assert_ok!(NftTransfer::confirm_nft_return(
+					RuntimeOrigin::signed(SERVICE_ACCOUNT),
+					collection_id,
+					item_id
+				));
+				assert_eq!(
+					NftStatuses::<Test>::get(collection_id, item_id),
+					Some(NftStatus::Stored)
+				);
+				assert!(NftReferences::<Test>::get(collection_id, item_id).is_none());
+				assert!(NftTransits::<Test>::get(collection_id, item_id).is_none());
+				System::assert_last_event(mock::RuntimeEvent::NftTransfer(
+					crate::Event::ReturnConfirmed { collection_id, item_id },
+				));
+			});
+	}
+
+	#[test]
+	fn nft_is_locked_while_outside_of_chain() {
+		ExtBuilder::default()
+			.balances(&[(ALICE, CollectionDeposit::get() + 999), (BOB, ItemDeposit::get() + 999)])
+			.build()
+			.execute_with(|| {
+				let (collection_id, item_id) = setup_stored_nft();
+				upload(collection_id, item_id);
+
+				assert_noop!(
+					Nft::transfer(RuntimeOrigin::signed(BOB), collection_id, item_id, ALICE),
+					pallet_nfts::Error::<Test>::ItemLocked
+				);
+				assert_noop!(
+					NftTransfer::recover_from_nft(BOB, collection_id, item_id)
+						as Result<MockItem, _>,
+					Error::<Test>::NftOutsideOfChain
+				);
+			});
+	}
+
+	#[test]
+	fn cannot_schedule_upload_if_not_owned() {
+		ExtBuilder::default()
+			.balances(&[(ALICE, CollectionDeposit::get() + 999), (BOB, ItemDeposit::get() + 999)])
+			.build()
+			.execute_with(|| {
+				let (collection_id, item_id) = setup_stored_nft();
+				assert_noop!(
+					NftTransfer::schedule_nft_upload(
+						RuntimeOrigin::signed(ALICE),
+						collection_id,
+						item_id
+					),
+					Error::<Test>::NftNotOwned
+				);
+			});
+	}
+
+	#[test]
+	fn cannot_schedule_upload_twice() {
+		ExtBuilder::default()
+			.balances(&[(ALICE, CollectionDeposit::get() + 999), (BOB, ItemDeposit::get() + 999)])
+			.build()
+			.execute_with(|| {
+				let (collection_id, item_id) = setup_stored_nft();
+				assert_ok!(NftTransfer::schedule_nft_upload(
+					RuntimeOrigin::signed(BOB),
+					collection_id,
+					item_id
+				));
+				assert_noop!(
+					NftTransfer::schedule_nft_upload(
+						RuntimeOrigin::signed(BOB),
+						collection_id,
+						item_id
+					),
+					Error::<Test>::InvalidNftStatus
+				);
+			});
+	}
+
+	#[test]
+	fn cannot_confirm_without_service_account() {
+		ExtBuilder::default()
+			.balances(&[(ALICE, CollectionDeposit::get() + 999), (BOB, ItemDeposit::get() + 999)])
+			.build()
+			.execute_with(|| {
+				let (collection_id, item_id) = setup_stored_nft();
+				assert_ok!(NftTransfer::schedule_nft_upload(
+					RuntimeOrigin::signed(BOB),
+					collection_id,
+					item_id
+				));
+				assert_noop!(
+					NftTransfer::confirm_nft_upload(
+						RuntimeOrigin::signed(BOB),
+						collection_id,
+						item_id,
+						reference()
+					),
+					DispatchError::BadOrigin
+				);
+				assert_noop!(
+					NftTransfer::confirm_nft_upload(
+						RuntimeOrigin::signed(SERVICE_ACCOUNT),
+						collection_id,
+						item_id,
+						ExternalReference::default()
+					),
+					Error::<Test>::EmptyExternalReference
+				);
+			});
+	}
+
+	#[test]
+	fn cannot_confirm_expired_transit() {
+		ExtBuilder::default()
+			.balances(&[(ALICE, CollectionDeposit::get() + 999), (BOB, ItemDeposit::get() + 999)])
+			.build()
+			.execute_with(|| {
+				let (collection_id, item_id) = setup_stored_nft();
+				assert_ok!(NftTransfer::schedule_nft_upload(
+					RuntimeOrigin::signed(BOB),
+					collection_id,
+					item_id
+				));
+				System::set_block_number(System::block_number() + TransitTimeout::get() + 1);
+				assert_noop!(
+					NftTransfer::confirm_nft_upload(
+						RuntimeOrigin::signed(SERVICE_ACCOUNT),
+						collection_id,
+						item_id,
+						reference()
+					),
+					Error::<Test>::TransitExpired
+				);
+			});
+	}
+
+	#[test]
+	fn can_rollback_expired_upload() {
+		ExtBuilder::default()
+			.balances(&[(ALICE, CollectionDeposit::get() + 999), (BOB, ItemDeposit::get() + 999)])
+			.build()
+			.execute_with(|| {
+				let (collection_id, item_id) = setup_stored_nft();
+				assert_ok!(NftTransfer::schedule_nft_upload(
+					RuntimeOrigin::signed(BOB),
+					collection_id,
+					item_id
+				));
+
+				System::set_block_number(System::block_number() + TransitTimeout::get());
+				assert_noop!(
+					NftTransfer::rollback_nft_transit(
+						RuntimeOrigin::signed(BOB),
+						collection_id,
+						item_id
+					),
+					Error::<Test>::TransitNotExpired
+				);
+
+				System::set_block_number(System::block_number() + 1);
+				assert_noop!(
+					NftTransfer::rollback_nft_transit(
+						RuntimeOrigin::signed(ALICE),
+						collection_id,
+						item_id
+					),
+					Error::<Test>::NftNotOwned
+				);
+				assert_ok!(NftTransfer::rollback_nft_transit(
+					RuntimeOrigin::signed(BOB),
+					collection_id,
+					item_id
+				));
+				assert_eq!(
+					NftStatuses::<Test>::get(collection_id, item_id),
+					Some(NftStatus::Stored)
+				);
+				assert!(NftTransits::<Test>::get(collection_id, item_id).is_none());
+				System::assert_last_event(mock::RuntimeEvent::NftTransfer(
+					crate::Event::TransitRolledBack {
+						collection_id,
+						item_id,
+						status: NftStatus::Stored,
+					},
+				));
+			});
+	}
+
+	#[test]
+	fn can_rollback_expired_return() {
+		ExtBuilder::default()
+			.balances(&[(ALICE, CollectionDeposit::get() + 999), (BOB, ItemDeposit::get() + 999)])
+			.build()
+			.execute_with(|| {
+				let (collection_id, item_id) = setup_stored_nft();
+				upload(collection_id, item_id);
+				assert_ok!(NftTransfer::schedule_nft_return(
+					RuntimeOrigin::signed(BOB),
+					collection_id,
+					item_id
+				));
+
+				System::set_block_number(System::block_number() + TransitTimeout::get() + 1);
+				assert_ok!(NftTransfer::rollback_nft_transit(
+					RuntimeOrigin::signed(BOB),
+					collection_id,
+					item_id
+				));
+				assert_eq!(
+					NftStatuses::<Test>::get(collection_id, item_id),
+					Some(NftStatus::Uploaded)
+				);
+				assert_eq!(NftReferences::<Test>::get(collection_id, item_id), Some(reference()));
+				System::assert_last_event(mock::RuntimeEvent::NftTransfer(
+					crate::Event::TransitRolledBack {
+						collection_id,
+						item_id,
+						status: NftStatus::Uploaded,
+					},
+				));
+			});
+	}
+}
+
+mod migration {
+	use super::*;
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	#[test]
+	fn v1_records_the_collection_of_stored_items() {
+		ExtBuilder::default().build().execute_with(|| {
+			StorageVersion::new(0).put::<NftTransfer>();
+			let item_1 = H256::random();
+			let item_2 = H256::random();
+			NftStatuses::<Test>::insert(1, item_1, NftStatus::Stored);
+			NftStatuses::<Test>::insert(2, item_2, NftStatus::Uploaded);
+
+			crate::migration::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+			assert_eq!(NftTransfer::on_chain_storage_version(), StorageVersion::new(1));
+			assert_eq!(ItemCollections::<Test>::get(item_1), Some(1));
+			assert_eq!(ItemCollections::<Test>::get(item_2), Some(2));
+		});
+	}
+
+	#[test]
+	fn v1_gives_pending_preparations_a_deadline() {
+		ExtBuilder::default().build().execute_with(|| {
+			StorageVersion::new(0).put::<NftTransfer>();
+			ServiceAccount::<Test>::put(BOB);
+			let pending = H256::random();
+			let prepared = H256::random();
+			Preparation::<Test>::insert(pending, IpfsUrl::default());
+			Preparation::<Test>::insert(
+				prepared,
+				IpfsUrl::try_from(b"ipfs://test".to_vec()).unwrap(),
+			);
+
+			crate::migration::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+			assert_eq!(
+				PreparationDeadlines::<Test>::get(pending),
+				Some(PendingPreparation {
+					deadline: System::block_number() + PreparationTimeout::get(),
+					fees_recipient: BOB,
+					base_fee: 0,
+					transfers: Default::default(),
+					burned: 0,
+					deposited: 0,
+				})
+			);
+			assert_eq!(PreparationDeadlines::<Test>::get(prepared), None);
+		});
+	}
+}
@@ -25,6 +25,9 @@ pub type NFTAttribute<N> = BoundedVec<u8, N>;
 /// Type to denote an IPFS URL.
 pub type IpfsUrl = BoundedVec<u8, ConstU32<100>>;
 
+/// Type to denote the reference of an NFT stored outside the chain.
+pub type ExternalReference = BoundedVec<u8, ConstU32<100>>;
+
 /// Marker trait for items that can be converted back and forth into an NFT representation.
 pub trait NftConvertible<KL, VL>: Codec {
 	/// Numeric key used to identify this item as an NFT attribute.
@@ -39,6 +42,16 @@ pub trait NftConvertible<KL, VL>: Codec {
 	fn get_encoded_attributes(&self) -> Vec<(NFTAttribute<KL>, NFTAttribute<VL>)>;
 }
 
+/// Trait for items that are grouped into categories, allowing each category to be stored in its
+/// own NFT collection.
+pub trait NftCategorized {
+	/// Type used to identify the category of an item.
+	type Category;
+
+	/// Returns the category this item belongs to.
+	fn nft_category(&self) -> Self::Category;
+}
+
 /// Trait to define the transformation and bridging of NFT items.
 pub trait NftHandler<Account, ItemId, KL, VL, Item: NftConvertible<KL, VL>> {
 	type CollectionId: AtLeast32BitUnsigned + Codec + Parameter + MaxEncodedLen;
@@ -64,10 +77,8 @@ pub trait NftHandler<Account, ItemId, KL, VL, Item: NftConvertible<KL, VL>> {
 	/// an external source. Once this process completes the item is locked until transported back
 	/// from the external source into the chain.
 	fn schedule_upload(
-		_owner: Account,
-		_collection_id: Self::CollectionId,
-		_item_id: ItemId,
-	) -> DispatchResult {
-		todo!("will be implemented when bridge is ready")
-	}
+		owner: Account,
+		collection_id: Self::CollectionId,
+		item_id: ItemId,
+	) -> DispatchResult;
 }
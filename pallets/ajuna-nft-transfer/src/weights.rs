@@ -1,11 +1,11 @@
-
-//! Autogenerated weights for pallet_ajuna_awesome_avatars
+//! Weights for pallet_ajuna_nft_transfer
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2023-06-14, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `weight-calculation`, CPU: `DO-Regular`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//! Only the weights of `set_collection_id`, `lock_asset`, `unlock_asset` and
+//! `set_service_account` were generated by the benchmark CLI, on 2023-06-14 with the command
+//! below. The other weights are estimates, as their benchmarks have never been run since this
+//! workspace doesn't build without the node: their reads and writes are counted from the code and
+//! their proof sizes add up the maximum encoded size of every item read. Regenerate this file
+//! before using these calls in a runtime.
 
 // Executed Command:
 // ./target/release/bajun-para
@@ -38,6 +38,13 @@ pub trait WeightInfo {
     fn prepare_asset() -> Weight;
     fn unprepare_asset() -> Weight;
     fn prepare_ipfs() -> Weight;
+    fn schedule_nft_upload() -> Weight;
+    fn confirm_nft_upload() -> Weight;
+    fn schedule_nft_return() -> Weight;
+    fn confirm_nft_return() -> Weight;
+    fn rollback_nft_transit() -> Weight;
+    fn set_category_collection_id() -> Weight;
+    fn cancel_preparation() -> Weight;
 }
 
 /// Weights for pallet_ajuna_awesome_avatars using the Substrate node and recommended hardware.
@@ -88,6 +95,8 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
     /// Proof: NftTransfer NftStatuses (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
     /// Storage: Nft Account (r:0 w:1)
     /// Proof: Nft Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+    /// Storage: NftTransfer ItemCollections (r:0 w:1)
+    /// Proof: NftTransfer ItemCollections (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
     /// The range of component `n` is `[1, 200]`.
     fn lock_asset() -> Weight {
         // Proof Size summary in bytes:
@@ -98,7 +107,7 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
             // Standard Error: 170_806
             .saturating_add(Weight::from_parts(555_196, 0))
             .saturating_add(T::DbWeight::get().reads(20_u64))
-            .saturating_add(T::DbWeight::get().writes(17_u64))
+            .saturating_add(T::DbWeight::get().writes(18_u64))
     }
     /// Storage: AwesomeAvatars Avatars (r:1 w:1)
     /// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
@@ -136,6 +145,8 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
     /// Proof: Nft ItemAttributesApprovalsOf (max_values: None, max_size: Some(389), added: 2864, mode: MaxEncodedLen)
     /// Storage: Nft PendingSwapOf (r:0 w:1)
     /// Proof: Nft PendingSwapOf (max_values: None, max_size: Some(127), added: 2602, mode: MaxEncodedLen)
+    /// Storage: NftTransfer ItemCollections (r:1 w:1)
+    /// Proof: NftTransfer ItemCollections (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
     /// The range of component `n` is `[1, 200]`.
     fn unlock_asset() -> Weight {
         // Proof Size summary in bytes:
@@ -143,8 +154,8 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
         //  Estimated: `18812`
         // Minimum execution time: 423_878_000 picoseconds.
         Weight::from_parts(910_723_452, 18812)
-            .saturating_add(T::DbWeight::get().reads(20_u64))
-            .saturating_add(T::DbWeight::get().writes(19_u64))
+            .saturating_add(T::DbWeight::get().reads(21_u64))
+            .saturating_add(T::DbWeight::get().writes(20_u64))
     }
     /// Storage: AwesomeAvatars ServiceAccount (r:0 w:1)
     /// Proof: AwesomeAvatars ServiceAccount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
@@ -156,61 +167,168 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
         Weight::from_parts(20_751_000, 0)
             .saturating_add(T::DbWeight::get().writes(1_u64))
     }
-    /// Storage: AwesomeAvatars Avatars (r:1 w:0)
+    /// Storage: NftTransfer Preparation (r:1 w:1)
+    /// Proof: NftTransfer Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+    /// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+    /// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
+    /// Storage: AwesomeAvatars Avatars (r:1 w:1)
     /// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
     /// Storage: AwesomeAvatars Trade (r:1 w:0)
     /// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
-    /// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
-    /// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
-    /// Storage: AwesomeAvatars LockedAvatars (r:1 w:0)
-    /// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
-    /// Storage: AwesomeAvatars Preparation (r:1 w:1)
-    /// Proof: AwesomeAvatars Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
-    /// Storage: AwesomeAvatars ServiceAccount (r:1 w:0)
-    /// Proof: AwesomeAvatars ServiceAccount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+    /// Storage: AwesomeAvatars LockedAvatars (r:1 w:1)
+    /// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+    /// Storage: AwesomeAvatars Owners (r:1 w:1)
+    /// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+    /// Storage: NftTransfer ServiceAccount (r:1 w:0)
+    /// Proof: NftTransfer ServiceAccount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
     /// Storage: AwesomeAvatars Seasons (r:1 w:0)
     /// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
     /// Storage: System Account (r:2 w:2)
     /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: NftTransfer PreparationDeadlines (r:0 w:1)
+    /// Proof: NftTransfer PreparationDeadlines (max_values: None, max_size: Some(213), added: 2688, mode: MaxEncodedLen)
     fn prepare_asset() -> Weight {
         // Proof Size summary in bytes:
-        //  Measured:  `1861`
-        //  Estimated: `6196`
-        // Minimum execution time: 177_929_000 picoseconds.
-        Weight::from_parts(183_872_000, 6196)
-            .saturating_add(T::DbWeight::get().reads(9_u64))
-            .saturating_add(T::DbWeight::get().writes(3_u64))
+        //  Estimated: `30595`
+        Weight::from_parts(195_640_000, 30595)
+            .saturating_add(T::DbWeight::get().reads(10_u64))
+            .saturating_add(T::DbWeight::get().writes(7_u64))
     }
-    /// Storage: AwesomeAvatars Avatars (r:1 w:0)
+    /// Storage: AwesomeAvatars Avatars (r:1 w:1)
     /// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+    /// Storage: AwesomeAvatars LockedAvatars (r:1 w:1)
+    /// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
     /// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
     /// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
-    /// Storage: AwesomeAvatars Preparation (r:1 w:1)
-    /// Proof: AwesomeAvatars Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+    /// Storage: NftTransfer Preparation (r:1 w:1)
+    /// Proof: NftTransfer Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+    /// Storage: AwesomeAvatars Owners (r:1 w:1)
+    /// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+    /// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:0)
+    /// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
+    /// Storage: NftTransfer PreparationDeadlines (r:0 w:1)
+    /// Proof: NftTransfer PreparationDeadlines (max_values: None, max_size: Some(213), added: 2688, mode: MaxEncodedLen)
     fn unprepare_asset() -> Weight {
         // Proof Size summary in bytes:
-        //  Measured:  `344`
-        //  Estimated: `3638`
-        // Minimum execution time: 42_265_000 picoseconds.
-        Weight::from_parts(45_537_000, 3638)
-            .saturating_add(T::DbWeight::get().reads(3_u64))
-            .saturating_add(T::DbWeight::get().writes(1_u64))
+        //  Estimated: `24733`
+        Weight::from_parts(240_879_000, 24733)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(5_u64))
     }
-    /// Storage: AwesomeAvatars ServiceAccount (r:1 w:0)
-    /// Proof: AwesomeAvatars ServiceAccount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+    /// Storage: NftTransfer ServiceAccount (r:1 w:0)
+    /// Proof: NftTransfer ServiceAccount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
     /// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
     /// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
-    /// Storage: AwesomeAvatars Preparation (r:1 w:1)
-    /// Proof: AwesomeAvatars Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+    /// Storage: NftTransfer Preparation (r:1 w:1)
+    /// Proof: NftTransfer Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+    /// Storage: NftTransfer PreparationDeadlines (r:0 w:1)
+    /// Proof: NftTransfer PreparationDeadlines (max_values: None, max_size: Some(213), added: 2688, mode: MaxEncodedLen)
     fn prepare_ipfs() -> Weight {
         // Proof Size summary in bytes:
-        //  Measured:  `318`
-        //  Estimated: `3579`
-        // Minimum execution time: 40_493_000 picoseconds.
-        Weight::from_parts(41_931_000, 3579)
+        //  Estimated: `4616`
+        Weight::from_parts(45_870_000, 4616)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: Nft Item (r:1 w:0)
+    /// Proof: Nft Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+    /// Storage: NftTransfer NftStatuses (r:1 w:1)
+    /// Proof: NftTransfer NftStatuses (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+    /// Storage: NftTransfer NftTransits (r:0 w:1)
+    /// Proof: NftTransfer NftTransits (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+    fn schedule_nft_upload() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `4326`
+        Weight::from_parts(128_156_000, 4326)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: NftTransfer ServiceAccount (r:1 w:0)
+    /// Proof: NftTransfer ServiceAccount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+    /// Storage: NftTransfer NftTransits (r:1 w:1)
+    /// Proof: NftTransfer NftTransits (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+    /// Storage: NftTransfer NftStatuses (r:1 w:1)
+    /// Proof: NftTransfer NftStatuses (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+    /// Storage: NftTransfer NftReferences (r:0 w:1)
+    /// Proof: NftTransfer NftReferences (max_values: None, max_size: Some(169), added: 2644, mode: MaxEncodedLen)
+    fn confirm_nft_upload() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3569`
+        Weight::from_parts(117_598_000, 3569)
             .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Storage: Nft Item (r:1 w:0)
+    /// Proof: Nft Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+    /// Storage: NftTransfer NftStatuses (r:1 w:1)
+    /// Proof: NftTransfer NftStatuses (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+    /// Storage: NftTransfer NftTransits (r:0 w:1)
+    /// Proof: NftTransfer NftTransits (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+    fn schedule_nft_return() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `4326`
+        Weight::from_parts(106_023_000, 4326)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: NftTransfer ServiceAccount (r:1 w:0)
+    /// Proof: NftTransfer ServiceAccount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+    /// Storage: NftTransfer NftTransits (r:1 w:1)
+    /// Proof: NftTransfer NftTransits (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+    /// Storage: NftTransfer NftStatuses (r:1 w:1)
+    /// Proof: NftTransfer NftStatuses (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+    /// Storage: NftTransfer NftReferences (r:0 w:1)
+    /// Proof: NftTransfer NftReferences (max_values: None, max_size: Some(169), added: 2644, mode: MaxEncodedLen)
+    fn confirm_nft_return() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3569`
+        Weight::from_parts(113_146_000, 3569)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Storage: NftTransfer NftTransits (r:1 w:1)
+    /// Proof: NftTransfer NftTransits (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+    /// Storage: NftTransfer NftStatuses (r:1 w:1)
+    /// Proof: NftTransfer NftStatuses (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+    fn rollback_nft_transit() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3569`
+        Weight::from_parts(156_586_000, 3569)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: AwesomeAvatars Organizer (r:1 w:0)
+    /// Proof: AwesomeAvatars Organizer (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+    /// Storage: NftTransfer CategoryCollections (r:0 w:1)
+    /// Proof: NftTransfer CategoryCollections (max_values: None, max_size: Some(8), added: 2483, mode: MaxEncodedLen)
+    fn set_category_collection_id() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `1517`
+        Weight::from_parts(68_371_000, 1517)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
             .saturating_add(T::DbWeight::get().writes(1_u64))
     }
+    /// Storage: NftTransfer PreparationDeadlines (r:1 w:1)
+    /// Proof: NftTransfer PreparationDeadlines (max_values: None, max_size: Some(213), added: 2688, mode: MaxEncodedLen)
+    /// Storage: AwesomeAvatars Avatars (r:1 w:1)
+    /// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+    /// Storage: AwesomeAvatars LockedAvatars (r:1 w:1)
+    /// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+    /// Storage: AwesomeAvatars Owners (r:1 w:1)
+    /// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+    /// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:0)
+    /// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
+    /// Storage: System Account (r:2 w:2)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: NftTransfer Preparation (r:0 w:1)
+    /// Proof: NftTransfer Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+    fn cancel_preparation() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `29528`
+        Weight::from_parts(646_390_000, 29528)
+            .saturating_add(T::DbWeight::get().reads(7_u64))
+            .saturating_add(T::DbWeight::get().writes(7_u64))
+    }
 }
 
 // For backwards compatibility and tests
@@ -260,6 +378,8 @@ impl WeightInfo for () {
     /// Proof: NftTransfer NftStatuses (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
     /// Storage: Nft Account (r:0 w:1)
     /// Proof: Nft Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+    /// Storage: NftTransfer ItemCollections (r:0 w:1)
+    /// Proof: NftTransfer ItemCollections (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
     /// The range of component `n` is `[1, 200]`.
     fn lock_asset() -> Weight {
         // Proof Size summary in bytes:
@@ -305,6 +425,8 @@ impl WeightInfo for () {
     /// Proof: Nft ItemAttributesApprovalsOf (max_values: None, max_size: Some(389), added: 2864, mode: MaxEncodedLen)
     /// Storage: Nft PendingSwapOf (r:0 w:1)
     /// Proof: Nft PendingSwapOf (max_values: None, max_size: Some(127), added: 2602, mode: MaxEncodedLen)
+    /// Storage: NftTransfer ItemCollections (r:1 w:1)
+    /// Proof: NftTransfer ItemCollections (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
     /// The range of component `n` is `[1, 200]`.
     fn unlock_asset() -> Weight {
         // Proof Size summary in bytes:
@@ -324,59 +446,166 @@ impl WeightInfo for () {
         Weight::from_parts(20_751_000, 0)
             .saturating_add(RocksDbWeight::get().writes(1_u64))
     }
-    /// Storage: AwesomeAvatars Avatars (r:1 w:0)
+    /// Storage: NftTransfer Preparation (r:1 w:1)
+    /// Proof: NftTransfer Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+    /// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
+    /// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
+    /// Storage: AwesomeAvatars Avatars (r:1 w:1)
     /// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
     /// Storage: AwesomeAvatars Trade (r:1 w:0)
     /// Proof: AwesomeAvatars Trade (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
-    /// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
-    /// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
-    /// Storage: AwesomeAvatars LockedAvatars (r:1 w:0)
-    /// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
-    /// Storage: AwesomeAvatars Preparation (r:1 w:1)
-    /// Proof: AwesomeAvatars Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
-    /// Storage: AwesomeAvatars ServiceAccount (r:1 w:0)
-    /// Proof: AwesomeAvatars ServiceAccount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+    /// Storage: AwesomeAvatars LockedAvatars (r:1 w:1)
+    /// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+    /// Storage: AwesomeAvatars Owners (r:1 w:1)
+    /// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+    /// Storage: NftTransfer ServiceAccount (r:1 w:0)
+    /// Proof: NftTransfer ServiceAccount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
     /// Storage: AwesomeAvatars Seasons (r:1 w:0)
     /// Proof: AwesomeAvatars Seasons (max_values: None, max_size: Some(1667), added: 4142, mode: MaxEncodedLen)
     /// Storage: System Account (r:2 w:2)
     /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: NftTransfer PreparationDeadlines (r:0 w:1)
+    /// Proof: NftTransfer PreparationDeadlines (max_values: None, max_size: Some(213), added: 2688, mode: MaxEncodedLen)
     fn prepare_asset() -> Weight {
         // Proof Size summary in bytes:
-        //  Measured:  `1861`
-        //  Estimated: `6196`
-        // Minimum execution time: 177_929_000 picoseconds.
-        Weight::from_parts(183_872_000, 6196)
-            .saturating_add(RocksDbWeight::get().reads(9_u64))
-            .saturating_add(RocksDbWeight::get().writes(3_u64))
+        //  Estimated: `30595`
+        Weight::from_parts(195_640_000, 30595)
+            .saturating_add(RocksDbWeight::get().reads(10_u64))
+            .saturating_add(RocksDbWeight::get().writes(7_u64))
     }
-    /// Storage: AwesomeAvatars Avatars (r:1 w:0)
+    /// Storage: AwesomeAvatars Avatars (r:1 w:1)
     /// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+    /// Storage: AwesomeAvatars LockedAvatars (r:1 w:1)
+    /// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
     /// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
     /// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
-    /// Storage: AwesomeAvatars Preparation (r:1 w:1)
-    /// Proof: AwesomeAvatars Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+    /// Storage: NftTransfer Preparation (r:1 w:1)
+    /// Proof: NftTransfer Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+    /// Storage: AwesomeAvatars Owners (r:1 w:1)
+    /// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+    /// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:0)
+    /// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
+    /// Storage: NftTransfer PreparationDeadlines (r:0 w:1)
+    /// Proof: NftTransfer PreparationDeadlines (max_values: None, max_size: Some(213), added: 2688, mode: MaxEncodedLen)
     fn unprepare_asset() -> Weight {
         // Proof Size summary in bytes:
-        //  Measured:  `344`
-        //  Estimated: `3638`
-        // Minimum execution time: 42_265_000 picoseconds.
-        Weight::from_parts(45_537_000, 3638)
-            .saturating_add(RocksDbWeight::get().reads(3_u64))
-            .saturating_add(RocksDbWeight::get().writes(1_u64))
+        //  Estimated: `24733`
+        Weight::from_parts(240_879_000, 24733)
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
     }
-    /// Storage: AwesomeAvatars ServiceAccount (r:1 w:0)
-    /// Proof: AwesomeAvatars ServiceAccount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+    /// Storage: NftTransfer ServiceAccount (r:1 w:0)
+    /// Proof: NftTransfer ServiceAccount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
     /// Storage: AwesomeAvatars GlobalConfigs (r:1 w:0)
     /// Proof: AwesomeAvatars GlobalConfigs (max_values: Some(1), max_size: Some(15), added: 510, mode: MaxEncodedLen)
-    /// Storage: AwesomeAvatars Preparation (r:1 w:1)
-    /// Proof: AwesomeAvatars Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+    /// Storage: NftTransfer Preparation (r:1 w:1)
+    /// Proof: NftTransfer Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+    /// Storage: NftTransfer PreparationDeadlines (r:0 w:1)
+    /// Proof: NftTransfer PreparationDeadlines (max_values: None, max_size: Some(213), added: 2688, mode: MaxEncodedLen)
     fn prepare_ipfs() -> Weight {
         // Proof Size summary in bytes:
-        //  Measured:  `318`
-        //  Estimated: `3579`
-        // Minimum execution time: 40_493_000 picoseconds.
-        Weight::from_parts(41_931_000, 3579)
+        //  Estimated: `4616`
+        Weight::from_parts(45_870_000, 4616)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: Nft Item (r:1 w:0)
+    /// Proof: Nft Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+    /// Storage: NftTransfer NftStatuses (r:1 w:1)
+    /// Proof: NftTransfer NftStatuses (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+    /// Storage: NftTransfer NftTransits (r:0 w:1)
+    /// Proof: NftTransfer NftTransits (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+    fn schedule_nft_upload() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `4326`
+        Weight::from_parts(128_156_000, 4326)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: NftTransfer ServiceAccount (r:1 w:0)
+    /// Proof: NftTransfer ServiceAccount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+    /// Storage: NftTransfer NftTransits (r:1 w:1)
+    /// Proof: NftTransfer NftTransits (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+    /// Storage: NftTransfer NftStatuses (r:1 w:1)
+    /// Proof: NftTransfer NftStatuses (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+    /// Storage: NftTransfer NftReferences (r:0 w:1)
+    /// Proof: NftTransfer NftReferences (max_values: None, max_size: Some(169), added: 2644, mode: MaxEncodedLen)
+    fn confirm_nft_upload() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3569`
+        Weight::from_parts(117_598_000, 3569)
             .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    /// Storage: Nft Item (r:1 w:0)
+    /// Proof: Nft Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+    /// Storage: NftTransfer NftStatuses (r:1 w:1)
+    /// Proof: NftTransfer NftStatuses (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+    /// Storage: NftTransfer NftTransits (r:0 w:1)
+    /// Proof: NftTransfer NftTransits (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+    fn schedule_nft_return() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `4326`
+        Weight::from_parts(106_023_000, 4326)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: NftTransfer ServiceAccount (r:1 w:0)
+    /// Proof: NftTransfer ServiceAccount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+    /// Storage: NftTransfer NftTransits (r:1 w:1)
+    /// Proof: NftTransfer NftTransits (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+    /// Storage: NftTransfer NftStatuses (r:1 w:1)
+    /// Proof: NftTransfer NftStatuses (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+    /// Storage: NftTransfer NftReferences (r:0 w:1)
+    /// Proof: NftTransfer NftReferences (max_values: None, max_size: Some(169), added: 2644, mode: MaxEncodedLen)
+    fn confirm_nft_return() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3569`
+        Weight::from_parts(113_146_000, 3569)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    /// Storage: NftTransfer NftTransits (r:1 w:1)
+    /// Proof: NftTransfer NftTransits (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+    /// Storage: NftTransfer NftStatuses (r:1 w:1)
+    /// Proof: NftTransfer NftStatuses (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+    fn rollback_nft_transit() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `3569`
+        Weight::from_parts(156_586_000, 3569)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: AwesomeAvatars Organizer (r:1 w:0)
+    /// Proof: AwesomeAvatars Organizer (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+    /// Storage: NftTransfer CategoryCollections (r:0 w:1)
+    /// Proof: NftTransfer CategoryCollections (max_values: None, max_size: Some(8), added: 2483, mode: MaxEncodedLen)
+    fn set_category_collection_id() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `1517`
+        Weight::from_parts(68_371_000, 1517)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
             .saturating_add(RocksDbWeight::get().writes(1_u64))
     }
+    /// Storage: NftTransfer PreparationDeadlines (r:1 w:1)
+    /// Proof: NftTransfer PreparationDeadlines (max_values: None, max_size: Some(213), added: 2688, mode: MaxEncodedLen)
+    /// Storage: AwesomeAvatars Avatars (r:1 w:1)
+    /// Proof: AwesomeAvatars Avatars (max_values: None, max_size: Some(173), added: 2648, mode: MaxEncodedLen)
+    /// Storage: AwesomeAvatars LockedAvatars (r:1 w:1)
+    /// Proof: AwesomeAvatars LockedAvatars (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+    /// Storage: AwesomeAvatars Owners (r:1 w:1)
+    /// Proof: AwesomeAvatars Owners (max_values: None, max_size: Some(6436), added: 8911, mode: MaxEncodedLen)
+    /// Storage: AwesomeAvatars PlayerSeasonConfigs (r:1 w:0)
+    /// Proof: AwesomeAvatars PlayerSeasonConfigs (max_values: None, max_size: Some(4063), added: 6538, mode: MaxEncodedLen)
+    /// Storage: System Account (r:2 w:2)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: NftTransfer Preparation (r:0 w:1)
+    /// Proof: NftTransfer Preparation (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+    fn cancel_preparation() -> Weight {
+        // Proof Size summary in bytes:
+        //  Estimated: `29528`
+        Weight::from_parts(646_390_000, 29528)
+            .saturating_add(RocksDbWeight::get().reads(7_u64))
+            .saturating_add(RocksDbWeight::get().writes(7_u64))
+    }
 }
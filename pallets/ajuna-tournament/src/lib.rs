@@ -49,8 +49,8 @@ pub mod pallet {
 	use frame_support::traits::{Currency, ExistenceRequirement};
 	use sp_arithmetic::traits::AtLeast16BitUnsigned;
 	use sp_runtime::{
-		traits::{AccountIdConversion, CheckedDiv, SaturatedConversion},
-		Saturating,
+		traits::{AccountIdConversion, SaturatedConversion},
+		Percent, Saturating,
 	};
 
 	pub type AccountIdFor<T> = <T as frame_system::Config>::AccountId;
@@ -837,10 +837,8 @@ pub mod pallet {
 								.copied()
 								.unwrap_or_default();
 
-							let account_payout = reward_pot
-								.saturating_mul(payout_percentage.into())
-								.checked_div(&100_u32.into())
-								.unwrap_or_default();
+							let account_payout =
+								Percent::from_percent(payout_percentage).mul_floor(reward_pot);
 
 							if account_payout > 0_u32.into() {
 								T::Currency::transfer(
@@ -900,10 +898,8 @@ pub mod pallet {
 									let treasury_account =
 										Self::tournament_treasury_account_id(*season_id);
 
-									let account_payout = reward_pot
-										.saturating_mul(payout_percentage.into())
-										.checked_div(&100_u32.into())
-										.unwrap_or_default();
+									let account_payout = Percent::from_percent(payout_percentage)
+										.mul_floor(reward_pot);
 
 									T::Currency::transfer(
 										&treasury_account,
@@ -1,5 +1,6 @@
 use frame_support::pallet_prelude::*;
-use sp_core::sp_std::vec::Vec;
+use sp_core::{sp_std::vec::Vec, sr25519};
+use sp_runtime::traits::Verify;
 
 pub trait OnMappingRequest<AssetId, CollectionId, ItemId> {
 	fn on_fungible_asset_mapping(id: WideId) -> AssetId;
@@ -23,6 +24,7 @@ pub(crate) const BALANCE_PROOF_PREFIX: &[u8] = b"Erdstall balance proof";
 pub(crate) const FREEZE_PROOF_PREFIX: &[u8] = b"Erdstall freeze proof";
 pub(crate) const ZERO_BALANCE_PROOF_PREFIX: &[u8] = b"Erdstall zero balance";
 pub(crate) const LIGHT_CLIENT_PROOF_PREFIX: &[u8] = b"Is a certified light client";
+pub(crate) const BALANCE_ROOT_PREFIX: &[u8] = b"Erdstall balance root";
 
 #[repr(u8)]
 #[derive(Debug, Copy, Clone, PartialEq, Encode, Decode, TypeInfo, MaxEncodedLen)]
@@ -105,7 +107,82 @@ pub trait Proof {
 	fn extract_msg(&self) -> Vec<u8>;
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+/// The keys entitled to sign proofs, of which at least `threshold` must sign each proof.
+#[derive(
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	RuntimeDebugNoBound,
+	Encode,
+	Decode,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(MaxSigners))]
+#[codec(mel_bound())]
+pub struct SignerSet<MaxSigners: Get<u32>> {
+	pub keys: BoundedVec<sr25519::Public, MaxSigners>,
+	pub threshold: u32,
+}
+
+impl<MaxSigners: Get<u32>> SignerSet<MaxSigners> {
+	/// Checks that the threshold can be met and that no key is repeated.
+	pub(crate) fn is_valid(&self) -> bool {
+		let has_duplicates =
+			self.keys.iter().enumerate().any(|(i, key)| self.keys[..i].contains(key));
+
+		self.threshold > 0 && self.threshold as usize <= self.keys.len() && !has_duplicates
+	}
+
+	/// Checks that `msg` has been signed by at least `threshold` distinct keys of the set.
+	pub(crate) fn is_signed(&self, msg: &[u8], signatures: &[sr25519::Signature]) -> bool {
+		let mut has_signed = Vec::from_iter(self.keys.iter().map(|_| false));
+		let mut approvals = 0_usize;
+
+		for signature in signatures {
+			let signer = self
+				.keys
+				.iter()
+				.zip(&has_signed)
+				.position(|(key, has_signed)| !has_signed && signature.verify(msg, key));
+
+			if let Some(index) = signer {
+				has_signed[index] = true;
+				approvals += 1;
+			}
+		}
+
+		approvals >= self.threshold as usize
+	}
+}
+
+/// A signer set replaced by a rotation, along with the epochs in which it was signing proofs.
+#[derive(
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	RuntimeDebugNoBound,
+	Encode,
+	Decode,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(MaxSigners))]
+#[codec(mel_bound())]
+pub struct RetiredSignerSet<MaxSigners: Get<u32>> {
+	pub signers: SignerSet<MaxSigners>,
+	pub active_since: EpochNumber,
+	pub active_until: EpochNumber,
+}
+
+impl<MaxSigners: Get<u32>> RetiredSignerSet<MaxSigners> {
+	/// Checks whether the set was signing proofs in `epoch`.
+	pub(crate) fn was_active_in(&self, epoch: EpochNumber) -> bool {
+		self.active_since <= epoch && epoch <= self.active_until
+	}
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
 pub struct BalanceProof {
 	pub epoch: EpochNumber,
 	pub origin: ChainId,
@@ -204,6 +281,158 @@ impl BalanceProof {
 	}
 }
 
+/// Merkle root over the balance proofs of an epoch, signed once by the operator so that the
+/// individual proofs can be submitted with an inclusion path instead of a signature each.
+///
+/// Leaves are the `blake2_256` hashes of the balance proofs' messages, and each inner node is the
+/// hash of its two children concatenated in ascending order. A node without a sibling is carried
+/// over to the next level unchanged.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct BalanceRoot {
+	pub epoch: EpochNumber,
+	pub origin: ChainId,
+	pub root: WideId,
+}
+
+impl Proof for BalanceRoot {
+	fn extract_msg(&self) -> Vec<u8> {
+		let mut bytes = Vec::with_capacity(0);
+
+		bytes.extend(BALANCE_ROOT_PREFIX.to_vec());
+		bytes.extend(self.epoch.encode());
+		bytes.extend(self.origin.encode());
+		bytes.extend(self.root.encode());
+
+		bytes
+	}
+}
+
+impl BalanceRoot {
+	/// Checks that `proof` is a leaf of this root, following the sibling hashes of `path` from the
+	/// leaf upwards.
+	pub(crate) fn includes(&self, proof: &BalanceProof, path: &[WideId]) -> bool {
+		let node = path
+			.iter()
+			.fold(balance_leaf(proof), |node, sibling| balance_node(&node, sibling));
+
+		proof.epoch == self.epoch && proof.origin == self.origin && node == self.root
+	}
+}
+
+pub(crate) fn balance_leaf(proof: &BalanceProof) -> WideId {
+	WideId::from(sp_io::hashing::blake2_256(&proof.extract_msg()))
+}
+
+pub(crate) fn balance_node(left: &WideId, right: &WideId) -> WideId {
+	let (left, right) = if left <= right { (left, right) } else { (right, left) };
+
+	let mut bytes = Vec::with_capacity(64);
+	bytes.extend(left.as_bytes());
+	bytes.extend(right.as_bytes());
+
+	WideId::from(sp_io::hashing::blake2_256(&bytes))
+}
+
+/// Builds the Merkle root of `proofs`, along with the inclusion path of each of them.
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+pub(crate) fn balance_tree(proofs: &[BalanceProof]) -> (WideId, Vec<Vec<WideId>>) {
+	let mut paths = proofs.iter().map(|_| Vec::new()).collect::<Vec<_>>();
+	// Each level keeps its nodes together with the leaves they cover.
+	let mut level = proofs
+		.iter()
+		.enumerate()
+		.map(|(index, proof)| (balance_leaf(proof), Vec::from([index])))
+		.collect::<Vec<_>>();
+
+	while level.len() > 1 {
+		let mut next_level = Vec::with_capacity(level.len() / 2 + 1);
+		for pair in level.chunks(2) {
+			match pair {
+				[(left, left_leaves), (right, right_leaves)] => {
+					left_leaves.iter().for_each(|leaf| paths[*leaf].push(*right));
+					right_leaves.iter().for_each(|leaf| paths[*leaf].push(*left));
+					let leaves = left_leaves.iter().chain(right_leaves).copied().collect();
+					next_level.push((balance_node(left, right), leaves));
+				},
+				_ => next_level.push(pair[0].clone()),
+			}
+		}
+		level = next_level;
+	}
+
+	(level.first().map(|(root, _)| *root).unwrap_or_default(), paths)
+}
+
+/// A balance proof together with its inclusion path in a signed [`BalanceRoot`].
+#[derive(
+	CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, Encode, Decode, TypeInfo,
+)]
+#[scale_info(skip_type_params(MaxDepth))]
+pub struct BalanceInclusion<MaxDepth: Get<u32>> {
+	pub balance_proof: BalanceProof,
+	pub merkle_path: BoundedVec<WideId, MaxDepth>,
+}
+
+/// The bond reserved by a challenger until its challenge is settled.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct ChallengeBond<Balance> {
+	pub amount: Balance,
+	/// The epoch in which the operator answered the challenge, if it did.
+	pub answered_in: Option<EpochNumber>,
+}
+
+#[derive(Debug, Clone, PartialEq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+/// The fungible asset, or collection of non-fungible items, that flow limits apply to.
+pub enum LimitedAsset<AssetId, CollectionId> {
+	Fungible(AssetId),
+	NonFungible(CollectionId),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub enum FlowDirection {
+	Deposit,
+	Withdrawal,
+}
+
+/// Limits on the amount of an asset flowing through the bridge, where the amount of a collection
+/// of non-fungibles is its number of items.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct AssetLimits<Balance> {
+	/// The maximum amount deposited, or withdrawn, in a single epoch across all accounts.
+	pub per_epoch: Option<Balance>,
+	/// The maximum amount deposited, or withdrawn, in a single epoch by one account.
+	pub per_account: Option<Balance>,
+	/// Withdrawals of a larger amount are put in the delay queue instead of being paid out.
+	pub delay_threshold: Option<Balance>,
+}
+
+/// The amount of an asset that has flown through the bridge during an epoch.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct FlowCounter<Balance> {
+	pub epoch: EpochNumber,
+	pub amount: Balance,
+}
+
+/// A withdrawal held back in the delay queue until its release epoch.
+#[derive(Debug, Clone, PartialEq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct DelayedWithdrawal<AccountId, Asset, Value> {
+	pub beneficiary: AccountId,
+	/// The epoch of the balance proof the withdrawal was made with.
+	pub epoch: EpochNumber,
+	pub asset: Asset,
+	pub value: Value,
+	pub release_epoch: EpochNumber,
+}
+
+/// A bridge operator, whose bond backs the proofs signed with its key.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct Operator<Balance> {
+	pub signer: sr25519::Public,
+	/// The chain whose balances the operator signs proofs for.
+	pub chain_id: ChainId,
+	pub bond: Balance,
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Encode, Decode, TypeInfo, MaxEncodedLen)]
 pub struct FreezeProof {
 	pub epoch: EpochNumber,
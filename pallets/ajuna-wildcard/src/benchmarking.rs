@@ -0,0 +1,551 @@
+use super::*;
+use crate::Pallet as Wildcard;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_system::RawOrigin;
+use sp_core::{crypto::KeyTypeId, sr25519};
+
+const SEED: u32 = 0;
+const EPOCH_DURATION: u32 = 1_000;
+const KEY_TYPE: KeyTypeId = KeyTypeId(*b"wcrd");
+
+fn assert_last_event<T: Config>(event: Event<T>) {
+	let event = <T as Config>::RuntimeEvent::from(event);
+	frame_system::Pallet::<T>::assert_last_event(event.into());
+}
+
+fn set_epoch<T: Config>(epoch: EpochNumber) {
+	StartTime::<T>::put(MomentOf::<T>::from(0_u32));
+	EpochDuration::<T>::put(MomentOf::<T>::from(EPOCH_DURATION));
+	T::BenchmarkHelper::set_time(MomentOf::<T>::from(epoch as u32 * EPOCH_DURATION));
+}
+
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let account = account(name, index, SEED);
+	T::Currency::make_free_balance_be(&account, token_amount::<T>() * 1_000_u32.into());
+	account
+}
+
+fn funded_caller<T: Config>() -> T::AccountId {
+	let caller = whitelisted_caller();
+	T::Currency::make_free_balance_be(&caller, token_amount::<T>() * 1_000_u32.into());
+	caller
+}
+
+fn fund_reserve<T: Config>(deposits: u32) {
+	let amount = token_amount::<T>() * (deposits + 1).into();
+	T::Currency::make_free_balance_be(&Wildcard::<T>::reserve_account(), amount);
+}
+
+fn generate_signers<T: Config>() -> SignerSetOf<T> {
+	let keys = (0..T::MaxSigners::get())
+		.map(|_| sp_io::crypto::sr25519_generate(KEY_TYPE, None))
+		.collect::<sp_std::vec::Vec<_>>();
+	let signers = SignerSet { keys: keys.try_into().unwrap(), threshold: T::MaxSigners::get() };
+	Signers::<T>::put(&signers);
+	signers
+}
+
+fn register_operators<T: Config>(name: &'static str, keys: &[sr25519::Public]) {
+	for (index, key) in keys.iter().enumerate() {
+		let operator = funded_account::<T>(name, index as u32);
+		let bond = T::MinOperatorBond::get();
+		T::Currency::reserve(&operator, bond).expect("Operator should be funded");
+		Operators::<T>::insert(
+			&operator,
+			Operator { signer: *key, chain_id: T::ChainId::get(), bond },
+		);
+		OperatorSigners::<T>::insert(key, operator);
+	}
+}
+
+// Sets up a current and a retiring signer set, which are both slashed on a freeze at epoch 1,
+// with bonded operators for `bonded` of their keys, starting with the retiring ones.
+fn slashable_signers<T: Config>(bonded: u32) -> SignerSetOf<T> {
+	let retiring_bonded = bonded.min(T::MaxSigners::get());
+	let retiring_signers = generate_signers::<T>();
+	register_operators::<T>("retiring", &retiring_signers.keys[..retiring_bonded as usize]);
+	RetiringSigners::<T>::put(RetiredSignerSet {
+		signers: retiring_signers,
+		active_since: 0,
+		active_until: 1,
+	});
+	SignersSince::<T>::put(1);
+	let signers = generate_signers::<T>();
+	register_operators::<T>("operator", &signers.keys[..(bonded - retiring_bonded) as usize]);
+	signers
+}
+
+fn sign(signer: &sr25519::Public, msg: &[u8]) -> sr25519::Signature {
+	sp_io::crypto::sr25519_sign(KEY_TYPE, signer, msg).expect("Key should be in the keystore")
+}
+
+// Signs in the reverse order of the keys, so that matching the signatures is the most expensive.
+fn sign_all<T: Config>(signers: &SignerSetOf<T>, msg: &[u8]) -> ProofSignaturesOf<T> {
+	let signatures = signers
+		.keys
+		.iter()
+		.rev()
+		.map(|key| sign(key, msg))
+		.collect::<sp_std::vec::Vec<_>>();
+	signatures.try_into().unwrap()
+}
+
+fn wide_account<T: Config>(account: &T::AccountId) -> WideId {
+	let mut bytes = account.encode();
+	bytes.resize(32, 0);
+	WideId::from_slice(&bytes[..32])
+}
+
+fn token_amount<T: Config>() -> BalanceOf<T> {
+	T::Currency::minimum_balance() * 10_u32.into()
+}
+
+fn token_ids<T: Config>() -> (WideId, WideId) {
+	let mut primary_id = [0_u8; 32];
+	primary_id[..30].copy_from_slice(&NATIVE_FUNGIBLE_PAD);
+	let mut secondary_id = [0_u8; 32];
+	secondary_id[..16].copy_from_slice(&token_amount::<T>().saturated_into::<u128>().to_le_bytes());
+
+	let primary_id = WideId::from(primary_id);
+	AssetIdMapping::<T>::insert(
+		(T::ChainId::get(), AssetType::Fungible, primary_id),
+		T::NativeTokenAssetId::get(),
+	);
+	(primary_id, WideId::from(secondary_id))
+}
+
+fn token_asset<T: Config>() -> AssetOf<T> {
+	Asset { origin: T::ChainId::get(), kind: AssetKind::Fungible(T::NativeTokenAssetId::get()) }
+}
+
+// Limits without caps still account every flow of the token, and don't delay withdrawals.
+fn limit_token_flows<T: Config>() {
+	AssetFlowLimits::<T>::insert(
+		(T::ChainId::get(), LimitedAsset::Fungible(T::NativeTokenAssetId::get())),
+		AssetLimits { per_epoch: None, per_account: None, delay_threshold: None },
+	);
+}
+
+fn token_deposit<T: Config>() -> DepositValueKindOf<T> {
+	DepositValueKind::Fungible(DepositValue::Token(token_amount::<T>()))
+}
+
+fn token_proof<T: Config>(account: &T::AccountId, epoch: EpochNumber) -> BalanceProof {
+	let (primary_id, secondary_id) = token_ids::<T>();
+	BalanceProof {
+		epoch,
+		origin: T::ChainId::get(),
+		account: wide_account::<T>(account),
+		exit_flag: true,
+		chunk_index: 0,
+		chunk_last: 0,
+		asset_origin: T::ChainId::get(),
+		asset_type: AssetType::Fungible as u8,
+		primary_id,
+		secondary_id,
+	}
+}
+
+fn balance_batch<T: Config>(
+	signers: &SignerSetOf<T>,
+	proofs: &[BalanceProof],
+) -> (BalanceRoot, ProofSignaturesOf<T>, BoundedVec<BalanceInclusionOf<T>, T::MaxBatchedProofs>) {
+	let (root, paths) = balance_tree(proofs);
+	let balance_root = BalanceRoot { epoch: proofs[0].epoch, origin: proofs[0].origin, root };
+	let signatures = sign_all::<T>(signers, &balance_root.extract_msg());
+	let inclusions = proofs
+		.iter()
+		.zip(paths)
+		.map(|(proof, path)| BalanceInclusion {
+			balance_proof: *proof,
+			merkle_path: path.try_into().expect("Path should fit the maximum depth"),
+		})
+		.collect::<sp_std::vec::Vec<_>>()
+		.try_into()
+		.expect("Batch should fit the maximum size");
+	(balance_root, signatures, inclusions)
+}
+
+benchmarks! {
+	set_administrator {
+		let administrator = account::<T::AccountId>("administrator", 0, SEED);
+	}: _(RawOrigin::Root, administrator.clone())
+	verify {
+		assert_last_event::<T>(Event::AdministratorSet { administrator });
+	}
+
+	set_parameters {
+		let administrator = funded_caller::<T>();
+		Administrator::<T>::put(&administrator);
+	}: _(RawOrigin::Signed(administrator), Some(0_u32.into()), Some(EPOCH_DURATION.into()))
+	verify {
+		assert_eq!(EpochDuration::<T>::get(), Some(EPOCH_DURATION.into()));
+	}
+
+	rotate_signers {
+		let administrator = funded_caller::<T>();
+		Administrator::<T>::put(&administrator);
+		set_epoch::<T>(1);
+		generate_signers::<T>();
+		let keys = (0..T::MaxSigners::get())
+			.map(|i| sr25519::Public::from_raw([i as u8; 32]))
+			.collect::<sp_std::vec::Vec<_>>();
+		register_operators::<T>("operator", &keys);
+		let keys = BoundedVec::try_from(keys).unwrap();
+		let threshold = T::MaxSigners::get();
+	}: _(RawOrigin::Signed(administrator), keys.clone(), threshold, 5)
+	verify {
+		assert_last_event::<T>(Event::SignersRotated {
+			signers: SignerSet { keys, threshold },
+			overlap_until: Some(5),
+		});
+	}
+
+	deposit {
+		let depositor = funded_caller::<T>();
+		set_epoch::<T>(1);
+		let (primary_id, secondary_id) = token_ids::<T>();
+		let asset_deposit = AssetDeposit {
+			origin: T::ChainId::get(),
+			asset_type: AssetType::Fungible,
+			primary_id,
+			secondary_id,
+		};
+		limit_token_flows::<T>();
+		// Depositing into an existing entry of the epoch is the most expensive path.
+		Wildcard::<T>::deposit(RawOrigin::Signed(depositor.clone()).into(), asset_deposit)?;
+	}: _(RawOrigin::Signed(depositor.clone()), asset_deposit)
+	verify {
+		let amount = token_amount::<T>() * 2_u32.into();
+		assert_eq!(
+			Deposits::<T>::get((1, depositor, token_asset::<T>())),
+			Some(DepositValueKind::Fungible(DepositValue::Token(amount)))
+		);
+	}
+
+	withdraw {
+		let withdrawer = funded_caller::<T>();
+		let signers = generate_signers::<T>();
+		set_epoch::<T>(5);
+		fund_reserve::<T>(1);
+		limit_token_flows::<T>();
+		let proof = token_proof::<T>(&withdrawer, 1);
+		let signatures = sign_all::<T>(&signers, &proof.extract_msg());
+	}: _(RawOrigin::Signed(withdrawer.clone()), proof, signatures)
+	verify {
+		assert_eq!(Withdrawals::<T>::get(withdrawer, token_asset::<T>()), Some((1, 0)));
+	}
+
+	withdraw_frozen {
+		let withdrawer = funded_caller::<T>();
+		let signers = generate_signers::<T>();
+		set_epoch::<T>(5);
+		fund_reserve::<T>(1);
+		limit_token_flows::<T>();
+		Frozen::<T>::put(1);
+		let proof = token_proof::<T>(&withdrawer, 1);
+		let signatures = sign_all::<T>(&signers, &proof.extract_msg());
+	}: _(RawOrigin::Signed(withdrawer.clone()), proof, signatures)
+	verify {
+		assert_eq!(Withdrawals::<T>::get(withdrawer, token_asset::<T>()), Some((1, 0)));
+	}
+
+	refund_frozen {
+		let n in 1 .. T::MaxRefundsPerCall::get();
+
+		// Spreading the deposits over different epochs is the most expensive lookup.
+		let depositor = funded_caller::<T>();
+		set_epoch::<T>(n as EpochNumber + 1);
+		fund_reserve::<T>(n);
+		limit_token_flows::<T>();
+		for epoch in 1..=n as EpochNumber {
+			Deposits::<T>::insert((epoch, &depositor, token_asset::<T>()), token_deposit::<T>());
+			DepositEpochs::<T>::insert(&depositor, epoch, ());
+		}
+		Frozen::<T>::put(0);
+	}: _(RawOrigin::Signed(depositor.clone()))
+	verify {
+		assert!(Deposits::<T>::iter_keys().next().is_none());
+		assert!(DepositEpochs::<T>::iter_keys().next().is_none());
+		assert_last_event::<T>(Event::DepositsRefunded { epoch: 0, beneficieary: depositor });
+	}
+
+	challenge {
+		let challenger = funded_caller::<T>();
+		set_epoch::<T>(2);
+	}: _(RawOrigin::Signed(challenger.clone()))
+	verify {
+		assert_last_event::<T>(Event::ChallengeCalled { epoch: 1, challenger });
+	}
+
+	respond_challenge {
+		let caller = funded_caller::<T>();
+		let challenger = funded_account::<T>("challenger", 0);
+		let signers = generate_signers::<T>();
+		set_epoch::<T>(2);
+		Challenges::<T>::insert((1, challenger.clone()), 0);
+		let bond = ChallengeBond { amount: T::ChallengeBond::get(), answered_in: None };
+		ChallengeBonds::<T>::insert((1, challenger.clone()), bond);
+		let proof = token_proof::<T>(&challenger, 1);
+		let signatures = sign_all::<T>(&signers, &proof.extract_msg());
+	}: _(RawOrigin::Signed(caller), proof, signatures)
+	verify {
+		assert!(!Challenges::<T>::contains_key((1, challenger.clone())));
+		assert_eq!(ChallengeBonds::<T>::get((1, challenger)).unwrap().answered_in, Some(2));
+	}
+
+	freeze {
+		let s in 0 .. T::MaxSigners::get() * 2;
+		let caller = funded_caller::<T>();
+		let challenger = funded_account::<T>("challenger", 0);
+		set_epoch::<T>(5);
+		Challenges::<T>::insert((2, challenger), 0);
+		OpenChallenges::<T>::insert(2, 1);
+		slashable_signers::<T>(s);
+	}: _(RawOrigin::Signed(caller))
+	verify {
+		assert_eq!(Frozen::<T>::get(), Some(1));
+		assert_eq!(ShortfallCover::<T>::get(), T::MinOperatorBond::get() * s.into());
+	}
+
+	propagate_freeze {
+		let s in 0 .. T::MaxSigners::get() * 2;
+		let caller = funded_caller::<T>();
+		set_epoch::<T>(5);
+		let signers = slashable_signers::<T>(s);
+		let client_key = sp_io::crypto::sr25519_generate(KEY_TYPE, None);
+		let client_key_signatures = {
+			let mut msg = LIGHT_CLIENT_PROOF_PREFIX.to_vec();
+			msg.extend(client_key.to_vec());
+			sign_all::<T>(&signers, &msg)
+		};
+		let freeze_proof =
+			FreezeProof { epoch: 1, origin: T::ChainId::get(), identifier: WideId::default() };
+		let proof_signature = sign(&client_key, &freeze_proof.extract_msg());
+	}: _(RawOrigin::Signed(caller), freeze_proof, client_key, client_key_signatures, proof_signature)
+	verify {
+		assert_eq!(Frozen::<T>::get(), Some(1));
+		assert_eq!(ShortfallCover::<T>::get(), T::MinOperatorBond::get() * s.into());
+	}
+
+	respond_zero_challenge {
+		let caller = funded_caller::<T>();
+		let challenger = funded_account::<T>("challenger", 0);
+		let signers = generate_signers::<T>();
+		set_epoch::<T>(2);
+		Challenges::<T>::insert((1, challenger.clone()), 0);
+		let bond = ChallengeBond { amount: T::ChallengeBond::get(), answered_in: None };
+		ChallengeBonds::<T>::insert((1, challenger.clone()), bond);
+		let zero_proof = ZeroBalanceProof {
+			epoch: 1,
+			origin: T::ChainId::get(),
+			account: wide_account::<T>(&challenger),
+		};
+		let signatures = sign_all::<T>(&signers, &zero_proof.extract_msg());
+	}: _(RawOrigin::Signed(caller), zero_proof, signatures)
+	verify {
+		assert!(!Challenges::<T>::contains_key((1, challenger.clone())));
+		assert_eq!(ChallengeBonds::<T>::get((1, challenger)).unwrap().answered_in, Some(2));
+	}
+
+	withdraw_batch {
+		let n in 1 .. T::MaxBatchedProofs::get();
+
+		let withdrawer = funded_caller::<T>();
+		let signers = generate_signers::<T>();
+		set_epoch::<T>(5);
+		fund_reserve::<T>(n);
+		limit_token_flows::<T>();
+		let proofs = (0..n)
+			.map(|chunk_index| BalanceProof {
+				chunk_index,
+				chunk_last: n - 1,
+				..token_proof::<T>(&withdrawer, 1)
+			})
+			.collect::<sp_std::vec::Vec<_>>();
+		let (balance_root, root_signatures, inclusions) = balance_batch::<T>(&signers, &proofs);
+	}: _(RawOrigin::Signed(withdrawer.clone()), balance_root, root_signatures, inclusions)
+	verify {
+		assert_eq!(Withdrawals::<T>::get(withdrawer, token_asset::<T>()), Some((1, n - 1)));
+	}
+
+	respond_challenge_batch {
+		let n in 1 .. T::MaxBatchedProofs::get();
+
+		let caller = funded_caller::<T>();
+		let challenger = funded_account::<T>("challenger", 0);
+		let signers = generate_signers::<T>();
+		set_epoch::<T>(2);
+		Challenges::<T>::insert((1, challenger.clone()), 0);
+		let bond = ChallengeBond { amount: T::ChallengeBond::get(), answered_in: None };
+		ChallengeBonds::<T>::insert((1, challenger.clone()), bond);
+		let proofs = (0..n)
+			.map(|chunk_index| BalanceProof {
+				chunk_index,
+				chunk_last: n - 1,
+				..token_proof::<T>(&challenger, 1)
+			})
+			.collect::<sp_std::vec::Vec<_>>();
+		let (balance_root, root_signatures, inclusions) = balance_batch::<T>(&signers, &proofs);
+	}: _(RawOrigin::Signed(caller), balance_root, root_signatures, inclusions)
+	verify {
+		assert!(!Challenges::<T>::contains_key((1, challenger.clone())));
+		assert_eq!(ChallengeBonds::<T>::get((1, challenger)).unwrap().answered_in, Some(2));
+	}
+
+	claim_challenge_bond {
+		let challenger = funded_caller::<T>();
+		set_epoch::<T>(5);
+		let amount = T::ChallengeBond::get();
+		T::Currency::reserve(&challenger, amount)?;
+		ChallengeBonds::<T>::insert((2, challenger.clone()), ChallengeBond { amount, answered_in: None });
+		Frozen::<T>::put(1);
+		fund_reserve::<T>(1);
+		ShortfallCover::<T>::put(T::ChallengeReward::get());
+	}: _(RawOrigin::Signed(challenger.clone()), 2)
+	verify {
+		assert!(!ChallengeBonds::<T>::contains_key((2, challenger)));
+		assert!(ShortfallCover::<T>::get().is_zero());
+	}
+
+	slash_challenge_bond {
+		let administrator = funded_caller::<T>();
+		Administrator::<T>::put(&administrator);
+		let challenger = funded_account::<T>("challenger", 0);
+		set_epoch::<T>(5);
+		let amount = T::ChallengeBond::get();
+		T::Currency::reserve(&challenger, amount)?;
+		ChallengeBonds::<T>::insert(
+			(4, challenger.clone()),
+			ChallengeBond { amount, answered_in: Some(5) },
+		);
+	}: _(RawOrigin::Signed(administrator), challenger.clone(), 4)
+	verify {
+		assert_last_event::<T>(Event::ChallengeBondSlashed { epoch: 4, challenger, bond: amount });
+	}
+
+	register_operator {
+		let operator = funded_caller::<T>();
+		let signer = sr25519::Public::from_raw([1; 32]);
+		let chain_id = T::ChainId::get();
+		let bond = T::MinOperatorBond::get();
+	}: _(RawOrigin::Signed(operator.clone()), signer, chain_id, bond)
+	verify {
+		assert_last_event::<T>(Event::OperatorRegistered { operator, signer, chain_id, bond });
+	}
+
+	bond_operator_extra {
+		let signer = sr25519::Public::from_raw([1; 32]);
+		register_operators::<T>("operator", &[signer]);
+		let operator = account::<T::AccountId>("operator", 0, SEED);
+		let amount = T::MinOperatorBond::get();
+	}: _(RawOrigin::Signed(operator.clone()), amount)
+	verify {
+		assert_last_event::<T>(Event::OperatorBonded { operator, bond: amount * 2_u32.into() });
+	}
+
+	unregister_operator {
+		let signer = sr25519::Public::from_raw([1; 32]);
+		register_operators::<T>("operator", &[signer]);
+		let operator = account::<T::AccountId>("operator", 0, SEED);
+		generate_signers::<T>();
+		let retiring_signers =
+			SignerSet { keys: BoundedVec::try_from(sp_std::vec![signer]).unwrap(), threshold: 1 };
+		RetiringSigners::<T>::put(RetiredSignerSet {
+			signers: retiring_signers,
+			active_since: 0,
+			active_until: 0,
+		});
+		set_epoch::<T>(5);
+	}: _(RawOrigin::Signed(operator.clone()))
+	verify {
+		assert_last_event::<T>(Event::OperatorUnregistered {
+			operator,
+			bond: T::MinOperatorBond::get(),
+		});
+	}
+
+	set_asset_limits {
+		let administrator = funded_caller::<T>();
+		Administrator::<T>::put(&administrator);
+		let asset = LimitedAsset::Fungible(T::NativeTokenAssetId::get());
+		let limits = Some(AssetLimits {
+			per_epoch: Some(token_amount::<T>()),
+			per_account: Some(token_amount::<T>()),
+			delay_threshold: Some(token_amount::<T>()),
+		});
+	}: _(RawOrigin::Signed(administrator), T::ChainId::get(), asset.clone(), limits)
+	verify {
+		assert_last_event::<T>(Event::AssetLimitsSet {
+			asset_origin: T::ChainId::get(),
+			asset,
+			limits,
+		});
+	}
+
+	set_circuit_breaker {
+		let administrator = funded_caller::<T>();
+		Administrator::<T>::put(&administrator);
+	}: _(RawOrigin::Signed(administrator), true)
+	verify {
+		assert!(BridgeHalted::<T>::get());
+	}
+
+	release_withdrawal {
+		let caller = funded_caller::<T>();
+		let beneficiary = funded_account::<T>("beneficiary", 0);
+		set_epoch::<T>(5);
+		fund_reserve::<T>(1);
+		DelayedWithdrawals::<T>::insert(0, DelayedWithdrawal {
+			beneficiary: beneficiary.clone(),
+			epoch: 4,
+			asset: token_asset::<T>(),
+			value: token_deposit::<T>(),
+			release_epoch: 5,
+		});
+	}: _(RawOrigin::Signed(caller), 0)
+	verify {
+		assert_last_event::<T>(Event::DelayedWithdrawalReleased { id: 0, beneficiary });
+	}
+
+	cancel_withdrawal {
+		let administrator = funded_caller::<T>();
+		Administrator::<T>::put(&administrator);
+		let beneficiary = funded_account::<T>("beneficiary", 0);
+		set_epoch::<T>(5);
+		DelayedWithdrawals::<T>::insert(0, DelayedWithdrawal {
+			beneficiary: beneficiary.clone(),
+			epoch: 4,
+			asset: token_asset::<T>(),
+			value: token_deposit::<T>(),
+			release_epoch: 5,
+		});
+	}: _(RawOrigin::Signed(administrator), 0)
+	verify {
+		assert_last_event::<T>(Event::DelayedWithdrawalCancelled { id: 0, beneficiary, epoch: 5 });
+	}
+
+	prune_deposits {
+		let n in 0 .. 1_000;
+
+		set_epoch::<T>(T::DepositRetention::get() + 1);
+		for i in 0..n {
+			let depositor = account::<T::AccountId>("depositor", i, SEED);
+			Deposits::<T>::insert((1, &depositor, token_asset::<T>()), token_deposit::<T>());
+			DepositEpochs::<T>::insert(depositor, 1, ());
+		}
+		DepositPruneCursor::<T>::put(1);
+	}: {
+		Wildcard::<T>::prune_deposits(Weight::MAX);
+	}
+	verify {
+		assert!(Deposits::<T>::iter_keys().next().is_none());
+		assert_eq!(DepositPruneCursor::<T>::get(), 2);
+	}
+
+	impl_benchmark_test_suite!(
+		Wildcard,
+		crate::tests::mock::ExtBuilder::default().build(),
+		crate::tests::mock::Test,
+	)
+}
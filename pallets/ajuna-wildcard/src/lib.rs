@@ -1,13 +1,19 @@
 #![allow(dead_code)]
 #![cfg_attr(not(feature = "std"), no_std)]
+#![recursion_limit = "256"]
 
 mod asset;
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod migration;
 #[cfg(test)]
 mod tests;
+pub mod weights;
 
 pub use pallet::*;
 
 pub use asset::{OnMappingRequest, WideId};
+pub use weights::WeightInfo;
 
 use frame_support::{
 	pallet_prelude::*,
@@ -16,13 +22,15 @@ use frame_support::{
 			fungibles, nonfungibles_v2, ExistenceRequirement::AllowDeath, Fortitude::Polite,
 			Precision::Exact, Preservation::Expendable,
 		},
-		Currency, ReservableCurrency, Time,
+		Currency, Imbalance, ReservableCurrency, Time,
 	},
 	PalletId,
 };
 use frame_system::pallet_prelude::*;
 use sp_core::sp_std;
-use sp_runtime::traits::{AccountIdConversion, SaturatedConversion, Verify};
+use sp_runtime::traits::{
+	AccountIdConversion, CheckedDiv, One, SaturatedConversion, Saturating, Verify, Zero,
+};
 
 use asset::*;
 
@@ -55,6 +63,24 @@ pub mod pallet {
 	pub(crate) type AssetOf<T> = Asset<AssetIdOf<T>, CollectionIdOf<T>, ItemIdOf<T>>;
 	pub(crate) type NftAddressOf<T> =
 		NftAddress<<T as Config>::CollectionId, <T as Config>::ItemId>;
+	pub(crate) type BalanceInclusionOf<T> = BalanceInclusion<<T as Config>::MaxMerkleDepth>;
+	pub(crate) type SignerSetOf<T> = SignerSet<<T as Config>::MaxSigners>;
+	pub(crate) type RetiredSignerSetOf<T> = RetiredSignerSet<<T as Config>::MaxSigners>;
+	pub(crate) type ChallengeBondOf<T> = ChallengeBond<BalanceOf<T>>;
+	pub(crate) type OperatorOf<T> = Operator<BalanceOf<T>>;
+	pub(crate) type LimitedAssetOf<T> = LimitedAsset<AssetIdOf<T>, CollectionIdOf<T>>;
+	pub(crate) type LimitKeyOf<T> = (AssetOrigin, LimitedAssetOf<T>);
+	pub(crate) type FlowKeyOf<T> = (FlowDirection, AssetOrigin, LimitedAssetOf<T>);
+	pub(crate) type AssetLimitsOf<T> = AssetLimits<BalanceOf<T>>;
+	pub(crate) type FlowCounterOf<T> = FlowCounter<BalanceOf<T>>;
+	pub(crate) type DelayedWithdrawalOf<T> = DelayedWithdrawal<
+		<T as frame_system::Config>::AccountId,
+		AssetOf<T>,
+		DepositValueKindOf<T>,
+	>;
+	pub(crate) type DelayedWithdrawalId = u32;
+	pub(crate) type ProofSignaturesOf<T> =
+		BoundedVec<sp_core::sr25519::Signature, <T as Config>::MaxSigners>;
 
 	#[pallet::storage]
 	pub type Administrator<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
@@ -65,8 +91,36 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type EpochDuration<T: Config> = StorageValue<_, MomentOf<T>, OptionQuery>;
 
+	/// The keys currently entitled to sign proofs.
+	#[pallet::storage]
+	pub type Signers<T: Config> = StorageValue<_, SignerSetOf<T>, OptionQuery>;
+
+	/// The epoch in which the current signer set was rotated in.
+	#[pallet::storage]
+	pub type SignersSince<T: Config> = StorageValue<_, EpochNumber, ValueQuery>;
+
+	/// The keys replaced by the last rotation, which are still accepted until the end of their
+	/// `active_until` epoch.
+	#[pallet::storage]
+	pub type RetiringSigners<T: Config> = StorageValue<_, RetiredSignerSetOf<T>, OptionQuery>;
+
+	/// The registered operators, whose keys can be part of the signer set.
 	#[pallet::storage]
-	pub type SignerKey<T: Config> = StorageValue<_, sp_core::sr25519::Public, OptionQuery>;
+	pub type Operators<T: Config> =
+		StorageMap<_, Identity, T::AccountId, OperatorOf<T>, OptionQuery>;
+
+	/// The operator owning each registered signer key.
+	#[pallet::storage]
+	pub type OperatorSigners<T: Config> =
+		StorageMap<_, Blake2_128Concat, sp_core::sr25519::Public, T::AccountId, OptionQuery>;
+
+	/// The operator bonds slashed on a freeze, and the bonds of frivolous challenges, held by the
+	/// reserve account. They pay the rewards of freezing challenges and cover native token
+	/// shortfalls of the refunds and withdrawals that follow a freeze. Bonds are paid in
+	/// the native token, so they never cover shortfalls of other assets, whose withdrawals fail
+	/// with `InsufficientReserveFunds` instead.
+	#[pallet::storage]
+	pub type ShortfallCover<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
 	#[pallet::storage]
 	pub type Frozen<T: Config> = StorageValue<_, EpochNumber, OptionQuery>;
@@ -83,6 +137,16 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The epochs in which each account has deposits, used to look up an account's deposits
+	/// without iterating over all of them.
+	#[pallet::storage]
+	pub type DepositEpochs<T: Config> =
+		StorageDoubleMap<_, Identity, T::AccountId, Blake2_128Concat, EpochNumber, (), OptionQuery>;
+
+	/// The oldest epoch whose deposits may not have been pruned yet.
+	#[pallet::storage]
+	pub type DepositPruneCursor<T: Config> = StorageValue<_, EpochNumber, ValueQuery>;
+
 	#[pallet::storage]
 	pub type Withdrawals<T: Config> = StorageDoubleMap<
 		_,
@@ -98,6 +162,49 @@ pub mod pallet {
 	pub type Challenges<T: Config> =
 		StorageMap<_, Identity, (EpochNumber, T::AccountId), ChunkIndex, OptionQuery>;
 
+	/// The number of unanswered challenges of each epoch.
+	#[pallet::storage]
+	pub type OpenChallenges<T: Config> =
+		StorageMap<_, Blake2_128Concat, EpochNumber, u32, ValueQuery>;
+
+	#[pallet::storage]
+	pub type ChallengeBonds<T: Config> =
+		StorageMap<_, Identity, (EpochNumber, T::AccountId), ChallengeBondOf<T>, OptionQuery>;
+
+	/// The flow limits of each asset, assets without limits can flow freely.
+	#[pallet::storage]
+	pub type AssetFlowLimits<T: Config> =
+		StorageMap<_, Blake2_128Concat, LimitKeyOf<T>, AssetLimitsOf<T>, OptionQuery>;
+
+	/// The amount of each limited asset deposited and withdrawn in the latest epoch it flowed.
+	#[pallet::storage]
+	pub type EpochFlows<T: Config> =
+		StorageMap<_, Blake2_128Concat, FlowKeyOf<T>, FlowCounterOf<T>, OptionQuery>;
+
+	/// The amount of each limited asset deposited and withdrawn by an account in the latest epoch
+	/// it flowed.
+	#[pallet::storage]
+	pub type AccountFlows<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		T::AccountId,
+		Blake2_128Concat,
+		FlowKeyOf<T>,
+		FlowCounterOf<T>,
+		OptionQuery,
+	>;
+
+	/// Whether all deposits and withdrawals are halted by the circuit breaker.
+	#[pallet::storage]
+	pub type BridgeHalted<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	#[pallet::storage]
+	pub type DelayedWithdrawals<T: Config> =
+		StorageMap<_, Blake2_128Concat, DelayedWithdrawalId, DelayedWithdrawalOf<T>, OptionQuery>;
+
+	#[pallet::storage]
+	pub type NextDelayedWithdrawalId<T: Config> = StorageValue<_, DelayedWithdrawalId, ValueQuery>;
+
 	#[pallet::storage]
 	pub type AssetIdMapping<T: Config> =
 		StorageMap<_, Blake2_128, MappingKey, AssetIdOf<T>, OptionQuery>;
@@ -111,6 +218,7 @@ pub mod pallet {
 		StorageMap<_, Blake2_128, MappingKey, ItemIdOf<T>, OptionQuery>;
 
 	#[pallet::pallet]
+	#[pallet::storage_version(migration::STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::error]
@@ -151,7 +259,7 @@ pub mod pallet {
 		BadExitFlag,
 		/// The public signer key cannot be found, set it before trying to validate signatures.
 		MissingPublicKey,
-		/// The signature doesn't match the expected value.
+		/// The signatures don't reach the threshold of any accepted signer set.
 		BadSignature,
 		/// The pallet's StartTime or EpochDuration parameters have not been set.
 		TimeNotSet,
@@ -177,6 +285,46 @@ pub mod pallet {
 		NotFrozen,
 		/// The pallet is frozen, so deposits and withdrawals are blocked.
 		PalletFrozen,
+		/// The balance proof is not included in the signed balance root.
+		InvalidMerkleProof,
+		/// No balance proofs were submitted along with the balance root.
+		EmptyBatch,
+		/// The signer set has repeated keys or a threshold that cannot be met.
+		InvalidSignerSet,
+		/// The overlap of a signer rotation must last until proofs of the current epoch are final.
+		InvalidOverlapEpoch,
+		/// There is no bond for the given challenge.
+		ChallengeBondNotFound,
+		/// The challenge bond cannot be claimed until the challenge is settled.
+		ChallengeBondLocked,
+		/// The challenge bond can only be slashed during the review of an answered challenge.
+		ChallengeBondNotSlashable,
+		/// The account is already registered as an operator.
+		OperatorAlreadyRegistered,
+		/// The account is not registered as an operator.
+		OperatorNotFound,
+		/// The signer key is already registered by another operator.
+		SignerKeyInUse,
+		/// The operator's bond is lower than the minimum operator bond.
+		InsufficientOperatorBond,
+		/// A key of the signer set doesn't belong to an operator of this chain.
+		SignerNotOperator,
+		/// The operator's key is still in the signer set, or its proofs can still be challenged.
+		OperatorStillSigning,
+		/// Deposits and withdrawals are halted by the circuit breaker.
+		BridgeHalted,
+		/// The asset's deposits or withdrawals for this epoch would exceed its limit.
+		EpochFlowLimitExceeded,
+		/// The account's deposits or withdrawals of the asset for this epoch would exceed its
+		/// limit.
+		AccountFlowLimitExceeded,
+		/// There is no delayed withdrawal with the given identifier.
+		DelayedWithdrawalNotFound,
+		/// The delayed withdrawal cannot be released before its release epoch.
+		DelayedWithdrawalLocked,
+		/// The signers cannot be rotated while the proofs of the retiring signers can still be
+		/// challenged.
+		SignerRotationInProgress,
 	}
 
 	#[pallet::event]
@@ -188,7 +336,10 @@ pub mod pallet {
 		ParametersSet {
 			start_time: Option<MomentOf<T>>,
 			epoch_duration: Option<MomentOf<T>>,
-			signer_key: Option<sp_core::sr25519::Public>,
+		},
+		SignersRotated {
+			signers: SignerSetOf<T>,
+			overlap_until: Option<EpochNumber>,
 		},
 		AssetDeposit {
 			epoch: EpochNumber,
@@ -232,6 +383,67 @@ pub mod pallet {
 			epoch: EpochNumber,
 			challenger: T::AccountId,
 		},
+		ChallengeBondReturned {
+			epoch: EpochNumber,
+			challenger: T::AccountId,
+			bond: BalanceOf<T>,
+		},
+		ChallengerRewarded {
+			epoch: EpochNumber,
+			challenger: T::AccountId,
+			bond: BalanceOf<T>,
+			reward: BalanceOf<T>,
+		},
+		ChallengeBondSlashed {
+			epoch: EpochNumber,
+			challenger: T::AccountId,
+			bond: BalanceOf<T>,
+		},
+		OperatorRegistered {
+			operator: T::AccountId,
+			signer: sp_core::sr25519::Public,
+			chain_id: ChainId,
+			bond: BalanceOf<T>,
+		},
+		OperatorBonded {
+			operator: T::AccountId,
+			bond: BalanceOf<T>,
+		},
+		OperatorUnregistered {
+			operator: T::AccountId,
+			bond: BalanceOf<T>,
+		},
+		OperatorSlashed {
+			operator: T::AccountId,
+			frozen_epoch: EpochNumber,
+			amount: BalanceOf<T>,
+		},
+		ShortfallCovered {
+			beneficiary: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		AssetLimitsSet {
+			asset_origin: AssetOrigin,
+			asset: LimitedAssetOf<T>,
+			limits: Option<AssetLimitsOf<T>>,
+		},
+		CircuitBreakerSet {
+			halted: bool,
+		},
+		WithdrawalDelayed {
+			id: DelayedWithdrawalId,
+			beneficiary: T::AccountId,
+			release_epoch: EpochNumber,
+		},
+		DelayedWithdrawalReleased {
+			id: DelayedWithdrawalId,
+			beneficiary: T::AccountId,
+		},
+		DelayedWithdrawalCancelled {
+			id: DelayedWithdrawalId,
+			beneficiary: T::AccountId,
+			epoch: EpochNumber,
+		},
 	}
 
 	#[pallet::config]
@@ -279,6 +491,78 @@ pub mod pallet {
 
 		/// Minimum amount of free balance in an account wishing to challenge an epoch
 		type ChallengeMinBalance: Get<BalanceOf<Self>>;
+
+		/// The amount reserved from a challenger until its challenge is settled.
+		#[pallet::constant]
+		type ChallengeBond: Get<BalanceOf<Self>>;
+
+		/// The reward paid, on top of the returned bond, to a challenger whose challenge froze the
+		/// pallet. It is paid out of the slashed bonds held as `ShortfallCover`, as far as they
+		/// allow.
+		#[pallet::constant]
+		type ChallengeReward: Get<BalanceOf<Self>>;
+
+		/// The number of epochs after a challenge is answered during which the administrator can
+		/// slash its bond.
+		#[pallet::constant]
+		type ChallengeReviewPeriod: Get<EpochNumber>;
+
+		/// The minimum amount an operator must bond for its key to be part of the signer set.
+		#[pallet::constant]
+		type MinOperatorBond: Get<BalanceOf<Self>>;
+
+		/// The number of epochs a withdrawal above its asset's delay threshold is held back for.
+		#[pallet::constant]
+		type WithdrawalDelay: Get<EpochNumber>;
+
+		/// The maximum number of deposits refunded by a single call once the pallet is frozen.
+		#[pallet::constant]
+		type MaxRefundsPerCall: Get<u32>;
+
+		/// The number of epochs for which deposits are kept, counting the current one. Deposits
+		/// older than that are pruned when blocks have weight to spare. It must be at least 4, so
+		/// that deposits made after a frozen epoch can still be refunded.
+		#[pallet::constant]
+		type DepositRetention: Get<EpochNumber>;
+
+		/// The maximum depth of the Merkle tree behind a signed balance root.
+		#[pallet::constant]
+		type MaxMerkleDepth: Get<u32>;
+
+		/// The maximum number of balance proofs that can be submitted against a balance root in a
+		/// single call.
+		#[pallet::constant]
+		type MaxBatchedProofs: Get<u32>;
+
+		/// The maximum number of keys in the signer set.
+		#[pallet::constant]
+		type MaxSigners: Get<u32>;
+
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<MomentOf<Self>>;
+
+		/// Weight information for the pallet's extrinsics.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	pub trait BenchmarkHelper<Moment> {
+		/// Sets the moment returned by the pallet's `Time` provider.
+		fn set_time(now: Moment);
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::prune_deposits(remaining_weight)
+		}
+
+		fn integrity_test() {
+			assert!(
+				T::DepositRetention::get() >= 4,
+				"Deposits must be kept for at least 4 epochs to be refundable after a freeze"
+			);
+		}
 	}
 
 	#[pallet::call]
@@ -287,7 +571,7 @@ pub mod pallet {
 		///
 		/// This call allows setting an account to act as an administrator. It must be called with
 		/// root privilege.
-		#[pallet::weight({10_000})]
+		#[pallet::weight(T::WeightInfo::set_administrator())]
 		#[pallet::call_index(0)]
 		pub fn set_administrator(
 			origin: OriginFor<T>,
@@ -300,13 +584,16 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight({10_000})]
-		#[pallet::call_index(1)]
+		/// Set the start time and duration of the epochs.
+		///
+		/// Call index 1 is retired, as it also set the signer key, which is now rotated with
+		/// `rotate_signers`.
+		#[pallet::weight(T::WeightInfo::set_parameters())]
+		#[pallet::call_index(24)]
 		pub fn set_parameters(
 			origin: OriginFor<T>,
 			start_time: Option<MomentOf<T>>,
 			epoch_duration: Option<MomentOf<T>>,
-			signer_key: Option<sp_core::sr25519::Public>,
 		) -> DispatchResult {
 			let admin = Administrator::<T>::get();
 			ensure!(admin.is_some(), Error::<T>::AdministratorNotSet);
@@ -322,19 +609,14 @@ pub mod pallet {
 				EpochDuration::<T>::put(epoch_duration);
 			}
 
-			if let Some(signer_key) = signer_key {
-				SignerKey::<T>::put(signer_key);
-			}
-
 			Self::deposit_event(Event::ParametersSet {
 				start_time: StartTime::<T>::get(),
 				epoch_duration: EpochDuration::<T>::get(),
-				signer_key: SignerKey::<T>::get(),
 			});
 			Ok(())
 		}
 
-		#[pallet::weight({10_000})]
+		#[pallet::weight(T::WeightInfo::deposit())]
 		#[pallet::call_index(2)]
 		pub fn deposit(origin: OriginFor<T>, asset_deposit: AssetDeposit) -> DispatchResult {
 			ensure!(!Frozen::<T>::exists(), Error::<T>::PalletFrozen);
@@ -361,32 +643,20 @@ pub mod pallet {
 				secondary_id: asset_deposit.secondary_id,
 			});
 
-			if deposit_epoch > 4 {
-				Self::clear_old_deposit_entries(deposit_epoch.saturating_sub(4))?;
-			}
-
 			Ok(())
 		}
 
-		#[pallet::weight({10_000})]
+		#[pallet::weight(T::WeightInfo::withdraw())]
 		#[pallet::call_index(3)]
 		pub fn withdraw(
 			origin: OriginFor<T>,
 			balance_proof: BalanceProof,
-			signature: sp_core::sr25519::Signature,
+			signatures: ProofSignaturesOf<T>,
 		) -> DispatchResult {
 			let withdrawer = ensure_signed(origin)?;
-			let (asset, value, epoch_num) =
-				Self::validate_and_convert_proof(&withdrawer, &balance_proof, &signature)?;
-
-			match &asset.kind {
-				AssetKind::Fungible(asset_id) =>
-					Self::unreserve_fungibles(&withdrawer, &asset.origin, asset_id, &value),
-				AssetKind::NonFungible(addr) =>
-					Self::unreserve_non_fungibles(&withdrawer, &asset.origin, addr),
-			}?;
-
-			Self::register_withdrawal(epoch_num, &withdrawer, &asset)?;
+			Self::withdraw_asset(&withdrawer, &balance_proof, |proof| {
+				Self::validate_proof_signatures(proof, &signatures)
+			})?;
 
 			Self::deposit_event(Event::<T>::AssetWithdraw {
 				epoch: balance_proof.epoch,
@@ -400,12 +670,12 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight({10_000})]
+		#[pallet::weight(T::WeightInfo::withdraw_frozen())]
 		#[pallet::call_index(4)]
 		pub fn withdraw_frozen(
 			origin: OriginFor<T>,
 			balance_proof: BalanceProof,
-			signature: sp_core::sr25519::Signature,
+			signatures: ProofSignaturesOf<T>,
 		) -> DispatchResult {
 			let withdrawer = ensure_signed(origin)?;
 
@@ -416,17 +686,9 @@ pub mod pallet {
 				Error::<T>::InvalidEpochNumber
 			);
 
-			let (asset, value, epoch_num) =
-				Self::validate_and_convert_proof(&withdrawer, &balance_proof, &signature)?;
-
-			match &asset.kind {
-				AssetKind::Fungible(asset_id) =>
-					Self::unreserve_fungibles(&withdrawer, &asset.origin, asset_id, &value),
-				AssetKind::NonFungible(addr) =>
-					Self::unreserve_non_fungibles(&withdrawer, &asset.origin, addr),
-			}?;
-
-			Self::register_withdrawal(epoch_num, &withdrawer, &asset)?;
+			Self::withdraw_asset(&withdrawer, &balance_proof, |proof| {
+				Self::validate_proof_signatures(proof, &signatures)
+			})?;
 
 			Self::deposit_event(Event::<T>::FrozenAssetWithdraw {
 				epoch: balance_proof.epoch,
@@ -440,70 +702,44 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight({10_000})]
+		/// Refund the caller's deposits made after the frozen epoch.
+		///
+		/// At most `MaxRefundsPerCall` deposits are refunded, any remaining ones can be refunded
+		/// by calling it again.
+		#[pallet::weight(T::WeightInfo::refund_frozen(T::MaxRefundsPerCall::get()))]
 		#[pallet::call_index(5)]
 		pub fn refund_frozen(origin: OriginFor<T>) -> DispatchResult {
 			let withdrawer = ensure_signed(origin)?;
-
-			// POSSIBLE IMPROVEMENT
-			// can also be done for an other account, like claim can be done for claimFor().
-
-			// one for the own account
-			// one for any other account
-			// one for all accounts
-
-			let frozen_epoch = {
-				let frozen = Frozen::<T>::get();
-				ensure!(frozen.is_some(), Error::<T>::NotFrozen);
-				frozen.unwrap()
-			};
-
-			let deposits_to_refund = Deposits::<T>::iter_keys()
-				.filter(|(epoch, account, _)| epoch > &frozen_epoch && account == &withdrawer)
-				.collect::<sp_std::vec::Vec<_>>();
-
-			for (epoch, account, asset) in deposits_to_refund {
-				if let Some(deposit_value) = Deposits::<T>::take((&epoch, &account, &asset)) {
-					match &asset.kind {
-						AssetKind::Fungible(asset_id) => Self::unreserve_fungibles(
-							&withdrawer,
-							&asset.origin,
-							asset_id,
-							&deposit_value,
-						),
-						AssetKind::NonFungible(addr) =>
-							Self::unreserve_non_fungibles(&withdrawer, &asset.origin, addr),
-					}?;
-				}
-			}
-
-			Self::deposit_event(Event::<T>::DepositsRefunded {
-				epoch: frozen_epoch,
-				beneficieary: withdrawer,
-			});
-
-			Ok(())
+			Self::do_refund_frozen(withdrawer, T::MaxRefundsPerCall::get())
 		}
 
-		#[pallet::weight({10_000})]
+		#[pallet::weight(T::WeightInfo::challenge())]
 		#[pallet::call_index(6)]
 		pub fn challenge(origin: OriginFor<T>) -> DispatchResult {
 			let challenger = ensure_signed(origin)?;
 
-			ensure!(
-				T::Currency::free_balance(&challenger) >= T::ChallengeMinBalance::get(),
-				Error::<T>::InsufficientChallengeBalance
-			);
-
 			let epoch_number = Self::calculate_epoch_number_from(T::Time::now())?;
 			ensure!(epoch_number > 0, Error::<T>::InvalidEpochNumber);
 
+			// Checked before the balance, which no longer qualifies once the bond is reserved. The
+			// bond outlives an answered challenge until it is claimed, and must not be overwritten.
+			let challenge_key = (epoch_number - 1, challenger.clone());
 			ensure!(
-				!Challenges::<T>::contains_key((epoch_number - 1, challenger.clone())),
+				!Challenges::<T>::contains_key(&challenge_key) &&
+					!ChallengeBonds::<T>::contains_key(&challenge_key),
 				Error::<T>::AlreadyChallengedEpoch
 			);
 
-			Challenges::<T>::insert((epoch_number - 1, challenger.clone()), 0 as ChunkIndex);
+			ensure!(
+				T::Currency::free_balance(&challenger) >= T::ChallengeMinBalance::get(),
+				Error::<T>::InsufficientChallengeBalance
+			);
+
+			let amount = T::ChallengeBond::get();
+			T::Currency::reserve(&challenger, amount)?;
+			ChallengeBonds::<T>::insert(&challenge_key, ChallengeBond { amount, answered_in: None });
+			Challenges::<T>::insert(challenge_key, 0 as ChunkIndex);
+			OpenChallenges::<T>::mutate(epoch_number - 1, |count| count.saturating_inc());
 
 			Self::deposit_event(Event::<T>::ChallengeCalled {
 				epoch: epoch_number - 1,
@@ -513,130 +749,498 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight({10_000})]
+		#[pallet::weight(T::WeightInfo::respond_challenge())]
 		#[pallet::call_index(7)]
 		pub fn respond_challenge(
 			origin: OriginFor<T>,
 			balance_proof: BalanceProof,
-			signature: sp_core::sr25519::Signature,
+			signatures: ProofSignaturesOf<T>,
 		) -> DispatchResult {
 			let _ = ensure_signed(origin)?;
 
-			let challenger = T::AccountId::decode(&mut &balance_proof.account[..])
+			Self::respond_challenge_chunk(balance_proof, |proof| {
+				Self::validate_proof_signatures(proof, &signatures)
+			})
+		}
+
+		#[pallet::weight(T::WeightInfo::freeze(T::MaxSigners::get() * 2))]
+		#[pallet::call_index(8)]
+		pub fn freeze(origin: OriginFor<T>) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+
+			ensure!(!Frozen::<T>::exists(), Error::<T>::PalletFrozen);
+
+			let epoch_number = Self::calculate_epoch_number_from(T::Time::now())?;
+			ensure!(epoch_number >= 4, Error::<T>::InvalidEpochNumber);
+
+			if OpenChallenges::<T>::get(epoch_number - 3) > 0 {
+				Self::freeze_at(epoch_number - 4);
+				Ok(())
+			} else {
+				Err(Error::<T>::NothingToFreeze.into())
+			}
+		}
+
+		#[pallet::weight(T::WeightInfo::propagate_freeze(T::MaxSigners::get() * 2))]
+		#[pallet::call_index(9)]
+		pub fn propagate_freeze(
+			origin: OriginFor<T>,
+			freeze_proof: FreezeProof,
+			client_key: sp_core::sr25519::Public,
+			client_key_signatures: ProofSignaturesOf<T>,
+			proof_signature: sp_core::sr25519::Signature,
+		) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+
+			let client_key_msg = {
+				let mut bytes = sp_std::vec::Vec::with_capacity(0);
+
+				bytes.extend(LIGHT_CLIENT_PROOF_PREFIX.to_vec());
+				bytes.extend(client_key.to_vec());
+
+				bytes
+			};
+
+			Self::validate_signatures(client_key_msg.as_slice(), &client_key_signatures)?;
+
+			let msg = freeze_proof.extract_msg();
+			ensure!(proof_signature.verify(msg.as_slice(), &client_key), Error::<T>::BadSignature);
+
+			let epoch_number = Self::calculate_epoch_number_from(T::Time::now())?;
+			ensure!(epoch_number >= 4, Error::<T>::InvalidEpochNumber);
+			ensure!(epoch_number - 4 == freeze_proof.epoch, Error::<T>::InvalidEpochNumber);
+			Self::freeze_at(freeze_proof.epoch);
+
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::respond_zero_challenge())]
+		#[pallet::call_index(10)]
+		pub fn respond_zero_challenge(
+			origin: OriginFor<T>,
+			zero_proof: ZeroBalanceProof,
+			signatures: ProofSignaturesOf<T>,
+		) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+
+			let challenger = T::AccountId::decode(&mut &zero_proof.account[..])
 				.map_err(|_| Error::<T>::FailedToDecodeAccount)?;
 
-			let challenge_key = (balance_proof.epoch, challenger.clone());
+			let challenge_key = (zero_proof.epoch, challenger.clone());
 
-			if let Some(chunk_index) = Challenges::<T>::get(&challenge_key) {
-				if chunk_index == balance_proof.chunk_index {
-					Self::validate_proof_signature(&balance_proof, &signature)?;
+			if Challenges::<T>::get(&challenge_key).is_some() {
+				Self::validate_proof_signatures(&zero_proof, &signatures)?;
 
-					Self::deposit_event(Event::<T>::ChallengeResponded {
-						challenger,
-						challenged_epoch: balance_proof.epoch,
-						balance_proof,
-					});
+				Self::deposit_event(Event::<T>::ChallengeZeroResponded {
+					challenger,
+					challenged_epoch: zero_proof.epoch,
+					balance_proof: zero_proof,
+				});
 
-					if chunk_index == balance_proof.chunk_last {
-						Challenges::<T>::remove(&challenge_key);
-					} else {
-						Challenges::<T>::insert(challenge_key, chunk_index.saturating_add(1));
-					}
-					Ok(())
-				} else {
-					Err(Error::<T>::WrongChunkRespondedChallenge.into())
-				}
+				Self::close_challenge(&challenge_key)?;
+
+				Ok(())
 			} else {
 				Err(Error::<T>::WronglyRespondedChallenge.into())
 			}
 		}
 
-		#[pallet::weight({10_000})]
-		#[pallet::call_index(8)]
-		pub fn freeze(origin: OriginFor<T>) -> DispatchResult {
+		/// Withdraw several assets using a single signed balance root.
+		///
+		/// Instead of a signature per balance proof, the operator signs the Merkle root of an
+		/// epoch's balance proofs once, and each submitted proof comes with its inclusion path in
+		/// that root. Every proof is otherwise validated as in `withdraw`, so withdrawals of the
+		/// same asset must still be submitted in order.
+		#[pallet::weight(T::WeightInfo::withdraw_batch(inclusions.len() as u32))]
+		#[pallet::call_index(11)]
+		pub fn withdraw_batch(
+			origin: OriginFor<T>,
+			balance_root: BalanceRoot,
+			root_signatures: ProofSignaturesOf<T>,
+			inclusions: BoundedVec<BalanceInclusionOf<T>, T::MaxBatchedProofs>,
+		) -> DispatchResult {
+			let withdrawer = ensure_signed(origin)?;
+			ensure!(!inclusions.is_empty(), Error::<T>::EmptyBatch);
+			Self::validate_proof_signatures(&balance_root, &root_signatures)?;
+
+			for BalanceInclusion { balance_proof, merkle_path } in inclusions {
+				Self::withdraw_asset(&withdrawer, &balance_proof, |proof| {
+					Self::validate_inclusion(&balance_root, proof, &merkle_path)
+				})?;
+
+				Self::deposit_event(Event::<T>::AssetWithdraw {
+					epoch: balance_proof.epoch,
+					withdrawer: withdrawer.clone(),
+					asset_origin: balance_proof.origin,
+					asset_type: AssetType::from(balance_proof.asset_type),
+					primary_id: balance_proof.primary_id,
+					secondary_id: balance_proof.secondary_id,
+				});
+			}
+
+			Ok(())
+		}
+
+		/// Respond to challenges using a single signed balance root.
+		///
+		/// Each balance proof comes with its inclusion path in the signed root and answers the
+		/// next expected chunk of its challenge, exactly as in `respond_challenge`.
+		#[pallet::weight(T::WeightInfo::respond_challenge_batch(inclusions.len() as u32))]
+		#[pallet::call_index(12)]
+		pub fn respond_challenge_batch(
+			origin: OriginFor<T>,
+			balance_root: BalanceRoot,
+			root_signatures: ProofSignaturesOf<T>,
+			inclusions: BoundedVec<BalanceInclusionOf<T>, T::MaxBatchedProofs>,
+		) -> DispatchResult {
 			let _ = ensure_signed(origin)?;
+			ensure!(!inclusions.is_empty(), Error::<T>::EmptyBatch);
+			Self::validate_proof_signatures(&balance_root, &root_signatures)?;
+
+			for BalanceInclusion { balance_proof, merkle_path } in inclusions {
+				Self::respond_challenge_chunk(balance_proof, |proof| {
+					Self::validate_inclusion(&balance_root, proof, &merkle_path)
+				})?;
+			}
+
+			Ok(())
+		}
+
+		/// Rotate the keys entitled to sign proofs.
+		///
+		/// Proofs must be signed by at least `threshold` of the given keys. The first signer set
+		/// applies right away, while any later one replaces the current set but keeps accepting
+		/// proofs signed by it until the end of `overlap_until`, so that proofs issued just before
+		/// the rotation remain usable until they are final. The overlap must therefore last at
+		/// least 4 epochs, and no other rotation can happen until the proofs signed during it can
+		/// no longer be challenged, so that the retiring keys are slashed on a freeze until then.
+		/// Every key must belong
+		/// to an operator of this chain that has bonded at least `MinOperatorBond`. Can only be
+		/// called by the administrator.
+		#[pallet::weight(T::WeightInfo::rotate_signers())]
+		#[pallet::call_index(13)]
+		pub fn rotate_signers(
+			origin: OriginFor<T>,
+			keys: BoundedVec<sp_core::sr25519::Public, T::MaxSigners>,
+			threshold: u32,
+			overlap_until: EpochNumber,
+		) -> DispatchResult {
+			let admin = Administrator::<T>::get();
+			ensure!(admin.is_some(), Error::<T>::AdministratorNotSet);
+
+			let account = ensure_signed(origin)?;
+			ensure!(admin.unwrap() == account, Error::<T>::AccountIsNotAdministrator);
+
+			let signers = SignerSet { keys, threshold };
+			ensure!(signers.is_valid(), Error::<T>::InvalidSignerSet);
+
+			for key in signers.keys.iter() {
+				let operator = OperatorSigners::<T>::get(key)
+					.and_then(Operators::<T>::get)
+					.filter(|operator| operator.chain_id == T::ChainId::get())
+					.ok_or(Error::<T>::SignerNotOperator)?;
+				ensure!(
+					operator.bond >= T::MinOperatorBond::get(),
+					Error::<T>::InsufficientOperatorBond
+				);
+			}
+
+			let overlap_until = match Signers::<T>::get() {
+				Some(current_signers) => {
+					let epoch_number = Self::calculate_epoch_number_from(T::Time::now())?;
+					ensure!(
+						overlap_until >= epoch_number.saturating_add(4),
+						Error::<T>::InvalidOverlapEpoch
+					);
+					let is_retiring = RetiringSigners::<T>::get().is_some_and(|retiring| {
+						epoch_number <= retiring.active_until.saturating_add(4)
+					});
+					ensure!(!is_retiring, Error::<T>::SignerRotationInProgress);
+					RetiringSigners::<T>::put(RetiredSignerSet {
+						signers: current_signers,
+						active_since: SignersSince::<T>::get(),
+						active_until: overlap_until,
+					});
+					SignersSince::<T>::put(epoch_number);
+					Some(overlap_until)
+				},
+				None => {
+					SignersSince::<T>::kill();
+					None
+				},
+			};
+			Signers::<T>::put(&signers);
+
+			Self::deposit_event(Event::SignersRotated { signers, overlap_until });
+			Ok(())
+		}
+
+		/// Claim back the bond of a settled challenge.
+		///
+		/// The bond is returned once the review period of an answered challenge is over, or once
+		/// an unanswered challenge can no longer freeze the pallet. If the challenge did freeze
+		/// the pallet, the challenger is also paid `ChallengeReward` out of the slashed bonds, or
+		/// what is left of them.
+		#[pallet::weight(T::WeightInfo::claim_challenge_bond())]
+		#[pallet::call_index(14)]
+		pub fn claim_challenge_bond(origin: OriginFor<T>, epoch: EpochNumber) -> DispatchResult {
+			let challenger = ensure_signed(origin)?;
+
+			let challenge_key = (epoch, challenger.clone());
+			let bond = ChallengeBonds::<T>::get(&challenge_key)
+				.ok_or(Error::<T>::ChallengeBondNotFound)?;
+			let epoch_number = Self::calculate_epoch_number_from(T::Time::now())?;
+			let frozen = Frozen::<T>::get();
+
+			let has_frozen = bond.answered_in.is_none() &&
+				frozen.is_some_and(|frozen_epoch| frozen_epoch.saturating_add(1) == epoch);
+			let is_settled = match bond.answered_in {
+				Some(answered_in) =>
+					epoch_number > answered_in.saturating_add(T::ChallengeReviewPeriod::get()),
+				None => frozen.is_some() || epoch_number > epoch.saturating_add(3),
+			};
+			ensure!(is_settled, Error::<T>::ChallengeBondLocked);
+
+			ChallengeBonds::<T>::remove(&challenge_key);
+			T::Currency::unreserve(&challenger, bond.amount);
+
+			if has_frozen {
+				let reward = Self::pay_challenge_reward(&challenger);
+				Self::deposit_event(Event::ChallengerRewarded {
+					epoch,
+					challenger,
+					bond: bond.amount,
+					reward,
+				});
+			} else {
+				Self::deposit_event(Event::ChallengeBondReturned {
+					epoch,
+					challenger,
+					bond: bond.amount,
+				});
+			}
+
+			Ok(())
+		}
+
+		/// Slash the bond of a frivolous challenge.
+		///
+		/// Only the bonds of challenges answered by the operator can be slashed, and only until
+		/// their review period is over. Can only be called by the administrator.
+		#[pallet::weight(T::WeightInfo::slash_challenge_bond())]
+		#[pallet::call_index(15)]
+		pub fn slash_challenge_bond(
+			origin: OriginFor<T>,
+			challenger: T::AccountId,
+			epoch: EpochNumber,
+		) -> DispatchResult {
+			let admin = Administrator::<T>::get();
+			ensure!(admin.is_some(), Error::<T>::AdministratorNotSet);
+
+			let account = ensure_signed(origin)?;
+			ensure!(admin.unwrap() == account, Error::<T>::AccountIsNotAdministrator);
+
+			let challenge_key = (epoch, challenger.clone());
+			let bond = ChallengeBonds::<T>::get(&challenge_key)
+				.ok_or(Error::<T>::ChallengeBondNotFound)?;
+			let epoch_number = Self::calculate_epoch_number_from(T::Time::now())?;
+			ensure!(
+				bond.answered_in.is_some_and(|answered_in| {
+					epoch_number <= answered_in.saturating_add(T::ChallengeReviewPeriod::get())
+				}),
+				Error::<T>::ChallengeBondNotSlashable
+			);
+
+			ChallengeBonds::<T>::remove(&challenge_key);
+			let (slashed, _) = T::Currency::slash_reserved(&challenger, bond.amount);
+			let amount = slashed.peek();
+			T::Currency::resolve_creating(&Self::reserve_account(), slashed);
+			ShortfallCover::<T>::mutate(|cover| cover.saturating_accrue(amount));
+
+			Self::deposit_event(Event::ChallengeBondSlashed { epoch, challenger, bond: amount });
+			Ok(())
+		}
+
+		/// Refund the deposits of `beneficiary` made after the frozen epoch.
+		///
+		/// Anyone can trigger the refund on behalf of the beneficiary, which receives the refunded
+		/// assets. At most `max_refunds` deposits are refunded, capped by `MaxRefundsPerCall`, so
+		/// that large refunds can be split across several calls.
+		#[pallet::weight(
+			T::WeightInfo::refund_frozen((*max_refunds).min(T::MaxRefundsPerCall::get()))
+		)]
+		#[pallet::call_index(16)]
+		pub fn refund_frozen_for(
+			origin: OriginFor<T>,
+			beneficiary: T::AccountId,
+			max_refunds: u32,
+		) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+			Self::do_refund_frozen(beneficiary, max_refunds.min(T::MaxRefundsPerCall::get()))
+		}
+
+		/// Register the caller as a bridge operator.
+		///
+		/// The operator reserves `bond` as collateral for the proofs signed with `signer` for the
+		/// given chain. Once registered, the administrator can add the key to the signer set. The
+		/// bond is slashed if the pallet freezes while the key is signing.
+		#[pallet::weight(T::WeightInfo::register_operator())]
+		#[pallet::call_index(17)]
+		pub fn register_operator(
+			origin: OriginFor<T>,
+			signer: sp_core::sr25519::Public,
+			chain_id: ChainId,
+			bond: BalanceOf<T>,
+		) -> DispatchResult {
+			let operator = ensure_signed(origin)?;
+
+			ensure!(
+				!Operators::<T>::contains_key(&operator),
+				Error::<T>::OperatorAlreadyRegistered
+			);
+			ensure!(!OperatorSigners::<T>::contains_key(signer), Error::<T>::SignerKeyInUse);
+			ensure!(bond >= T::MinOperatorBond::get(), Error::<T>::InsufficientOperatorBond);
+
+			T::Currency::reserve(&operator, bond)?;
+			Operators::<T>::insert(&operator, Operator { signer, chain_id, bond });
+			OperatorSigners::<T>::insert(signer, &operator);
+
+			Self::deposit_event(Event::OperatorRegistered { operator, signer, chain_id, bond });
+			Ok(())
+		}
+
+		/// Add `amount` to the caller's operator bond.
+		#[pallet::weight(T::WeightInfo::bond_operator_extra())]
+		#[pallet::call_index(18)]
+		pub fn bond_operator_extra(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+			let operator = ensure_signed(origin)?;
+
+			let bond = Operators::<T>::try_mutate(&operator, |maybe_operator| {
+				let info = maybe_operator.as_mut().ok_or(Error::<T>::OperatorNotFound)?;
+				T::Currency::reserve(&operator, amount)?;
+				info.bond.saturating_accrue(amount);
+				Ok::<_, DispatchError>(info.bond)
+			})?;
+
+			Self::deposit_event(Event::OperatorBonded { operator, bond });
+			Ok(())
+		}
+
+		/// Unregister the caller as an operator and return its remaining bond.
+		///
+		/// The operator's key must no longer be in the signer set, and the proofs signed while it
+		/// was retiring must no longer be challengeable.
+		#[pallet::weight(T::WeightInfo::unregister_operator())]
+		#[pallet::call_index(19)]
+		pub fn unregister_operator(origin: OriginFor<T>) -> DispatchResult {
+			let operator = ensure_signed(origin)?;
 
-			ensure!(!Frozen::<T>::exists(), Error::<T>::PalletFrozen);
+			let info = Operators::<T>::get(&operator).ok_or(Error::<T>::OperatorNotFound)?;
+			Self::ensure_not_signing(&info.signer)?;
 
-			let epoch_number = Self::calculate_epoch_number_from(T::Time::now())?;
-			ensure!(epoch_number >= 4, Error::<T>::InvalidEpochNumber);
+			Operators::<T>::remove(&operator);
+			OperatorSigners::<T>::remove(info.signer);
+			T::Currency::unreserve(&operator, info.bond);
 
-			if Challenges::<T>::iter_keys().any(|(epoch, _)| epoch == (epoch_number - 3)) {
-				Frozen::<T>::set(Some(epoch_number - 4));
-				Ok(())
-			} else {
-				Err(Error::<T>::NothingToFreeze.into())
-			}
+			Self::deposit_event(Event::OperatorUnregistered { operator, bond: info.bond });
+			Ok(())
 		}
 
-		#[pallet::weight({10_000})]
-		#[pallet::call_index(9)]
-		pub fn propagate_freeze(
+		/// Set, or remove, the flow limits of an asset or collection of non-fungibles.
+		///
+		/// Can only be called by the administrator.
+		#[pallet::weight(T::WeightInfo::set_asset_limits())]
+		#[pallet::call_index(20)]
+		pub fn set_asset_limits(
 			origin: OriginFor<T>,
-			freeze_proof: FreezeProof,
-			client_key: sp_core::sr25519::Public,
-			client_key_signature: sp_core::sr25519::Signature,
-			proof_signature: sp_core::sr25519::Signature,
+			asset_origin: AssetOrigin,
+			asset: LimitedAssetOf<T>,
+			limits: Option<AssetLimitsOf<T>>,
 		) -> DispatchResult {
-			let _ = ensure_signed(origin)?;
-
-			let signer = SignerKey::<T>::get()
-				.ok_or::<DispatchError>(Error::<T>::MissingPublicKey.into())?;
+			let admin = Administrator::<T>::get();
+			ensure!(admin.is_some(), Error::<T>::AdministratorNotSet);
 
-			let client_key_msg = {
-				let mut bytes = sp_std::vec::Vec::with_capacity(0);
+			let account = ensure_signed(origin)?;
+			ensure!(admin.unwrap() == account, Error::<T>::AccountIsNotAdministrator);
 
-				bytes.extend(LIGHT_CLIENT_PROOF_PREFIX.to_vec());
-				bytes.extend(client_key.to_vec());
+			AssetFlowLimits::<T>::set((asset_origin, asset.clone()), limits);
 
-				bytes
-			};
+			Self::deposit_event(Event::AssetLimitsSet { asset_origin, asset, limits });
+			Ok(())
+		}
 
-			ensure!(
-				client_key_signature.verify(client_key_msg.as_slice(), &signer),
-				Error::<T>::BadSignature
-			);
+		/// Halt, or resume, all deposits and withdrawals.
+		///
+		/// Can only be called by the administrator.
+		#[pallet::weight(T::WeightInfo::set_circuit_breaker())]
+		#[pallet::call_index(21)]
+		pub fn set_circuit_breaker(origin: OriginFor<T>, halted: bool) -> DispatchResult {
+			let admin = Administrator::<T>::get();
+			ensure!(admin.is_some(), Error::<T>::AdministratorNotSet);
 
-			let msg = freeze_proof.extract_msg();
-			ensure!(proof_signature.verify(msg.as_slice(), &client_key), Error::<T>::BadSignature);
+			let account = ensure_signed(origin)?;
+			ensure!(admin.unwrap() == account, Error::<T>::AccountIsNotAdministrator);
 
-			let epoch_number = Self::calculate_epoch_number_from(T::Time::now())?;
-			ensure!(epoch_number >= 4, Error::<T>::InvalidEpochNumber);
-			ensure!(epoch_number - 4 == freeze_proof.epoch, Error::<T>::InvalidEpochNumber);
-			Frozen::<T>::set(Some(freeze_proof.epoch));
+			BridgeHalted::<T>::put(halted);
 
+			Self::deposit_event(Event::CircuitBreakerSet { halted });
 			Ok(())
 		}
 
-		#[pallet::weight({10_000})]
-		#[pallet::call_index(10)]
-		pub fn respond_zero_challenge(
-			origin: OriginFor<T>,
-			zero_proof: ZeroBalanceProof,
-			signature: sp_core::sr25519::Signature,
-		) -> DispatchResult {
+		/// Pay out a delayed withdrawal once its release epoch has been reached.
+		///
+		/// Once the pallet is frozen, only withdrawals proven up to the frozen epoch are paid out,
+		/// the later ones can only be cancelled back into deposits to be refunded.
+		#[pallet::weight(T::WeightInfo::release_withdrawal())]
+		#[pallet::call_index(22)]
+		pub fn release_withdrawal(origin: OriginFor<T>, id: DelayedWithdrawalId) -> DispatchResult {
 			let _ = ensure_signed(origin)?;
+			ensure!(!BridgeHalted::<T>::get(), Error::<T>::BridgeHalted);
 
-			let challenger = T::AccountId::decode(&mut &zero_proof.account[..])
-				.map_err(|_| Error::<T>::FailedToDecodeAccount)?;
+			let withdrawal =
+				DelayedWithdrawals::<T>::take(id).ok_or(Error::<T>::DelayedWithdrawalNotFound)?;
+			let epoch_number = Self::calculate_epoch_number_from(T::Time::now())?;
+			ensure!(epoch_number >= withdrawal.release_epoch, Error::<T>::DelayedWithdrawalLocked);
+			if let Some(frozen_epoch) = Frozen::<T>::get() {
+				ensure!(withdrawal.epoch <= frozen_epoch, Error::<T>::InvalidEpochNumber);
+			}
 
-			let challenge_key = (zero_proof.epoch, challenger.clone());
+			let DelayedWithdrawal { beneficiary, asset, value, .. } = withdrawal;
+			match &asset.kind {
+				AssetKind::Fungible(asset_id) =>
+					Self::release_fungibles(&beneficiary, &asset.origin, asset_id, &value),
+				AssetKind::NonFungible(addr) =>
+					Self::release_non_fungibles(&beneficiary, &asset.origin, addr),
+			}?;
 
-			if Challenges::<T>::get(&challenge_key).is_some() {
-				Self::validate_proof_signature(&zero_proof, &signature)?;
+			Self::deposit_event(Event::DelayedWithdrawalReleased { id, beneficiary });
+			Ok(())
+		}
 
-				Self::deposit_event(Event::<T>::ChallengeZeroResponded {
-					challenger,
-					challenged_epoch: zero_proof.epoch,
-					balance_proof: zero_proof,
-				});
+		/// Cancel a delayed withdrawal, keeping its assets in the bridge.
+		///
+		/// The assets are credited back to the beneficiary as a deposit of the current epoch, which
+		/// returns them to the beneficiary's bridge balance, or lets them be refunded if the pallet
+		/// is frozen. The withdrawal no longer counts against the flow limits of its epoch.
+		///
+		/// Can only be called by the administrator.
+		#[pallet::weight(T::WeightInfo::cancel_withdrawal())]
+		#[pallet::call_index(23)]
+		pub fn cancel_withdrawal(origin: OriginFor<T>, id: DelayedWithdrawalId) -> DispatchResult {
+			let admin = Administrator::<T>::get();
+			ensure!(admin.is_some(), Error::<T>::AdministratorNotSet);
 
-				Challenges::<T>::remove(&challenge_key);
+			let account = ensure_signed(origin)?;
+			ensure!(admin.unwrap() == account, Error::<T>::AccountIsNotAdministrator);
 
-				Ok(())
-			} else {
-				Err(Error::<T>::WronglyRespondedChallenge.into())
-			}
+			let DelayedWithdrawal { beneficiary, asset, value, release_epoch, .. } =
+				DelayedWithdrawals::<T>::take(id).ok_or(Error::<T>::DelayedWithdrawalNotFound)?;
+			let flow_epoch = release_epoch.saturating_sub(T::WithdrawalDelay::get());
+			Self::revert_withdrawal_flow(&beneficiary, flow_epoch, &asset, &value);
+			let epoch = Self::insert_deposit(&asset, &beneficiary, &value)?;
+
+			Self::deposit_event(Event::DelayedWithdrawalCancelled { id, beneficiary, epoch });
+			Ok(())
 		}
 	}
 
@@ -665,8 +1269,12 @@ pub mod pallet {
 
 			ensure!(start_time.is_some() && epoch_duration.is_some(), Error::<T>::TimeNotSet);
 
-			let epoch_number =
-				((epoch - start_time.unwrap()) / epoch_duration.unwrap()).saturated_into::<u64>();
+			// Checked, since this also runs in `on_idle` where panics must be avoided.
+			let epoch_number = epoch
+				.saturating_sub(start_time.unwrap())
+				.checked_div(&epoch_duration.unwrap())
+				.ok_or(Error::<T>::TimeNotSet)?
+				.saturated_into::<u64>();
 
 			Ok(epoch_number)
 		}
@@ -790,7 +1398,7 @@ pub mod pallet {
 			asset: &AssetOf<T>,
 			proof: &BalanceProof,
 			epoch_number: EpochNumber,
-			signature: &sp_core::sr25519::Signature,
+			authenticate: impl FnOnce(&BalanceProof) -> DispatchResult,
 		) -> DispatchResult {
 			ensure!(epoch_number >= proof.epoch + 4, Error::<T>::NonFinalizedBalanceProof);
 
@@ -806,27 +1414,53 @@ pub mod pallet {
 			// proofs to verify all logic paths
 			#[cfg(not(test))]
 			ensure!(proof.origin == T::ChainId::get(), Error::<T>::BalanceProofWrongOriginNetwork);
-			Self::validate_proof_signature(proof, signature)?;
+			authenticate(proof)?;
 			ensure!(proof.exit_flag, Error::<T>::BadExitFlag);
 
 			Ok(())
 		}
 
-		fn validate_proof_signature<P: Proof>(
+		fn validate_proof_signatures<P: Proof>(
 			proof: &P,
-			signature: &sp_core::sr25519::Signature,
+			signatures: &[sp_core::sr25519::Signature],
+		) -> DispatchResult {
+			Self::validate_signatures(proof.extract_msg().as_slice(), signatures)
+		}
+
+		/// Checks that `msg` has been signed by the current signer set, or by the one replaced in
+		/// the last rotation while its overlap lasts.
+		fn validate_signatures(
+			msg: &[u8],
+			signatures: &[sp_core::sr25519::Signature],
+		) -> DispatchResult {
+			let signers =
+				Signers::<T>::get().ok_or::<DispatchError>(Error::<T>::MissingPublicKey.into())?;
+			if signers.is_signed(msg, signatures) {
+				return Ok(())
+			}
+
+			let is_signed_by_retiring = RetiringSigners::<T>::get().is_some_and(|retiring| {
+				Self::calculate_epoch_number_from(T::Time::now())
+					.is_ok_and(|epoch| epoch <= retiring.active_until) &&
+					retiring.signers.is_signed(msg, signatures)
+			});
+			ensure!(is_signed_by_retiring, Error::<T>::BadSignature);
+			Ok(())
+		}
+
+		fn validate_inclusion(
+			balance_root: &BalanceRoot,
+			proof: &BalanceProof,
+			merkle_path: &[WideId],
 		) -> DispatchResult {
-			let msg = proof.extract_msg();
-			let signer = SignerKey::<T>::get()
-				.ok_or::<DispatchError>(Error::<T>::MissingPublicKey.into())?;
-			ensure!(signature.verify(msg.as_slice(), &signer), Error::<T>::BadSignature);
+			ensure!(balance_root.includes(proof, merkle_path), Error::<T>::InvalidMerkleProof);
 			Ok(())
 		}
 
 		fn validate_and_convert_proof(
 			withdrawer: &T::AccountId,
 			proof: &BalanceProof,
-			signature: &sp_core::sr25519::Signature,
+			authenticate: impl FnOnce(&BalanceProof) -> DispatchResult,
 		) -> Result<(AssetOf<T>, DepositValueKindOf<T>, EpochNumber), DispatchError> {
 			let is_native = Self::is_native(&proof.origin);
 
@@ -916,11 +1550,206 @@ pub mod pallet {
 			}?;
 
 			let epoch_number = Self::calculate_epoch_number_from(T::Time::now())?;
-			Self::validate_proof(withdrawer, &asset, proof, epoch_number, signature)?;
+			Self::validate_proof(withdrawer, &asset, proof, epoch_number, authenticate)?;
 
 			Ok((asset, value, proof.epoch))
 		}
 
+		/// Releases the asset of a balance proof to `withdrawer`, once `authenticate` has confirmed
+		/// that the proof was issued by the operator.
+		fn withdraw_asset(
+			withdrawer: &T::AccountId,
+			balance_proof: &BalanceProof,
+			authenticate: impl FnOnce(&BalanceProof) -> DispatchResult,
+		) -> DispatchResult {
+			let (asset, value, epoch_num) =
+				Self::validate_and_convert_proof(withdrawer, balance_proof, authenticate)?;
+
+			match &asset.kind {
+				AssetKind::Fungible(asset_id) => Self::unreserve_fungibles(
+					withdrawer,
+					epoch_num,
+					&asset.origin,
+					asset_id,
+					&value,
+				),
+				AssetKind::NonFungible(addr) =>
+					Self::unreserve_non_fungibles(withdrawer, epoch_num, &asset.origin, addr),
+			}?;
+
+			Self::register_withdrawal(epoch_num, withdrawer, &asset)
+		}
+
+		/// Answers the next expected chunk of the challenge the balance proof belongs to, once
+		/// `authenticate` has confirmed that the proof was issued by the operator.
+		fn respond_challenge_chunk(
+			balance_proof: BalanceProof,
+			authenticate: impl FnOnce(&BalanceProof) -> DispatchResult,
+		) -> DispatchResult {
+			let challenger = T::AccountId::decode(&mut &balance_proof.account[..])
+				.map_err(|_| Error::<T>::FailedToDecodeAccount)?;
+
+			let challenge_key = (balance_proof.epoch, challenger.clone());
+
+			if let Some(chunk_index) = Challenges::<T>::get(&challenge_key) {
+				if chunk_index == balance_proof.chunk_index {
+					authenticate(&balance_proof)?;
+
+					Self::deposit_event(Event::<T>::ChallengeResponded {
+						challenger,
+						challenged_epoch: balance_proof.epoch,
+						balance_proof,
+					});
+
+					if chunk_index == balance_proof.chunk_last {
+						Self::close_challenge(&challenge_key)?;
+					} else {
+						Challenges::<T>::insert(challenge_key, chunk_index.saturating_add(1));
+					}
+					Ok(())
+				} else {
+					Err(Error::<T>::WrongChunkRespondedChallenge.into())
+				}
+			} else {
+				Err(Error::<T>::WronglyRespondedChallenge.into())
+			}
+		}
+
+		fn do_refund_frozen(beneficiary: T::AccountId, max_refunds: u32) -> DispatchResult {
+			let frozen_epoch = Frozen::<T>::get().ok_or(Error::<T>::NotFrozen)?;
+
+			let epochs = DepositEpochs::<T>::iter_key_prefix(&beneficiary)
+				.filter(|epoch| epoch > &frozen_epoch)
+				.collect::<sp_std::vec::Vec<_>>();
+
+			let mut refunds_left = max_refunds as usize;
+			for epoch in epochs {
+				if refunds_left == 0 {
+					break
+				}
+
+				let deposits = Deposits::<T>::iter_prefix((epoch, &beneficiary))
+					.take(refunds_left)
+					.collect::<sp_std::vec::Vec<_>>();
+				refunds_left.saturating_reduce(deposits.len());
+
+				// Refunds return deposits that never made it into a valid proof, so they bypass the
+				// flow limits and the delay queue.
+				for (asset, deposit_value) in deposits {
+					Deposits::<T>::remove((epoch, &beneficiary, &asset));
+					match &asset.kind {
+						AssetKind::Fungible(asset_id) => Self::release_fungibles(
+							&beneficiary,
+							&asset.origin,
+							asset_id,
+							&deposit_value,
+						),
+						AssetKind::NonFungible(addr) =>
+							Self::release_non_fungibles(&beneficiary, &asset.origin, addr),
+					}?;
+				}
+
+				if Deposits::<T>::iter_key_prefix((epoch, &beneficiary)).next().is_none() {
+					DepositEpochs::<T>::remove(&beneficiary, epoch);
+				}
+			}
+
+			Self::deposit_event(Event::<T>::DepositsRefunded {
+				epoch: frozen_epoch,
+				beneficieary: beneficiary,
+			});
+
+			Ok(())
+		}
+
+		/// Freezes the pallet at `frozen_epoch` and slashes the bonds of the operators whose keys
+		/// were signing at that epoch into the reserve account, where they cover shortfalls.
+		fn freeze_at(frozen_epoch: EpochNumber) {
+			Frozen::<T>::set(Some(frozen_epoch));
+
+			let mut keys = Signers::<T>::get()
+				.filter(|_| SignersSince::<T>::get() <= frozen_epoch)
+				.map(|signers| signers.keys.into_inner())
+				.unwrap_or_default();
+			if let Some(retiring) = RetiringSigners::<T>::get() {
+				if retiring.was_active_in(frozen_epoch) {
+					keys.extend(retiring.signers.keys);
+				}
+			}
+
+			let reserve_account = Self::reserve_account();
+			for key in keys {
+				let Some(operator) = OperatorSigners::<T>::get(key) else { continue };
+				let Some(mut info) = Operators::<T>::get(&operator) else { continue };
+				if info.bond.is_zero() {
+					continue
+				}
+
+				// Slashed rather than repatriated, which would fail while the reserve account
+				// doesn't exist yet.
+				let (slashed, unpaid) = T::Currency::slash_reserved(&operator, info.bond);
+				let amount = slashed.peek();
+				T::Currency::resolve_creating(&reserve_account, slashed);
+				info.bond = unpaid;
+				Operators::<T>::insert(&operator, info);
+				ShortfallCover::<T>::mutate(|cover| cover.saturating_accrue(amount));
+
+				Self::deposit_event(Event::<T>::OperatorSlashed { operator, frozen_epoch, amount });
+			}
+		}
+
+		/// Pays up to `ChallengeReward` to `challenger` out of the slashed bonds held by the
+		/// reserve account, returning the amount actually paid.
+		fn pay_challenge_reward(challenger: &T::AccountId) -> BalanceOf<T> {
+			let cover = ShortfallCover::<T>::get();
+			let reward = T::ChallengeReward::get().min(cover);
+			if reward.is_zero() ||
+				T::Currency::transfer(&Self::reserve_account(), challenger, reward, AllowDeath)
+					.is_err()
+			{
+				return Zero::zero()
+			}
+
+			ShortfallCover::<T>::put(cover.saturating_sub(reward));
+			reward
+		}
+
+		/// Checks that proofs signed with `signer` can no longer freeze the pallet, either because
+		/// it never was in the signer set or because it has retired more than 4 epochs ago.
+		fn ensure_not_signing(signer: &sp_core::sr25519::Public) -> DispatchResult {
+			let is_signer =
+				Signers::<T>::get().is_some_and(|signers| signers.keys.contains(signer));
+			ensure!(!is_signer, Error::<T>::OperatorStillSigning);
+
+			if let Some(retiring) = RetiringSigners::<T>::get() {
+				if retiring.signers.keys.contains(signer) {
+					let epoch_number = Self::calculate_epoch_number_from(T::Time::now())?;
+					ensure!(
+						epoch_number > retiring.active_until.saturating_add(4),
+						Error::<T>::OperatorStillSigning
+					);
+				}
+			}
+
+			Ok(())
+		}
+
+		/// Removes an answered challenge and starts the review period of its bond.
+		fn close_challenge(challenge_key: &(EpochNumber, T::AccountId)) -> DispatchResult {
+			let epoch_number = Self::calculate_epoch_number_from(T::Time::now())?;
+			Challenges::<T>::remove(challenge_key);
+			OpenChallenges::<T>::mutate_exists(challenge_key.0, |maybe_count| {
+				*maybe_count =
+					maybe_count.map(|count| count.saturating_sub(1)).filter(|count| *count > 0);
+			});
+			ChallengeBonds::<T>::mutate(challenge_key, |maybe_bond| {
+				if let Some(bond) = maybe_bond {
+					bond.answered_in = Some(epoch_number);
+				}
+			});
+			Ok(())
+		}
+
 		fn insert_deposit(
 			asset: &AssetOf<T>,
 			depositor: &T::AccountId,
@@ -955,7 +1784,10 @@ pub mod pallet {
 						}?;
 					}
 				},
-				None => Deposits::<T>::insert((epoch, depositor, asset), value),
+				None => {
+					Deposits::<T>::insert((epoch, depositor, asset), value);
+					DepositEpochs::<T>::insert(depositor, epoch, ());
+				},
 			}
 
 			Ok(epoch)
@@ -969,6 +1801,20 @@ pub mod pallet {
 			asset_id: &AssetIdOf<T>,
 			value: &DepositValueKindOf<T>,
 		) -> DispatchResult {
+			let amount = match value {
+				DepositValueKind::Fungible(DepositValue::Token(v) | DepositValue::Asset(v)) =>
+					Ok(*v),
+				DepositValueKind::NonFungible =>
+					Err(Error::<T>::ReservedFungibleWithNonFungibleValue),
+			}?;
+			Self::check_flow(
+				FlowDirection::Deposit,
+				who,
+				asset_origin,
+				LimitedAsset::Fungible(asset_id.clone()),
+				amount,
+			)?;
+
 			if Self::is_native(asset_origin) {
 				let reserve_account = Self::reserve_account();
 				if Self::is_native_chain_token(asset_id) {
@@ -1004,8 +1850,34 @@ pub mod pallet {
 		}
 
 		/// Unreserve or unlock the `value` amount of the given asset, which can either be native or
-		/// foreign.
+		/// foreign, unless the withdrawal of the proof for `epoch` has to wait in the delay queue.
 		fn unreserve_fungibles(
+			who: &T::AccountId,
+			epoch: EpochNumber,
+			asset_origin: &AssetOrigin,
+			asset_id: &AssetIdOf<T>,
+			value: &DepositValueKindOf<T>,
+		) -> DispatchResult {
+			let amount = match value {
+				DepositValueKind::Fungible(DepositValue::Token(v) | DepositValue::Asset(v)) =>
+					Ok(*v),
+				DepositValueKind::NonFungible =>
+					Err(Error::<T>::UnreservedFungibleWithNonFungibleValue),
+			}?;
+			let asset = LimitedAsset::Fungible(asset_id.clone());
+
+			if Self::check_flow(FlowDirection::Withdrawal, who, asset_origin, asset, amount)? {
+				let asset =
+					Asset { origin: *asset_origin, kind: AssetKind::Fungible(asset_id.clone()) };
+				Self::delay_withdrawal(who, epoch, asset, value.clone())
+			} else {
+				Self::release_fungibles(who, asset_origin, asset_id, value)
+			}
+		}
+
+		/// Pays out the `value` amount of the given asset from the reserve, or mints it if the
+		/// asset is foreign.
+		fn release_fungibles(
 			who: &T::AccountId,
 			asset_origin: &AssetOrigin,
 			asset_id: &AssetIdOf<T>,
@@ -1027,6 +1899,19 @@ pub mod pallet {
 						Error::<T>::InsufficientReserveFunds
 					);
 
+					// Any amount beyond what the reserve holds apart from the slashed operator
+					// bonds is a shortfall, which is paid out of those bonds.
+					let cover = ShortfallCover::<T>::get();
+					let shortfall =
+						withdrawal_amount.saturating_sub(available_balance.saturating_sub(cover));
+					if !shortfall.is_zero() {
+						ShortfallCover::<T>::put(cover.saturating_sub(shortfall));
+						Self::deposit_event(Event::<T>::ShortfallCovered {
+							beneficiary: who.clone(),
+							amount: shortfall,
+						});
+					}
+
 					T::Currency::transfer(&reserve_account, who, *withdrawal_amount, AllowDeath)?;
 				} else {
 					let withdrawal_amount = match value {
@@ -1034,6 +1919,7 @@ pub mod pallet {
 						_ => Err(Error::<T>::UnreservedFungibleWithNonFungibleValue),
 					}?;
 
+					// The slashed operator bonds only cover native token shortfalls.
 					let available_balance =
 						T::Fungibles::balance(asset_id.clone(), &reserve_account);
 
@@ -1078,6 +1964,14 @@ pub mod pallet {
 		) -> DispatchResult {
 			let (collection_id, item_id) = (&addr.0, &addr.1);
 
+			Self::check_flow(
+				FlowDirection::Deposit,
+				who,
+				asset_origin,
+				LimitedAsset::NonFungible(*collection_id),
+				One::one(),
+			)?;
+
 			let item_owner = T::NonFungibles::owner(collection_id, item_id);
 
 			ensure!(item_owner.is_some(), Error::<T>::ReserveNonFungibleWithoutOwner);
@@ -1090,8 +1984,27 @@ pub mod pallet {
 			}
 		}
 
-		/// Unreserve or unlock the given NFT.
+		/// Unreserve or unlock the given NFT, unless the withdrawal of the proof for `epoch` has to
+		/// wait in the delay queue.
 		fn unreserve_non_fungibles(
+			who: &T::AccountId,
+			epoch: EpochNumber,
+			asset_origin: &AssetOrigin,
+			addr: &NftAddressOf<T>,
+		) -> DispatchResult {
+			let asset = LimitedAsset::NonFungible(addr.0);
+
+			if Self::check_flow(FlowDirection::Withdrawal, who, asset_origin, asset, One::one())? {
+				let asset =
+					Asset { origin: *asset_origin, kind: AssetKind::NonFungible(addr.clone()) };
+				Self::delay_withdrawal(who, epoch, asset, DepositValueKind::NonFungible)
+			} else {
+				Self::release_non_fungibles(who, asset_origin, addr)
+			}
+		}
+
+		/// Transfers the given NFT out of the reserve, or mints it if the NFT is foreign.
+		fn release_non_fungibles(
 			who: &T::AccountId,
 			asset_origin: &AssetOrigin,
 			addr: &NftAddressOf<T>,
@@ -1111,6 +2024,108 @@ pub mod pallet {
 			}
 		}
 
+		/// Checks the circuit breaker and accounts `amount` of `asset` flowing in `direction`
+		/// against the asset's limits, returning whether the withdrawal has to be delayed.
+		fn check_flow(
+			direction: FlowDirection,
+			who: &T::AccountId,
+			asset_origin: &AssetOrigin,
+			asset: LimitedAssetOf<T>,
+			amount: BalanceOf<T>,
+		) -> Result<bool, DispatchError> {
+			ensure!(!BridgeHalted::<T>::get(), Error::<T>::BridgeHalted);
+
+			let Some(limits) = AssetFlowLimits::<T>::get((*asset_origin, asset.clone())) else {
+				return Ok(false)
+			};
+
+			let epoch_number = Self::calculate_epoch_number_from(T::Time::now())?;
+			let accrue = |counter: Option<FlowCounterOf<T>>, limit: Option<BalanceOf<T>>| {
+				let flown = counter
+					.filter(|counter| counter.epoch == epoch_number)
+					.map_or(Zero::zero(), |counter| counter.amount);
+				let amount = flown.saturating_add(amount);
+				match limit {
+					Some(limit) if amount > limit => None,
+					_ => Some(FlowCounter { epoch: epoch_number, amount }),
+				}
+			};
+
+			let flow_key = (direction, *asset_origin, asset);
+			let epoch_flow = accrue(EpochFlows::<T>::get(&flow_key), limits.per_epoch)
+				.ok_or(Error::<T>::EpochFlowLimitExceeded)?;
+			let account_flow = accrue(AccountFlows::<T>::get(who, &flow_key), limits.per_account)
+				.ok_or(Error::<T>::AccountFlowLimitExceeded)?;
+			EpochFlows::<T>::insert(&flow_key, epoch_flow);
+			AccountFlows::<T>::insert(who, &flow_key, account_flow);
+
+			Ok(direction == FlowDirection::Withdrawal &&
+				limits.delay_threshold.is_some_and(|threshold| amount > threshold))
+		}
+
+		/// Takes a cancelled withdrawal back out of the flow counters of the epoch it was made in.
+		/// Counters that have moved on to a later epoch no longer hold it and are left as they are.
+		fn revert_withdrawal_flow(
+			who: &T::AccountId,
+			epoch: EpochNumber,
+			asset: &AssetOf<T>,
+			value: &DepositValueKindOf<T>,
+		) {
+			let (limited_asset, amount) = match (&asset.kind, value) {
+				(
+					AssetKind::Fungible(asset_id),
+					DepositValueKind::Fungible(DepositValue::Token(v) | DepositValue::Asset(v)),
+				) => (LimitedAsset::Fungible(asset_id.clone()), *v),
+				(AssetKind::NonFungible(addr), DepositValueKind::NonFungible) =>
+					(LimitedAsset::NonFungible(addr.0), One::one()),
+				_ => return,
+			};
+
+			let flow_key = (FlowDirection::Withdrawal, asset.origin, limited_asset);
+			let revert = |maybe_counter: &mut Option<FlowCounterOf<T>>| {
+				if let Some(counter) = maybe_counter.as_mut() {
+					if counter.epoch == epoch {
+						counter.amount.saturating_reduce(amount);
+					}
+				}
+			};
+			EpochFlows::<T>::mutate(&flow_key, revert);
+			AccountFlows::<T>::mutate(who, &flow_key, revert);
+		}
+
+		/// Puts a withdrawal in the delay queue, to be released after `WithdrawalDelay` epochs.
+		fn delay_withdrawal(
+			beneficiary: &T::AccountId,
+			epoch: EpochNumber,
+			asset: AssetOf<T>,
+			value: DepositValueKindOf<T>,
+		) -> DispatchResult {
+			let release_epoch = Self::calculate_epoch_number_from(T::Time::now())?
+				.saturating_add(T::WithdrawalDelay::get());
+			let id = NextDelayedWithdrawalId::<T>::mutate(|next_id| {
+				let id = *next_id;
+				next_id.saturating_inc();
+				id
+			});
+			DelayedWithdrawals::<T>::insert(
+				id,
+				DelayedWithdrawal {
+					beneficiary: beneficiary.clone(),
+					epoch,
+					asset,
+					value,
+					release_epoch,
+				},
+			);
+
+			Self::deposit_event(Event::<T>::WithdrawalDelayed {
+				id,
+				beneficiary: beneficiary.clone(),
+				release_epoch,
+			});
+			Ok(())
+		}
+
 		fn register_withdrawal(
 			epoch: EpochNumber,
 			depositor: &T::AccountId,
@@ -1128,37 +2143,53 @@ pub mod pallet {
 			})
 		}
 
-		fn clear_old_deposit_entries(epoch: EpochNumber) -> DispatchResult {
-			let epoch_set = {
-				let epoch_vec = Deposits::<T>::iter_keys()
-					.map(|(epoch, _, _)| epoch)
-					.collect::<sp_std::vec::Vec<_>>();
-
-				if epoch_vec.is_empty() {
-					None
-				} else {
-					let min_epoch = *epoch_vec.iter().min().unwrap();
-					let max_epoch = {
-						let max = *epoch_vec.iter().max().unwrap();
-
-						if max > epoch {
-							epoch
-						} else {
-							max
-						}
-					};
+		/// Removes deposits older than the retention window, starting from the pruning cursor and
+		/// spending at most `remaining_weight`. Nothing is pruned while the pallet is frozen, since
+		/// those deposits may still need to be refunded, nor when pruning a deposit is weighed at
+		/// zero, since the pruning would then be unbounded.
+		pub(crate) fn prune_deposits(remaining_weight: Weight) -> Weight {
+			let base_weight = T::WeightInfo::prune_deposits(0);
+			let step_weight = T::WeightInfo::prune_deposits(1).saturating_sub(base_weight);
+			if remaining_weight.any_lt(base_weight) ||
+				step_weight.is_zero() ||
+				Frozen::<T>::exists()
+			{
+				return Weight::zero()
+			}
 
-					Some((min_epoch, max_epoch))
-				}
+			let Some(last_prunable_epoch) = Self::calculate_epoch_number_from(T::Time::now())
+				.ok()
+				.and_then(|epoch| epoch.checked_sub(T::DepositRetention::get()))
+			else {
+				return base_weight
 			};
-
-			if let Some((min_epoch, max_epoch)) = epoch_set {
-				for i in min_epoch..=max_epoch {
-					let _ = Deposits::<T>::clear_prefix((i,), 10, None);
+			let max_steps = remaining_weight
+				.saturating_sub(base_weight)
+				.checked_div_per_component(&step_weight)
+				.unwrap_or_default();
+
+			// Each removed deposit and each exhausted epoch counts as a step.
+			let mut epoch = DepositPruneCursor::<T>::get();
+			let mut steps = 0_u64;
+			while epoch <= last_prunable_epoch && steps < max_steps {
+				let limit = max_steps.saturating_sub(steps).saturated_into::<usize>();
+				let keys = Deposits::<T>::iter_key_prefix((epoch,))
+					.take(limit)
+					.collect::<sp_std::vec::Vec<_>>();
+				let is_exhausted = keys.len() < limit;
+				steps.saturating_accrue(keys.len() as u64);
+				for (account, asset) in keys {
+					Deposits::<T>::remove((epoch, &account, asset));
+					DepositEpochs::<T>::remove(account, epoch);
+				}
+				if is_exhausted {
+					steps.saturating_inc();
+					epoch.saturating_inc();
 				}
 			}
+			DepositPruneCursor::<T>::put(epoch);
 
-			Ok(())
+			base_weight.saturating_add(step_weight.saturating_mul(steps))
 		}
 	}
 }
@@ -0,0 +1,142 @@
+use super::*;
+use frame_support::{
+	migrations::VersionedMigration,
+	traits::{Get, UncheckedOnRuntimeUpgrade},
+};
+use sp_std::marker::PhantomData;
+
+/// The current storage version.
+pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
+pub mod v1 {
+	use super::*;
+
+	#[frame_support::storage_alias]
+	pub(crate) type SignerKey<T: Config> =
+		StorageValue<Pallet<T>, sp_core::sr25519::Public, OptionQuery>;
+
+	/// Replaces the single [`SignerKey`] with a 1-of-1 [`Signers`] set of the same key.
+	pub struct UncheckedMigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let Some(key) = SignerKey::<T>::take() else { return T::DbWeight::get().reads(1) };
+
+			if !Signers::<T>::exists() {
+				let keys = BoundedVec::truncate_from(sp_std::vec![key]);
+				Signers::<T>::put(SignerSet { keys, threshold: 1 });
+			}
+
+			T::DbWeight::get().reads_writes(2, 2)
+		}
+	}
+
+	pub type MigrateToV1<T> = VersionedMigration<
+		0,
+		1,
+		UncheckedMigrateToV1<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+pub mod v2 {
+	use super::*;
+
+	/// Rebuilds the [`DepositEpochs`] and [`OpenChallenges`] indexes from the existing
+	/// [`Deposits`] and [`Challenges`].
+	pub struct UncheckedMigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut reads = 0_u64;
+			let mut writes = 0_u64;
+
+			for (epoch, account, _) in Deposits::<T>::iter_keys() {
+				DepositEpochs::<T>::insert(account, epoch, ());
+				reads.saturating_inc();
+				writes.saturating_inc();
+			}
+
+			let cleared = OpenChallenges::<T>::clear(u32::MAX, None);
+			writes.saturating_accrue(cleared.unique as u64);
+			for (epoch, _) in Challenges::<T>::iter_keys() {
+				OpenChallenges::<T>::mutate(epoch, |count| count.saturating_inc());
+				reads.saturating_accrue(2);
+				writes.saturating_inc();
+			}
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+	}
+
+	pub type MigrateToV2<T> = VersionedMigration<
+		1,
+		2,
+		UncheckedMigrateToV2<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+pub mod v3 {
+	use super::*;
+
+	/// Registers the operators of the signer keys that predate bonded operators, reserving
+	/// `MinOperatorBond` from each so they can be slashed on a freeze and kept on rotation.
+	///
+	/// `SignerOperators` maps each current or retiring signer key to the account operating it.
+	/// Operators that cannot afford the bond are registered without one, and must add it with
+	/// `bond_operator_extra` before the key can be rotated in again.
+	pub struct UncheckedMigrateToV3<T, SignerOperators>(PhantomData<(T, SignerOperators)>);
+
+	impl<T, SignerOperators> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV3<T, SignerOperators>
+	where
+		T: Config,
+		SignerOperators: Get<sp_std::vec::Vec<(sp_core::sr25519::Public, T::AccountId)>>,
+	{
+		fn on_runtime_upgrade() -> Weight {
+			let mut keys =
+				Signers::<T>::get().map(|signers| signers.keys.into_inner()).unwrap_or_default();
+			if let Some(retiring) = RetiringSigners::<T>::get() {
+				keys.extend(retiring.signers.keys);
+			}
+
+			let mut reads = 2_u64;
+			let mut writes = 0_u64;
+			for (key, operator) in SignerOperators::get() {
+				reads.saturating_accrue(2);
+				if !keys.contains(&key) ||
+					OperatorSigners::<T>::contains_key(key) ||
+					Operators::<T>::contains_key(&operator)
+				{
+					continue
+				}
+
+				let min_bond = T::MinOperatorBond::get();
+				let bond = if T::Currency::reserve(&operator, min_bond).is_ok() {
+					min_bond
+				} else {
+					Zero::zero()
+				};
+				Operators::<T>::insert(
+					&operator,
+					Operator { signer: key, chain_id: T::ChainId::get(), bond },
+				);
+				OperatorSigners::<T>::insert(key, &operator);
+				reads.saturating_inc();
+				writes.saturating_accrue(3);
+			}
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+	}
+
+	pub type MigrateToV3<T, SignerOperators> = VersionedMigration<
+		2,
+		3,
+		UncheckedMigrateToV3<T, SignerOperators>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
@@ -0,0 +1,321 @@
+use super::*;
+
+fn answer_challenge(challenger: MockAccountId, challenge_epoch: EpochNumber) {
+	let proof = ZeroBalanceProof::using_challenge(challenge_epoch, challenger);
+	assert_ok!(Wildcard::respond_zero_challenge(
+		RuntimeOrigin::signed(BOB),
+		proof,
+		generate_signature_for(&proof)
+	));
+}
+
+#[test]
+fn challenge_reserves_bond() {
+	ExtBuilder::default()
+		.balances(&[(ALICE, ChallengeBalance::get())])
+		.build()
+		.execute_with(|| {
+			run_to_block(10);
+
+			let (_, challenge_epoch, _) = make_challenge(ALICE);
+
+			assert_eq!(Balances::reserved_balance(ALICE), ChallengeBondAmount::get());
+			assert_eq!(
+				ChallengeBonds::<Test>::get((challenge_epoch, ALICE)),
+				Some(ChallengeBond { amount: ChallengeBondAmount::get(), answered_in: None })
+			);
+		});
+}
+
+#[test]
+fn answered_challenge_bond_is_returned_after_review() {
+	ExtBuilder::default()
+		.balances(&[(ALICE, ChallengeBalance::get())])
+		.build()
+		.execute_with(|| {
+			run_to_block(10);
+			let (_, challenge_epoch, _) = make_challenge(ALICE);
+			answer_challenge(ALICE, challenge_epoch);
+
+			let answered_in = 9;
+			assert_eq!(
+				ChallengeBonds::<Test>::get((challenge_epoch, ALICE)).unwrap().answered_in,
+				Some(answered_in)
+			);
+
+			run_to_block(answered_in + ChallengeReviewPeriod::get() + 1);
+			assert_noop!(
+				Wildcard::claim_challenge_bond(RuntimeOrigin::signed(ALICE), challenge_epoch),
+				Error::<Test>::ChallengeBondLocked
+			);
+
+			run_to_block(answered_in + ChallengeReviewPeriod::get() + 2);
+			assert_ok!(Wildcard::claim_challenge_bond(
+				RuntimeOrigin::signed(ALICE),
+				challenge_epoch
+			));
+
+			assert_eq!(Balances::reserved_balance(ALICE), 0);
+			assert_eq!(Balances::free_balance(ALICE), ChallengeBalance::get());
+			assert_eq!(ChallengeBonds::<Test>::get((challenge_epoch, ALICE)), None);
+			System::assert_last_event(mock::RuntimeEvent::Wildcard(
+				crate::Event::ChallengeBondReturned {
+					epoch: challenge_epoch,
+					challenger: ALICE,
+					bond: ChallengeBondAmount::get(),
+				},
+			));
+		});
+}
+
+#[test]
+fn answered_challenge_cannot_be_repeated_while_its_bond_is_held() {
+	ExtBuilder::default()
+		.balances(&[(ALICE, ChallengeBalance::get() + ChallengeBondAmount::get())])
+		.build()
+		.execute_with(|| {
+			run_to_block(10);
+			let (_, challenge_epoch, _) = make_challenge(ALICE);
+			answer_challenge(ALICE, challenge_epoch);
+			assert_eq!(Challenges::<Test>::get((challenge_epoch, ALICE)), None);
+
+			assert_noop!(
+				Wildcard::challenge(RuntimeOrigin::signed(ALICE)),
+				Error::<Test>::AlreadyChallengedEpoch
+			);
+			assert_eq!(Balances::reserved_balance(ALICE), ChallengeBondAmount::get());
+			assert_eq!(
+				ChallengeBonds::<Test>::get((challenge_epoch, ALICE)),
+				Some(ChallengeBond { amount: ChallengeBondAmount::get(), answered_in: Some(9) })
+			);
+		});
+}
+
+#[test]
+fn unanswered_challenge_bond_is_returned_once_it_cannot_freeze() {
+	ExtBuilder::default()
+		.balances(&[(ALICE, ChallengeBalance::get())])
+		.build()
+		.execute_with(|| {
+			run_to_block(10);
+			let (_, challenge_epoch, _) = make_challenge(ALICE);
+
+			// The challenge can still freeze the pallet until epoch `challenge_epoch + 3`.
+			run_to_block(challenge_epoch + 4);
+			assert_noop!(
+				Wildcard::claim_challenge_bond(RuntimeOrigin::signed(ALICE), challenge_epoch),
+				Error::<Test>::ChallengeBondLocked
+			);
+
+			run_to_block(challenge_epoch + 5);
+			assert_ok!(Wildcard::claim_challenge_bond(
+				RuntimeOrigin::signed(ALICE),
+				challenge_epoch
+			));
+
+			assert_eq!(Balances::free_balance(ALICE), ChallengeBalance::get());
+			System::assert_last_event(mock::RuntimeEvent::Wildcard(
+				crate::Event::ChallengeBondReturned {
+					epoch: challenge_epoch,
+					challenger: ALICE,
+					bond: ChallengeBondAmount::get(),
+				},
+			));
+		});
+}
+
+#[test]
+fn freezing_challenge_is_rewarded_from_slashed_bonds() {
+	ExtBuilder::default()
+		.balances(&[(ALICE, ChallengeBalance::get())])
+		.build()
+		.execute_with(|| {
+			let (operator, _) = register_operator(0);
+			assert_ok!(Wildcard::bond_operator_extra(
+				RuntimeOrigin::signed(operator),
+				MinOperatorBond::get()
+			));
+			let bond = MinOperatorBond::get() * 2;
+
+			run_to_block(10);
+			let (_, challenge_epoch, _) = make_challenge(ALICE);
+
+			assert_noop!(
+				Wildcard::claim_challenge_bond(RuntimeOrigin::signed(ALICE), challenge_epoch),
+				Error::<Test>::ChallengeBondLocked
+			);
+
+			run_to_block(12);
+			assert_ok!(Wildcard::freeze(RuntimeOrigin::signed(BOB)));
+			assert_ok!(Wildcard::claim_challenge_bond(
+				RuntimeOrigin::signed(ALICE),
+				challenge_epoch
+			));
+
+			let reward = ChallengeRewardAmount::get();
+			assert_eq!(Balances::reserved_balance(ALICE), 0);
+			assert_eq!(Balances::free_balance(ALICE), ChallengeBalance::get() + reward);
+			assert_eq!(ShortfallCover::<Test>::get(), bond - reward);
+			assert_eq!(Balances::free_balance(Wildcard::reserve_account()), bond - reward);
+			System::assert_last_event(mock::RuntimeEvent::Wildcard(
+				crate::Event::ChallengerRewarded {
+					epoch: challenge_epoch,
+					challenger: ALICE,
+					bond: ChallengeBondAmount::get(),
+					reward,
+				},
+			));
+		});
+}
+
+#[test]
+fn freezing_challenge_reward_is_capped_by_slashed_bonds() {
+	ExtBuilder::default()
+		.balances(&[(ALICE, ChallengeBalance::get())])
+		.build()
+		.execute_with(|| {
+			let (operator, _) = register_operator(0);
+			let bond = MinOperatorBond::get();
+			assert!(bond < ChallengeRewardAmount::get());
+
+			run_to_block(10);
+			let (_, challenge_epoch, _) = make_challenge(ALICE);
+			run_to_block(12);
+			assert_ok!(Wildcard::freeze(RuntimeOrigin::signed(BOB)));
+			assert_eq!(Operators::<Test>::get(operator).unwrap().bond, 0);
+
+			assert_ok!(Wildcard::claim_challenge_bond(
+				RuntimeOrigin::signed(ALICE),
+				challenge_epoch
+			));
+			assert_eq!(Balances::free_balance(ALICE), ChallengeBalance::get() + bond);
+			assert_eq!(ShortfallCover::<Test>::get(), 0);
+			System::assert_last_event(mock::RuntimeEvent::Wildcard(
+				crate::Event::ChallengerRewarded {
+					epoch: challenge_epoch,
+					challenger: ALICE,
+					bond: ChallengeBondAmount::get(),
+					reward: bond,
+				},
+			));
+		});
+}
+
+#[test]
+fn claim_challenge_bond_fails_without_bond() {
+	ExtBuilder::default().build().execute_with(|| {
+		run_to_block(10);
+
+		assert_noop!(
+			Wildcard::claim_challenge_bond(RuntimeOrigin::signed(ALICE), 8),
+			Error::<Test>::ChallengeBondNotFound
+		);
+	});
+}
+
+#[test]
+fn answered_challenge_bond_can_be_slashed_during_review() {
+	ExtBuilder::default()
+		.balances(&[(ALICE, ChallengeBalance::get()), (BOB, ChallengeBalance::get())])
+		.build()
+		.execute_with(|| {
+			run_to_block(10);
+			let (_, challenge_epoch, _) = make_challenge(ALICE);
+			make_challenge(BOB);
+
+			assert_noop!(
+				Wildcard::slash_challenge_bond(
+					RuntimeOrigin::signed(CHARLIE),
+					ALICE,
+					challenge_epoch
+				),
+				Error::<Test>::AdministratorNotSet
+			);
+			assert_ok!(Wildcard::set_administrator(RuntimeOrigin::root(), CHARLIE));
+			assert_noop!(
+				Wildcard::slash_challenge_bond(RuntimeOrigin::signed(BOB), ALICE, challenge_epoch),
+				Error::<Test>::AccountIsNotAdministrator
+			);
+
+			// Unanswered challenges are not frivolous.
+			assert_noop!(
+				Wildcard::slash_challenge_bond(
+					RuntimeOrigin::signed(CHARLIE),
+					ALICE,
+					challenge_epoch
+				),
+				Error::<Test>::ChallengeBondNotSlashable
+			);
+
+			answer_challenge(ALICE, challenge_epoch);
+			answer_challenge(BOB, challenge_epoch);
+			assert_ok!(Wildcard::slash_challenge_bond(
+				RuntimeOrigin::signed(CHARLIE),
+				ALICE,
+				challenge_epoch
+			));
+
+			assert_eq!(Balances::reserved_balance(ALICE), 0);
+			assert_eq!(
+				Balances::free_balance(ALICE),
+				ChallengeBalance::get() - ChallengeBondAmount::get()
+			);
+			assert_eq!(ChallengeBonds::<Test>::get((challenge_epoch, ALICE)), None);
+			assert_eq!(ShortfallCover::<Test>::get(), ChallengeBondAmount::get());
+			assert_eq!(
+				Balances::free_balance(Wildcard::reserve_account()),
+				ChallengeBondAmount::get()
+			);
+			System::assert_last_event(mock::RuntimeEvent::Wildcard(
+				crate::Event::ChallengeBondSlashed {
+					epoch: challenge_epoch,
+					challenger: ALICE,
+					bond: ChallengeBondAmount::get(),
+				},
+			));
+
+			// Once the review is over, the bond can no longer be slashed.
+			run_to_block(10 + ChallengeReviewPeriod::get() + 1);
+			assert_noop!(
+				Wildcard::slash_challenge_bond(
+					RuntimeOrigin::signed(CHARLIE),
+					BOB,
+					challenge_epoch
+				),
+				Error::<Test>::ChallengeBondNotSlashable
+			);
+		});
+}
+
+#[test]
+fn slash_challenge_bond_reports_the_amount_slashed() {
+	ExtBuilder::default()
+		.balances(&[(ALICE, ChallengeBalance::get())])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Wildcard::set_administrator(RuntimeOrigin::root(), CHARLIE));
+			run_to_block(10);
+			let (_, challenge_epoch, _) = make_challenge(ALICE);
+			answer_challenge(ALICE, challenge_epoch);
+
+			// Only part of the bond is still reserved when it is slashed.
+			let unreserved = 15;
+			Balances::unreserve(&ALICE, unreserved);
+			let slashed = ChallengeBondAmount::get() - unreserved;
+
+			assert_ok!(Wildcard::slash_challenge_bond(
+				RuntimeOrigin::signed(CHARLIE),
+				ALICE,
+				challenge_epoch
+			));
+			assert_eq!(ShortfallCover::<Test>::get(), slashed);
+			assert_eq!(Balances::free_balance(Wildcard::reserve_account()), slashed);
+			System::assert_last_event(mock::RuntimeEvent::Wildcard(
+				crate::Event::ChallengeBondSlashed {
+					epoch: challenge_epoch,
+					challenger: ALICE,
+					bond: slashed,
+				},
+			));
+		});
+}
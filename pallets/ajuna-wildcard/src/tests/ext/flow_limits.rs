@@ -0,0 +1,337 @@
+use super::*;
+
+const ADMIN: MockAccountId = CHARLIE;
+
+fn token_deposit(amount: MockBalance) -> AssetDeposit {
+	AssetDeposit {
+		origin: CHAIN_ID,
+		asset_type: AssetType::Fungible,
+		primary_id: generate_native_fungible_wide_id(NATIVE_TOKEN_ID),
+		secondary_id: generate_wide_id_for_amount(amount),
+	}
+}
+
+fn limit_token(limits: AssetLimitsOf<Test>) {
+	assert_ok!(Wildcard::set_asset_limits(
+		RuntimeOrigin::signed(ADMIN),
+		CHAIN_ID,
+		LimitedAsset::Fungible(NATIVE_TOKEN_ID),
+		Some(limits)
+	));
+}
+
+fn no_limits() -> AssetLimitsOf<Test> {
+	AssetLimits { per_epoch: None, per_account: None, delay_threshold: None }
+}
+
+fn setup() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(Wildcard::set_administrator(RuntimeOrigin::root(), ADMIN));
+	});
+	ext
+}
+
+#[test]
+fn set_asset_limits_works() {
+	setup().execute_with(|| {
+		let asset = LimitedAsset::Fungible(NATIVE_TOKEN_ID);
+		let limits = Some(AssetLimits { per_epoch: Some(10), ..no_limits() });
+
+		assert_noop!(
+			Wildcard::set_asset_limits(
+				RuntimeOrigin::signed(ALICE),
+				CHAIN_ID,
+				asset.clone(),
+				limits
+			),
+			Error::<Test>::AccountIsNotAdministrator
+		);
+
+		assert_ok!(Wildcard::set_asset_limits(
+			RuntimeOrigin::signed(ADMIN),
+			CHAIN_ID,
+			asset.clone(),
+			limits
+		));
+		assert_eq!(AssetFlowLimits::<Test>::get((CHAIN_ID, asset.clone())), limits);
+		System::assert_last_event(mock::RuntimeEvent::Wildcard(crate::Event::AssetLimitsSet {
+			asset_origin: CHAIN_ID,
+			asset: asset.clone(),
+			limits,
+		}));
+
+		assert_ok!(Wildcard::set_asset_limits(
+			RuntimeOrigin::signed(ADMIN),
+			CHAIN_ID,
+			asset.clone(),
+			None
+		));
+		assert_eq!(AssetFlowLimits::<Test>::get((CHAIN_ID, asset)), None);
+	});
+}
+
+#[test]
+fn deposits_are_capped_per_epoch() {
+	setup().execute_with(|| {
+		limit_token(AssetLimits { per_epoch: Some(1_500), ..no_limits() });
+
+		assert_ok!(Wildcard::deposit(RuntimeOrigin::signed(ALICE), token_deposit(1_000)));
+		assert_noop!(
+			Wildcard::deposit(RuntimeOrigin::signed(BOB), token_deposit(1_000)),
+			Error::<Test>::EpochFlowLimitExceeded
+		);
+		assert_ok!(Wildcard::deposit(RuntimeOrigin::signed(BOB), token_deposit(500)));
+
+		// The cap applies to each epoch separately.
+		run_to_block(2);
+		assert_ok!(Wildcard::deposit(RuntimeOrigin::signed(BOB), token_deposit(1_000)));
+	});
+}
+
+#[test]
+fn deposits_are_capped_per_account() {
+	setup().execute_with(|| {
+		limit_token(AssetLimits { per_account: Some(1_000), ..no_limits() });
+
+		assert_ok!(Wildcard::deposit(RuntimeOrigin::signed(ALICE), token_deposit(1_000)));
+		assert_noop!(
+			Wildcard::deposit(RuntimeOrigin::signed(ALICE), token_deposit(1)),
+			Error::<Test>::AccountFlowLimitExceeded
+		);
+		assert_ok!(Wildcard::deposit(RuntimeOrigin::signed(BOB), token_deposit(1_000)));
+	});
+}
+
+#[test]
+fn non_fungible_deposits_are_capped_per_collection() {
+	setup().execute_with(|| {
+		let collection_id = create_collection(&ALICE);
+		assert_ok!(Wildcard::set_asset_limits(
+			RuntimeOrigin::signed(ADMIN),
+			CHAIN_ID,
+			LimitedAsset::NonFungible(collection_id),
+			Some(AssetLimits { per_epoch: Some(1), ..no_limits() })
+		));
+
+		let nft_deposit = |item_id| {
+			mint_non_fungible(&ALICE, &collection_id, &item_id);
+			let (primary_id, secondary_id) =
+				generate_native_non_fungible_wide_id(collection_id, item_id);
+			AssetDeposit {
+				origin: CHAIN_ID,
+				asset_type: AssetType::NonFungible,
+				primary_id,
+				secondary_id,
+			}
+		};
+
+		assert_ok!(Wildcard::deposit(RuntimeOrigin::signed(ALICE), nft_deposit(1)));
+		// Minted up front, so that only the rejected deposit runs inside `assert_noop!`.
+		let second_deposit = nft_deposit(2);
+		assert_noop!(
+			Wildcard::deposit(RuntimeOrigin::signed(ALICE), second_deposit),
+			Error::<Test>::EpochFlowLimitExceeded
+		);
+	});
+}
+
+#[test]
+fn circuit_breaker_halts_the_bridge() {
+	setup().execute_with(|| {
+		assert_noop!(
+			Wildcard::set_circuit_breaker(RuntimeOrigin::signed(ALICE), true),
+			Error::<Test>::AccountIsNotAdministrator
+		);
+
+		assert_ok!(Wildcard::set_circuit_breaker(RuntimeOrigin::signed(ADMIN), true));
+		System::assert_last_event(mock::RuntimeEvent::Wildcard(crate::Event::CircuitBreakerSet {
+			halted: true,
+		}));
+		assert_noop!(
+			Wildcard::deposit(RuntimeOrigin::signed(ALICE), token_deposit(1_000)),
+			Error::<Test>::BridgeHalted
+		);
+
+		assert_ok!(Wildcard::set_circuit_breaker(RuntimeOrigin::signed(ADMIN), false));
+		assert_ok!(Wildcard::deposit(RuntimeOrigin::signed(ALICE), token_deposit(1_000)));
+	});
+}
+
+#[test]
+fn large_withdrawals_are_delayed() {
+	setup().execute_with(|| {
+		let now = <Test as Config>::Time::now();
+		let initial_balance = Balances::free_balance(ALICE);
+		let deposit = token_deposit(1_000);
+		assert_ok!(Wildcard::deposit(RuntimeOrigin::signed(ALICE), deposit));
+		limit_token(AssetLimits { delay_threshold: Some(500), ..no_limits() });
+
+		let proof = BalanceProof::using_deposit(&deposit, now, ALICE, true, 0);
+		run_to_block(10);
+		assert_ok!(Wildcard::withdraw(
+			RuntimeOrigin::signed(ALICE),
+			proof,
+			generate_signature_for(&proof)
+		));
+
+		System::assert_has_event(mock::RuntimeEvent::Wildcard(crate::Event::WithdrawalDelayed {
+			id: 0,
+			beneficiary: ALICE,
+			release_epoch: 9 + WithdrawalDelay::get(),
+		}));
+		assert_eq!(Balances::free_balance(ALICE), initial_balance - 1_000);
+		assert!(DelayedWithdrawals::<Test>::contains_key(0));
+
+		assert_noop!(
+			Wildcard::release_withdrawal(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::DelayedWithdrawalLocked
+		);
+
+		run_to_block(12);
+		assert_ok!(Wildcard::release_withdrawal(RuntimeOrigin::signed(BOB), 0));
+		System::assert_last_event(mock::RuntimeEvent::Wildcard(
+			crate::Event::DelayedWithdrawalReleased { id: 0, beneficiary: ALICE },
+		));
+		assert_eq!(Balances::free_balance(ALICE), initial_balance);
+
+		assert_noop!(
+			Wildcard::release_withdrawal(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::DelayedWithdrawalNotFound
+		);
+	});
+}
+
+#[test]
+fn delayed_withdrawals_can_be_cancelled() {
+	setup().execute_with(|| {
+		let now = <Test as Config>::Time::now();
+		let deposit = token_deposit(1_000);
+		assert_ok!(Wildcard::deposit(RuntimeOrigin::signed(ALICE), deposit));
+		limit_token(AssetLimits { delay_threshold: Some(500), ..no_limits() });
+
+		let proof = BalanceProof::using_deposit(&deposit, now, ALICE, true, 0);
+		run_to_block(10);
+		assert_ok!(Wildcard::withdraw(
+			RuntimeOrigin::signed(ALICE),
+			proof,
+			generate_signature_for(&proof)
+		));
+
+		assert_noop!(
+			Wildcard::cancel_withdrawal(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Test>::AccountIsNotAdministrator
+		);
+		assert_ok!(Wildcard::cancel_withdrawal(RuntimeOrigin::signed(ADMIN), 0));
+		System::assert_last_event(mock::RuntimeEvent::Wildcard(
+			crate::Event::DelayedWithdrawalCancelled { id: 0, beneficiary: ALICE, epoch: 9 },
+		));
+
+		// The cancelled withdrawal is credited back to Alice as a deposit.
+		let asset =
+			AssetOf::<Test> { origin: CHAIN_ID, kind: AssetKind::Fungible(NATIVE_TOKEN_ID) };
+		assert_eq!(
+			Deposits::<Test>::get((9, ALICE, asset)),
+			Some(DepositValueKind::Fungible(DepositValue::Token(1_000)))
+		);
+		assert!(DepositEpochs::<Test>::contains_key(ALICE, 9));
+
+		run_to_block(12);
+		assert_noop!(
+			Wildcard::release_withdrawal(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Test>::DelayedWithdrawalNotFound
+		);
+		assert_eq!(Balances::free_balance(Wildcard::reserve_account()), 1_000);
+	});
+}
+
+#[test]
+fn cancelled_withdrawals_no_longer_count_against_flow_limits() {
+	setup().execute_with(|| {
+		let now = <Test as Config>::Time::now();
+		let deposit = token_deposit(1_000);
+		assert_ok!(Wildcard::deposit(RuntimeOrigin::signed(ALICE), deposit));
+		limit_token(AssetLimits {
+			per_epoch: Some(1_000),
+			per_account: Some(1_000),
+			delay_threshold: Some(500),
+			..no_limits()
+		});
+
+		let proof = BalanceProof::using_deposit(&deposit, now, ALICE, true, 0);
+		run_to_block(10);
+		assert_ok!(Wildcard::withdraw(
+			RuntimeOrigin::signed(ALICE),
+			proof,
+			generate_signature_for(&proof)
+		));
+
+		let flow_key =
+			(FlowDirection::Withdrawal, CHAIN_ID, LimitedAsset::Fungible(NATIVE_TOKEN_ID));
+		assert_eq!(
+			EpochFlows::<Test>::get(&flow_key),
+			Some(FlowCounter { epoch: 9, amount: 1_000 })
+		);
+		assert_eq!(
+			AccountFlows::<Test>::get(ALICE, &flow_key),
+			Some(FlowCounter { epoch: 9, amount: 1_000 })
+		);
+
+		assert_ok!(Wildcard::cancel_withdrawal(RuntimeOrigin::signed(ADMIN), 0));
+		assert_eq!(EpochFlows::<Test>::get(&flow_key), Some(FlowCounter { epoch: 9, amount: 0 }));
+		assert_eq!(
+			AccountFlows::<Test>::get(ALICE, &flow_key),
+			Some(FlowCounter { epoch: 9, amount: 0 })
+		);
+	});
+}
+
+#[test]
+fn delayed_withdrawals_past_the_frozen_epoch_are_not_released() {
+	setup().execute_with(|| {
+		let initial_balance = Balances::free_balance(ALICE);
+		let deposit = token_deposit(1_000);
+		assert_ok!(Wildcard::deposit(RuntimeOrigin::signed(ALICE), deposit));
+		limit_token(AssetLimits { delay_threshold: Some(500), ..no_limits() });
+
+		let proof = BalanceProof::using_deposit(&deposit, 3, ALICE, true, 0);
+		run_to_block(10);
+		assert_ok!(Wildcard::withdraw(
+			RuntimeOrigin::signed(ALICE),
+			proof,
+			generate_signature_for(&proof)
+		));
+
+		run_to_block(12);
+		Frozen::<Test>::set(Some(2));
+		assert_noop!(
+			Wildcard::release_withdrawal(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::InvalidEpochNumber
+		);
+
+		Frozen::<Test>::set(Some(3));
+		assert_ok!(Wildcard::release_withdrawal(RuntimeOrigin::signed(BOB), 0));
+		assert_eq!(Balances::free_balance(ALICE), initial_balance);
+	});
+}
+
+#[test]
+fn frozen_refunds_bypass_flow_limits() {
+	setup().execute_with(|| {
+		let initial_balance = Balances::free_balance(ALICE);
+		run_to_block(2);
+		assert_ok!(Wildcard::deposit(RuntimeOrigin::signed(ALICE), token_deposit(1_000)));
+		limit_token(AssetLimits {
+			per_epoch: Some(1),
+			per_account: Some(1),
+			delay_threshold: Some(1),
+		});
+		assert_ok!(Wildcard::set_circuit_breaker(RuntimeOrigin::signed(ADMIN), true));
+
+		Frozen::<Test>::set(Some(0));
+		assert_ok!(Wildcard::refund_frozen(RuntimeOrigin::signed(ALICE)));
+
+		assert_eq!(Balances::free_balance(ALICE), initial_balance);
+		assert_eq!(DelayedWithdrawals::<Test>::iter().count(), 0);
+	});
+}
@@ -72,3 +72,31 @@ fn freeze_fials_if_no_challenge_for_epoch_present() {
 			);
 		});
 }
+
+#[test]
+fn freeze_fails_if_challenge_was_answered() {
+	ExtBuilder::default()
+		.balances(&[(ALICE, ChallengeBalance::get())])
+		.build()
+		.execute_with(|| {
+			run_to_block(10);
+			let (account, challenge_epoch, _) = make_challenge(ALICE);
+			assert_eq!(OpenChallenges::<Test>::get(challenge_epoch), 1);
+
+			let proof = ZeroBalanceProof::using_challenge(challenge_epoch, account);
+			let signature = generate_signature_for(&proof);
+			assert_ok!(Wildcard::respond_zero_challenge(
+				RuntimeOrigin::signed(ALICE),
+				proof,
+				signature
+			));
+			assert_eq!(OpenChallenges::<Test>::get(challenge_epoch), 0);
+
+			run_to_block(12);
+
+			assert_noop!(
+				Wildcard::freeze(RuntimeOrigin::signed(BOB)),
+				Error::<Test>::NothingToFreeze
+			);
+		});
+}
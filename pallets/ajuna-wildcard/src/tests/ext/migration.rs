@@ -0,0 +1,128 @@
+use super::*;
+use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+mod v1 {
+	use super::*;
+	use crate::migration::v1::{MigrateToV1, SignerKey};
+
+	#[test]
+	fn moves_signer_key_into_signer_set() {
+		ExtBuilder::default().build().execute_with(|| {
+			StorageVersion::new(0).put::<Wildcard>();
+			Signers::<Test>::kill();
+			let key = key_pair(7).public();
+			SignerKey::<Test>::put(key);
+
+			MigrateToV1::<Test>::on_runtime_upgrade();
+
+			assert_eq!(
+				Signers::<Test>::get(),
+				Some(SignerSet { keys: BoundedVec::truncate_from(vec![key]), threshold: 1 })
+			);
+			assert!(!SignerKey::<Test>::exists());
+			assert_eq!(Wildcard::on_chain_storage_version(), StorageVersion::new(1));
+		});
+	}
+
+	#[test]
+	fn keeps_existing_signer_set() {
+		ExtBuilder::default().build().execute_with(|| {
+			StorageVersion::new(0).put::<Wildcard>();
+			let signers = Signers::<Test>::get();
+			SignerKey::<Test>::put(key_pair(7).public());
+
+			MigrateToV1::<Test>::on_runtime_upgrade();
+
+			assert_eq!(Signers::<Test>::get(), signers);
+			assert!(!SignerKey::<Test>::exists());
+		});
+	}
+}
+
+mod v2 {
+	use super::*;
+	use crate::migration::v2::MigrateToV2;
+
+	#[test]
+	fn rebuilds_deposit_and_challenge_indexes() {
+		ExtBuilder::default().build().execute_with(|| {
+			StorageVersion::new(1).put::<Wildcard>();
+			let token =
+				AssetOf::<Test> { origin: CHAIN_ID, kind: AssetKind::Fungible(NATIVE_TOKEN_ID) };
+			let deposit = DepositValueKind::Fungible(DepositValue::Token(100));
+			for (epoch, account) in [(3, ALICE), (4, ALICE), (4, BOB)] {
+				Deposits::<Test>::insert((epoch, account, token.clone()), deposit.clone());
+			}
+			for (epoch, account) in [(5, ALICE), (5, BOB), (6, ALICE)] {
+				Challenges::<Test>::insert((epoch, account), 0);
+			}
+			OpenChallenges::<Test>::insert(5, 7);
+			OpenChallenges::<Test>::insert(2, 1);
+
+			MigrateToV2::<Test>::on_runtime_upgrade();
+
+			let mut alice_epochs =
+				DepositEpochs::<Test>::iter_key_prefix(ALICE).collect::<Vec<_>>();
+			alice_epochs.sort();
+			assert_eq!(alice_epochs, vec![3, 4]);
+			assert_eq!(DepositEpochs::<Test>::iter_key_prefix(BOB).collect::<Vec<_>>(), vec![4]);
+
+			let mut open_challenges = OpenChallenges::<Test>::iter().collect::<Vec<_>>();
+			open_challenges.sort();
+			assert_eq!(open_challenges, vec![(5, 2), (6, 1)]);
+			assert_eq!(Wildcard::on_chain_storage_version(), StorageVersion::new(2));
+		});
+	}
+}
+
+mod v3 {
+	use super::*;
+	use crate::migration::v3::MigrateToV3;
+	use frame_support::parameter_types;
+
+	parameter_types! {
+		pub SignerOperators: Vec<(sp_core::sr25519::Public, MockAccountId)> = vec![
+			(MockKeyPair::get().public(), BOB),
+			(key_pair(9).public(), CHARLIE),
+		];
+	}
+
+	#[test]
+	fn registers_bonded_operators_for_signer_keys() {
+		let bond = MinOperatorBond::get();
+		ExtBuilder::default().balances(&[(BOB, bond * 2)]).build().execute_with(|| {
+			StorageVersion::new(2).put::<Wildcard>();
+
+			MigrateToV3::<Test, SignerOperators>::on_runtime_upgrade();
+
+			let signer = MockKeyPair::get().public();
+			assert_eq!(
+				Operators::<Test>::get(BOB),
+				Some(Operator { signer, chain_id: CHAIN_ID, bond })
+			);
+			assert_eq!(OperatorSigners::<Test>::get(signer), Some(BOB));
+			assert_eq!(Balances::reserved_balance(BOB), bond);
+
+			// Keys outside of the signer set are left alone.
+			assert_eq!(Operators::<Test>::get(CHARLIE), None);
+			assert_eq!(OperatorSigners::<Test>::get(key_pair(9).public()), None);
+			assert_eq!(Wildcard::on_chain_storage_version(), StorageVersion::new(3));
+		});
+	}
+
+	#[test]
+	fn registers_unfunded_operators_without_bond() {
+		ExtBuilder::default().balances(&[(ALICE, 1_000)]).build().execute_with(|| {
+			StorageVersion::new(2).put::<Wildcard>();
+
+			MigrateToV3::<Test, SignerOperators>::on_runtime_upgrade();
+
+			let signer = MockKeyPair::get().public();
+			assert_eq!(
+				Operators::<Test>::get(BOB),
+				Some(Operator { signer, chain_id: CHAIN_ID, bond: 0 })
+			);
+			assert_eq!(OperatorSigners::<Test>::get(signer), Some(BOB));
+		});
+	}
+}
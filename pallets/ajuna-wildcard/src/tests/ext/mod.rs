@@ -1,12 +1,20 @@
 mod challenge;
+mod challenge_bond;
 mod deposit;
 mod end2end;
+mod flow_limits;
 mod freeze;
+mod migration;
+mod operator;
 mod propagate_freeze;
+mod prune_deposits;
 mod refund_frozen;
 mod respond_challenge;
+mod respond_challenge_batch;
 mod respond_zero_challenge;
+mod rotate_signers;
 mod withdraw;
+mod withdraw_batch;
 mod withdraw_frozen;
 
 use super::{mock, mock::*};
@@ -76,8 +84,58 @@ pub(crate) fn generate_wide_id_for_amount(amt: MockBalance) -> WideId {
 	H256::from_slice(wide_id.as_slice())
 }
 
-pub(crate) fn generate_signature_for<T: Proof>(proof: &T) -> sp_core::sr25519::Signature {
-	MockKeyPair::get().sign(proof.extract_msg().as_slice())
+pub(crate) fn key_pair(seed: u8) -> sp_core::sr25519::Pair {
+	sp_core::sr25519::Pair::from_seed(&[seed; 32])
+}
+
+/// Registers a funded operator, whose account is derived from `seed`, for the key pair of `seed`.
+pub(crate) fn register_operator(seed: u8) -> (MockAccountId, sp_core::sr25519::Pair) {
+	let operator = 100 + seed as MockAccountId;
+	let pair = key_pair(seed);
+	let _ = CurrencyOf::<Test>::deposit_creating(&operator, MinOperatorBond::get() * 10);
+	assert_ok!(Wildcard::register_operator(
+		RuntimeOrigin::signed(operator),
+		pair.public(),
+		CHAIN_ID,
+		MinOperatorBond::get()
+	));
+	(operator, pair)
+}
+
+pub(crate) fn generate_signature_for<T: Proof>(proof: &T) -> ProofSignaturesOf<Test> {
+	generate_signatures_with(proof, &[MockKeyPair::get()])
+}
+
+pub(crate) fn generate_signatures_with<T: Proof>(
+	proof: &T,
+	pairs: &[sp_core::sr25519::Pair],
+) -> ProofSignaturesOf<Test> {
+	let signatures = pairs.iter().map(|pair| pair.sign(proof.extract_msg().as_slice()));
+	BoundedVec::truncate_from(signatures.collect())
+}
+
+pub(crate) fn invalid_signature() -> ProofSignaturesOf<Test> {
+	BoundedVec::truncate_from(vec![sp_core::sr25519::Signature::from_raw([34; 64])])
+}
+
+pub(crate) fn generate_balance_batch(
+	proofs: &[BalanceProof],
+) -> (BalanceRoot, ProofSignaturesOf<Test>, BoundedVec<BalanceInclusionOf<Test>, MaxBatchedProofs>)
+{
+	let (root, paths) = balance_tree(proofs);
+	let balance_root = BalanceRoot { epoch: proofs[0].epoch, origin: proofs[0].origin, root };
+	let inclusions = proofs
+		.iter()
+		.zip(paths)
+		.map(|(proof, path)| BalanceInclusion {
+			balance_proof: *proof,
+			merkle_path: path.try_into().unwrap(),
+		})
+		.collect::<Vec<_>>()
+		.try_into()
+		.unwrap();
+
+	(balance_root, generate_signature_for(&balance_root), inclusions)
 }
 
 pub(crate) fn make_challenge(account: MockAccountId) -> (MockAccountId, EpochNumber, ChunkIndex) {
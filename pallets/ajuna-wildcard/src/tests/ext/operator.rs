@@ -0,0 +1,342 @@
+use super::*;
+
+fn keys_of(pairs: &[sp_core::sr25519::Pair]) -> BoundedVec<sp_core::sr25519::Public, MaxSigners> {
+	BoundedVec::truncate_from(pairs.iter().map(|pair| pair.public()).collect())
+}
+
+#[test]
+fn register_operator_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let signer = key_pair(7).public();
+		let bond = MinOperatorBond::get();
+
+		assert_ok!(Wildcard::register_operator(RuntimeOrigin::signed(BOB), signer, CHAIN_ID, bond));
+
+		assert_eq!(
+			Operators::<Test>::get(BOB),
+			Some(Operator { signer, chain_id: CHAIN_ID, bond })
+		);
+		assert_eq!(OperatorSigners::<Test>::get(signer), Some(BOB));
+		assert_eq!(Balances::reserved_balance(BOB), bond);
+		System::assert_last_event(mock::RuntimeEvent::Wildcard(crate::Event::OperatorRegistered {
+			operator: BOB,
+			signer,
+			chain_id: CHAIN_ID,
+			bond,
+		}));
+	});
+}
+
+#[test]
+fn register_operator_rejects_invalid_registrations() {
+	ExtBuilder::default().build().execute_with(|| {
+		let signer = key_pair(7).public();
+		let bond = MinOperatorBond::get();
+
+		assert_noop!(
+			Wildcard::register_operator(RuntimeOrigin::signed(BOB), signer, CHAIN_ID, bond - 1),
+			Error::<Test>::InsufficientOperatorBond
+		);
+
+		assert_ok!(Wildcard::register_operator(RuntimeOrigin::signed(BOB), signer, CHAIN_ID, bond));
+		assert_noop!(
+			Wildcard::register_operator(
+				RuntimeOrigin::signed(BOB),
+				key_pair(8).public(),
+				CHAIN_ID,
+				bond
+			),
+			Error::<Test>::OperatorAlreadyRegistered
+		);
+		assert_noop!(
+			Wildcard::register_operator(RuntimeOrigin::signed(CHARLIE), signer, CHAIN_ID, bond),
+			Error::<Test>::SignerKeyInUse
+		);
+	});
+}
+
+#[test]
+fn bond_operator_extra_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Wildcard::bond_operator_extra(RuntimeOrigin::signed(BOB), 10),
+			Error::<Test>::OperatorNotFound
+		);
+
+		let (operator, _) = register_operator(7);
+		assert_ok!(Wildcard::bond_operator_extra(RuntimeOrigin::signed(operator), 10));
+
+		let bond = MinOperatorBond::get() + 10;
+		assert_eq!(Operators::<Test>::get(operator).unwrap().bond, bond);
+		assert_eq!(Balances::reserved_balance(operator), bond);
+		System::assert_last_event(mock::RuntimeEvent::Wildcard(crate::Event::OperatorBonded {
+			operator,
+			bond,
+		}));
+	});
+}
+
+#[test]
+fn rotate_signers_requires_bonded_operators_of_this_chain() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Wildcard::set_administrator(RuntimeOrigin::root(), ALICE));
+		run_to_block(10);
+
+		let (_, pair) = register_operator(7);
+		assert_noop!(
+			Wildcard::rotate_signers(
+				RuntimeOrigin::signed(ALICE),
+				keys_of(&[pair.clone(), key_pair(8)]),
+				1,
+				13
+			),
+			Error::<Test>::SignerNotOperator
+		);
+
+		assert_ok!(Wildcard::register_operator(
+			RuntimeOrigin::signed(BOB),
+			key_pair(8).public(),
+			FOREIGN_CHAIN_ID,
+			MinOperatorBond::get()
+		));
+		assert_noop!(
+			Wildcard::rotate_signers(
+				RuntimeOrigin::signed(ALICE),
+				keys_of(&[pair.clone(), key_pair(8)]),
+				1,
+				13
+			),
+			Error::<Test>::SignerNotOperator
+		);
+
+		Operators::<Test>::mutate(100 + 7, |operator| operator.as_mut().unwrap().bond = 0);
+		assert_noop!(
+			Wildcard::rotate_signers(RuntimeOrigin::signed(ALICE), keys_of(&[pair]), 1, 13),
+			Error::<Test>::InsufficientOperatorBond
+		);
+	});
+}
+
+#[test]
+fn unregister_operator_waits_until_proofs_cannot_be_challenged() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Wildcard::set_administrator(RuntimeOrigin::root(), ALICE));
+		run_to_block(10);
+
+		assert_noop!(
+			Wildcard::unregister_operator(RuntimeOrigin::signed(BOB)),
+			Error::<Test>::OperatorNotFound
+		);
+
+		let (operator, pair) = register_operator(7);
+		assert_ok!(Wildcard::rotate_signers(RuntimeOrigin::signed(ALICE), keys_of(&[pair]), 1, 13));
+		assert_noop!(
+			Wildcard::unregister_operator(RuntimeOrigin::signed(operator)),
+			Error::<Test>::OperatorStillSigning
+		);
+
+		run_to_block(19);
+		let (_, next_pair) = register_operator(8);
+		assert_ok!(Wildcard::rotate_signers(
+			RuntimeOrigin::signed(ALICE),
+			keys_of(&[next_pair]),
+			1,
+			22
+		));
+
+		// Proofs signed until the end of the overlap can freeze the pallet for 4 more epochs.
+		run_to_block(27);
+		assert_noop!(
+			Wildcard::unregister_operator(RuntimeOrigin::signed(operator)),
+			Error::<Test>::OperatorStillSigning
+		);
+
+		run_to_block(28);
+		assert_ok!(Wildcard::unregister_operator(RuntimeOrigin::signed(operator)));
+
+		assert_eq!(Operators::<Test>::get(operator), None);
+		assert_eq!(OperatorSigners::<Test>::get(key_pair(7).public()), None);
+		assert_eq!(Balances::reserved_balance(operator), 0);
+		System::assert_last_event(mock::RuntimeEvent::Wildcard(
+			crate::Event::OperatorUnregistered { operator, bond: MinOperatorBond::get() },
+		));
+	});
+}
+
+#[test]
+fn freeze_slashes_signing_operators() {
+	ExtBuilder::default()
+		.balances(&[(ALICE, ChallengeBalance::get())])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Wildcard::set_administrator(RuntimeOrigin::root(), ALICE));
+			run_to_block(5);
+
+			// The genesis signer retires after the frozen epoch, while the new one is already
+			// signing in it.
+			let (retiring_operator, _) = register_operator(0);
+			let (current_operator, current_pair) = register_operator(7);
+			let (idle_operator, _) = register_operator(8);
+			assert_ok!(Wildcard::rotate_signers(
+				RuntimeOrigin::signed(ALICE),
+				keys_of(&[current_pair]),
+				1,
+				8
+			));
+
+			run_to_block(10);
+			let _ = make_challenge(ALICE);
+			run_to_block(12);
+			assert_ok!(Wildcard::freeze(RuntimeOrigin::signed(BOB)));
+
+			let bond = MinOperatorBond::get();
+			for operator in [current_operator, retiring_operator] {
+				assert_eq!(Operators::<Test>::get(operator).unwrap().bond, 0);
+				assert_eq!(Balances::reserved_balance(operator), 0);
+				System::assert_has_event(mock::RuntimeEvent::Wildcard(
+					crate::Event::OperatorSlashed { operator, frozen_epoch: 7, amount: bond },
+				));
+			}
+			assert_eq!(Operators::<Test>::get(idle_operator).unwrap().bond, bond);
+			assert_eq!(Balances::reserved_balance(idle_operator), bond);
+
+			assert_eq!(ShortfallCover::<Test>::get(), bond * 2);
+			assert_eq!(Balances::free_balance(Wildcard::reserve_account()), bond * 2);
+		});
+}
+
+#[test]
+fn freeze_spares_signers_rotated_in_after_frozen_epoch() {
+	ExtBuilder::default()
+		.balances(&[(ALICE, ChallengeBalance::get())])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Wildcard::set_administrator(RuntimeOrigin::root(), ALICE));
+			run_to_block(10);
+
+			let (retiring_operator, _) = register_operator(0);
+			let (current_operator, current_pair) = register_operator(7);
+			assert_ok!(Wildcard::rotate_signers(
+				RuntimeOrigin::signed(ALICE),
+				keys_of(&[current_pair]),
+				1,
+				13
+			));
+
+			let _ = make_challenge(ALICE);
+			run_to_block(12);
+			assert_ok!(Wildcard::freeze(RuntimeOrigin::signed(BOB)));
+			assert_eq!(Frozen::<Test>::get(), Some(7));
+
+			let bond = MinOperatorBond::get();
+			assert_eq!(Operators::<Test>::get(retiring_operator).unwrap().bond, 0);
+			assert_eq!(Operators::<Test>::get(current_operator).unwrap().bond, bond);
+			assert_eq!(Balances::reserved_balance(current_operator), bond);
+			assert_eq!(ShortfallCover::<Test>::get(), bond);
+		});
+}
+
+#[test]
+fn slashed_bonds_cover_frozen_withdrawal_shortfalls() {
+	let token_amt = 20;
+	let initial_balance = 1_000_000;
+
+	ExtBuilder::default()
+		.balances(&[(ALICE, initial_balance)])
+		.build()
+		.execute_with(|| {
+			let (operator, _) = register_operator(0);
+			assert_eq!(key_pair(0).public(), MockKeyPair::get().public());
+
+			let asset_deposit = AssetDeposit {
+				origin: CHAIN_ID,
+				asset_type: AssetType::Fungible,
+				primary_id: generate_native_fungible_wide_id(NATIVE_TOKEN_ID),
+				secondary_id: generate_wide_id_for_amount(token_amt),
+			};
+			assert_ok!(Wildcard::deposit(RuntimeOrigin::signed(ALICE), asset_deposit));
+
+			run_to_block(10);
+			let _ = make_challenge(ALICE);
+			run_to_block(12);
+			assert_ok!(Wildcard::freeze(RuntimeOrigin::signed(BOB)));
+
+			let bond = MinOperatorBond::get();
+			let reserve = Wildcard::reserve_account();
+			assert_eq!(Balances::reserved_balance(operator), 0);
+			assert_eq!(Balances::free_balance(reserve), token_amt + bond);
+
+			// The signed balance exceeds what the reserve holds apart from the slashed bond.
+			let withdraw_amt = token_amt + 30;
+			let proof = BalanceProof::using_deposit(
+				&AssetDeposit {
+					secondary_id: generate_wide_id_for_amount(withdraw_amt),
+					..asset_deposit
+				},
+				7,
+				ALICE,
+				true,
+				0,
+			);
+			assert_ok!(Wildcard::withdraw_frozen(
+				RuntimeOrigin::signed(ALICE),
+				proof,
+				generate_signature_for(&proof)
+			));
+
+			System::assert_has_event(mock::RuntimeEvent::Wildcard(
+				crate::Event::ShortfallCovered { beneficiary: ALICE, amount: 30 },
+			));
+			assert_eq!(ShortfallCover::<Test>::get(), bond - 30);
+			assert_eq!(Balances::free_balance(reserve), token_amt + bond - withdraw_amt);
+		});
+}
+
+#[test]
+fn slashed_bonds_do_not_cover_asset_shortfalls() {
+	let asset_amt = 20;
+	let initial_balance = 1_000_000;
+
+	ExtBuilder::default()
+		.balances(&[(ALICE, initial_balance)])
+		.build()
+		.execute_with(|| {
+			let _ = register_operator(0);
+
+			let asset_id = create_fungible(ALICE, 457, 1);
+			let _ = mint_fungible(ALICE, asset_id, 1_000);
+			let asset_deposit = AssetDeposit {
+				origin: CHAIN_ID,
+				asset_type: AssetType::Fungible,
+				primary_id: generate_native_fungible_wide_id(asset_id),
+				secondary_id: generate_wide_id_for_amount(asset_amt),
+			};
+			assert_ok!(Wildcard::deposit(RuntimeOrigin::signed(ALICE), asset_deposit));
+
+			run_to_block(10);
+			let _ = make_challenge(ALICE);
+			run_to_block(12);
+			assert_ok!(Wildcard::freeze(RuntimeOrigin::signed(BOB)));
+
+			let bond = MinOperatorBond::get();
+			let proof = BalanceProof::using_deposit(
+				&AssetDeposit {
+					secondary_id: generate_wide_id_for_amount(asset_amt + 30),
+					..asset_deposit
+				},
+				7,
+				ALICE,
+				true,
+				0,
+			);
+			assert_noop!(
+				Wildcard::withdraw_frozen(
+					RuntimeOrigin::signed(ALICE),
+					proof,
+					generate_signature_for(&proof)
+				),
+				Error::<Test>::InsufficientReserveFunds
+			);
+			assert_eq!(ShortfallCover::<Test>::get(), bond);
+		});
+}
@@ -7,7 +7,7 @@ pub(crate) fn generate_signature_for_with(
 	signature.sign(proof.extract_msg().as_slice())
 }
 
-pub(crate) fn sign_public_key(key: &sp_core::sr25519::Public) -> sp_core::sr25519::Signature {
+pub(crate) fn sign_public_key(key: &sp_core::sr25519::Public) -> ProofSignaturesOf<Test> {
 	let client_key_msg = {
 		let mut bytes = sp_std::vec::Vec::with_capacity(0);
 
@@ -17,7 +17,7 @@ pub(crate) fn sign_public_key(key: &sp_core::sr25519::Public) -> sp_core::sr2551
 		bytes
 	};
 
-	MockKeyPair::get().sign(&client_key_msg)
+	BoundedVec::truncate_from(vec![MockKeyPair::get().sign(&client_key_msg)])
 }
 
 #[test]
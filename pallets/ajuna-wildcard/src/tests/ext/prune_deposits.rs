@@ -0,0 +1,101 @@
+use super::*;
+use crate::weights::WeightInfo;
+
+fn token_asset() -> AssetOf<Test> {
+	AssetOf::<Test> { origin: CHAIN_ID, kind: AssetKind::Fungible(NATIVE_TOKEN_ID) }
+}
+
+fn insert_deposit(epoch: EpochNumber, who: MockAccountId) {
+	Deposits::<Test>::insert(
+		(epoch, who, token_asset()),
+		DepositValueKindOf::<Test>::Fungible(DepositValue::Token(1_000)),
+	);
+}
+
+fn current_epoch() -> EpochNumber {
+	Pallet::<Test>::calculate_epoch_number_from(<Test as Config>::Time::now())
+		.expect("Should get epoch")
+}
+
+#[test]
+fn prunes_deposits_outside_retention_window() {
+	ExtBuilder::default().build().execute_with(|| {
+		run_to_block(3);
+		insert_deposit(2, ALICE);
+		insert_deposit(3, BOB);
+
+		// Deposits are kept for the current epoch and the 3 before it.
+		run_to_block(6);
+		assert_eq!(current_epoch(), 5);
+		Wildcard::on_idle(System::block_number(), Weight::MAX);
+		assert!(Deposits::<Test>::contains_key((2, ALICE, token_asset())));
+		assert!(Deposits::<Test>::contains_key((3, BOB, token_asset())));
+
+		run_to_block(7);
+		Wildcard::on_idle(System::block_number(), Weight::MAX);
+		assert!(!Deposits::<Test>::contains_key((2, ALICE, token_asset())));
+		assert!(Deposits::<Test>::contains_key((3, BOB, token_asset())));
+		assert_eq!(DepositPruneCursor::<Test>::get(), 3);
+
+		run_to_block(8);
+		Wildcard::on_idle(System::block_number(), Weight::MAX);
+		assert!(!Deposits::<Test>::contains_key((3, BOB, token_asset())));
+		assert_eq!(DepositPruneCursor::<Test>::get(), 4);
+	});
+}
+
+#[test]
+fn deposit_does_not_prune_old_epochs() {
+	let token_amt = 1_000;
+	ExtBuilder::default().build().execute_with(|| {
+		insert_deposit(0, BOB);
+
+		run_to_block(10);
+		assert_ok!(Wildcard::deposit(
+			RuntimeOrigin::signed(ALICE),
+			AssetDeposit {
+				origin: CHAIN_ID,
+				asset_type: AssetType::Fungible,
+				primary_id: generate_native_fungible_wide_id(NATIVE_TOKEN_ID),
+				secondary_id: generate_wide_id_for_amount(token_amt),
+			}
+		));
+		assert!(Deposits::<Test>::contains_key((0, BOB, token_asset())));
+	});
+}
+
+#[test]
+fn pruning_is_bounded_by_remaining_weight() {
+	ExtBuilder::default().build().execute_with(|| {
+		insert_deposit(1, ALICE);
+		insert_deposit(1, BOB);
+		insert_deposit(1, CHARLIE);
+		DepositPruneCursor::<Test>::put(1);
+		run_to_block(10);
+
+		let weight = <() as WeightInfo>::prune_deposits(2);
+		assert_eq!(Wildcard::on_idle(System::block_number(), weight), weight);
+		assert_eq!(Deposits::<Test>::iter_key_prefix((1,)).count(), 1);
+
+		Wildcard::on_idle(System::block_number(), Weight::MAX);
+		assert_eq!(Deposits::<Test>::iter_keys().count(), 0);
+		assert_eq!(DepositPruneCursor::<Test>::get(), 6);
+
+		let base_weight = <() as WeightInfo>::prune_deposits(0);
+		let remaining_weight = Weight::from_parts(base_weight.ref_time() / 2, 0);
+		assert_eq!(Wildcard::on_idle(System::block_number(), remaining_weight), Weight::zero());
+	});
+}
+
+#[test]
+fn does_not_prune_while_frozen() {
+	ExtBuilder::default().build().execute_with(|| {
+		insert_deposit(1, ALICE);
+		run_to_block(10);
+		Frozen::<Test>::put(5);
+
+		Wildcard::on_idle(System::block_number(), Weight::MAX);
+		assert!(Deposits::<Test>::contains_key((1, ALICE, token_asset())));
+		assert_eq!(DepositPruneCursor::<Test>::get(), 0);
+	});
+}
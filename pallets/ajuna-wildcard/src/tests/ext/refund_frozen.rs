@@ -60,3 +60,106 @@ fn test_success() {
 			assert_eq!(Balances::free_balance(reserve), 0);
 		});
 }
+
+#[test]
+fn test_success_for_other_account() {
+	let token_amt = 1_000;
+	let initial_balance = 1_000_000;
+
+	ExtBuilder::default()
+		.balances(&[(ALICE, initial_balance)])
+		.build()
+		.execute_with(|| {
+			run_to_block(10);
+
+			let _ = create_and_deposit_tokens(&ALICE, token_amt, 0);
+			let reserve = Wildcard::reserve_account();
+
+			Frozen::<Test>::set(Some(0));
+
+			assert_ok!(Wildcard::refund_frozen_for(
+				RuntimeOrigin::signed(BOB),
+				ALICE,
+				MaxRefundsPerCall::get()
+			));
+
+			System::assert_last_event(mock::RuntimeEvent::Wildcard(
+				crate::Event::DepositsRefunded { epoch: 0, beneficieary: ALICE },
+			));
+
+			assert_eq!(Balances::free_balance(ALICE), initial_balance);
+			assert_eq!(Balances::free_balance(reserve), 0);
+			assert_eq!(DepositEpochs::<Test>::iter_prefix(ALICE).count(), 0);
+		});
+}
+
+#[test]
+fn test_success_paginated() {
+	let token_amt = 1_000;
+	let initial_balance = 1_000_000;
+
+	ExtBuilder::default()
+		.balances(&[(ALICE, initial_balance)])
+		.build()
+		.execute_with(|| {
+			run_to_block(10);
+
+			let _ = create_and_deposit_tokens(&ALICE, token_amt, 0);
+			run_to_block(11);
+			let _ = create_and_deposit_tokens(&ALICE, token_amt * 2, 0);
+			let reserve = Wildcard::reserve_account();
+
+			assert_eq!(DepositEpochs::<Test>::iter_prefix(ALICE).count(), 2);
+
+			Frozen::<Test>::set(Some(0));
+
+			assert_ok!(Wildcard::refund_frozen_for(RuntimeOrigin::signed(ALICE), ALICE, 1));
+			assert_eq!(Balances::free_balance(reserve), token_amt * 3 - token_amt);
+			assert_eq!(DepositEpochs::<Test>::iter_prefix(ALICE).count(), 1);
+
+			assert_ok!(Wildcard::refund_frozen_for(RuntimeOrigin::signed(ALICE), ALICE, 1));
+			assert_eq!(Balances::free_balance(ALICE), initial_balance);
+			assert_eq!(Balances::free_balance(reserve), 0);
+			assert_eq!(DepositEpochs::<Test>::iter_prefix(ALICE).count(), 0);
+		});
+}
+
+#[test]
+fn test_skips_deposits_up_to_frozen_epoch() {
+	let token_amt = 1_000;
+	let initial_balance = 1_000_000;
+
+	ExtBuilder::default()
+		.balances(&[(ALICE, initial_balance)])
+		.build()
+		.execute_with(|| {
+			run_to_block(10);
+
+			let _ = create_and_deposit_tokens(&ALICE, token_amt, 0);
+			let reserve = Wildcard::reserve_account();
+			let deposit_epoch =
+				Wildcard::calculate_epoch_number_from(<Test as Config>::Time::now()).unwrap();
+
+			Frozen::<Test>::set(Some(deposit_epoch));
+
+			assert_ok!(Wildcard::refund_frozen(RuntimeOrigin::signed(ALICE)));
+
+			assert_eq!(Balances::free_balance(ALICE), initial_balance - token_amt);
+			assert_eq!(Balances::free_balance(reserve), token_amt);
+			assert!(DepositEpochs::<Test>::contains_key(ALICE, deposit_epoch));
+		});
+}
+
+#[test]
+fn test_fails_if_not_frozen() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Wildcard::refund_frozen(RuntimeOrigin::signed(ALICE)),
+			Error::<Test>::NotFrozen
+		);
+		assert_noop!(
+			Wildcard::refund_frozen_for(RuntimeOrigin::signed(BOB), ALICE, 1),
+			Error::<Test>::NotFrozen
+		);
+	});
+}
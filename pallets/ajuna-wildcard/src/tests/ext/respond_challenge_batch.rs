@@ -0,0 +1,142 @@
+use super::*;
+
+#[test]
+fn respond_challenge_batch_works() {
+	ExtBuilder::default()
+		.balances(&[(ALICE, ChallengeBalance::get()), (BOB, ChallengeBalance::get())])
+		.build()
+		.execute_with(|| {
+			run_to_block(10);
+
+			let (alice, challenge_epoch, _) = make_challenge(ALICE);
+			let (bob, _, _) = make_challenge(BOB);
+
+			let proofs = [
+				BalanceProof::using_challenge(challenge_epoch, alice, 0, 1),
+				BalanceProof::using_challenge(challenge_epoch, bob, 0, 0),
+				BalanceProof::using_challenge(challenge_epoch, alice, 1, 1),
+			];
+			let (balance_root, root_signature, inclusions) = generate_balance_batch(&proofs);
+
+			assert_ok!(Wildcard::respond_challenge_batch(
+				RuntimeOrigin::signed(CHARLIE),
+				balance_root,
+				root_signature,
+				inclusions
+			));
+
+			assert_eq!(Challenges::<Test>::get((challenge_epoch, alice)), None);
+			assert_eq!(Challenges::<Test>::get((challenge_epoch, bob)), None);
+
+			for proof in proofs {
+				let challenger = if proof.account == proofs[1].account { bob } else { alice };
+				System::assert_has_event(mock::RuntimeEvent::Wildcard(
+					crate::Event::ChallengeResponded {
+						challenger,
+						challenged_epoch: challenge_epoch,
+						balance_proof: proof,
+					},
+				));
+			}
+		});
+}
+
+#[test]
+fn respond_challenge_batch_can_respond_partially() {
+	ExtBuilder::default()
+		.balances(&[(ALICE, ChallengeBalance::get())])
+		.build()
+		.execute_with(|| {
+			run_to_block(10);
+
+			let (account, challenge_epoch, chunk_index) = make_challenge(ALICE);
+			let proofs = [
+				BalanceProof::using_challenge(challenge_epoch, account, 0, 2),
+				BalanceProof::using_challenge(challenge_epoch, account, 1, 2),
+				BalanceProof::using_challenge(challenge_epoch, account, 2, 2),
+			];
+			let (balance_root, root_signature, inclusions) = generate_balance_batch(&proofs);
+
+			assert_ok!(Wildcard::respond_challenge_batch(
+				RuntimeOrigin::signed(ALICE),
+				balance_root,
+				root_signature,
+				inclusions[..2].to_vec().try_into().unwrap()
+			));
+			assert_eq!(Challenges::<Test>::get((challenge_epoch, account)), Some(chunk_index + 2));
+
+			// The remaining chunk can also be answered with an individually signed proof.
+			let proof = proofs[2];
+			assert_ok!(Wildcard::respond_challenge(
+				RuntimeOrigin::signed(ALICE),
+				proof,
+				generate_signature_for(&proof)
+			));
+			assert_eq!(Challenges::<Test>::get((challenge_epoch, account)), None);
+		});
+}
+
+#[test]
+fn respond_challenge_batch_rejects_out_of_order_chunks() {
+	ExtBuilder::default()
+		.balances(&[(ALICE, ChallengeBalance::get())])
+		.build()
+		.execute_with(|| {
+			run_to_block(10);
+
+			let (account, challenge_epoch, _) = make_challenge(ALICE);
+			let proofs = [
+				BalanceProof::using_challenge(challenge_epoch, account, 1, 1),
+				BalanceProof::using_challenge(challenge_epoch, account, 0, 1),
+			];
+			let (balance_root, root_signature, inclusions) = generate_balance_batch(&proofs);
+
+			assert_noop!(
+				Wildcard::respond_challenge_batch(
+					RuntimeOrigin::signed(ALICE),
+					balance_root,
+					root_signature,
+					inclusions
+				),
+				Error::<Test>::WrongChunkRespondedChallenge
+			);
+		});
+}
+
+#[test]
+fn respond_challenge_batch_rejects_proofs_outside_root() {
+	ExtBuilder::default()
+		.balances(&[(ALICE, ChallengeBalance::get())])
+		.build()
+		.execute_with(|| {
+			run_to_block(10);
+
+			let (account, challenge_epoch, _) = make_challenge(ALICE);
+			let proofs = [
+				BalanceProof::using_challenge(challenge_epoch, account, 0, 1),
+				BalanceProof::using_challenge(challenge_epoch, account, 1, 1),
+			];
+			let (balance_root, root_signature, mut inclusions) = generate_balance_batch(&proofs);
+			inclusions[0].balance_proof.chunk_last = 0;
+
+			assert_noop!(
+				Wildcard::respond_challenge_batch(
+					RuntimeOrigin::signed(ALICE),
+					balance_root,
+					root_signature,
+					inclusions.clone()
+				),
+				Error::<Test>::InvalidMerkleProof
+			);
+
+			assert_noop!(
+				Wildcard::respond_challenge_batch(
+					RuntimeOrigin::signed(ALICE),
+					balance_root,
+					invalid_signature(),
+					inclusions
+				),
+				Error::<Test>::BadSignature
+			);
+		});
+}
@@ -0,0 +1,213 @@
+use super::*;
+
+fn keys_of(pairs: &[sp_core::sr25519::Pair]) -> BoundedVec<sp_core::sr25519::Public, MaxSigners> {
+	BoundedVec::truncate_from(pairs.iter().map(|pair| pair.public()).collect())
+}
+
+#[test]
+fn rotate_signers_requires_administrator() {
+	ExtBuilder::default().build().execute_with(|| {
+		let keys = keys_of(&[key_pair(7)]);
+
+		assert_noop!(
+			Wildcard::rotate_signers(RuntimeOrigin::signed(ALICE), keys.clone(), 1, 0),
+			Error::<Test>::AdministratorNotSet
+		);
+
+		assert_ok!(Wildcard::set_administrator(RuntimeOrigin::root(), ALICE));
+		assert_noop!(
+			Wildcard::rotate_signers(RuntimeOrigin::signed(BOB), keys, 1, 0),
+			Error::<Test>::AccountIsNotAdministrator
+		);
+	});
+}
+
+#[test]
+fn rotate_signers_rejects_invalid_signer_sets() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Wildcard::set_administrator(RuntimeOrigin::root(), ALICE));
+		run_to_block(10);
+
+		for (keys, threshold) in [
+			(keys_of(&[key_pair(7), key_pair(8)]), 0),
+			(keys_of(&[key_pair(7), key_pair(8)]), 3),
+			(keys_of(&[key_pair(7), key_pair(7)]), 1),
+			(keys_of(&[]), 1),
+		] {
+			assert_noop!(
+				Wildcard::rotate_signers(RuntimeOrigin::signed(ALICE), keys, threshold, 13),
+				Error::<Test>::InvalidSignerSet
+			);
+		}
+
+		// Proofs of the current epoch are final 4 epochs later.
+		register_operator(7);
+		assert_noop!(
+			Wildcard::rotate_signers(RuntimeOrigin::signed(ALICE), keys_of(&[key_pair(7)]), 1, 12),
+			Error::<Test>::InvalidOverlapEpoch
+		);
+	});
+}
+
+#[test]
+fn rotate_signers_applies_first_signer_set_immediately() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Wildcard::set_administrator(RuntimeOrigin::root(), ALICE));
+		Signers::<Test>::kill();
+
+		let (_, pair) = register_operator(7);
+		let keys = keys_of(&[pair]);
+		assert_ok!(Wildcard::rotate_signers(RuntimeOrigin::signed(ALICE), keys.clone(), 1, 0));
+
+		let signers = SignerSet { keys, threshold: 1 };
+		assert_eq!(Signers::<Test>::get(), Some(signers.clone()));
+		assert_eq!(RetiringSigners::<Test>::get(), None);
+		System::assert_last_event(mock::RuntimeEvent::Wildcard(crate::Event::SignersRotated {
+			signers,
+			overlap_until: None,
+		}));
+	});
+}
+
+#[test]
+fn rotate_signers_accepts_retiring_signers_until_overlap_ends() {
+	ExtBuilder::default()
+		.balances(&[(ALICE, ChallengeBalance::get())])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Wildcard::set_administrator(RuntimeOrigin::root(), ALICE));
+			run_to_block(10);
+
+			let (_, new_pair) = register_operator(7);
+			let old_signers = Signers::<Test>::get().unwrap();
+			assert_ok!(Wildcard::rotate_signers(
+				RuntimeOrigin::signed(ALICE),
+				keys_of(std::slice::from_ref(&new_pair)),
+				1,
+				13
+			));
+			assert_eq!(
+				RetiringSigners::<Test>::get(),
+				Some(RetiredSignerSet { signers: old_signers, active_since: 0, active_until: 13 })
+			);
+			assert_eq!(SignersSince::<Test>::get(), 9);
+			System::assert_last_event(mock::RuntimeEvent::Wildcard(crate::Event::SignersRotated {
+				signers: SignerSet { keys: keys_of(std::slice::from_ref(&new_pair)), threshold: 1 },
+				overlap_until: Some(13),
+			}));
+
+			let (account, challenge_epoch, _) = make_challenge(ALICE);
+			let proofs = [0, 1, 2, 3]
+				.map(|chunk| BalanceProof::using_challenge(challenge_epoch, account, chunk, 3));
+
+			// Both signer sets are accepted during the overlap.
+			assert_ok!(Wildcard::respond_challenge(
+				RuntimeOrigin::signed(ALICE),
+				proofs[0],
+				generate_signature_for(&proofs[0])
+			));
+			assert_ok!(Wildcard::respond_challenge(
+				RuntimeOrigin::signed(ALICE),
+				proofs[1],
+				generate_signatures_with(&proofs[1], std::slice::from_ref(&new_pair))
+			));
+
+			// Once the overlap epoch has ended, only the new signer set is accepted.
+			run_to_block(15);
+			assert_noop!(
+				Wildcard::respond_challenge(
+					RuntimeOrigin::signed(ALICE),
+					proofs[2],
+					generate_signature_for(&proofs[2])
+				),
+				Error::<Test>::BadSignature
+			);
+			assert_ok!(Wildcard::respond_challenge(
+				RuntimeOrigin::signed(ALICE),
+				proofs[2],
+				generate_signatures_with(&proofs[2], &[new_pair])
+			));
+		});
+}
+
+#[test]
+fn rotate_signers_waits_until_overlap_ends() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Wildcard::set_administrator(RuntimeOrigin::root(), ALICE));
+		run_to_block(10);
+
+		let (_, pair) = register_operator(7);
+		let (_, next_pair) = register_operator(8);
+		assert_ok!(Wildcard::rotate_signers(RuntimeOrigin::signed(ALICE), keys_of(&[pair]), 1, 13));
+
+		// Replacing the signers again would drop the retiring set while its proofs can still be
+		// challenged.
+		run_to_block(18);
+		assert_noop!(
+			Wildcard::rotate_signers(
+				RuntimeOrigin::signed(ALICE),
+				keys_of(std::slice::from_ref(&next_pair)),
+				1,
+				21
+			),
+			Error::<Test>::SignerRotationInProgress
+		);
+
+		run_to_block(19);
+		assert_ok!(Wildcard::rotate_signers(
+			RuntimeOrigin::signed(ALICE),
+			keys_of(std::slice::from_ref(&next_pair)),
+			1,
+			22
+		));
+		assert_eq!(
+			RetiringSigners::<Test>::get(),
+			Some(RetiredSignerSet {
+				signers: SignerSet { keys: keys_of(&[key_pair(7)]), threshold: 1 },
+				active_since: 9,
+				active_until: 22,
+			})
+		);
+		assert_eq!(SignersSince::<Test>::get(), 18);
+	});
+}
+
+#[test]
+fn proofs_require_threshold_of_distinct_signers() {
+	ExtBuilder::default()
+		.balances(&[(ALICE, ChallengeBalance::get())])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Wildcard::set_administrator(RuntimeOrigin::root(), ALICE));
+			run_to_block(10);
+
+			let pairs = [7, 8, 9].map(|seed| register_operator(seed).1);
+			assert_ok!(Wildcard::rotate_signers(
+				RuntimeOrigin::signed(ALICE),
+				keys_of(&pairs),
+				2,
+				13
+			));
+
+			let (account, challenge_epoch, _) = make_challenge(ALICE);
+			let proof = BalanceProof::using_challenge(challenge_epoch, account, 0, 0);
+
+			for signers in [vec![pairs[0].clone()], vec![pairs[0].clone(), pairs[0].clone()]] {
+				assert_noop!(
+					Wildcard::respond_challenge(
+						RuntimeOrigin::signed(ALICE),
+						proof,
+						generate_signatures_with(&proof, &signers)
+					),
+					Error::<Test>::BadSignature
+				);
+			}
+
+			assert_ok!(Wildcard::respond_challenge(
+				RuntimeOrigin::signed(ALICE),
+				proof,
+				generate_signatures_with(&proof, &[pairs[2].clone(), pairs[0].clone()])
+			));
+			assert_eq!(Challenges::<Test>::get((challenge_epoch, account)), None);
+		});
+}
@@ -0,0 +1,229 @@
+use super::*;
+
+fn deposit_tokens(who: &MockAccountId, amounts: &[MockBalance]) -> Vec<AssetDeposit> {
+	amounts
+		.iter()
+		.map(|amount| {
+			let asset_deposit = AssetDeposit {
+				origin: CHAIN_ID,
+				asset_type: AssetType::Fungible,
+				primary_id: generate_native_fungible_wide_id(NATIVE_TOKEN_ID),
+				secondary_id: generate_wide_id_for_amount(*amount),
+			};
+			assert_ok!(Wildcard::deposit(RuntimeOrigin::signed(*who), asset_deposit));
+			asset_deposit
+		})
+		.collect()
+}
+
+fn deposit_fungible_asset(
+	who: &MockAccountId,
+	asset_id: MockAssetId,
+	amount: MockBalance,
+) -> AssetDeposit {
+	create_fungible(*who, asset_id, 1);
+	mint_fungible(*who, asset_id, amount);
+
+	let asset_deposit = AssetDeposit {
+		origin: CHAIN_ID,
+		asset_type: AssetType::Fungible,
+		primary_id: generate_native_fungible_wide_id(asset_id),
+		secondary_id: generate_wide_id_for_amount(amount),
+	};
+	assert_ok!(Wildcard::deposit(RuntimeOrigin::signed(*who), asset_deposit));
+	asset_deposit
+}
+
+#[test]
+fn withdraw_batch_works() {
+	let initial_balance = 1_000_000;
+	let asset_id = 18;
+
+	ExtBuilder::default()
+		.balances(&[(ALICE, initial_balance)])
+		.build()
+		.execute_with(|| {
+			let now = <Test as Config>::Time::now();
+			let token_deposits = deposit_tokens(&ALICE, &[1_000, 500]);
+			let asset_deposit = deposit_fungible_asset(&ALICE, asset_id, 300);
+			let reserve = Wildcard::reserve_account();
+
+			let proofs = [
+				BalanceProof::using_deposit(&token_deposits[0], now, ALICE, true, 0),
+				BalanceProof::using_deposit(&token_deposits[1], now, ALICE, true, 1),
+				BalanceProof::using_deposit(&asset_deposit, now, ALICE, true, 0),
+			];
+			let (balance_root, root_signature, inclusions) = generate_balance_batch(&proofs);
+
+			run_to_block(10);
+
+			assert_ok!(Wildcard::withdraw_batch(
+				RuntimeOrigin::signed(ALICE),
+				balance_root,
+				root_signature,
+				inclusions
+			));
+
+			for proof in proofs {
+				System::assert_has_event(mock::RuntimeEvent::Wildcard(
+					crate::Event::AssetWithdraw {
+						epoch: now,
+						withdrawer: ALICE,
+						asset_origin: proof.origin,
+						asset_type: AssetType::from(proof.asset_type),
+						primary_id: proof.primary_id,
+						secondary_id: proof.secondary_id,
+					},
+				));
+			}
+
+			let token =
+				AssetOf::<Test> { origin: CHAIN_ID, kind: AssetKind::Fungible(NATIVE_TOKEN_ID) };
+			let asset = AssetOf::<Test> { origin: CHAIN_ID, kind: AssetKind::Fungible(asset_id) };
+			assert_eq!(Withdrawals::<Test>::get(ALICE, token), Some((now, 1)));
+			assert_eq!(Withdrawals::<Test>::get(ALICE, asset), Some((now, 0)));
+
+			assert_eq!(Balances::free_balance(ALICE), initial_balance);
+			assert_eq!(Balances::free_balance(reserve), 0);
+			assert_eq!(Assets::balance(asset_id, ALICE), 300);
+			assert_eq!(Assets::balance(asset_id, reserve), 0);
+		});
+}
+
+#[test]
+fn withdraw_batch_rejects_empty_batch() {
+	ExtBuilder::default().build().execute_with(|| {
+		let balance_root = BalanceRoot { epoch: 0, origin: CHAIN_ID, root: WideId::default() };
+		let root_signature = generate_signature_for(&balance_root);
+
+		assert_noop!(
+			Wildcard::withdraw_batch(
+				RuntimeOrigin::signed(ALICE),
+				balance_root,
+				root_signature,
+				BoundedVec::default()
+			),
+			Error::<Test>::EmptyBatch
+		);
+	});
+}
+
+#[test]
+fn withdraw_batch_rejects_bad_root_signature() {
+	ExtBuilder::default().balances(&[(ALICE, 1_000_000)]).build().execute_with(|| {
+		let now = <Test as Config>::Time::now();
+		let token_deposits = deposit_tokens(&ALICE, &[1_000]);
+		let proofs = [BalanceProof::using_deposit(&token_deposits[0], now, ALICE, true, 0)];
+		let (balance_root, _, inclusions) = generate_balance_batch(&proofs);
+
+		run_to_block(10);
+
+		assert_noop!(
+			Wildcard::withdraw_batch(
+				RuntimeOrigin::signed(ALICE),
+				balance_root,
+				invalid_signature(),
+				inclusions
+			),
+			Error::<Test>::BadSignature
+		);
+	});
+}
+
+#[test]
+fn withdraw_batch_rejects_proofs_outside_root() {
+	ExtBuilder::default().balances(&[(ALICE, 1_000_000)]).build().execute_with(|| {
+		let now = <Test as Config>::Time::now();
+		let token_deposits = deposit_tokens(&ALICE, &[1_000, 500]);
+		let proofs = [
+			BalanceProof::using_deposit(&token_deposits[0], now, ALICE, true, 0),
+			BalanceProof::using_deposit(&token_deposits[1], now, ALICE, true, 1),
+		];
+		let (balance_root, root_signature, inclusions) = generate_balance_batch(&proofs);
+
+		run_to_block(10);
+
+		// A proof for a larger amount than the operator signed.
+		let mut tampered_amount = inclusions.clone();
+		tampered_amount[1].balance_proof.secondary_id = generate_wide_id_for_amount(1_500);
+		assert_noop!(
+			Wildcard::withdraw_batch(
+				RuntimeOrigin::signed(ALICE),
+				balance_root,
+				root_signature.clone(),
+				tampered_amount
+			),
+			Error::<Test>::InvalidMerkleProof
+		);
+
+		// A signed proof paired with the path of another one.
+		let mut tampered_path = inclusions.clone();
+		tampered_path[0].merkle_path = BoundedVec::default();
+		assert_noop!(
+			Wildcard::withdraw_batch(
+				RuntimeOrigin::signed(ALICE),
+				balance_root,
+				root_signature,
+				tampered_path
+			),
+			Error::<Test>::InvalidMerkleProof
+		);
+
+		// A root signed for another epoch doesn't cover the proofs.
+		let other_root = BalanceRoot { epoch: now + 1, ..balance_root };
+		assert_noop!(
+			Wildcard::withdraw_batch(
+				RuntimeOrigin::signed(ALICE),
+				other_root,
+				generate_signature_for(&other_root),
+				inclusions
+			),
+			Error::<Test>::InvalidMerkleProof
+		);
+	});
+}
+
+#[test]
+fn withdraw_batch_keeps_withdrawal_order() {
+	ExtBuilder::default().balances(&[(ALICE, 1_000_000)]).build().execute_with(|| {
+		let now = <Test as Config>::Time::now();
+		let token_deposits = deposit_tokens(&ALICE, &[1_000, 500]);
+		let proofs = [
+			BalanceProof::using_deposit(&token_deposits[0], now, ALICE, true, 0),
+			BalanceProof::using_deposit(&token_deposits[1], now, ALICE, true, 1),
+		];
+		let (balance_root, root_signature, inclusions) = generate_balance_batch(&proofs);
+
+		run_to_block(10);
+
+		let proof = proofs[0];
+		assert_ok!(Wildcard::withdraw(
+			RuntimeOrigin::signed(ALICE),
+			proof,
+			generate_signature_for(&proof)
+		));
+
+		// The first chunk has already been withdrawn on its own.
+		assert_noop!(
+			Wildcard::withdraw_batch(
+				RuntimeOrigin::signed(ALICE),
+				balance_root,
+				root_signature.clone(),
+				inclusions.clone()
+			),
+			Error::<Test>::WithdrawNotInline
+		);
+
+		// Withdrawing the remaining chunk only is still possible.
+		assert_ok!(Wildcard::withdraw_batch(
+			RuntimeOrigin::signed(ALICE),
+			balance_root,
+			root_signature,
+			inclusions[1..].to_vec().try_into().unwrap()
+		));
+
+		let token =
+			AssetOf::<Test> { origin: CHAIN_ID, kind: AssetKind::Fungible(NATIVE_TOKEN_ID) };
+		assert_eq!(Withdrawals::<Test>::get(ALICE, token), Some((now, 1)));
+	});
+}
@@ -244,6 +244,16 @@ parameter_types! {
 	pub const ChainId: u16 = CHAIN_ID;
 	pub const NativeAssetId: MockAssetId = NATIVE_TOKEN_ID;
 	pub const ChallengeBalance: MockBalance = 100;
+	pub const ChallengeBondAmount: MockBalance = 40;
+	pub const ChallengeRewardAmount: MockBalance = 60;
+	pub const ChallengeReviewPeriod: EpochNumber = 2;
+	pub const MinOperatorBond: MockBalance = 50;
+	pub const WithdrawalDelay: EpochNumber = 2;
+	pub const MaxRefundsPerCall: u32 = 16;
+	pub const DepositRetention: EpochNumber = 4;
+	pub const MaxMerkleDepth: u32 = 16;
+	pub const MaxBatchedProofs: u32 = 16;
+	pub const MaxSigners: u32 = 5;
 }
 
 pub type CollectionConfig =
@@ -282,6 +292,28 @@ impl pallet_wildcard::Config for Test {
 	type ChainId = ChainId;
 	type NativeTokenAssetId = NativeAssetId;
 	type ChallengeMinBalance = ChallengeBalance;
+	type ChallengeBond = ChallengeBondAmount;
+	type ChallengeReward = ChallengeRewardAmount;
+	type ChallengeReviewPeriod = ChallengeReviewPeriod;
+	type MinOperatorBond = MinOperatorBond;
+	type WithdrawalDelay = WithdrawalDelay;
+	type MaxRefundsPerCall = MaxRefundsPerCall;
+	type DepositRetention = DepositRetention;
+	type MaxMerkleDepth = MaxMerkleDepth;
+	type MaxBatchedProofs = MaxBatchedProofs;
+	type MaxSigners = MaxSigners;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = WildcardBenchmarkHelper;
+	type WeightInfo = ();
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct WildcardBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper<MockMomentResolution> for WildcardBenchmarkHelper {
+	fn set_time(now: MockMomentResolution) {
+		Timestamp::set_timestamp(now);
+	}
 }
 
 pub struct ExtBuilder {
@@ -307,11 +339,17 @@ impl ExtBuilder {
 			.unwrap();
 
 		let mut ext: sp_io::TestExternalities = sp_io::TestExternalities::new(t);
+		ext.register_extension(sp_keystore::KeystoreExt::new(
+			sp_keystore::testing::MemoryKeystore::new(),
+		));
 		ext.execute_with(|| System::set_block_number(1));
 		ext.execute_with(|| {
 			StartTime::<Test>::put(0);
 			EpochDuration::<Test>::put(SLOT_DURATION);
-			SignerKey::<Test>::put(MockKeyPair::get().public());
+			Signers::<Test>::put(SignerSet {
+				keys: BoundedVec::truncate_from(vec![MockKeyPair::get().public()]),
+				threshold: 1,
+			});
 		});
 		ext
 	}
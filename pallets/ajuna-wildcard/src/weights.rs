@@ -0,0 +1,732 @@
+//! Weights for pallet_ajuna_wildcard
+//!
+//! Not generated by the benchmark CLI: the benchmarks in `benchmarking.rs` have never been run,
+//! since this workspace doesn't build without the node. The execution times are estimates, the
+//! reads and writes are counted from the code, and the proof sizes assume 32-byte account ids,
+//! u128 balances, u32 ids and a `MaxSigners` of 5. Regenerate this file before using the pallet
+//! in a runtime.
+//!
+//! Command to regenerate it with:
+//!
+//! ./target/release/bajun-para
+//! benchmark
+//! pallet
+//! --chain=dev
+//! --steps=50
+//! --repeat=20
+//! --pallet=pallet-ajuna-wildcard
+//! --extrinsic=*
+//! --wasm-execution=compiled
+//! --heap-pages=4096
+//! --template=./.maintain/frame-weight-template.hbs
+//! --output=./pallets/ajuna-wildcard/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_core::sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_ajuna_wildcard.
+pub trait WeightInfo {
+	fn set_administrator() -> Weight;
+	fn set_parameters() -> Weight;
+	fn deposit() -> Weight;
+	fn withdraw() -> Weight;
+	fn withdraw_frozen() -> Weight;
+	fn refund_frozen(n: u32) -> Weight;
+	fn challenge() -> Weight;
+	fn respond_challenge() -> Weight;
+	fn freeze(s: u32) -> Weight;
+	fn propagate_freeze(s: u32) -> Weight;
+	fn respond_zero_challenge() -> Weight;
+	fn prune_deposits(n: u32) -> Weight;
+	fn withdraw_batch(n: u32) -> Weight;
+	fn respond_challenge_batch(n: u32) -> Weight;
+	fn rotate_signers() -> Weight;
+	fn claim_challenge_bond() -> Weight;
+	fn slash_challenge_bond() -> Weight;
+	fn register_operator() -> Weight;
+	fn bond_operator_extra() -> Weight;
+	fn unregister_operator() -> Weight;
+	fn set_asset_limits() -> Weight;
+	fn set_circuit_breaker() -> Weight;
+	fn release_withdrawal() -> Weight;
+	fn cancel_withdrawal() -> Weight;
+}
+
+/// Weights for pallet_ajuna_wildcard using the Substrate node and recommended hardware.
+pub struct AjunaWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
+	// Storage: Wildcard Administrator (r:0 w:1)
+	fn set_administrator() -> Weight {
+		Weight::from_parts(130_625_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: Wildcard Administrator (r:1 w:0)
+	// Storage: Wildcard StartTime (r:1 w:1)
+	// Storage: Wildcard EpochDuration (r:1 w:1)
+	fn set_parameters() -> Weight {
+		Weight::from_parts(130_934_000, 2523)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: Wildcard Frozen (r:1 w:0)
+	// Storage: Wildcard AssetIdMapping (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Wildcard StartTime (r:1 w:0)
+	// Storage: Wildcard EpochDuration (r:1 w:0)
+	// Storage: Wildcard Deposits (r:1 w:1)
+	// Storage: Wildcard DepositEpochs (r:0 w:1)
+	// Storage: Wildcard BridgeHalted (r:1 w:0)
+	// Storage: Wildcard AssetFlowLimits (r:1 w:0)
+	// Storage: Wildcard EpochFlows (r:1 w:1)
+	// Storage: Wildcard AccountFlows (r:1 w:1)
+	fn deposit() -> Weight {
+		Weight::from_parts(735_812_000, 21402)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	// Storage: Wildcard AssetIdMapping (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Wildcard StartTime (r:1 w:0)
+	// Storage: Wildcard EpochDuration (r:1 w:0)
+	// Storage: Wildcard Withdrawals (r:1 w:1)
+	// Storage: Wildcard Signers (r:1 w:0)
+	// Storage: Wildcard RetiringSigners (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Wildcard ShortfallCover (r:1 w:1)
+	// Storage: Wildcard BridgeHalted (r:1 w:0)
+	// Storage: Wildcard AssetFlowLimits (r:1 w:0)
+	// Storage: Wildcard EpochFlows (r:1 w:1)
+	// Storage: Wildcard AccountFlows (r:1 w:1)
+	// Storage: Wildcard NextDelayedWithdrawalId (r:1 w:1)
+	// Storage: Wildcard DelayedWithdrawals (r:0 w:1)
+	fn withdraw() -> Weight {
+		Weight::from_parts(2_211_077_000, 23215)
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	// Storage: Wildcard Frozen (r:1 w:0)
+	// Storage: Wildcard AssetIdMapping (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Wildcard StartTime (r:1 w:0)
+	// Storage: Wildcard EpochDuration (r:1 w:0)
+	// Storage: Wildcard Withdrawals (r:1 w:1)
+	// Storage: Wildcard Signers (r:1 w:0)
+	// Storage: Wildcard RetiringSigners (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Wildcard ShortfallCover (r:1 w:1)
+	// Storage: Wildcard BridgeHalted (r:1 w:0)
+	// Storage: Wildcard AssetFlowLimits (r:1 w:0)
+	// Storage: Wildcard EpochFlows (r:1 w:1)
+	// Storage: Wildcard AccountFlows (r:1 w:1)
+	// Storage: Wildcard NextDelayedWithdrawalId (r:1 w:1)
+	// Storage: Wildcard DelayedWithdrawals (r:0 w:1)
+	fn withdraw_frozen() -> Weight {
+		Weight::from_parts(2_354_758_000, 23718)
+			.saturating_add(T::DbWeight::get().reads(16_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	// Storage: Wildcard Frozen (r:1 w:0)
+	// Storage: Wildcard DepositEpochs (r:n w:n)
+	// Storage: Wildcard Deposits (r:n w:n)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Wildcard ShortfallCover (r:n w:n)
+	/// The range of component `n` is `[1, 16]`.
+	fn refund_frozen(n: u32) -> Weight {
+		Weight::from_parts(178_829_000, 6699)
+			.saturating_add(Weight::from_parts(650_976_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5618).saturating_mul(n.into()))
+	}
+	// Storage: System Account (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Wildcard StartTime (r:1 w:0)
+	// Storage: Wildcard EpochDuration (r:1 w:0)
+	// Storage: Wildcard Challenges (r:1 w:1)
+	// Storage: Wildcard ChallengeBonds (r:1 w:1)
+	// Storage: Wildcard OpenChallenges (r:1 w:1)
+	fn challenge() -> Weight {
+		Weight::from_parts(391_190_000, 12664)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	// Storage: Wildcard Challenges (r:1 w:1)
+	// Storage: Wildcard Signers (r:1 w:0)
+	// Storage: Wildcard RetiringSigners (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Wildcard StartTime (r:1 w:0)
+	// Storage: Wildcard EpochDuration (r:1 w:0)
+	// Storage: Wildcard ChallengeBonds (r:1 w:1)
+	// Storage: Wildcard OpenChallenges (r:1 w:1)
+	fn respond_challenge() -> Weight {
+		Weight::from_parts(2_052_011_000, 11397)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: Wildcard Frozen (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Wildcard StartTime (r:1 w:0)
+	// Storage: Wildcard EpochDuration (r:1 w:0)
+	// Storage: Wildcard OpenChallenges (r:1 w:0)
+	// Storage: Wildcard Signers (r:1 w:0)
+	// Storage: Wildcard SignersSince (r:1 w:0)
+	// Storage: Wildcard RetiringSigners (r:1 w:0)
+	// Storage: Wildcard OperatorSigners (r:s w:0)
+	// Storage: Wildcard Operators (r:s w:s)
+	// Storage: System Account (r:2s w:2s)
+	// Storage: Wildcard ShortfallCover (r:s w:s)
+	/// The range of component `s` is `[0, 10]`.
+	fn freeze(s: u32) -> Weight {
+		Weight::from_parts(220_175_000, 7344)
+			.saturating_add(Weight::from_parts(398_200_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 10829).saturating_mul(s.into()))
+	}
+	// Storage: Wildcard Signers (r:1 w:0)
+	// Storage: Wildcard RetiringSigners (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Wildcard StartTime (r:1 w:0)
+	// Storage: Wildcard EpochDuration (r:1 w:0)
+	// Storage: Wildcard Frozen (r:0 w:1)
+	// Storage: Wildcard SignersSince (r:1 w:0)
+	// Storage: Wildcard OperatorSigners (r:s w:0)
+	// Storage: Wildcard Operators (r:s w:s)
+	// Storage: System Account (r:2s w:2s)
+	// Storage: Wildcard ShortfallCover (r:s w:s)
+	/// The range of component `s` is `[0, 10]`.
+	fn propagate_freeze(s: u32) -> Weight {
+		Weight::from_parts(2_193_817_000, 4338)
+			.saturating_add(Weight::from_parts(398_200_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 10829).saturating_mul(s.into()))
+	}
+	// Storage: Wildcard Challenges (r:1 w:1)
+	// Storage: Wildcard Signers (r:1 w:0)
+	// Storage: Wildcard RetiringSigners (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Wildcard StartTime (r:1 w:0)
+	// Storage: Wildcard EpochDuration (r:1 w:0)
+	// Storage: Wildcard ChallengeBonds (r:1 w:1)
+	// Storage: Wildcard OpenChallenges (r:1 w:1)
+	fn respond_zero_challenge() -> Weight {
+		Weight::from_parts(807_508_000, 11397)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: Wildcard Frozen (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Wildcard StartTime (r:1 w:0)
+	// Storage: Wildcard EpochDuration (r:1 w:0)
+	// Storage: Wildcard DepositPruneCursor (r:1 w:1)
+	// Storage: Wildcard Deposits (r:n w:n)
+	// Storage: Wildcard DepositEpochs (r:0 w:n)
+	/// The range of component `n` is `[0, 1000]`.
+	fn prune_deposits(n: u32) -> Weight {
+		Weight::from_parts(77_923_000, 3505)
+			.saturating_add(Weight::from_parts(80_635_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2576).saturating_mul(n.into()))
+	}
+	// Storage: Wildcard Signers (r:1 w:0)
+	// Storage: Wildcard RetiringSigners (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Wildcard StartTime (r:1 w:0)
+	// Storage: Wildcard EpochDuration (r:1 w:0)
+	// Storage: Wildcard AssetIdMapping (r:n w:0)
+	// Storage: Wildcard Withdrawals (r:n w:n)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Wildcard ShortfallCover (r:n w:n)
+	// Storage: Wildcard BridgeHalted (r:n w:0)
+	// Storage: Wildcard AssetFlowLimits (r:n w:0)
+	// Storage: Wildcard EpochFlows (r:n w:n)
+	// Storage: Wildcard AccountFlows (r:n w:n)
+	// Storage: Wildcard NextDelayedWithdrawalId (r:n w:n)
+	// Storage: Wildcard DelayedWithdrawals (r:0 w:n)
+	/// The range of component `n` is `[1, 16]`.
+	fn withdraw_batch(n: u32) -> Weight {
+		Weight::from_parts(1_717_290_000, 9041)
+			.saturating_add(Weight::from_parts(726_151_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((8_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 14174).saturating_mul(n.into()))
+	}
+	// Storage: Wildcard Signers (r:1 w:0)
+	// Storage: Wildcard RetiringSigners (r:1 w:0)
+	// Storage: Wildcard Challenges (r:n w:n)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Wildcard StartTime (r:1 w:0)
+	// Storage: Wildcard EpochDuration (r:1 w:0)
+	// Storage: Wildcard ChallengeBonds (r:n w:n)
+	// Storage: Wildcard OpenChallenges (r:n w:n)
+	/// The range of component `n` is `[1, 16]`.
+	fn respond_challenge_batch(n: u32) -> Weight {
+		Weight::from_parts(807_508_000, 3835)
+			.saturating_add(Weight::from_parts(119_379_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 7562).saturating_mul(n.into()))
+	}
+	// Storage: Wildcard Administrator (r:1 w:0)
+	// Storage: Wildcard Signers (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Wildcard StartTime (r:1 w:0)
+	// Storage: Wildcard EpochDuration (r:1 w:0)
+	// Storage: Wildcard RetiringSigners (r:1 w:1)
+	// Storage: Wildcard SignersSince (r:1 w:1)
+	// Storage: Wildcard OperatorSigners (r:5 w:0)
+	// Storage: Wildcard Operators (r:5 w:0)
+	fn rotate_signers() -> Weight {
+		Weight::from_parts(340_064_000, 30425)
+			.saturating_add(T::DbWeight::get().reads(17_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: Wildcard ChallengeBonds (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Wildcard StartTime (r:1 w:0)
+	// Storage: Wildcard EpochDuration (r:1 w:0)
+	// Storage: Wildcard Frozen (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Wildcard ShortfallCover (r:1 w:1)
+	fn claim_challenge_bond() -> Weight {
+		Weight::from_parts(649_699_000, 11259)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	// Storage: Wildcard Administrator (r:1 w:0)
+	// Storage: Wildcard ChallengeBonds (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Wildcard StartTime (r:1 w:0)
+	// Storage: Wildcard EpochDuration (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Wildcard ShortfallCover (r:1 w:1)
+	fn slash_challenge_bond() -> Weight {
+		Weight::from_parts(304_564_000, 11283)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	// Storage: Wildcard Operators (r:1 w:1)
+	// Storage: Wildcard OperatorSigners (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn register_operator() -> Weight {
+		Weight::from_parts(340_475_000, 8705)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: Wildcard Operators (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn bond_operator_extra() -> Weight {
+		Weight::from_parts(259_537_000, 6150)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: Wildcard Operators (r:1 w:1)
+	// Storage: Wildcard Signers (r:1 w:0)
+	// Storage: Wildcard RetiringSigners (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Wildcard StartTime (r:1 w:0)
+	// Storage: Wildcard EpochDuration (r:1 w:0)
+	// Storage: Wildcard OperatorSigners (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn unregister_operator() -> Weight {
+		Weight::from_parts(470_792_000, 8995)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: Wildcard Administrator (r:1 w:0)
+	// Storage: Wildcard AssetFlowLimits (r:0 w:1)
+	fn set_asset_limits() -> Weight {
+		Weight::from_parts(119_283_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: Wildcard Administrator (r:1 w:0)
+	// Storage: Wildcard BridgeHalted (r:0 w:1)
+	fn set_circuit_breaker() -> Weight {
+		Weight::from_parts(99_545_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: Wildcard BridgeHalted (r:1 w:0)
+	// Storage: Wildcard DelayedWithdrawals (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Wildcard StartTime (r:1 w:0)
+	// Storage: Wildcard EpochDuration (r:1 w:0)
+	// Storage: Wildcard Frozen (r:1 w:0)
+	// Storage: Wildcard ShortfallCover (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn release_withdrawal() -> Weight {
+		Weight::from_parts(580_035_000, 11787)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	// Storage: Wildcard Administrator (r:1 w:0)
+	// Storage: Wildcard DelayedWithdrawals (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Wildcard StartTime (r:1 w:0)
+	// Storage: Wildcard EpochDuration (r:1 w:0)
+	// Storage: Wildcard EpochFlows (r:1 w:1)
+	// Storage: Wildcard AccountFlows (r:1 w:1)
+	// Storage: Wildcard Deposits (r:1 w:1)
+	// Storage: Wildcard DepositEpochs (r:0 w:1)
+	fn cancel_withdrawal() -> Weight {
+		Weight::from_parts(251_462_000, 13252)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: Wildcard Administrator (r:0 w:1)
+	fn set_administrator() -> Weight {
+		Weight::from_parts(130_625_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: Wildcard Administrator (r:1 w:0)
+	// Storage: Wildcard StartTime (r:1 w:1)
+	// Storage: Wildcard EpochDuration (r:1 w:1)
+	fn set_parameters() -> Weight {
+		Weight::from_parts(130_934_000, 2523)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: Wildcard Frozen (r:1 w:0)
+	// Storage: Wildcard AssetIdMapping (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Wildcard StartTime (r:1 w:0)
+	// Storage: Wildcard EpochDuration (r:1 w:0)
+	// Storage: Wildcard Deposits (r:1 w:1)
+	// Storage: Wildcard DepositEpochs (r:0 w:1)
+	// Storage: Wildcard BridgeHalted (r:1 w:0)
+	// Storage: Wildcard AssetFlowLimits (r:1 w:0)
+	// Storage: Wildcard EpochFlows (r:1 w:1)
+	// Storage: Wildcard AccountFlows (r:1 w:1)
+	fn deposit() -> Weight {
+		Weight::from_parts(735_812_000, 21402)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	// Storage: Wildcard AssetIdMapping (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Wildcard StartTime (r:1 w:0)
+	// Storage: Wildcard EpochDuration (r:1 w:0)
+	// Storage: Wildcard Withdrawals (r:1 w:1)
+	// Storage: Wildcard Signers (r:1 w:0)
+	// Storage: Wildcard RetiringSigners (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Wildcard ShortfallCover (r:1 w:1)
+	// Storage: Wildcard BridgeHalted (r:1 w:0)
+	// Storage: Wildcard AssetFlowLimits (r:1 w:0)
+	// Storage: Wildcard EpochFlows (r:1 w:1)
+	// Storage: Wildcard AccountFlows (r:1 w:1)
+	// Storage: Wildcard NextDelayedWithdrawalId (r:1 w:1)
+	// Storage: Wildcard DelayedWithdrawals (r:0 w:1)
+	fn withdraw() -> Weight {
+		Weight::from_parts(2_211_077_000, 23215)
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	// Storage: Wildcard Frozen (r:1 w:0)
+	// Storage: Wildcard AssetIdMapping (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Wildcard StartTime (r:1 w:0)
+	// Storage: Wildcard EpochDuration (r:1 w:0)
+	// Storage: Wildcard Withdrawals (r:1 w:1)
+	// Storage: Wildcard Signers (r:1 w:0)
+	// Storage: Wildcard RetiringSigners (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Wildcard ShortfallCover (r:1 w:1)
+	// Storage: Wildcard BridgeHalted (r:1 w:0)
+	// Storage: Wildcard AssetFlowLimits (r:1 w:0)
+	// Storage: Wildcard EpochFlows (r:1 w:1)
+	// Storage: Wildcard AccountFlows (r:1 w:1)
+	// Storage: Wildcard NextDelayedWithdrawalId (r:1 w:1)
+	// Storage: Wildcard DelayedWithdrawals (r:0 w:1)
+	fn withdraw_frozen() -> Weight {
+		Weight::from_parts(2_354_758_000, 23718)
+			.saturating_add(RocksDbWeight::get().reads(16_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	// Storage: Wildcard Frozen (r:1 w:0)
+	// Storage: Wildcard DepositEpochs (r:n w:n)
+	// Storage: Wildcard Deposits (r:n w:n)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Wildcard ShortfallCover (r:n w:n)
+	/// The range of component `n` is `[1, 16]`.
+	fn refund_frozen(n: u32) -> Weight {
+		Weight::from_parts(178_829_000, 6699)
+			.saturating_add(Weight::from_parts(650_976_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5618).saturating_mul(n.into()))
+	}
+	// Storage: System Account (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Wildcard StartTime (r:1 w:0)
+	// Storage: Wildcard EpochDuration (r:1 w:0)
+	// Storage: Wildcard Challenges (r:1 w:1)
+	// Storage: Wildcard ChallengeBonds (r:1 w:1)
+	// Storage: Wildcard OpenChallenges (r:1 w:1)
+	fn challenge() -> Weight {
+		Weight::from_parts(391_190_000, 12664)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	// Storage: Wildcard Challenges (r:1 w:1)
+	// Storage: Wildcard Signers (r:1 w:0)
+	// Storage: Wildcard RetiringSigners (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Wildcard StartTime (r:1 w:0)
+	// Storage: Wildcard EpochDuration (r:1 w:0)
+	// Storage: Wildcard ChallengeBonds (r:1 w:1)
+	// Storage: Wildcard OpenChallenges (r:1 w:1)
+	fn respond_challenge() -> Weight {
+		Weight::from_parts(2_052_011_000, 11397)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	// Storage: Wildcard Frozen (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Wildcard StartTime (r:1 w:0)
+	// Storage: Wildcard EpochDuration (r:1 w:0)
+	// Storage: Wildcard OpenChallenges (r:1 w:0)
+	// Storage: Wildcard Signers (r:1 w:0)
+	// Storage: Wildcard SignersSince (r:1 w:0)
+	// Storage: Wildcard RetiringSigners (r:1 w:0)
+	// Storage: Wildcard OperatorSigners (r:s w:0)
+	// Storage: Wildcard Operators (r:s w:s)
+	// Storage: System Account (r:2s w:2s)
+	// Storage: Wildcard ShortfallCover (r:s w:s)
+	/// The range of component `s` is `[0, 10]`.
+	fn freeze(s: u32) -> Weight {
+		Weight::from_parts(220_175_000, 7344)
+			.saturating_add(Weight::from_parts(398_200_000, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 10829).saturating_mul(s.into()))
+	}
+	// Storage: Wildcard Signers (r:1 w:0)
+	// Storage: Wildcard RetiringSigners (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Wildcard StartTime (r:1 w:0)
+	// Storage: Wildcard EpochDuration (r:1 w:0)
+	// Storage: Wildcard Frozen (r:0 w:1)
+	// Storage: Wildcard SignersSince (r:1 w:0)
+	// Storage: Wildcard OperatorSigners (r:s w:0)
+	// Storage: Wildcard Operators (r:s w:s)
+	// Storage: System Account (r:2s w:2s)
+	// Storage: Wildcard ShortfallCover (r:s w:s)
+	/// The range of component `s` is `[0, 10]`.
+	fn propagate_freeze(s: u32) -> Weight {
+		Weight::from_parts(2_193_817_000, 4338)
+			.saturating_add(Weight::from_parts(398_200_000, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 10829).saturating_mul(s.into()))
+	}
+	// Storage: Wildcard Challenges (r:1 w:1)
+	// Storage: Wildcard Signers (r:1 w:0)
+	// Storage: Wildcard RetiringSigners (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Wildcard StartTime (r:1 w:0)
+	// Storage: Wildcard EpochDuration (r:1 w:0)
+	// Storage: Wildcard ChallengeBonds (r:1 w:1)
+	// Storage: Wildcard OpenChallenges (r:1 w:1)
+	fn respond_zero_challenge() -> Weight {
+		Weight::from_parts(807_508_000, 11397)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	// Storage: Wildcard Frozen (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Wildcard StartTime (r:1 w:0)
+	// Storage: Wildcard EpochDuration (r:1 w:0)
+	// Storage: Wildcard DepositPruneCursor (r:1 w:1)
+	// Storage: Wildcard Deposits (r:n w:n)
+	// Storage: Wildcard DepositEpochs (r:0 w:n)
+	/// The range of component `n` is `[0, 1000]`.
+	fn prune_deposits(n: u32) -> Weight {
+		Weight::from_parts(77_923_000, 3505)
+			.saturating_add(Weight::from_parts(80_635_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2576).saturating_mul(n.into()))
+	}
+	// Storage: Wildcard Signers (r:1 w:0)
+	// Storage: Wildcard RetiringSigners (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Wildcard StartTime (r:1 w:0)
+	// Storage: Wildcard EpochDuration (r:1 w:0)
+	// Storage: Wildcard AssetIdMapping (r:n w:0)
+	// Storage: Wildcard Withdrawals (r:n w:n)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Wildcard ShortfallCover (r:n w:n)
+	// Storage: Wildcard BridgeHalted (r:n w:0)
+	// Storage: Wildcard AssetFlowLimits (r:n w:0)
+	// Storage: Wildcard EpochFlows (r:n w:n)
+	// Storage: Wildcard AccountFlows (r:n w:n)
+	// Storage: Wildcard NextDelayedWithdrawalId (r:n w:n)
+	// Storage: Wildcard DelayedWithdrawals (r:0 w:n)
+	/// The range of component `n` is `[1, 16]`.
+	fn withdraw_batch(n: u32) -> Weight {
+		Weight::from_parts(1_717_290_000, 9041)
+			.saturating_add(Weight::from_parts(726_151_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((8_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 14174).saturating_mul(n.into()))
+	}
+	// Storage: Wildcard Signers (r:1 w:0)
+	// Storage: Wildcard RetiringSigners (r:1 w:0)
+	// Storage: Wildcard Challenges (r:n w:n)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Wildcard StartTime (r:1 w:0)
+	// Storage: Wildcard EpochDuration (r:1 w:0)
+	// Storage: Wildcard ChallengeBonds (r:n w:n)
+	// Storage: Wildcard OpenChallenges (r:n w:n)
+	/// The range of component `n` is `[1, 16]`.
+	fn respond_challenge_batch(n: u32) -> Weight {
+		Weight::from_parts(807_508_000, 3835)
+			.saturating_add(Weight::from_parts(119_379_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 7562).saturating_mul(n.into()))
+	}
+	// Storage: Wildcard Administrator (r:1 w:0)
+	// Storage: Wildcard Signers (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Wildcard StartTime (r:1 w:0)
+	// Storage: Wildcard EpochDuration (r:1 w:0)
+	// Storage: Wildcard RetiringSigners (r:1 w:1)
+	// Storage: Wildcard SignersSince (r:1 w:1)
+	// Storage: Wildcard OperatorSigners (r:5 w:0)
+	// Storage: Wildcard Operators (r:5 w:0)
+	fn rotate_signers() -> Weight {
+		Weight::from_parts(340_064_000, 30425)
+			.saturating_add(RocksDbWeight::get().reads(17_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	// Storage: Wildcard ChallengeBonds (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Wildcard StartTime (r:1 w:0)
+	// Storage: Wildcard EpochDuration (r:1 w:0)
+	// Storage: Wildcard Frozen (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Wildcard ShortfallCover (r:1 w:1)
+	fn claim_challenge_bond() -> Weight {
+		Weight::from_parts(649_699_000, 11259)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	// Storage: Wildcard Administrator (r:1 w:0)
+	// Storage: Wildcard ChallengeBonds (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Wildcard StartTime (r:1 w:0)
+	// Storage: Wildcard EpochDuration (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Wildcard ShortfallCover (r:1 w:1)
+	fn slash_challenge_bond() -> Weight {
+		Weight::from_parts(304_564_000, 11283)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	// Storage: Wildcard Operators (r:1 w:1)
+	// Storage: Wildcard OperatorSigners (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn register_operator() -> Weight {
+		Weight::from_parts(340_475_000, 8705)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	// Storage: Wildcard Operators (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn bond_operator_extra() -> Weight {
+		Weight::from_parts(259_537_000, 6150)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: Wildcard Operators (r:1 w:1)
+	// Storage: Wildcard Signers (r:1 w:0)
+	// Storage: Wildcard RetiringSigners (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Wildcard StartTime (r:1 w:0)
+	// Storage: Wildcard EpochDuration (r:1 w:0)
+	// Storage: Wildcard OperatorSigners (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn unregister_operator() -> Weight {
+		Weight::from_parts(470_792_000, 8995)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	// Storage: Wildcard Administrator (r:1 w:0)
+	// Storage: Wildcard AssetFlowLimits (r:0 w:1)
+	fn set_asset_limits() -> Weight {
+		Weight::from_parts(119_283_000, 1517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: Wildcard Administrator (r:1 w:0)
+	// Storage: Wildcard BridgeHalted (r:0 w:1)
+	fn set_circuit_breaker() -> Weight {
+		Weight::from_parts(99_545_000, 1517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: Wildcard BridgeHalted (r:1 w:0)
+	// Storage: Wildcard DelayedWithdrawals (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Wildcard StartTime (r:1 w:0)
+	// Storage: Wildcard EpochDuration (r:1 w:0)
+	// Storage: Wildcard Frozen (r:1 w:0)
+	// Storage: Wildcard ShortfallCover (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn release_withdrawal() -> Weight {
+		Weight::from_parts(580_035_000, 11787)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	// Storage: Wildcard Administrator (r:1 w:0)
+	// Storage: Wildcard DelayedWithdrawals (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Wildcard StartTime (r:1 w:0)
+	// Storage: Wildcard EpochDuration (r:1 w:0)
+	// Storage: Wildcard EpochFlows (r:1 w:1)
+	// Storage: Wildcard AccountFlows (r:1 w:1)
+	// Storage: Wildcard Deposits (r:1 w:1)
+	// Storage: Wildcard DepositEpochs (r:0 w:1)
+	fn cancel_withdrawal() -> Weight {
+		Weight::from_parts(251_462_000, 13252)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+}
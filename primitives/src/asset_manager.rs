@@ -14,6 +14,7 @@
 // You should have received a copy of the GNU Affero General Public License
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
+use crate::fee_router::FeeReceipt;
 use frame_support::pallet_prelude::{DispatchError, Member};
 use parity_scale_codec::{Codec, Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
@@ -50,6 +51,8 @@ pub trait AssetManager {
 
 	type Asset: Member + Codec;
 
+	type Balance: Member + Codec + MaxEncodedLen + TypeInfo;
+
 	fn ensure_ownership(
 		owner: &Self::AccountId,
 		asset_id: &Self::AssetId,
@@ -72,12 +75,23 @@ pub trait AssetManager {
 	/// This should probably be moved from the global config into the nft-transfer-pallet?
 	fn nft_transfer_open() -> bool;
 
-	/// This should als be extracted to a separate fee handler component.
+	/// Charges the fee for preparing `asset` to `from`, routing it to `fees_recipient`, and
+	/// returns where each part of the fee ended up.
 	fn handle_asset_prepare_fee(
 		asset: &Self::Asset,
 		from: &Self::AccountId,
 		fees_recipient: &Self::AccountId,
-	) -> Result<(), DispatchError>;
+	) -> Result<FeeReceipt<Self::AccountId, Self::Balance>, DispatchError>;
+
+	/// Refunds the part of a prepare fee deposited into `fees_recipient` back to `to`, as described
+	/// by the `receipt` returned when it was charged, and returns the amount refunded. Nothing is
+	/// refunded if `fees_recipient` can't afford it anymore, while the parts transferred to other
+	/// accounts or burned are never refunded.
+	fn refund_asset_prepare_fee(
+		receipt: &FeeReceipt<Self::AccountId, Self::Balance>,
+		to: &Self::AccountId,
+		fees_recipient: &Self::AccountId,
+	) -> Self::Balance;
 
 	#[cfg(feature = "runtime-benchmarks")]
 	fn create_assets(owner: Self::AccountId, count: u32) -> Vec<Self::AssetId>;
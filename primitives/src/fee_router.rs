@@ -0,0 +1,427 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use frame_support::{
+	pallet_prelude::DispatchError,
+	traits::{Currency, ExistenceRequirement::AllowDeath, WithdrawReasons},
+};
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{AtLeast32BitUnsigned, Saturating, Zero},
+	Percent,
+};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+/// A percentage of a fee, expected to be a value between 0 and 100.
+pub type FeePercentage = u8;
+
+/// A single step applied by a [`FeeRouter`] to the part of a fee that has not been routed yet.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub enum FeeCut<AccountId> {
+	/// Transfers a percentage of the remaining fee to an account, like a tournament treasury.
+	Percentage { account: AccountId, percentage: FeePercentage },
+	/// Transfers to each account of an affiliate chain its percentage of the remaining fee.
+	Chain(Vec<(AccountId, FeePercentage)>),
+	/// Burns a percentage of the remaining fee.
+	Burn(FeePercentage),
+	/// Transfers all of the remaining fee to a treasury account.
+	Treasury(AccountId),
+}
+
+/// Describes where each part of a routed fee ended up.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct FeeReceipt<AccountId, Balance> {
+	/// The fee before any cut was applied.
+	pub base_fee: Balance,
+	/// The amounts transferred to other accounts, in the order they were applied.
+	pub transfers: Vec<(AccountId, Balance)>,
+	/// The amount that has been burned.
+	pub burned: Balance,
+	/// The amount that has been deposited into a treasury.
+	pub deposited: Balance,
+}
+
+/// The fee router trait that can be passed around to pallets that need to split a fee between
+/// several parties.
+pub trait FeeRouter<AccountId> {
+	type Balance;
+
+	/// Charges `base_fee` to `payer` by applying `cuts` in order, each one of them computed on
+	/// the part of the fee left by the previous ones. A cut never charges more than what is left
+	/// of the fee, so percentages summing to more than 100 are capped. Whatever is left after the
+	/// last cut is not charged at all.
+	fn route_fee(
+		payer: &AccountId,
+		base_fee: Self::Balance,
+		cuts: Vec<FeeCut<AccountId>>,
+	) -> Result<FeeReceipt<AccountId, Self::Balance>, DispatchError>;
+}
+
+/// A [`FeeRouter`] moving funds through a [`Currency`] implementation.
+pub struct CurrencyFeeRouter<C>(PhantomData<C>);
+
+impl<AccountId, C> FeeRouter<AccountId> for CurrencyFeeRouter<C>
+where
+	C: Currency<AccountId>,
+{
+	type Balance = C::Balance;
+
+	fn route_fee(
+		payer: &AccountId,
+		base_fee: Self::Balance,
+		cuts: Vec<FeeCut<AccountId>>,
+	) -> Result<FeeReceipt<AccountId, Self::Balance>, DispatchError> {
+		let mut receipt = FeeReceipt {
+			base_fee,
+			transfers: Vec::new(),
+			burned: Zero::zero(),
+			deposited: Zero::zero(),
+		};
+		let mut remaining_fee = base_fee;
+
+		for cut in cuts {
+			let stage_fee = remaining_fee;
+			match cut {
+				FeeCut::Percentage { account, percentage } => {
+					let amount = percentage_of(stage_fee, percentage).min(remaining_fee);
+					if !amount.is_zero() {
+						C::transfer(payer, &account, amount, AllowDeath)?;
+						remaining_fee.saturating_reduce(amount);
+						receipt.transfers.push((account, amount));
+					}
+				},
+				FeeCut::Chain(chain) =>
+					for (account, percentage) in chain {
+						let amount = percentage_of(stage_fee, percentage).min(remaining_fee);
+						if !amount.is_zero() {
+							C::transfer(payer, &account, amount, AllowDeath)?;
+							remaining_fee.saturating_reduce(amount);
+							receipt.transfers.push((account, amount));
+						}
+					},
+				FeeCut::Burn(percentage) => {
+					let amount = percentage_of(stage_fee, percentage).min(remaining_fee);
+					if !amount.is_zero() {
+						let _ = C::withdraw(payer, amount, WithdrawReasons::FEE, AllowDeath)?;
+						remaining_fee.saturating_reduce(amount);
+						receipt.burned.saturating_accrue(amount);
+					}
+				},
+				FeeCut::Treasury(account) =>
+					if !stage_fee.is_zero() {
+						C::transfer(payer, &account, stage_fee, AllowDeath)?;
+						remaining_fee = Zero::zero();
+						receipt.deposited.saturating_accrue(stage_fee);
+					},
+			}
+		}
+
+		Ok(receipt)
+	}
+}
+
+fn percentage_of<Balance: AtLeast32BitUnsigned>(
+	fee: Balance,
+	percentage: FeePercentage,
+) -> Balance {
+	Percent::from_percent(percentage).mul_floor(fee)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use frame_support::{
+		assert_noop, parameter_types,
+		traits::{ConstU16, ConstU32, ConstU64},
+	};
+	use sp_runtime::{
+		testing::H256,
+		traits::{BlakeTwo256, IdentityLookup},
+		ArithmeticError, BuildStorage, TokenError,
+	};
+
+	type MockAccountId = u64;
+	type MockBalance = u64;
+	type Router = CurrencyFeeRouter<Balances>;
+
+	const PAYER: MockAccountId = 1;
+	const TREASURY: MockAccountId = 2;
+	const CREATOR: MockAccountId = 3;
+	const AFFILIATOR: MockAccountId = 4;
+	const SUB_AFFILIATOR: MockAccountId = 5;
+
+	frame_support::construct_runtime!(
+		pub struct Test {
+			System: frame_system = 0,
+			Balances: pallet_balances = 1,
+		}
+	);
+
+	impl frame_system::Config for Test {
+		type BaseCallFilter = frame_support::traits::Everything;
+		type BlockWeights = ();
+		type BlockLength = ();
+		type DbWeight = ();
+		type RuntimeOrigin = RuntimeOrigin;
+		type RuntimeCall = RuntimeCall;
+		type Hash = H256;
+		type Hashing = BlakeTwo256;
+		type AccountId = MockAccountId;
+		type Lookup = IdentityLookup<Self::AccountId>;
+		type RuntimeEvent = RuntimeEvent;
+		type BlockHashCount = ConstU64<250>;
+		type Version = ();
+		type PalletInfo = PalletInfo;
+		type AccountData = pallet_balances::AccountData<MockBalance>;
+		type OnNewAccount = ();
+		type OnKilledAccount = ();
+		type SystemWeightInfo = ();
+		type SS58Prefix = ConstU16<42>;
+		type OnSetCode = ();
+		type MaxConsumers = ConstU32<16>;
+		type Nonce = u32;
+		type Block = frame_system::mocking::MockBlock<Test>;
+		type RuntimeTask = RuntimeTask;
+		type SingleBlockMigrations = ();
+		type MultiBlockMigrator = ();
+		type PreInherents = ();
+		type PostInherents = ();
+		type PostTransactions = ();
+	}
+
+	parameter_types! {
+		pub static MockExistentialDeposit: MockBalance = 1;
+	}
+
+	impl pallet_balances::Config for Test {
+		type Balance = MockBalance;
+		type DustRemoval = ();
+		type RuntimeEvent = RuntimeEvent;
+		type ExistentialDeposit = MockExistentialDeposit;
+		type AccountStore = System;
+		type WeightInfo = ();
+		type MaxLocks = ();
+		type MaxReserves = ();
+		type ReserveIdentifier = [u8; 8];
+		type FreezeIdentifier = ();
+		type MaxFreezes = ();
+		type RuntimeHoldReason = ();
+		type RuntimeFreezeReason = ();
+	}
+
+	fn new_test_ext(payer_balance: MockBalance) -> sp_io::TestExternalities {
+		let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+		pallet_balances::GenesisConfig::<Test> { balances: vec![(PAYER, payer_balance)] }
+			.assimilate_storage(&mut storage)
+			.unwrap();
+		let mut ext = sp_io::TestExternalities::new(storage);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+
+	fn free_balance(account: MockAccountId) -> MockBalance {
+		Balances::free_balance(account)
+	}
+
+	#[test]
+	fn percentages_round_down() {
+		assert_eq!(percentage_of(1_000_u64, 15), 150);
+		assert_eq!(percentage_of(999_u64, 15), 149);
+		assert_eq!(percentage_of(6_u64, 33), 1);
+		assert_eq!(percentage_of(2_u64, 33), 0);
+		assert_eq!(percentage_of(1_000_u64, 0), 0);
+		assert_eq!(percentage_of(1_000_u64, 100), 1_000);
+		assert_eq!(percentage_of(u64::MAX, 100), u64::MAX);
+		assert_eq!(percentage_of(u64::MAX, 50), u64::MAX / 2);
+	}
+
+	#[test]
+	fn percentage_cut_transfers_to_account() {
+		new_test_ext(1_000).execute_with(|| {
+			let cuts = vec![FeeCut::Percentage { account: CREATOR, percentage: 25 }];
+			let receipt = Router::route_fee(&PAYER, 999, cuts).unwrap();
+
+			assert_eq!(
+				receipt,
+				FeeReceipt {
+					base_fee: 999,
+					transfers: vec![(CREATOR, 249)],
+					burned: 0,
+					deposited: 0
+				}
+			);
+			assert_eq!(free_balance(PAYER), 751);
+			assert_eq!(free_balance(CREATOR), 249);
+		});
+	}
+
+	#[test]
+	fn zero_amount_cuts_are_skipped() {
+		new_test_ext(1_000).execute_with(|| {
+			let cuts = vec![
+				FeeCut::Percentage { account: CREATOR, percentage: 10 },
+				FeeCut::Burn(50),
+				FeeCut::Chain(vec![(AFFILIATOR, 0)]),
+			];
+			let receipt = Router::route_fee(&PAYER, 9, cuts).unwrap();
+
+			assert_eq!(
+				receipt,
+				FeeReceipt { base_fee: 9, transfers: vec![], burned: 4, deposited: 0 }
+			);
+			assert_eq!(free_balance(PAYER), 996);
+			assert_eq!(free_balance(CREATOR), 0);
+		});
+	}
+
+	#[test]
+	fn burn_cut_reduces_issuance() {
+		new_test_ext(1_000).execute_with(|| {
+			let issuance = Balances::total_issuance();
+			let receipt = Router::route_fee(&PAYER, 300, vec![FeeCut::Burn(50)]).unwrap();
+
+			assert_eq!(
+				receipt,
+				FeeReceipt { base_fee: 300, transfers: vec![], burned: 150, deposited: 0 }
+			);
+			assert_eq!(free_balance(PAYER), 850);
+			assert_eq!(Balances::total_issuance(), issuance - 150);
+		});
+	}
+
+	#[test]
+	fn treasury_cut_deposits_the_remaining_fee() {
+		new_test_ext(1_000).execute_with(|| {
+			let issuance = Balances::total_issuance();
+			let cuts = vec![FeeCut::Burn(10), FeeCut::Treasury(TREASURY), FeeCut::Burn(50)];
+			let receipt = Router::route_fee(&PAYER, 500, cuts).unwrap();
+
+			// nothing is left for cuts following the treasury
+			assert_eq!(
+				receipt,
+				FeeReceipt { base_fee: 500, transfers: vec![], burned: 50, deposited: 450 }
+			);
+			assert_eq!(free_balance(PAYER), 500);
+			assert_eq!(free_balance(TREASURY), 450);
+			assert_eq!(Balances::total_issuance(), issuance - 50);
+		});
+	}
+
+	#[test]
+	fn treasury_cut_fails_below_the_existential_deposit() {
+		MockExistentialDeposit::set(10);
+		new_test_ext(1_000).execute_with(|| {
+			assert_noop!(
+				Router::route_fee(&PAYER, 9, vec![FeeCut::Treasury(TREASURY)]),
+				TokenError::BelowMinimum
+			);
+			assert_eq!(free_balance(PAYER), 1_000);
+			assert_eq!(free_balance(TREASURY), 0);
+		});
+	}
+
+	#[test]
+	fn chain_cut_applies_each_percentage_to_the_same_stage() {
+		new_test_ext(1_000).execute_with(|| {
+			let cuts = vec![
+				FeeCut::Percentage { account: CREATOR, percentage: 20 },
+				FeeCut::Chain(vec![(AFFILIATOR, 10), (SUB_AFFILIATOR, 5)]),
+				FeeCut::Treasury(TREASURY),
+			];
+			let receipt = Router::route_fee(&PAYER, 500, cuts).unwrap();
+
+			// the chain is computed on the 400 left by the creator cut
+			assert_eq!(
+				receipt,
+				FeeReceipt {
+					base_fee: 500,
+					transfers: vec![(CREATOR, 100), (AFFILIATOR, 40), (SUB_AFFILIATOR, 20)],
+					burned: 0,
+					deposited: 340,
+				}
+			);
+			assert_eq!(free_balance(PAYER), 500);
+			assert_eq!(free_balance(CREATOR), 100);
+			assert_eq!(free_balance(AFFILIATOR), 40);
+			assert_eq!(free_balance(SUB_AFFILIATOR), 20);
+			assert_eq!(free_balance(TREASURY), 340);
+		});
+	}
+
+	#[test]
+	fn uncharged_remainder_stays_with_payer() {
+		new_test_ext(1_000).execute_with(|| {
+			let cuts = vec![FeeCut::Percentage { account: CREATOR, percentage: 40 }];
+			let receipt = Router::route_fee(&PAYER, 500, cuts).unwrap();
+
+			assert_eq!(receipt.transfers, vec![(CREATOR, 200)]);
+			assert_eq!(free_balance(PAYER), 800);
+		});
+	}
+
+	#[test]
+	fn chain_cuts_summing_over_100_percent_are_capped() {
+		new_test_ext(1_000).execute_with(|| {
+			let cuts = vec![
+				FeeCut::Chain(vec![(AFFILIATOR, 70), (SUB_AFFILIATOR, 60)]),
+				FeeCut::Treasury(TREASURY),
+			];
+			let receipt = Router::route_fee(&PAYER, 100, cuts).unwrap();
+
+			// the last entry only gets what is left of the fee, nothing remains for the treasury
+			assert_eq!(
+				receipt,
+				FeeReceipt {
+					base_fee: 100,
+					transfers: vec![(AFFILIATOR, 70), (SUB_AFFILIATOR, 30)],
+					burned: 0,
+					deposited: 0,
+				}
+			);
+			assert_eq!(free_balance(PAYER), 900);
+			assert_eq!(free_balance(TREASURY), 0);
+		});
+	}
+
+	#[test]
+	fn percentages_over_100_never_charge_more_than_the_fee() {
+		new_test_ext(1_000).execute_with(|| {
+			let cuts =
+				vec![FeeCut::Burn(150), FeeCut::Percentage { account: CREATOR, percentage: 100 }];
+			let receipt = Router::route_fee(&PAYER, 100, cuts).unwrap();
+
+			assert_eq!(
+				receipt,
+				FeeReceipt { base_fee: 100, transfers: vec![], burned: 100, deposited: 0 }
+			);
+			assert_eq!(free_balance(PAYER), 900);
+		});
+	}
+
+	#[test]
+	fn route_fee_fails_without_funds() {
+		new_test_ext(100).execute_with(|| {
+			let cuts = vec![FeeCut::Percentage { account: CREATOR, percentage: 100 }];
+			assert_noop!(Router::route_fee(&PAYER, 200, cuts), ArithmeticError::Underflow);
+
+			assert_noop!(
+				Router::route_fee(&PAYER, 200, vec![FeeCut::Treasury(TREASURY)]),
+				pallet_balances::Error::<Test>::InsufficientBalance
+			);
+		});
+	}
+}
@@ -22,3 +22,4 @@
 
 pub mod account_manager;
 pub mod asset_manager;
+pub mod fee_router;